@@ -40,4 +40,137 @@ pub trait BaseMap {
     fn get_pathing_distance(&self, _idx1: usize, _idx2: usize) -> f32 {
         1.0
     }
+
+    /// Return how strongly a tile attenuates light passing through it, from 0.0 (clear) to
+    /// 1.0 (fully blocking). Used by lighting-aware field-of-view; override it for partial
+    /// occluders such as smoke or foliage. Default implementation derives it from `is_opaque`,
+    /// so you don't have to implement it when not using lighting.
+    fn get_opacity(&self, idx: usize) -> f32 {
+        if self.is_opaque(idx) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    /// Return a counter that you bump whenever the map changes in a way that affects pathing.
+    /// Cached results (such as `PathCache` in bracket-pathfinding) compare it to decide whether
+    /// stored paths are still valid. Default implementation returns 0, meaning the map never
+    /// changes, so you don't have to implement it when not caching.
+    fn get_generation(&self) -> u64 {
+        0
+    }
+}
+
+/// The context-aware counterpart of `BaseMap`, for maps where passability or costs depend on
+/// who is moving: a flying creature ignores chasms, a swimmer prefers water, a door-opener
+/// treats doors as exits. Implement this once with your movement-profile type as `Ctx`, then
+/// bind a profile with `with_context` to get an ordinary `BaseMap`/`Algorithm2D` that the
+/// path-finding and field-of-view functions accept unchanged - no map cloning required.
+pub trait BaseMapFor<Ctx> {
+    /// As `BaseMap::is_opaque`, for one movement context.
+    fn is_opaque_for(&self, _idx: usize, _ctx: &Ctx) -> bool {
+        true
+    }
+
+    /// As `BaseMap::get_available_exits`, for one movement context.
+    fn get_available_exits_for(&self, _idx: usize, _ctx: &Ctx) -> SmallVec<[(usize, f32); 10]> {
+        SmallVec::new()
+    }
+
+    /// As `BaseMap::get_pathing_distance`, for one movement context.
+    fn get_pathing_distance_for(&self, _idx1: usize, _idx2: usize, _ctx: &Ctx) -> f32 {
+        1.0
+    }
+
+    /// As `BaseMap::get_generation`, for one movement context.
+    fn get_generation_for(&self, _ctx: &Ctx) -> u64 {
+        0
+    }
+
+    /// Borrow the map together with one movement context, yielding a `BaseMap` view of it.
+    fn with_context<'a>(&'a self, ctx: &'a Ctx) -> WithContext<'a, Self, Ctx> {
+        WithContext { map: self, ctx }
+    }
+}
+
+/// A `BaseMapFor` map bound to one movement context. Borrows both, and implements `BaseMap`
+/// (and `Algorithm2D`, when the map has one) by delegating with the context attached.
+pub struct WithContext<'a, M: ?Sized, Ctx> {
+    map: &'a M,
+    ctx: &'a Ctx,
+}
+
+impl<M: BaseMapFor<Ctx> + ?Sized, Ctx> BaseMap for WithContext<'_, M, Ctx> {
+    fn is_opaque(&self, idx: usize) -> bool {
+        self.map.is_opaque_for(idx, self.ctx)
+    }
+
+    fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+        self.map.get_available_exits_for(idx, self.ctx)
+    }
+
+    fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+        self.map.get_pathing_distance_for(idx1, idx2, self.ctx)
+    }
+
+    fn get_generation(&self) -> u64 {
+        self.map.get_generation_for(self.ctx)
+    }
+}
+
+impl<M, Ctx> crate::prelude::Algorithm2D for WithContext<'_, M, Ctx>
+where
+    M: BaseMapFor<Ctx> + crate::prelude::Algorithm2D + ?Sized,
+{
+    fn point2d_to_index(&self, pt: bracket_geometry::prelude::Point) -> usize {
+        self.map.point2d_to_index(pt)
+    }
+
+    fn index_to_point2d(&self, idx: usize) -> bracket_geometry::prelude::Point {
+        self.map.index_to_point2d(idx)
+    }
+
+    fn dimensions(&self) -> bracket_geometry::prelude::Point {
+        self.map.dimensions()
+    }
+
+    fn in_bounds(&self, pos: bracket_geometry::prelude::Point) -> bool {
+        self.map.in_bounds(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{BaseMap, BaseMapFor, SmallVec};
+
+    enum Profile {
+        Walking,
+        Flying,
+    }
+
+    // A 3-tile strip whose middle tile is a chasm.
+    struct Chasm;
+
+    impl BaseMapFor<Profile> for Chasm {
+        fn get_available_exits_for(&self, idx: usize, ctx: &Profile) -> SmallVec<[(usize, f32); 10]> {
+            let mut exits = SmallVec::new();
+            for next in [idx.wrapping_sub(1), idx + 1] {
+                let crosses_chasm = next == 1 && matches!(ctx, Profile::Walking);
+                if next < 3 && !crosses_chasm {
+                    exits.push((next, 1.0));
+                }
+            }
+            exits
+        }
+    }
+
+    #[test]
+    fn contexts_see_different_exits() {
+        let map = Chasm;
+        let walking = map.with_context(&Profile::Walking);
+        let flying = map.with_context(&Profile::Flying);
+        assert!(walking.get_available_exits(0).is_empty());
+        assert_eq!(flying.get_available_exits(0).len(), 1);
+    }
 }
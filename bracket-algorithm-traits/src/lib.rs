@@ -37,7 +37,7 @@ pub mod prelude {
     pub use crate::algorithm3d::Algorithm3D;
 
     /// `BaseMap` support
-    pub use crate::basemap::BaseMap;
+    pub use crate::basemap::{BaseMap, BaseMapFor, WithContext};
 
     /// Since we use `SmallVec`, it's only polite to export it so you don't have to have multiple copies.
     pub use smallvec::{smallvec, SmallVec};
@@ -0,0 +1,307 @@
+//! Bevy plugin for bracket-terminal. `BracketBevyPlugin` replaces Bevy's
+//! runner with the bracket main loop: every frame it copies the terminal's
+//! input state into [`BTermInput`], runs the Bevy schedule, then drains the
+//! draw commands queued on [`BracketContext`] into the consoles. ECS games get
+//! bracket consoles as their renderer without fighting two event loops.
+//!
+//! ```no_run
+//! use bevy_app::App;
+//! use bevy_ecs::prelude::*;
+//! use bracket_bevy::{BracketBevyPlugin, BracketContext, ConsoleDraw};
+//!
+//! fn draw(ctx: Res<BracketContext>) {
+//!     ctx.cls();
+//!     ctx.print(1, 1, "Hello Bevy World");
+//! }
+//!
+//! App::new()
+//!     .add_plugin(BracketBevyPlugin::default())
+//!     .add_system_to_stage(ConsoleDraw, draw)
+//!     .run();
+//! ```
+
+use bevy_app::{App, CoreStage, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_ecs::schedule::{StageLabel, SystemStage};
+use bracket_terminal::prelude::{
+    BTerm, BTermBuilder, FontCharType, GameState, Point, VirtualKeyCode, RGB,
+};
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Stage that runs after `CoreStage::Update`, intended for console-drawing
+/// systems. Drawing is buffered, so ordering between draw systems only matters
+/// when they paint the same cells.
+#[derive(StageLabel, Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct ConsoleDraw;
+
+/// Drives the bracket main loop from a Bevy `App`. Construct it with a
+/// closure producing the `BTermBuilder` for your console stack - a closure
+/// rather than a finished builder because `Plugin` must be `Send` and sprite
+/// sheets hold `Rc` fonts. The window opens when the app's `run()` is called.
+pub struct BracketBevyPlugin {
+    builder: Box<dyn Fn() -> BTermBuilder + Send + Sync>,
+}
+
+impl BracketBevyPlugin {
+    pub fn new<F: Fn() -> BTermBuilder + Send + Sync + 'static>(builder: F) -> Self {
+        Self {
+            builder: Box::new(builder),
+        }
+    }
+}
+
+impl Default for BracketBevyPlugin {
+    fn default() -> Self {
+        Self::new(BTermBuilder::simple80x50)
+    }
+}
+
+impl Plugin for BracketBevyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<BTermInput>()
+            .init_resource::<BracketFrame>()
+            .init_resource::<BracketContext>()
+            .add_stage_after(CoreStage::Update, ConsoleDraw, SystemStage::parallel());
+
+        let builder = (self.builder)();
+        let builder = Mutex::new(Some(builder));
+        app.set_runner(move |app| {
+            let builder = builder
+                .lock()
+                .take()
+                .expect("BracketBevyPlugin runner started twice");
+            let bterm = builder.build().expect("Failed to build the terminal");
+            bracket_terminal::prelude::main_loop(bterm, BevyState { app })
+                .expect("Main loop failed");
+        });
+    }
+}
+
+/// A snapshot of the terminal's input state, refreshed before every schedule
+/// run. Mirrors the fields `GameState::tick` reads from `BTerm`.
+#[derive(Resource, Default)]
+pub struct BTermInput {
+    pub key: Option<VirtualKeyCode>,
+    pub mouse_pos: (i32, i32),
+    pub left_click: bool,
+    pub shift: bool,
+    pub control: bool,
+    pub alt: bool,
+}
+
+impl BTermInput {
+    pub fn mouse_point(&self) -> Point {
+        Point::new(self.mouse_pos.0, self.mouse_pos.1)
+    }
+}
+
+/// Frame timing from the terminal, refreshed before every schedule run.
+#[derive(Resource, Default)]
+pub struct BracketFrame {
+    pub fps: f32,
+    pub frame_time_ms: f32,
+}
+
+/// Console access for systems. Draw calls are buffered (so read-only access
+/// suffices and draw systems can run in parallel) and replayed onto the real
+/// consoles after the schedule finishes.
+#[derive(Resource, Default)]
+pub struct BracketContext {
+    commands: Mutex<Vec<DrawCommand>>,
+    quit_requested: AtomicBool,
+}
+
+enum DrawCommand {
+    SetTarget(usize),
+    Cls,
+    Print {
+        x: i32,
+        y: i32,
+        text: String,
+    },
+    PrintColor {
+        x: i32,
+        y: i32,
+        fg: RGB,
+        bg: RGB,
+        text: String,
+    },
+    PrintCentered {
+        y: i32,
+        text: String,
+    },
+    Set {
+        x: i32,
+        y: i32,
+        fg: RGB,
+        bg: RGB,
+        glyph: FontCharType,
+    },
+    SetBg {
+        x: i32,
+        y: i32,
+        bg: RGB,
+    },
+    DrawBox {
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        fg: RGB,
+        bg: RGB,
+    },
+}
+
+impl BracketContext {
+    fn push(&self, command: DrawCommand) {
+        self.commands.lock().push(command);
+    }
+
+    /// Directs subsequent draw calls at the numbered console layer.
+    pub fn set_active_console(&self, id: usize) {
+        self.push(DrawCommand::SetTarget(id));
+    }
+
+    /// Clears the active console.
+    pub fn cls(&self) {
+        self.push(DrawCommand::Cls);
+    }
+
+    /// Prints text in white-on-black at the given location.
+    pub fn print<S: ToString>(&self, x: i32, y: i32, text: S) {
+        self.push(DrawCommand::Print {
+            x,
+            y,
+            text: text.to_string(),
+        });
+    }
+
+    /// Prints text in the given colors at the given location.
+    pub fn print_color<S: ToString>(&self, x: i32, y: i32, fg: RGB, bg: RGB, text: S) {
+        self.push(DrawCommand::PrintColor {
+            x,
+            y,
+            fg,
+            bg,
+            text: text.to_string(),
+        });
+    }
+
+    /// Prints text centered horizontally on the given line.
+    pub fn print_centered<S: ToString>(&self, y: i32, text: S) {
+        self.push(DrawCommand::PrintCentered {
+            y,
+            text: text.to_string(),
+        });
+    }
+
+    /// Sets a single cell.
+    pub fn set(&self, x: i32, y: i32, fg: RGB, bg: RGB, glyph: FontCharType) {
+        self.push(DrawCommand::Set { x, y, fg, bg, glyph });
+    }
+
+    /// Sets a single cell's background.
+    pub fn set_bg(&self, x: i32, y: i32, bg: RGB) {
+        self.push(DrawCommand::SetBg { x, y, bg });
+    }
+
+    /// Draws a single-line box with a filled interior.
+    pub fn draw_box(&self, x: i32, y: i32, width: i32, height: i32, fg: RGB, bg: RGB) {
+        self.push(DrawCommand::DrawBox {
+            x,
+            y,
+            width,
+            height,
+            fg,
+            bg,
+        });
+    }
+
+    /// Asks the main loop to exit after this frame.
+    pub fn quit(&self) {
+        self.quit_requested.store(true, Ordering::Relaxed);
+    }
+
+    fn flush(&self, bterm: &mut BTerm) {
+        for command in self.commands.lock().drain(..) {
+            match command {
+                DrawCommand::SetTarget(id) => bterm.set_active_console(id),
+                DrawCommand::Cls => bterm.cls(),
+                DrawCommand::Print { x, y, text } => bterm.print(x, y, text),
+                DrawCommand::PrintColor { x, y, fg, bg, text } => {
+                    bterm.print_color(x, y, fg, bg, text)
+                }
+                DrawCommand::PrintCentered { y, text } => bterm.print_centered(y, text),
+                DrawCommand::Set { x, y, fg, bg, glyph } => bterm.set(x, y, fg, bg, glyph),
+                DrawCommand::SetBg { x, y, bg } => bterm.set_bg(x, y, bg),
+                DrawCommand::DrawBox {
+                    x,
+                    y,
+                    width,
+                    height,
+                    fg,
+                    bg,
+                } => bterm.draw_box(x, y, width, height, fg, bg),
+            }
+        }
+    }
+}
+
+/// The `GameState` bridging bracket's tick into Bevy's schedule.
+struct BevyState {
+    app: App,
+}
+
+impl GameState for BevyState {
+    fn tick(&mut self, ctx: &mut BTerm) {
+        {
+            let mut input = self.app.world.resource_mut::<BTermInput>();
+            input.key = ctx.key;
+            input.mouse_pos = ctx.mouse_pos;
+            input.left_click = ctx.left_click;
+            input.shift = ctx.shift;
+            input.control = ctx.control;
+            input.alt = ctx.alt;
+        }
+        {
+            let mut frame = self.app.world.resource_mut::<BracketFrame>();
+            frame.fps = ctx.fps;
+            frame.frame_time_ms = ctx.frame_time_ms;
+        }
+
+        self.app.update();
+
+        let context = self.app.world.resource::<BracketContext>();
+        context.flush(ctx);
+        if context.quit_requested.swap(false, Ordering::Relaxed) {
+            ctx.quit();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draw_commands_buffer_in_order() {
+        let context = BracketContext::default();
+        context.cls();
+        context.print(1, 1, "Hello");
+        context.set_active_console(1);
+        let commands = context.commands.lock();
+        assert_eq!(commands.len(), 3);
+        assert!(matches!(commands[0], DrawCommand::Cls));
+        assert!(matches!(commands[2], DrawCommand::SetTarget(1)));
+    }
+
+    #[test]
+    fn quit_is_latched_until_read(){
+        let context = BracketContext::default();
+        assert!(!context.quit_requested.load(Ordering::Relaxed));
+        context.quit();
+        assert!(context.quit_requested.swap(false, Ordering::Relaxed));
+        assert!(!context.quit_requested.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,113 @@
+use crate::oklab::{linear_to_srgb, srgb_to_linear};
+use crate::prelude::{RGB, RGBA};
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+/// A form of color vision deficiency to simulate. The three dichromacies
+/// cover the large majority of colorblind players.
+pub enum ColorblindMode {
+    /// Missing red cones; red/green confusion, reds appear dark.
+    Protanopia,
+    /// Missing green cones; the most common red/green confusion.
+    Deuteranopia,
+    /// Missing blue cones; blue/yellow confusion (rare).
+    Tritanopia,
+}
+
+impl ColorblindMode {
+    /// The simulation matrix for this mode, applied to linear RGB
+    /// (row-major; Machado, Oliveira and Fernandes 2009, full severity).
+    /// Exposed so renderers can run the same transform on the GPU.
+    #[must_use]
+    pub const fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+}
+
+impl RGB {
+    /// Simulates how this color appears with the given color vision
+    /// deficiency. Useful for previewing game palettes, or as a fallback
+    /// when the renderer can't run the whole-frame filter.
+    #[must_use]
+    pub fn simulate_colorblind(&self, mode: ColorblindMode) -> Self {
+        let m = mode.matrix();
+        let r = srgb_to_linear(self.r);
+        let g = srgb_to_linear(self.g);
+        let b = srgb_to_linear(self.b);
+        Self {
+            r: linear_to_srgb((m[0][0] * r + m[0][1] * g + m[0][2] * b).clamp(0.0, 1.0)),
+            g: linear_to_srgb((m[1][0] * r + m[1][1] * g + m[1][2] * b).clamp(0.0, 1.0)),
+            b: linear_to_srgb((m[2][0] * r + m[2][1] * g + m[2][2] * b).clamp(0.0, 1.0)),
+        }
+    }
+}
+
+impl RGBA {
+    /// Simulates how this color appears with the given color vision
+    /// deficiency, leaving alpha untouched.
+    #[must_use]
+    pub fn simulate_colorblind(&self, mode: ColorblindMode) -> Self {
+        self.to_rgb().simulate_colorblind(mode).to_rgba(self.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorblindMode;
+    use crate::prelude::{RGB, RGBA};
+
+    #[test]
+    // Tests that grays pass through every mode nearly unchanged - each
+    // matrix's rows sum to one.
+    fn grays_are_stable() {
+        for mode in &[
+            ColorblindMode::Protanopia,
+            ColorblindMode::Deuteranopia,
+            ColorblindMode::Tritanopia,
+        ] {
+            let gray = RGB::from_f32(0.5, 0.5, 0.5);
+            let seen = gray.simulate_colorblind(*mode);
+            assert!(f32::abs(seen.r - 0.5) < 0.01);
+            assert!(f32::abs(seen.g - 0.5) < 0.01);
+            assert!(f32::abs(seen.b - 0.5) < 0.01);
+        }
+    }
+
+    #[test]
+    // Tests that red/green confusion collapses red and green together.
+    fn red_green_confusion() {
+        let red = RGB::from_f32(1.0, 0.0, 0.0).simulate_colorblind(ColorblindMode::Deuteranopia);
+        let green = RGB::from_f32(0.0, 1.0, 0.0).simulate_colorblind(ColorblindMode::Deuteranopia);
+        let distance = f32::abs(red.r - green.r) + f32::abs(red.g - green.g);
+        // Far closer together than the originals (distance 2.0).
+        assert!(distance < 0.8);
+        // Tritanopia keeps red and green distinct.
+        let red_t = RGB::from_f32(1.0, 0.0, 0.0).simulate_colorblind(ColorblindMode::Tritanopia);
+        let green_t = RGB::from_f32(0.0, 1.0, 0.0).simulate_colorblind(ColorblindMode::Tritanopia);
+        assert!(f32::abs(red_t.r - green_t.r) > 0.5);
+    }
+
+    #[test]
+    // Tests that the RGBA wrapper preserves alpha.
+    fn rgba_keeps_alpha() {
+        let color = RGBA::from_f32(1.0, 0.2, 0.2, 0.25);
+        let seen = color.simulate_colorblind(ColorblindMode::Protanopia);
+        assert!(f32::abs(seen.a - 0.25) < 1.0e-6);
+    }
+}
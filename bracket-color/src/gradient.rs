@@ -0,0 +1,187 @@
+use crate::prelude::RGB;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+/// The color space a [`Gradient`] interpolates through. RGB is the cheapest,
+/// HSV sweeps hue, `OKLab` reads as perceptually even.
+pub enum GradientSpace {
+    RGB,
+    HSV,
+    OKLab,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+/// A multi-stop color gradient: ordered color stops at positions 0..1,
+/// sampled with [`Gradient::at`]. The workhorse for heightmap shading, health
+/// bars and lighting falloff. Ships with `heat`, `viridis` and `grayscale`
+/// presets.
+pub struct Gradient {
+    stops: Vec<(f32, RGB)>,
+    space: GradientSpace,
+}
+
+impl Gradient {
+    /// Constructs a gradient from `(position, color)` stops, interpolated in
+    /// RGB space; the stops are sorted by position for you.
+    #[must_use]
+    pub fn new(mut stops: Vec<(f32, RGB)>) -> Self {
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self {
+            stops,
+            space: GradientSpace::RGB,
+        }
+    }
+
+    /// Constructs a gradient with colors spaced evenly from 0 to 1
+    #[allow(clippy::cast_precision_loss)]
+    #[must_use]
+    pub fn evenly_spaced(colors: &[RGB]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        Self::new(
+            colors
+                .iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, *color))
+                .collect(),
+        )
+    }
+
+    /// Switches the space the gradient interpolates through
+    #[must_use]
+    pub fn with_space(mut self, space: GradientSpace) -> Self {
+        self.space = space;
+        self
+    }
+
+    /// Samples the gradient at `t` (0 to 1, clamped); positions outside the
+    /// first/last stop return that stop's color
+    #[must_use]
+    pub fn at(&self, t: f32) -> RGB {
+        match self.stops.as_slice() {
+            [] => RGB::new(),
+            [only] => only.1,
+            [first, ..] if t <= first.0 => first.1,
+            [.., last] if t >= last.0 => last.1,
+            stops => {
+                // The guards above put t strictly inside the stops, so a
+                // bracketing pair always exists.
+                let Some(&[start, end]) = stops.windows(2).find(|pair| t <= pair[1].0) else {
+                    return RGB::new();
+                };
+                let span = end.0 - start.0;
+                let percent = if span > 0.0 {
+                    (t - start.0) / span
+                } else {
+                    0.0
+                };
+                match self.space {
+                    GradientSpace::RGB => start.1.lerp(end.1, percent),
+                    GradientSpace::HSV => start.1.to_hsv().lerp(end.1.to_hsv(), percent).to_rgb(),
+                    GradientSpace::OKLab => start.1.lerp_oklab(end.1, percent),
+                }
+            }
+        }
+    }
+
+    /// Black through red and yellow to white - classic heat/fire ramp
+    #[must_use]
+    pub fn heat() -> Self {
+        Self::evenly_spaced(&[
+            RGB::from_u8(0, 0, 0),
+            RGB::from_u8(178, 0, 0),
+            RGB::from_u8(255, 160, 0),
+            RGB::from_u8(255, 255, 255),
+        ])
+        .with_space(GradientSpace::OKLab)
+    }
+
+    /// The familiar viridis ramp: dark purple through teal to yellow,
+    /// color-blind friendly and perceptually even
+    #[must_use]
+    pub fn viridis() -> Self {
+        Self::evenly_spaced(&[
+            RGB::from_u8(68, 1, 84),
+            RGB::from_u8(59, 82, 139),
+            RGB::from_u8(33, 145, 140),
+            RGB::from_u8(94, 201, 98),
+            RGB::from_u8(253, 231, 37),
+        ])
+        .with_space(GradientSpace::OKLab)
+    }
+
+    /// Plain black to white
+    #[must_use]
+    pub fn grayscale() -> Self {
+        Self::evenly_spaced(&[RGB::from_u8(0, 0, 0), RGB::from_u8(255, 255, 255)])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gradient, GradientSpace};
+    use crate::prelude::RGB;
+
+    #[test]
+    // Tests that sampling hits the stops exactly at their positions.
+    fn gradient_endpoints() {
+        let gradient = Gradient::new(vec![
+            (0.0, RGB::from_u8(255, 0, 0)),
+            (1.0, RGB::from_u8(0, 0, 255)),
+        ]);
+        assert!(gradient.at(0.0) == RGB::from_u8(255, 0, 0));
+        assert!(gradient.at(1.0) == RGB::from_u8(0, 0, 255));
+        // Out-of-range samples clamp to the end stops.
+        assert!(gradient.at(-1.0) == RGB::from_u8(255, 0, 0));
+        assert!(gradient.at(2.0) == RGB::from_u8(0, 0, 255));
+    }
+
+    #[test]
+    // Tests that stops sort by position and the midpoint lands between them.
+    fn gradient_sorts_and_blends() {
+        let gradient = Gradient::new(vec![
+            (1.0, RGB::from_f32(1.0, 1.0, 1.0)),
+            (0.0, RGB::from_f32(0.0, 0.0, 0.0)),
+        ]);
+        let mid = gradient.at(0.5);
+        assert!(f32::abs(mid.r - 0.5) < 1.0e-6);
+        assert!(f32::abs(mid.g - 0.5) < 1.0e-6);
+        assert!(f32::abs(mid.b - 0.5) < 1.0e-6);
+    }
+
+    #[test]
+    // Tests that the interpolation space changes the midpoint.
+    fn gradient_spaces_differ() {
+        let stops = vec![
+            (0.0, RGB::from_u8(255, 0, 0)),
+            (1.0, RGB::from_u8(0, 0, 255)),
+        ];
+        let rgb_mid = Gradient::new(stops.clone()).at(0.5);
+        let oklab_mid = Gradient::new(stops.clone())
+            .with_space(GradientSpace::OKLab)
+            .at(0.5);
+        let hsv_mid = Gradient::new(stops).with_space(GradientSpace::HSV).at(0.5);
+        assert!(rgb_mid != oklab_mid);
+        assert!(rgb_mid != hsv_mid);
+    }
+
+    #[test]
+    // Tests the presets run dark-to-light across their range.
+    fn presets_brighten() {
+        for gradient in &[Gradient::heat(), Gradient::viridis(), Gradient::grayscale()] {
+            let dark = gradient.at(0.0).to_oklab().l;
+            let light = gradient.at(1.0).to_oklab().l;
+            assert!(light > dark);
+        }
+    }
+
+    #[test]
+    // Tests degenerate gradients stay well-behaved.
+    fn degenerate_gradients() {
+        let empty = Gradient::new(Vec::new());
+        assert!(empty.at(0.5) == RGB::new());
+        let single = Gradient::new(vec![(0.5, RGB::from_u8(10, 20, 30))]);
+        assert!(single.at(0.0) == RGB::from_u8(10, 20, 30));
+        assert!(single.at(1.0) == RGB::from_u8(10, 20, 30));
+    }
+}
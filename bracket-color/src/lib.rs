@@ -32,15 +32,23 @@ extern crate lazy_static;
 
 /// Import color pair support
 mod color_pair;
+/// Import colorblindness simulation support
+mod colorblind;
+/// Import gradient/color ramp support
+mod gradient;
 /// Import HSV color support
 mod hsv;
 /// Import Lerp as an iterator
 mod lerpit;
 /// Import library of named colors
 mod named;
+/// Import OKLab/OKLCH color support
+mod oklab;
 /// Import Palette support
 #[cfg(feature = "palette")]
 mod palette;
+/// Import palette file (.gpl/.hex/.pal) loaders
+mod palette_import;
 /// Import RGB color support
 mod rgb;
 /// Import RGBA color support
@@ -52,11 +60,15 @@ mod xpcolor;
 /// Exports the color functions/types in the `prelude` namespace.
 pub mod prelude {
     pub use crate::color_pair::*;
+    pub use crate::colorblind::*;
+    pub use crate::gradient::*;
     pub use crate::hsv::*;
     pub use crate::lerpit::*;
     pub use crate::named::*;
+    pub use crate::oklab::*;
     #[cfg(feature = "palette")]
     pub use crate::palette::*;
+    pub use crate::palette_import::*;
     pub use crate::rgb::*;
     pub use crate::rgba::*;
     #[cfg(feature = "rex")]
@@ -0,0 +1,344 @@
+use crate::prelude::{RGB, RGBA};
+use std::convert::From;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Copy, Clone, Default, Debug)]
+/// Represents a color in the perceptual `OKLab` space: `l` is lightness (0..1),
+/// `a` runs green-red and `b` blue-yellow (both roughly -0.4..0.4). Linear
+/// interpolation here stays perceptually uniform, avoiding the muddy
+/// midpoints RGB and HSV lerps produce.
+pub struct OKLab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Copy, Clone, Default, Debug)]
+/// The cylindrical form of [`OKLab`]: `l` is lightness (0..1), `c` chroma and
+/// `h` the hue angle in radians. Handy when a gradient should hold lightness
+/// or chroma steady while the hue sweeps.
+pub struct OKLCH {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+/// Support conversion from RGB
+impl From<RGB> for OKLab {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_oklab()
+    }
+}
+
+/// Support conversion from RGBA
+impl From<RGBA> for OKLab {
+    fn from(rgba: RGBA) -> Self {
+        rgba.to_rgb().to_oklab()
+    }
+}
+
+/// Support conversion from `OKLab`
+impl From<OKLab> for RGB {
+    fn from(lab: OKLab) -> Self {
+        lab.to_rgb()
+    }
+}
+
+/// Support conversion from RGB
+impl From<RGB> for OKLCH {
+    fn from(rgb: RGB) -> Self {
+        rgb.to_oklch()
+    }
+}
+
+/// Support conversion from OKLCH
+impl From<OKLCH> for RGB {
+    fn from(lch: OKLCH) -> Self {
+        lch.to_rgb()
+    }
+}
+
+// Undoes the sRGB transfer curve; OKLab is defined over linear light.
+pub(crate) fn srgb_to_linear(component: f32) -> f32 {
+    if component <= 0.040_45 {
+        component / 12.92
+    } else {
+        ((component + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+// Re-applies the sRGB transfer curve after conversion back from OKLab.
+pub(crate) fn linear_to_srgb(component: f32) -> f32 {
+    if component <= 0.003_130_8 {
+        component * 12.92
+    } else {
+        1.055 * component.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+impl OKLab {
+    /// Constructs a new, zeroed (black) `OKLab` color.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            l: 0.0,
+            a: 0.0,
+            b: 0.0,
+        }
+    }
+
+    /// Constructs a new `OKLab` color, from 3 32-bit floats
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Converts an `OKLab` color to an RGB triple (Björn Ottosson's reference
+    /// transform), clamped to the sRGB gamut by the RGB constructor
+    #[must_use]
+    pub fn to_rgb(&self) -> RGB {
+        let l_cube = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m_cube = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s_cube = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+
+        let l = l_cube * l_cube * l_cube;
+        let m = m_cube * m_cube * m_cube;
+        let s = s_cube * s_cube * s_cube;
+
+        RGB::from_f32(
+            linear_to_srgb(4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s),
+            linear_to_srgb(-1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s),
+            linear_to_srgb(-0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s),
+        )
+    }
+
+    /// Converts to an RGBA value with a specified alpha level
+    #[inline]
+    #[must_use]
+    pub fn to_rgba(&self, alpha: f32) -> RGBA {
+        self.to_rgb().to_rgba(alpha)
+    }
+
+    /// Converts to the cylindrical OKLCH form
+    #[inline]
+    #[must_use]
+    pub fn to_oklch(&self) -> OKLCH {
+        OKLCH {
+            l: self.l,
+            c: self.a.hypot(self.b),
+            h: self.b.atan2(self.a),
+        }
+    }
+
+    /// Lerps by a specified percentage (from 0 to 1) between this color and
+    /// another; straight lines in `OKLab` read as perceptually even gradients
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, color: Self, percent: f32) -> Self {
+        let range = (color.l - self.l, color.a - self.a, color.b - self.b);
+        Self {
+            l: self.l + range.0 * percent,
+            a: self.a + range.1 * percent,
+            b: self.b + range.2 * percent,
+        }
+    }
+}
+
+impl OKLCH {
+    /// Constructs a new, zeroed (black) OKLCH color.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            l: 0.0,
+            c: 0.0,
+            h: 0.0,
+        }
+    }
+
+    /// Constructs a new OKLCH color, from 3 32-bit floats
+    #[inline]
+    #[must_use]
+    pub const fn from_f32(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Converts back to the rectangular `OKLab` form
+    #[inline]
+    #[must_use]
+    pub fn to_oklab(&self) -> OKLab {
+        OKLab {
+            l: self.l,
+            a: self.c * self.h.cos(),
+            b: self.c * self.h.sin(),
+        }
+    }
+
+    /// Converts an OKLCH color to an RGB triple
+    #[inline]
+    #[must_use]
+    pub fn to_rgb(&self) -> RGB {
+        self.to_oklab().to_rgb()
+    }
+
+    /// Converts to an RGBA value with a specified alpha level
+    #[inline]
+    #[must_use]
+    pub fn to_rgba(&self, alpha: f32) -> RGBA {
+        self.to_rgb().to_rgba(alpha)
+    }
+
+    /// Lerps by a specified percentage (from 0 to 1) between this color and
+    /// another, taking the shortest way around the hue circle
+    #[inline]
+    #[must_use]
+    pub fn lerp(&self, color: Self, percent: f32) -> Self {
+        let mut hue_range = color.h - self.h;
+        if hue_range > std::f32::consts::PI {
+            hue_range -= std::f32::consts::TAU;
+        } else if hue_range < -std::f32::consts::PI {
+            hue_range += std::f32::consts::TAU;
+        }
+        Self {
+            l: self.l + (color.l - self.l) * percent,
+            c: self.c + (color.c - self.c) * percent,
+            h: self.h + hue_range * percent,
+        }
+    }
+}
+
+impl RGB {
+    /// Converts an RGB triple to an `OKLab` color (Björn Ottosson's reference
+    /// transform)
+    #[allow(clippy::many_single_char_names)]
+    #[must_use]
+    pub fn to_oklab(&self) -> OKLab {
+        let r = srgb_to_linear(self.r);
+        let g = srgb_to_linear(self.g);
+        let b = srgb_to_linear(self.b);
+
+        let l = (0.412_221_46 * r + 0.536_332_54 * g + 0.051_445_995 * b).cbrt();
+        let m = (0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b).cbrt();
+        let s = (0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b).cbrt();
+
+        OKLab {
+            l: 0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            a: 1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            b: 0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        }
+    }
+
+    /// Converts an RGB triple to an OKLCH color
+    #[inline]
+    #[must_use]
+    pub fn to_oklch(&self) -> OKLCH {
+        self.to_oklab().to_oklch()
+    }
+
+    /// Lerps by a specified percentage (from 0 to 1) between this color and
+    /// another, through `OKLab` space - perceptually uniform, where a plain
+    /// `lerp` passes through muddy midpoints
+    #[inline]
+    #[must_use]
+    pub fn lerp_oklab(&self, color: Self, percent: f32) -> Self {
+        self.to_oklab().lerp(color.to_oklab(), percent).to_rgb()
+    }
+}
+
+impl RGBA {
+    /// Converts an RGBA to an `OKLab` color; the alpha channel is dropped
+    #[inline]
+    #[must_use]
+    pub fn to_oklab(&self) -> OKLab {
+        self.to_rgb().to_oklab()
+    }
+
+    /// Lerps by a specified percentage (from 0 to 1) between this color and
+    /// another, through `OKLab` space, lerping alpha linearly alongside
+    #[inline]
+    #[must_use]
+    pub fn lerp_oklab(&self, color: Self, percent: f32) -> Self {
+        let lab = self.to_rgb().lerp_oklab(color.to_rgb(), percent);
+        lab.to_rgba(self.a + (color.a - self.a) * percent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    // Tests that we make an OKLab color at defaults and it is black.
+    fn make_oklab_minimal() {
+        let black = OKLab::new();
+        assert!(black.l < std::f32::EPSILON);
+        assert!(black.a.abs() < std::f32::EPSILON);
+        assert!(black.b.abs() < std::f32::EPSILON);
+    }
+
+    #[test]
+    // White sits at the top of the lightness axis, with no chroma.
+    fn convert_white_to_oklab() {
+        let white = RGB::from_f32(1.0, 1.0, 1.0).to_oklab();
+        assert!(f32::abs(white.l - 1.0) < 1.0e-3);
+        assert!(white.a.abs() < 1.0e-3);
+        assert!(white.b.abs() < 1.0e-3);
+    }
+
+    #[test]
+    // Round trips through OKLab and OKLCH land back on the original color.
+    fn oklab_round_trip() {
+        for color in &[
+            RGB::from_u8(255, 0, 0),
+            RGB::from_u8(0, 128, 255),
+            RGB::from_u8(40, 200, 90),
+            RGB::from_u8(128, 128, 128),
+        ] {
+            let via_lab = color.to_oklab().to_rgb();
+            assert!(f32::abs(via_lab.r - color.r) < 1.0e-2);
+            assert!(f32::abs(via_lab.g - color.g) < 1.0e-2);
+            assert!(f32::abs(via_lab.b - color.b) < 1.0e-2);
+
+            let via_lch = color.to_oklch().to_rgb();
+            assert!(f32::abs(via_lch.r - color.r) < 1.0e-2);
+            assert!(f32::abs(via_lch.g - color.g) < 1.0e-2);
+            assert!(f32::abs(via_lch.b - color.b) < 1.0e-2);
+        }
+    }
+
+    #[test]
+    // Test the perceptual lerp endpoints and midpoint lightness.
+    fn test_lerp_oklab() {
+        let black = RGB::named(BLACK);
+        let white = RGB::named(WHITE);
+        let start = black.lerp_oklab(white, 0.0);
+        let end = black.lerp_oklab(white, 1.0);
+        assert!(f32::abs(start.r - black.r) < 1.0e-3);
+        assert!(f32::abs(end.r - white.r) < 1.0e-3);
+
+        // The OKLab midpoint of black and white is mid-lightness, which is
+        // noticeably brighter than the linear-RGB midpoint.
+        let mid = black.lerp_oklab(white, 0.5);
+        assert!(f32::abs(mid.to_oklab().l - 0.5) < 1.0e-2);
+    }
+
+    #[test]
+    // Test that the OKLCH lerp takes the short way around the hue circle.
+    fn test_lerp_oklch_hue_wrap() {
+        let a = OKLCH::from_f32(0.5, 0.1, 3.0);
+        let b = OKLCH::from_f32(0.5, 0.1, -3.0);
+        let mid = a.lerp(b, 0.5);
+        // Halfway between 3.0 and -3.0 radians the short way is near pi.
+        assert!(mid.h.abs() > 3.0);
+    }
+
+    #[test]
+    // Test the RGBA lerp carries alpha along.
+    fn test_lerp_oklab_alpha() {
+        let clear_red = RGBA::from_f32(1.0, 0.0, 0.0, 0.0);
+        let solid_blue = RGBA::from_f32(0.0, 0.0, 1.0, 1.0);
+        let mid = clear_red.lerp_oklab(solid_blue, 0.5);
+        assert!(f32::abs(mid.a - 0.5) < 1.0e-6);
+    }
+}
@@ -21,12 +21,83 @@ pub fn palette_color<S: ToString>(name: &S) -> Option<RGBA> {
     plock.get(&name.to_string()).copied()
 }
 
+/// Retrieve a palette color by name, or the provided default if the name
+/// isn't registered. Handy when reading color names from data files.
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn palette_color_or<S: ToString, COLOR: Into<RGBA>>(name: &S, default: COLOR) -> RGBA {
+    palette_color(name).unwrap_or_else(|| default.into())
+}
+
+/// Register a palette color inside a namespace; the entry is stored as
+/// `namespace/name` (e.g. `ui/highlight`).
+#[allow(clippy::needless_pass_by_value)]
+pub fn register_palette_color_in_namespace<NS: ToString, S: ToString, COLOR: Into<RGBA>>(
+    namespace: NS,
+    name: S,
+    color: COLOR,
+) {
+    PALETTE.lock().insert(
+        format!("{}/{}", namespace.to_string(), name.to_string()),
+        color.into(),
+    );
+}
+
+/// Retrieve `namespace/name` from the registry, falling back to the bare
+/// `name` if the namespace doesn't define it - so `ui/highlight` can override
+/// a global `highlight` without every namespace re-registering everything.
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn palette_color_in_namespace<NS: ToString, S: ToString>(
+    namespace: &NS,
+    name: &S,
+) -> Option<RGBA> {
+    let plock = PALETTE.lock();
+    plock
+        .get(&format!("{}/{}", namespace.to_string(), name.to_string()))
+        .or_else(|| plock.get(&name.to_string()))
+        .copied()
+}
+
+/// Lists every registered palette name, sorted alphabetically.
+#[must_use]
+#[allow(clippy::module_name_repetitions)]
+pub fn palette_names() -> Vec<String> {
+    let mut names: Vec<String> = PALETTE.lock().keys().cloned().collect();
+    names.sort();
+    names
+}
+
+/// Lists the names registered inside a namespace (without the
+/// `namespace/` prefix), sorted alphabetically.
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn palette_namespace_names<NS: ToString>(namespace: &NS) -> Vec<String> {
+    let prefix = format!("{}/", namespace.to_string());
+    let mut names: Vec<String> = PALETTE
+        .lock()
+        .keys()
+        .filter_map(|key| key.strip_prefix(&prefix).map(ToString::to_string))
+        .collect();
+    names.sort();
+    names
+}
+
 /// Empties the palette
 #[allow(clippy::module_name_repetitions)]
 pub fn clear_palette() {
     PALETTE.lock().clear();
 }
 
+/// Removes every entry inside a namespace, leaving the rest of the palette
+/// alone.
+#[allow(clippy::module_name_repetitions)]
+#[allow(clippy::needless_pass_by_value)]
+pub fn clear_palette_namespace<NS: ToString>(namespace: &NS) {
+    let prefix = format!("{}/", namespace.to_string());
+    PALETTE.lock().retain(|key, _| !key.starts_with(&prefix));
+}
+
 macro_rules! w3c_color_helper {
     ( $( $n:literal, $name:expr ),* ) => {
         let mut plock = PALETTE.lock();
@@ -1599,3 +1670,70 @@ pub fn add_named_colors_to_palette() {
         TEAL
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        clear_palette_namespace, palette_color, palette_color_in_namespace, palette_color_or,
+        palette_names, palette_namespace_names, register_palette_color,
+        register_palette_color_in_namespace,
+    };
+    use crate::prelude::{RGB, RGBA};
+
+    #[test]
+    // Tests namespaced registration and the bare-name fallback.
+    fn namespaces_and_fallback() {
+        register_palette_color("ns_test_highlight", RGB::from_u8(10, 20, 30));
+        register_palette_color_in_namespace(
+            "ns_test_ui",
+            "ns_test_highlight",
+            RGB::from_u8(200, 0, 0),
+        );
+
+        // The namespace wins when it defines the name...
+        assert_eq!(
+            palette_color_in_namespace(&"ns_test_ui", &"ns_test_highlight"),
+            Some(RGBA::from_u8(200, 0, 0, 255))
+        );
+        // ...and lookups fall back to the bare name otherwise.
+        assert_eq!(
+            palette_color_in_namespace(&"ns_test_hud", &"ns_test_highlight"),
+            Some(RGBA::from_u8(10, 20, 30, 255))
+        );
+        assert_eq!(
+            palette_color_in_namespace(&"ns_test_ui", &"ns_test_absent"),
+            None
+        );
+        // The namespaced entry is addressable by its full name, too.
+        assert_eq!(
+            palette_color(&"ns_test_ui/ns_test_highlight"),
+            Some(RGBA::from_u8(200, 0, 0, 255))
+        );
+    }
+
+    #[test]
+    // Tests the default-returning lookup.
+    fn lookup_with_default() {
+        register_palette_color("or_test_present", RGB::from_u8(1, 2, 3));
+        assert_eq!(
+            palette_color_or(&"or_test_present", RGB::from_u8(9, 9, 9)),
+            RGBA::from_u8(1, 2, 3, 255)
+        );
+        assert_eq!(
+            palette_color_or(&"or_test_missing", RGB::from_u8(9, 9, 9)),
+            RGBA::from_u8(9, 9, 9, 255)
+        );
+    }
+
+    #[test]
+    // Tests enumeration and namespace clearing.
+    fn enumerate_and_clear_namespace() {
+        register_palette_color_in_namespace("enum_test", "b", RGB::from_u8(0, 0, 1));
+        register_palette_color_in_namespace("enum_test", "a", RGB::from_u8(0, 0, 2));
+        assert_eq!(palette_namespace_names(&"enum_test"), vec!["a", "b"]);
+        assert!(palette_names().contains(&"enum_test/a".to_string()));
+
+        clear_palette_namespace(&"enum_test");
+        assert!(palette_namespace_names(&"enum_test").is_empty());
+    }
+}
@@ -0,0 +1,193 @@
+use crate::prelude::RGB;
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+/// Error message type when failing to parse a palette file.
+pub enum PaletteFileError {
+    /// The file didn't start with the header the format requires.
+    MissingHeader,
+    /// A line couldn't be parsed as a color; carries the 1-based line number.
+    InvalidLine(usize),
+    /// The file parsed but contained no colors.
+    EmptyPalette,
+}
+
+/// Parses a GIMP palette (`.gpl`): a `GIMP Palette` header, optional
+/// `Name:`/`Columns:` lines and `#` comments, then one `R G B` triple (with
+/// an optional trailing name) per line.
+///
+/// # Errors
+/// Returns a [`PaletteFileError`] if the header is missing, a color line is
+/// malformed, or no colors are found.
+pub fn parse_gpl(text: &str) -> Result<Vec<RGB>, PaletteFileError> {
+    let mut lines = text.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == "GIMP Palette" => {}
+        _ => return Err(PaletteFileError::MissingHeader),
+    }
+
+    let mut colors = Vec::new();
+    for (index, line) in lines {
+        let line = line.trim();
+        if line.is_empty()
+            || line.starts_with('#')
+            || line.starts_with("Name:")
+            || line.starts_with("Columns:")
+        {
+            continue;
+        }
+        colors.push(parse_rgb_triple(line).ok_or(PaletteFileError::InvalidLine(index + 1))?);
+    }
+    if colors.is_empty() {
+        return Err(PaletteFileError::EmptyPalette);
+    }
+    Ok(colors)
+}
+
+/// Parses a Lospec-style hex palette (`.hex`): one 6-digit hex color per
+/// line, with or without a leading `#`.
+///
+/// # Errors
+/// Returns a [`PaletteFileError`] if a line isn't a 6-digit hex color, or no
+/// colors are found.
+pub fn parse_hex(text: &str) -> Result<Vec<RGB>, PaletteFileError> {
+    let mut colors = Vec::new();
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let digits = line.strip_prefix('#').unwrap_or(line);
+        if digits.len() != 6 {
+            return Err(PaletteFileError::InvalidLine(index + 1));
+        }
+        let packed = u32::from_str_radix(digits, 16)
+            .map_err(|_| PaletteFileError::InvalidLine(index + 1))?;
+        colors.push(RGB::from_u8(
+            ((packed >> 16) & 0xFF) as u8,
+            ((packed >> 8) & 0xFF) as u8,
+            (packed & 0xFF) as u8,
+        ));
+    }
+    if colors.is_empty() {
+        return Err(PaletteFileError::EmptyPalette);
+    }
+    Ok(colors)
+}
+
+/// Parses a JASC palette (`.pal`): a `JASC-PAL` header, a version line, a
+/// count line, then one `R G B` triple per line.
+///
+/// # Errors
+/// Returns a [`PaletteFileError`] if the header is missing, a color line is
+/// malformed, or no colors are found.
+pub fn parse_pal(text: &str) -> Result<Vec<RGB>, PaletteFileError> {
+    let mut lines = text.lines().enumerate();
+    match lines.next() {
+        Some((_, header)) if header.trim() == "JASC-PAL" => {}
+        _ => return Err(PaletteFileError::MissingHeader),
+    }
+    // The version ("0100") and color-count lines carry nothing we need.
+    lines.next();
+    lines.next();
+
+    let mut colors = Vec::new();
+    for (index, line) in lines {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        colors.push(parse_rgb_triple(line).ok_or(PaletteFileError::InvalidLine(index + 1))?);
+    }
+    if colors.is_empty() {
+        return Err(PaletteFileError::EmptyPalette);
+    }
+    Ok(colors)
+}
+
+// Parses "R G B" (0-255, whitespace separated) from the start of a line,
+// ignoring anything after the triple - GIMP palettes put the color name there.
+fn parse_rgb_triple(line: &str) -> Option<RGB> {
+    let mut fields = line.split_whitespace();
+    let r = fields.next()?.parse::<u8>().ok()?;
+    let g = fields.next()?.parse::<u8>().ok()?;
+    let b = fields.next()?.parse::<u8>().ok()?;
+    Some(RGB::from_u8(r, g, b))
+}
+
+/// Registers every color of a parsed palette with the global registry, as
+/// `prefix0`, `prefix1` and so on - e.g. loading a 16-color artist palette
+/// under `"art"` makes `palette_color(&"art3")` work.
+#[cfg(feature = "palette")]
+#[allow(clippy::needless_pass_by_value)]
+pub fn register_palette_colors<S: ToString>(prefix: S, colors: &[RGB]) {
+    for (index, color) in colors.iter().enumerate() {
+        crate::prelude::register_palette_color(format!("{}{}", prefix.to_string(), index), *color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_gpl, parse_hex, parse_pal, PaletteFileError};
+    use crate::prelude::RGB;
+
+    #[test]
+    // Tests a small GIMP palette, with comments and named entries.
+    fn parse_gpl_palette() {
+        let text =
+            "GIMP Palette\nName: Test\nColumns: 2\n# a comment\n255 0 0\tRed\n0 255 0\tGreen\n";
+        let colors = parse_gpl(text).expect("Invalid GIMP palette");
+        assert_eq!(colors.len(), 2);
+        assert!(colors[0] == RGB::from_u8(255, 0, 0));
+        assert!(colors[1] == RGB::from_u8(0, 255, 0));
+
+        assert_eq!(
+            parse_gpl("not a palette"),
+            Err(PaletteFileError::MissingHeader)
+        );
+    }
+
+    #[test]
+    // Tests a Lospec hex palette, with and without hash prefixes.
+    fn parse_hex_palette() {
+        let colors = parse_hex("ff0000\n#00ff00\n0000ff\n").expect("Invalid hex palette");
+        assert_eq!(colors.len(), 3);
+        assert!(colors[0] == RGB::from_u8(255, 0, 0));
+        assert!(colors[2] == RGB::from_u8(0, 0, 255));
+
+        assert_eq!(parse_hex("zzzzzz"), Err(PaletteFileError::InvalidLine(1)));
+        assert_eq!(parse_hex(""), Err(PaletteFileError::EmptyPalette));
+    }
+
+    #[test]
+    // Tests a JASC .pal file, including the header and count lines.
+    fn parse_pal_palette() {
+        let text = "JASC-PAL\n0100\n2\n255 255 255\n0 0 0\n";
+        let colors = parse_pal(text).expect("Invalid JASC palette");
+        assert_eq!(colors.len(), 2);
+        assert!(colors[0] == RGB::from_u8(255, 255, 255));
+        assert!(colors[1] == RGB::from_u8(0, 0, 0));
+
+        assert_eq!(
+            parse_pal("JASC-PAL\n0100\n1\nnot a color\n"),
+            Err(PaletteFileError::InvalidLine(4))
+        );
+    }
+
+    #[cfg(feature = "palette")]
+    #[test]
+    // Tests bulk registration into the named-palette registry.
+    fn register_parsed_palette() {
+        use super::register_palette_colors;
+        use crate::prelude::{palette_color, RGBA};
+        let colors = parse_hex("ff0000\n00ff00\n").expect("Invalid hex palette");
+        register_palette_colors("imported", &colors);
+        assert_eq!(
+            palette_color(&"imported0"),
+            Some(RGBA::from_u8(255, 0, 0, 255))
+        );
+        assert_eq!(
+            palette_color(&"imported1"),
+            Some(RGBA::from_u8(0, 255, 0, 255))
+        );
+    }
+}
@@ -328,6 +328,81 @@ impl RGB {
             b: self.b + range.2 * percent,
         }
     }
+
+    /// Multiply blend: darkens by the other color, as colored lighting does.
+    /// White leaves the color unchanged, black produces black.
+    #[inline]
+    #[must_use]
+    pub fn multiply(&self, color: Self) -> Self {
+        Self::from_f32(self.r * color.r, self.g * color.g, self.b * color.b)
+    }
+
+    /// Screen blend: the inverse of multiply, brightening without ever
+    /// overshooting. Black leaves the color unchanged, white produces white.
+    #[inline]
+    #[must_use]
+    pub fn screen(&self, color: Self) -> Self {
+        Self::from_f32(
+            1.0 - (1.0 - self.r) * (1.0 - color.r),
+            1.0 - (1.0 - self.g) * (1.0 - color.g),
+            1.0 - (1.0 - self.b) * (1.0 - color.b),
+        )
+    }
+
+    /// Overlay blend: multiplies the dark half of the base and screens the
+    /// bright half, boosting contrast while tinting
+    #[inline]
+    #[must_use]
+    pub fn overlay(&self, color: Self) -> Self {
+        Self::from_f32(
+            overlay_channel(self.r, color.r),
+            overlay_channel(self.g, color.g),
+            overlay_channel(self.b, color.b),
+        )
+    }
+
+    /// Soft-light blend (the W3C compositing formula): a gentler overlay, for
+    /// subtle lighting and tint effects
+    #[inline]
+    #[must_use]
+    pub fn soft_light(&self, color: Self) -> Self {
+        Self::from_f32(
+            soft_light_channel(self.r, color.r),
+            soft_light_channel(self.g, color.g),
+            soft_light_channel(self.b, color.b),
+        )
+    }
+
+    /// Additive blend, clamped to the displayable range - light sources
+    /// stacking on each other
+    #[inline]
+    #[must_use]
+    pub fn additive_clamped(&self, color: Self) -> Self {
+        Self::from_f32(self.r + color.r, self.g + color.g, self.b + color.b)
+    }
+}
+
+// One channel of the overlay blend: multiply below mid-grey, screen above.
+fn overlay_channel(base: f32, blend: f32) -> f32 {
+    if base < 0.5 {
+        2.0 * base * blend
+    } else {
+        1.0 - 2.0 * (1.0 - base) * (1.0 - blend)
+    }
+}
+
+// One channel of the W3C soft-light blend.
+fn soft_light_channel(base: f32, blend: f32) -> f32 {
+    if blend <= 0.5 {
+        base - (1.0 - 2.0 * blend) * base * (1.0 - base)
+    } else {
+        let d = if base <= 0.25 {
+            ((16.0 * base - 12.0) * base + 4.0) * base
+        } else {
+            base.sqrt()
+        };
+        base + (2.0 * blend - 1.0) * (d - base)
+    }
 }
 
 #[cfg(feature = "crossterm")]
@@ -472,4 +547,52 @@ mod tests {
         assert!(black.lerp(white, 0.0) == black);
         assert!(black.lerp(white, 1.0) == white);
     }
+
+    #[test]
+    // Test the multiply and screen blends against their identities.
+    fn test_multiply_and_screen() {
+        let color = RGB::from_f32(0.25, 0.5, 0.75);
+        let black = RGB::named(BLACK);
+        let white = RGB::named(WHITE);
+        assert!(color.multiply(white) == color);
+        assert!(color.multiply(black) == black);
+        assert!(color.screen(black) == color);
+        assert!(color.screen(white) == white);
+    }
+
+    #[test]
+    // Test that overlay multiplies dark bases and screens bright ones.
+    fn test_overlay() {
+        let blend = RGB::from_f32(0.5, 0.5, 0.5);
+        let dark = RGB::from_f32(0.25, 0.25, 0.25);
+        let bright = RGB::from_f32(0.75, 0.75, 0.75);
+        assert!(f32::abs(dark.overlay(blend).r - 0.25) < 1.0e-6);
+        assert!(f32::abs(bright.overlay(blend).r - 0.75) < 1.0e-6);
+    }
+
+    #[test]
+    // Test that soft light at mid-grey blend is the identity, and it stays in range.
+    fn test_soft_light() {
+        let color = RGB::from_f32(0.3, 0.6, 0.9);
+        let neutral = RGB::from_f32(0.5, 0.5, 0.5);
+        let unchanged = color.soft_light(neutral);
+        assert!(f32::abs(unchanged.r - color.r) < 1.0e-6);
+        assert!(f32::abs(unchanged.g - color.g) < 1.0e-6);
+        assert!(f32::abs(unchanged.b - color.b) < 1.0e-6);
+
+        let lit = color.soft_light(RGB::named(WHITE));
+        assert!(lit.r <= 1.0 && lit.g <= 1.0 && lit.b <= 1.0);
+        assert!(lit.r >= color.r);
+    }
+
+    #[test]
+    // Test that additive blending clamps rather than overflowing.
+    fn test_additive_clamped() {
+        let bright = RGB::from_f32(0.8, 0.8, 0.8);
+        let sum = bright.additive_clamped(bright);
+        assert!(f32::abs(sum.r - 1.0) < std::f32::EPSILON);
+
+        let dim = RGB::from_f32(0.25, 0.0, 0.0);
+        assert!(f32::abs(dim.additive_clamped(dim).r - 0.5) < 1.0e-6);
+    }
 }
@@ -283,6 +283,44 @@ impl RGBA {
         }
     }
 
+    /// Multiply blend on the color channels; this color's alpha is kept
+    #[inline]
+    #[must_use]
+    pub fn multiply(&self, color: Self) -> Self {
+        self.to_rgb().multiply(color.to_rgb()).to_rgba(self.a)
+    }
+
+    /// Screen blend on the color channels; this color's alpha is kept
+    #[inline]
+    #[must_use]
+    pub fn screen(&self, color: Self) -> Self {
+        self.to_rgb().screen(color.to_rgb()).to_rgba(self.a)
+    }
+
+    /// Overlay blend on the color channels; this color's alpha is kept
+    #[inline]
+    #[must_use]
+    pub fn overlay(&self, color: Self) -> Self {
+        self.to_rgb().overlay(color.to_rgb()).to_rgba(self.a)
+    }
+
+    /// Soft-light blend on the color channels; this color's alpha is kept
+    #[inline]
+    #[must_use]
+    pub fn soft_light(&self, color: Self) -> Self {
+        self.to_rgb().soft_light(color.to_rgb()).to_rgba(self.a)
+    }
+
+    /// Additive blend on the color channels, clamped to the displayable
+    /// range; this color's alpha is kept
+    #[inline]
+    #[must_use]
+    pub fn additive_clamped(&self, color: Self) -> Self {
+        self.to_rgb()
+            .additive_clamped(color.to_rgb())
+            .to_rgba(self.a)
+    }
+
     /// Converts an RGB to an xp file color component
     #[allow(clippy::cast_sign_loss)]
     #[allow(clippy::cast_possible_truncation)]
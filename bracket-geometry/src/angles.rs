@@ -1,4 +1,4 @@
-use crate::prelude::{Point, Radians};
+use crate::prelude::{Point, PointF, Radians};
 
 /// From a given start point, project forward radius units at an angle of angle_radians degrees.
 /// 0 Degrees is north (negative Y), 90 degrees is east (positive X)
@@ -13,9 +13,54 @@ where
     )
 }
 
+/// A unit-length PointF facing along an angle, using the same convention as
+/// [`project_angle`]: 0 radians is north (negative Y), PI/2 is east (positive X).
+pub fn pointf_from_angle<ANGLE>(angle_radians: ANGLE) -> PointF
+where
+    ANGLE: Into<Radians>,
+{
+    let theta = angle_radians.into().0;
+    PointF::new(theta.sin(), -theta.cos())
+}
+
+/// The unit step that most closely faces `to` from `from`, snapped to one of
+/// the 8 compass directions. Identical points yield a zero step.
+pub fn direction_to_8_way(from: Point, to: Point) -> Point {
+    const STEPS: [(i32, i32); 8] = [
+        (0, -1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+        (0, 1),
+        (-1, 1),
+        (-1, 0),
+        (-1, -1),
+    ];
+    if from == to {
+        return Point::zero();
+    }
+    let angle = from.angle_to(to).0;
+    let octant = (angle / std::f32::consts::FRAC_PI_4).round().rem_euclid(8.0) as usize;
+    Point::from_tuple(STEPS[octant % 8])
+}
+
+/// As [`direction_to_8_way`], but snapped to the 4 cardinal directions.
+pub fn direction_to_4_way(from: Point, to: Point) -> Point {
+    const STEPS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+    if from == to {
+        return Point::zero();
+    }
+    let angle = from.angle_to(to).0;
+    let quadrant = (angle / std::f32::consts::FRAC_PI_2).round().rem_euclid(4.0) as usize;
+    Point::from_tuple(STEPS[quadrant % 4])
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::prelude::{project_angle, Degrees, Point, Radians};
+    use crate::prelude::{
+        direction_to_4_way, direction_to_8_way, pointf_from_angle, project_angle, Degrees, Point,
+        Radians,
+    };
 
     #[test]
     fn test_project_angle() {
@@ -48,4 +93,40 @@ mod tests {
         dest = project_angle(start, 10.0, Degrees::new(315.0)); // 315 degrees, north-west
         assert_eq!(dest, Point::new(-7, -7));
     }
+
+    #[test]
+    fn test_pointf_from_angle() {
+        let north = pointf_from_angle(Radians::new(0.0));
+        assert!(north.x.abs() < 1e-6 && (north.y + 1.0).abs() < 1e-6);
+
+        let east = pointf_from_angle(Degrees::new(90.0));
+        assert!((east.x - 1.0).abs() < 1e-4 && east.y.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_angle_to() {
+        let start = Point::new(5, 5);
+        assert!(start.angle_to(Point::new(5, 0)).0.abs() < 1e-6); // north
+        let east = start.angle_to(Point::new(10, 5)).0;
+        assert!((east - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rotate_around() {
+        let center = Point::new(5, 5);
+        let north = Point::new(5, 0);
+        assert_eq!(north.rotate_around(center, Degrees::new(90.0)), Point::new(10, 5));
+        assert_eq!(north.rotate_around(center, Degrees::new(180.0)), Point::new(5, 10));
+        assert_eq!(north.rotate_around(center, Degrees::new(360.0)), north);
+    }
+
+    #[test]
+    fn test_snapped_directions() {
+        let start = Point::new(0, 0);
+        assert_eq!(direction_to_8_way(start, Point::new(10, -9)), Point::new(1, -1));
+        assert_eq!(direction_to_8_way(start, Point::new(-10, 1)), Point::new(-1, 0));
+        assert_eq!(direction_to_4_way(start, Point::new(10, -9)), Point::new(1, 0));
+        assert_eq!(direction_to_4_way(start, Point::new(-1, -10)), Point::new(0, -1));
+        assert_eq!(direction_to_8_way(start, start), Point::zero());
+    }
 }
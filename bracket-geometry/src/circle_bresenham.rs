@@ -124,9 +124,80 @@ impl Iterator for BresenhamCircleNoDiag {
     }
 }
 
+/// A filled circle: yields every cell inside the circle exactly once, row by
+/// row. The integer constructor uses the roguelike convention of including
+/// cells with `dx*dx + dy*dy <= r*(r+1)` - midway between `r^2` and `(r+1)^2` -
+/// so successive radii nest without gaps and every [`BresenhamCircle`] outline
+/// cell of the same radius is covered. For an exact Euclidean radius, including
+/// fractional ones, use [`circle_fill`].
+pub struct BresenhamCircleFilled {
+    center: Point,
+    radius_sq: f32,
+    y_extent: i32,
+    x: i32,
+    y: i32,
+    half_width: i32,
+}
+
+impl BresenhamCircleFilled {
+    #[allow(dead_code)]
+    pub fn new(center: Point, radius: i32) -> Self {
+        let radius = radius.max(0);
+        Self::with_radius_sq(center, (radius * (radius + 1)) as f32)
+    }
+
+    fn with_radius_sq(center: Point, radius_sq: f32) -> Self {
+        let y_extent = radius_sq.max(0.0).sqrt().floor() as i32;
+        let y = -y_extent;
+        let half_width = Self::row_half_width(radius_sq, y);
+        Self {
+            center,
+            radius_sq,
+            y_extent,
+            x: -half_width,
+            y,
+            half_width,
+        }
+    }
+
+    /// How far the circle extends either side of the center on a given row.
+    fn row_half_width(radius_sq: f32, y: i32) -> i32 {
+        (radius_sq - (y * y) as f32).max(0.0).sqrt().floor() as i32
+    }
+}
+
+impl Iterator for BresenhamCircleFilled {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y > self.y_extent {
+            return None;
+        }
+        let point = Point::new(self.center.x + self.x, self.center.y + self.y);
+        if self.x < self.half_width {
+            self.x += 1;
+        } else {
+            self.y += 1;
+            self.half_width = Self::row_half_width(self.radius_sq, self.y);
+            self.x = -self.half_width;
+        }
+        Some(point)
+    }
+}
+
+/// A filled circle with an exact (optionally fractional) Euclidean radius:
+/// yields every cell within `radius` of the center exactly once. Fractional
+/// radii fall between the integer [`BresenhamCircleFilled`] rings.
+pub fn circle_fill(center: Point, radius: f32) -> BresenhamCircleFilled {
+    BresenhamCircleFilled::with_radius_sq(center, radius.max(0.0).powi(2))
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::prelude::{BresenhamCircle, BresenhamCircleNoDiag, Point};
+    use crate::prelude::{
+        circle_fill, BresenhamCircle, BresenhamCircleFilled, BresenhamCircleNoDiag, Point,
+    };
 
     #[test]
     fn circle_test_radius1() {
@@ -204,4 +275,28 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn circle_filled_covers_its_outline_once() {
+        let filled: Vec<Point> = BresenhamCircleFilled::new(Point::new(0, 0), 3).collect();
+        for outline_point in BresenhamCircle::new(Point::new(0, 0), 3) {
+            assert!(filled.contains(&outline_point));
+        }
+        let unique: std::collections::HashSet<Point> = filled.iter().copied().collect();
+        assert_eq!(filled.len(), unique.len());
+    }
+
+    #[test]
+    fn circle_filled_radius0() {
+        let filled: Vec<Point> = BresenhamCircleFilled::new(Point::new(5, 5), 0).collect();
+        assert_eq!(filled, vec![Point::new(5, 5)]);
+    }
+
+    #[test]
+    fn circle_fill_fractional_radii() {
+        // An exact radius of 1 is just the center plus its orthogonal neighbors.
+        assert_eq!(circle_fill(Point::new(0, 0), 1.0).count(), 5);
+        // Half a cell more picks up the diagonals.
+        assert_eq!(circle_fill(Point::new(0, 0), 1.5).count(), 9);
+    }
 }
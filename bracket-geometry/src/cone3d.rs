@@ -0,0 +1,90 @@
+use crate::prelude::{Point3, Radians};
+use ultraviolet::Vec3;
+
+/// A 3D cone of cells: everything within `length` of the apex whose direction
+/// from the apex is within `half_angle` of the apex-to-target direction. The
+/// apex itself is included. Useful for breath weapons and directional blasts
+/// on multi-z-level maps.
+pub struct Cone3D {
+    points: Vec<Point3>,
+    index: usize,
+}
+
+impl Cone3D {
+    #[allow(dead_code)]
+    pub fn new<ANGLE>(apex: Point3, target: Point3, length: f32, half_angle: ANGLE) -> Self
+    where
+        ANGLE: Into<Radians>,
+    {
+        let mut points = vec![apex];
+        let aim = Vec3::new(
+            (target.x - apex.x) as f32,
+            (target.y - apex.y) as f32,
+            (target.z - apex.z) as f32,
+        );
+        if aim.mag() > 0.0 && length > 0.0 {
+            let aim = aim.normalized();
+            let min_cos = half_angle.into().0.cos();
+            let reach = length.ceil() as i32;
+            for z in -reach..=reach {
+                for y in -reach..=reach {
+                    for x in -reach..=reach {
+                        if x == 0 && y == 0 && z == 0 {
+                            continue;
+                        }
+                        let offset = Vec3::new(x as f32, y as f32, z as f32);
+                        let distance = offset.mag();
+                        if distance <= length && offset.dot(aim) / distance >= min_cos {
+                            points.push(Point3::new(apex.x + x, apex.y + y, apex.z + z));
+                        }
+                    }
+                }
+            }
+        }
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for Cone3D {
+    type Item = Point3;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Cone3D, Degrees, Point3};
+
+    #[test]
+    fn cone_points_toward_its_target() {
+        let cone: Vec<Point3> = Cone3D::new(
+            Point3::new(0, 0, 0),
+            Point3::new(5, 0, 0),
+            5.0,
+            Degrees::new(30.0),
+        )
+        .collect();
+        assert!(cone.contains(&Point3::new(0, 0, 0)));
+        assert!(cone.contains(&Point3::new(5, 0, 0)));
+        assert!(cone.contains(&Point3::new(4, 1, 1)));
+        // Nothing behind the apex or far off-axis.
+        assert!(!cone.contains(&Point3::new(-1, 0, 0)));
+        assert!(!cone.contains(&Point3::new(1, 4, 0)));
+        // Everything stays within the length.
+        for p in &cone {
+            assert!(p.x * p.x + p.y * p.y + p.z * p.z <= 25);
+        }
+    }
+
+    #[test]
+    fn degenerate_cone_is_its_apex() {
+        let apex = Point3::new(3, 3, 3);
+        let cone: Vec<Point3> = Cone3D::new(apex, apex, 5.0, Degrees::new(45.0)).collect();
+        assert_eq!(cone, vec![apex]);
+    }
+}
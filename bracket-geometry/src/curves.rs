@@ -0,0 +1,213 @@
+use crate::prelude::Point;
+use ultraviolet::Vec2;
+
+/// Samples a parametric curve finely enough that successive cells touch, and
+/// collects the deduplicated grid cells it passes through.
+fn plot_curve<F>(control_length: f32, curve: F) -> Vec<Point>
+where
+    F: Fn(f32) -> Vec2,
+{
+    // Four samples per cell of control-polygon length keeps adjacent samples
+    // well under a cell apart.
+    let steps = ((control_length * 4.0).ceil() as usize).max(8);
+    let mut points: Vec<Point> = Vec::new();
+    for i in 0..=steps {
+        let t = i as f32 / steps as f32;
+        let sample = curve(t);
+        let cell = Point::new(sample.x.round() as i32, sample.y.round() as i32);
+        if points.last() != Some(&cell) {
+            points.push(cell);
+        }
+    }
+    points
+}
+
+fn to_vec2(point: Point) -> Vec2 {
+    Vec2::new(point.x as f32, point.y as f32)
+}
+
+/// A quadratic Bezier curve from `start` to `end`, pulled toward `control`.
+/// Yields the grid cells along the curve, start to end, analogous to the line
+/// iterators - useful for projectile arcs.
+pub struct QuadraticBezier {
+    points: Vec<Point>,
+    index: usize,
+}
+
+impl QuadraticBezier {
+    #[allow(dead_code)]
+    pub fn new(start: Point, control: Point, end: Point) -> Self {
+        let (p0, p1, p2) = (to_vec2(start), to_vec2(control), to_vec2(end));
+        let control_length = (p1 - p0).mag() + (p2 - p1).mag();
+        let points = plot_curve(control_length, |t| {
+            let u = 1.0 - t;
+            p0 * (u * u) + p1 * (2.0 * u * t) + p2 * (t * t)
+        });
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for QuadraticBezier {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+/// A cubic Bezier curve from `start` to `end` with two control points. Yields
+/// the grid cells along the curve, start to end.
+pub struct CubicBezier {
+    points: Vec<Point>,
+    index: usize,
+}
+
+impl CubicBezier {
+    #[allow(dead_code)]
+    pub fn new(start: Point, control1: Point, control2: Point, end: Point) -> Self {
+        let (p0, p1, p2, p3) = (
+            to_vec2(start),
+            to_vec2(control1),
+            to_vec2(control2),
+            to_vec2(end),
+        );
+        let control_length = (p1 - p0).mag() + (p2 - p1).mag() + (p3 - p2).mag();
+        let points = plot_curve(control_length, |t| {
+            let u = 1.0 - t;
+            p0 * (u * u * u)
+                + p1 * (3.0 * u * u * t)
+                + p2 * (3.0 * u * t * t)
+                + p3 * (t * t * t)
+        });
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for CubicBezier {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+/// A Catmull-Rom spline through every supplied waypoint, in order. Tangents at
+/// the endpoints are taken from duplicated end waypoints, so the curve passes
+/// through all of them. Yields the grid cells along the spline - useful for
+/// rivers and road generation. Fewer than two waypoints yield the waypoints
+/// themselves.
+pub struct CatmullRomSpline {
+    points: Vec<Point>,
+    index: usize,
+}
+
+impl CatmullRomSpline {
+    #[allow(dead_code)]
+    pub fn new(waypoints: &[Point]) -> Self {
+        if waypoints.len() < 2 {
+            return Self {
+                points: waypoints.to_vec(),
+                index: 0,
+            };
+        }
+        let mut points: Vec<Point> = Vec::new();
+        for segment in 0..waypoints.len() - 1 {
+            // Clamp the neighbor lookups so the end tangents reuse the endpoints.
+            let p0 = to_vec2(waypoints[segment.saturating_sub(1)]);
+            let p1 = to_vec2(waypoints[segment]);
+            let p2 = to_vec2(waypoints[segment + 1]);
+            let p3 = to_vec2(waypoints[(segment + 2).min(waypoints.len() - 1)]);
+            let segment_points = plot_curve((p2 - p1).mag(), |t| {
+                let t2 = t * t;
+                let t3 = t2 * t;
+                ((p1 * 2.0)
+                    + (p2 - p0) * t
+                    + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * t2
+                    + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * t3)
+                    * 0.5
+            });
+            // Segments share endpoints; skip the duplicate join cell.
+            for cell in segment_points {
+                if points.last() != Some(&cell) {
+                    points.push(cell);
+                }
+            }
+        }
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for CatmullRomSpline {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{CatmullRomSpline, CubicBezier, Point, QuadraticBezier};
+
+    fn contiguous(points: &[Point]) -> bool {
+        points.windows(2).all(|w| {
+            (w[0].x - w[1].x).abs() <= 1 && (w[0].y - w[1].y).abs() <= 1 && w[0] != w[1]
+        })
+    }
+
+    #[test]
+    fn quadratic_hits_its_endpoints() {
+        let curve: Vec<Point> =
+            QuadraticBezier::new(Point::new(0, 0), Point::new(5, 10), Point::new(10, 0)).collect();
+        assert_eq!(curve.first(), Some(&Point::new(0, 0)));
+        assert_eq!(curve.last(), Some(&Point::new(10, 0)));
+        assert!(contiguous(&curve));
+        // The arc bends toward the control point.
+        assert!(curve.iter().any(|p| p.y >= 4));
+    }
+
+    #[test]
+    fn cubic_hits_its_endpoints() {
+        let curve: Vec<Point> = CubicBezier::new(
+            Point::new(0, 0),
+            Point::new(0, 8),
+            Point::new(10, 8),
+            Point::new(10, 0),
+        )
+        .collect();
+        assert_eq!(curve.first(), Some(&Point::new(0, 0)));
+        assert_eq!(curve.last(), Some(&Point::new(10, 0)));
+        assert!(contiguous(&curve));
+    }
+
+    #[test]
+    fn spline_passes_through_waypoints() {
+        let waypoints = [
+            Point::new(0, 0),
+            Point::new(5, 3),
+            Point::new(10, 0),
+            Point::new(15, 5),
+        ];
+        let curve: Vec<Point> = CatmullRomSpline::new(&waypoints).collect();
+        for waypoint in &waypoints {
+            assert!(curve.contains(waypoint));
+        }
+        assert!(contiguous(&curve));
+    }
+
+    #[test]
+    fn degenerate_splines_yield_their_waypoints() {
+        let single: Vec<Point> = CatmullRomSpline::new(&[Point::new(3, 3)]).collect();
+        assert_eq!(single, vec![Point::new(3, 3)]);
+        assert_eq!(CatmullRomSpline::new(&[]).count(), 0);
+    }
+}
@@ -9,6 +9,9 @@ pub enum DistanceAlg {
     Manhattan,
     Chebyshev,
     Diagonal,
+    /// Minkowski distance of order `p`: `(dx^p + dy^p)^(1/p)`. Order 1 is
+    /// Manhattan, 2 is Pythagoras, and large orders approach Chebyshev.
+    Minkowski(f32),
 }
 
 impl DistanceAlg {
@@ -20,6 +23,7 @@ impl DistanceAlg {
             DistanceAlg::Manhattan => distance2d_manhattan(start, end),
             DistanceAlg::Chebyshev => distance2d_chebyshev(start, end),
             DistanceAlg::Diagonal => distance2d_diagonal(start, end),
+            DistanceAlg::Minkowski(order) => distance2d_minkowski(start, end, order),
         }
     }
     /// Provides a 3D distance between points, using the specified algorithm.
@@ -30,10 +34,35 @@ impl DistanceAlg {
             DistanceAlg::Manhattan => distance3d_manhattan(start, end),
             DistanceAlg::Chebyshev => distance3d_pythagoras(start, end),
             DistanceAlg::Diagonal => distance3d_diagonal(start, end),
+            DistanceAlg::Minkowski(order) => distance3d_minkowski(start, end, order),
         }
     }
 }
 
+/// A pluggable distance metric. Implemented by [`DistanceAlg`] and by any
+/// `Fn(Point, Point) -> f32` closure, so field-of-view and pathfinding
+/// heuristics can take `&dyn Distance` and support hex-like or wrap-around
+/// worlds without new enum variants.
+pub trait Distance {
+    /// Provides a 2D distance between points.
+    fn distance2d(&self, start: Point, end: Point) -> f32;
+}
+
+impl Distance for DistanceAlg {
+    fn distance2d(&self, start: Point, end: Point) -> f32 {
+        DistanceAlg::distance2d(*self, start, end)
+    }
+}
+
+impl<F> Distance for F
+where
+    F: Fn(Point, Point) -> f32,
+{
+    fn distance2d(&self, start: Point, end: Point) -> f32 {
+        self(start, end)
+    }
+}
+
 /// Calculates a Pythagoras distance between two points, and skips the square root for speed.
 fn distance2d_pythagoras_squared(start: Point, end: Point) -> f32 {
     let dx = (max(start.x, end.x) - min(start.x, end.x)) as f32;
@@ -88,6 +117,21 @@ fn distance3d_pythagoras(start: Point3, end: Point3) -> f32 {
     f32::sqrt(dsq)
 }
 
+/// Calculates a Minkowski distance of the given order between two points.
+fn distance2d_minkowski(start: Point, end: Point, order: f32) -> f32 {
+    let dx = (start.x - end.x).abs() as f32;
+    let dy = (start.y - end.y).abs() as f32;
+    (dx.powf(order) + dy.powf(order)).powf(1.0 / order)
+}
+
+/// Calculates a Minkowski distance of the given order between two 3D points.
+fn distance3d_minkowski(start: Point3, end: Point3, order: f32) -> f32 {
+    let dx = (start.x - end.x).abs() as f32;
+    let dy = (start.y - end.y).abs() as f32;
+    let dz = (start.z - end.z).abs() as f32;
+    (dx.powf(order) + dy.powf(order) + dz.powf(order)).powf(1.0 / order)
+}
+
 // Calculates a diagonal distance
 fn distance2d_diagonal(start: Point, end: Point) -> f32 {
     i32::max((start.x - end.x).abs(), (start.y - end.y).abs()) as f32
@@ -213,6 +257,39 @@ mod tests {
         assert!(f32::abs(d - 5.0) < std::f32::EPSILON);
     }
 
+    #[test]
+    fn test_minkowski_distance() {
+        // Order 1 is Manhattan, order 2 is Pythagoras.
+        let d = DistanceAlg::Minkowski(1.0).distance2d(Point::new(0, 0), Point::new(5, 5));
+        assert!(f32::abs(d - 10.0) < std::f32::EPSILON);
+
+        let d = DistanceAlg::Minkowski(2.0).distance2d(Point::new(0, 0), Point::new(5, 5));
+        assert!(f32::abs(d - 7.071_068) < std::f32::EPSILON);
+
+        // Large orders approach Chebyshev.
+        let d = DistanceAlg::Minkowski(32.0).distance2d(Point::new(0, 0), Point::new(5, 5));
+        assert!(f32::abs(d - 5.0) < 0.2);
+    }
+
+    #[test]
+    fn test_custom_metric() {
+        use crate::prelude::Distance;
+
+        // A wrap-around metric on a 10-wide torus.
+        let wrapping = |start: Point, end: Point| {
+            let dx = (start.x - end.x).abs().min(10 - (start.x - end.x).abs()) as f32;
+            let dy = (start.y - end.y).abs() as f32;
+            (dx * dx + dy * dy).sqrt()
+        };
+        let d = wrapping.distance2d(Point::new(0, 0), Point::new(9, 0));
+        assert!(f32::abs(d - 1.0) < std::f32::EPSILON);
+
+        // DistanceAlg works through the same trait.
+        let metric: &dyn Distance = &DistanceAlg::Manhattan;
+        let d = metric.distance2d(Point::new(0, 0), Point::new(5, 5));
+        assert!(f32::abs(d - 10.0) < std::f32::EPSILON);
+    }
+
     #[test]
     fn test_algorithm_from_shared_reference() {
         let mut algorithm = DistanceAlg::Chebyshev;
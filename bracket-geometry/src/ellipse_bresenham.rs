@@ -0,0 +1,194 @@
+use crate::prelude::Point;
+
+/// A midpoint (Bresenham-style) ellipse outline, parameterized by center and the
+/// two radii. The plot is computed up front and iterated point by point; the
+/// four-way symmetric points are emitted together, so consumers that care about
+/// ordering should collect and sort.
+pub struct BresenhamEllipse {
+    points: Vec<Point>,
+    index: usize,
+}
+
+impl BresenhamEllipse {
+    #[allow(dead_code)]
+    pub fn new(center: Point, radius_x: i32, radius_y: i32) -> Self {
+        let mut points = Vec::new();
+        let (rx, ry) = (i64::from(radius_x.max(0)), i64::from(radius_y.max(0)));
+        let (rx2, ry2) = (rx * rx, ry * ry);
+        let mut x: i64 = 0;
+        let mut y: i64 = ry;
+        let mut px: i64 = 0;
+        let mut py: i64 = 2 * rx2 * y;
+
+        let mut emit = |x: i64, y: i64| {
+            let (x, y) = (x as i32, y as i32);
+            points.push(Point::new(center.x + x, center.y + y));
+            if x != 0 {
+                points.push(Point::new(center.x - x, center.y + y));
+            }
+            if y != 0 {
+                points.push(Point::new(center.x + x, center.y - y));
+            }
+            if x != 0 && y != 0 {
+                points.push(Point::new(center.x - x, center.y - y));
+            }
+        };
+
+        if rx > 0 && ry > 0 {
+            // Region 1: gradient steeper than -1, stepping in x.
+            let mut p = ry2 - rx2 * ry + rx2 / 4;
+            while px < py {
+                emit(x, y);
+                x += 1;
+                px += 2 * ry2;
+                if p < 0 {
+                    p += ry2 + px;
+                } else {
+                    y -= 1;
+                    py -= 2 * rx2;
+                    p += ry2 + px - py;
+                }
+            }
+            // Region 2: gradient shallower than -1, stepping in y.
+            p = ry2 * (2 * x + 1) * (2 * x + 1) / 4 + rx2 * (y - 1) * (y - 1) - rx2 * ry2;
+            while y >= 0 {
+                emit(x, y);
+                y -= 1;
+                py -= 2 * rx2;
+                if p > 0 {
+                    p += rx2 - py;
+                } else {
+                    x += 1;
+                    px += 2 * ry2;
+                    p += rx2 - py + px;
+                }
+            }
+        } else if rx > 0 || ry > 0 {
+            // Degenerate ellipse: a horizontal or vertical line of cells.
+            for x in 0..=rx {
+                emit(x, 0);
+            }
+            for y in 1..=ry {
+                emit(0, y);
+            }
+        } else {
+            points.push(center);
+        }
+
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for BresenhamEllipse {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+/// A filled ellipse: yields every cell within the given radii of the center,
+/// row by row.
+pub struct EllipseFill {
+    center: Point,
+    radius_x: i32,
+    radius_y: i32,
+    x: i32,
+    y: i32,
+    half_width: i32,
+}
+
+impl EllipseFill {
+    #[allow(dead_code)]
+    pub fn new(center: Point, radius_x: i32, radius_y: i32) -> Self {
+        let radius_x = radius_x.max(0);
+        let radius_y = radius_y.max(0);
+        let y = -radius_y;
+        let half_width = Self::row_half_width(radius_x, radius_y, y);
+        Self {
+            center,
+            radius_x,
+            radius_y,
+            x: -half_width,
+            y,
+            half_width,
+        }
+    }
+
+    /// How far the ellipse extends either side of the center on a given row.
+    fn row_half_width(radius_x: i32, radius_y: i32, y: i32) -> i32 {
+        if radius_y == 0 {
+            return radius_x;
+        }
+        let fraction = 1.0 - (f64::from(y) / f64::from(radius_y)).powi(2);
+        (f64::from(radius_x) * fraction.max(0.0).sqrt()).floor() as i32
+    }
+}
+
+impl Iterator for EllipseFill {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y > self.radius_y {
+            return None;
+        }
+        let point = Point::new(self.center.x + self.x, self.center.y + self.y);
+        if self.x < self.half_width {
+            self.x += 1;
+        } else {
+            self.y += 1;
+            self.half_width = Self::row_half_width(self.radius_x, self.radius_y, self.y);
+            self.x = -self.half_width;
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{BresenhamEllipse, EllipseFill, Point};
+
+    #[test]
+    fn ellipse_reaches_its_extremes() {
+        let points: Vec<Point> = BresenhamEllipse::new(Point::new(0, 0), 4, 2).collect();
+        assert!(points.contains(&Point::new(4, 0)));
+        assert!(points.contains(&Point::new(-4, 0)));
+        assert!(points.contains(&Point::new(0, 2)));
+        assert!(points.contains(&Point::new(0, -2)));
+        // Four-way symmetry.
+        for p in &points {
+            assert!(points.contains(&Point::new(-p.x, p.y)));
+            assert!(points.contains(&Point::new(p.x, -p.y)));
+        }
+    }
+
+    #[test]
+    fn ellipse_has_no_duplicates() {
+        let points: Vec<Point> = BresenhamEllipse::new(Point::new(0, 0), 3, 2).collect();
+        let unique: std::collections::HashSet<Point> = points.iter().copied().collect();
+        assert_eq!(points.len(), unique.len());
+    }
+
+    #[test]
+    fn fill_covers_the_outline_rows() {
+        let fill: Vec<Point> = EllipseFill::new(Point::new(10, 10), 2, 1).collect();
+        assert!(fill.contains(&Point::new(10, 10)));
+        assert!(fill.contains(&Point::new(8, 10)));
+        assert!(fill.contains(&Point::new(12, 10)));
+        assert!(fill.contains(&Point::new(10, 9)));
+        assert!(fill.contains(&Point::new(10, 11)));
+        assert!(!fill.contains(&Point::new(12, 11)));
+    }
+
+    #[test]
+    fn degenerate_radii() {
+        let dot: Vec<Point> = BresenhamEllipse::new(Point::new(3, 3), 0, 0).collect();
+        assert_eq!(dot, vec![Point::new(3, 3)]);
+        let line: Vec<Point> = EllipseFill::new(Point::new(0, 0), 2, 0).collect();
+        assert_eq!(line.len(), 5);
+    }
+}
@@ -0,0 +1,230 @@
+//! Conversions to and from the `glam` and `nalgebra` math crates, behind the
+//! matching feature flags. `From`/`Into` are provided where the orphan rule
+//! allows it (our own `Point` and `Point3`); `PointF` is an alias for
+//! UltraViolet's `Vec2`, so its conversions are free functions instead.
+
+#[cfg(feature = "glam")]
+mod glam_interop {
+    use crate::prelude::{Point, Point3, PointF, Rect};
+
+    impl From<glam::IVec2> for Point {
+        fn from(item: glam::IVec2) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+            }
+        }
+    }
+
+    impl From<Point> for glam::IVec2 {
+        fn from(item: Point) -> Self {
+            glam::IVec2::new(item.x, item.y)
+        }
+    }
+
+    impl From<glam::Vec2> for Point {
+        fn from(item: glam::Vec2) -> Self {
+            Self {
+                x: item.x as i32,
+                y: item.y as i32,
+            }
+        }
+    }
+
+    impl From<Point> for glam::Vec2 {
+        fn from(item: Point) -> Self {
+            glam::Vec2::new(item.x as f32, item.y as f32)
+        }
+    }
+
+    impl From<glam::IVec3> for Point3 {
+        fn from(item: glam::IVec3) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+                z: item.z,
+            }
+        }
+    }
+
+    impl From<Point3> for glam::IVec3 {
+        fn from(item: Point3) -> Self {
+            glam::IVec3::new(item.x, item.y, item.z)
+        }
+    }
+
+    impl From<glam::Vec3> for Point3 {
+        fn from(item: glam::Vec3) -> Self {
+            Self {
+                x: item.x as i32,
+                y: item.y as i32,
+                z: item.z as i32,
+            }
+        }
+    }
+
+    impl From<Point3> for glam::Vec3 {
+        fn from(item: Point3) -> Self {
+            glam::Vec3::new(item.x as f32, item.y as f32, item.z as f32)
+        }
+    }
+
+    /// Converts a PointF to a glam Vec2.
+    pub fn pointf_to_glam(point: PointF) -> glam::Vec2 {
+        glam::Vec2::new(point.x, point.y)
+    }
+
+    /// Creates a PointF from a glam Vec2.
+    pub fn pointf_from_glam(v: glam::Vec2) -> PointF {
+        PointF::new(v.x, v.y)
+    }
+
+    /// Converts a Rect into a glam min/max AABB pair.
+    pub fn rect_to_glam_aabb(rect: Rect) -> (glam::Vec2, glam::Vec2) {
+        (
+            glam::Vec2::new(rect.x1 as f32, rect.y1 as f32),
+            glam::Vec2::new(rect.x2 as f32, rect.y2 as f32),
+        )
+    }
+
+    /// Creates the smallest Rect containing a glam min/max AABB: the minimum
+    /// corner is floored and the maximum corner is ceilinged.
+    pub fn rect_from_glam_aabb(min: glam::Vec2, max: glam::Vec2) -> Rect {
+        Rect {
+            x1: min.x.floor() as i32,
+            y1: min.y.floor() as i32,
+            x2: max.x.ceil() as i32,
+            y2: max.y.ceil() as i32,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{pointf_from_glam, pointf_to_glam, rect_from_glam_aabb, rect_to_glam_aabb};
+        use crate::prelude::{Point, Point3, Rect};
+
+        #[test]
+        fn test_glam_points() {
+            let p: Point = glam::IVec2::new(3, 4).into();
+            assert_eq!(p, Point::new(3, 4));
+            let v: glam::Vec2 = p.into();
+            assert_eq!(v, glam::Vec2::new(3.0, 4.0));
+            let p3: Point3 = glam::Vec3::new(1.0, 2.0, 3.0).into();
+            assert_eq!(p3, Point3::new(1, 2, 3));
+            let roundtrip = pointf_to_glam(pointf_from_glam(glam::Vec2::new(0.5, 1.5)));
+            assert_eq!(roundtrip, glam::Vec2::new(0.5, 1.5));
+        }
+
+        #[test]
+        fn test_glam_aabb() {
+            let (min, max) = rect_to_glam_aabb(Rect::with_size(1, 2, 3, 4));
+            assert_eq!(min, glam::Vec2::new(1.0, 2.0));
+            assert_eq!(max, glam::Vec2::new(4.0, 6.0));
+            let rect = rect_from_glam_aabb(glam::Vec2::new(0.25, 0.25), glam::Vec2::new(4.75, 4.75));
+            assert_eq!(rect, Rect::with_exact(0, 0, 5, 5));
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+pub use glam_interop::*;
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_interop {
+    use crate::prelude::{Point, Point3, PointF};
+
+    impl From<nalgebra::Point2<i32>> for Point {
+        fn from(item: nalgebra::Point2<i32>) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+            }
+        }
+    }
+
+    impl From<Point> for nalgebra::Point2<i32> {
+        fn from(item: Point) -> Self {
+            nalgebra::Point2::new(item.x, item.y)
+        }
+    }
+
+    impl From<nalgebra::Vector2<i32>> for Point {
+        fn from(item: nalgebra::Vector2<i32>) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+            }
+        }
+    }
+
+    impl From<Point> for nalgebra::Vector2<i32> {
+        fn from(item: Point) -> Self {
+            nalgebra::Vector2::new(item.x, item.y)
+        }
+    }
+
+    impl From<nalgebra::Point3<i32>> for Point3 {
+        fn from(item: nalgebra::Point3<i32>) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+                z: item.z,
+            }
+        }
+    }
+
+    impl From<Point3> for nalgebra::Point3<i32> {
+        fn from(item: Point3) -> Self {
+            nalgebra::Point3::new(item.x, item.y, item.z)
+        }
+    }
+
+    impl From<nalgebra::Vector3<i32>> for Point3 {
+        fn from(item: nalgebra::Vector3<i32>) -> Self {
+            Self {
+                x: item.x,
+                y: item.y,
+                z: item.z,
+            }
+        }
+    }
+
+    impl From<Point3> for nalgebra::Vector3<i32> {
+        fn from(item: Point3) -> Self {
+            nalgebra::Vector3::new(item.x, item.y, item.z)
+        }
+    }
+
+    /// Converts a PointF to an nalgebra Point2.
+    pub fn pointf_to_nalgebra(point: PointF) -> nalgebra::Point2<f32> {
+        nalgebra::Point2::new(point.x, point.y)
+    }
+
+    /// Creates a PointF from an nalgebra Point2.
+    pub fn pointf_from_nalgebra(point: nalgebra::Point2<f32>) -> PointF {
+        PointF::new(point.x, point.y)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{pointf_from_nalgebra, pointf_to_nalgebra};
+        use crate::prelude::{Point, Point3};
+
+        #[test]
+        fn test_nalgebra_points() {
+            let p: Point = nalgebra::Point2::new(3, 4).into();
+            assert_eq!(p, Point::new(3, 4));
+            let v: nalgebra::Vector2<i32> = p.into();
+            assert_eq!(v, nalgebra::Vector2::new(3, 4));
+            let p3: Point3 = nalgebra::Vector3::new(1, 2, 3).into();
+            assert_eq!(p3, Point3::new(1, 2, 3));
+            let roundtrip = pointf_to_nalgebra(pointf_from_nalgebra(nalgebra::Point2::new(
+                0.5, 1.5,
+            )));
+            assert_eq!(roundtrip, nalgebra::Point2::new(0.5, 1.5));
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+pub use nalgebra_interop::*;
@@ -62,25 +62,47 @@
 mod angle;
 mod angles;
 mod circle_bresenham;
+mod cone3d;
+mod curves;
 mod distance;
+mod ellipse_bresenham;
+#[cfg(any(feature = "glam", feature = "nalgebra"))]
+mod interop;
 mod line_bresenham;
+mod line_bresenham3d;
+mod line_supercover;
 mod line_vector;
 mod lines;
 mod point;
 mod point3;
+mod polygon;
 mod rect;
+mod rect3d;
 mod rectf;
+mod sphere;
+mod triangle;
 
 pub mod prelude {
     pub use crate::angle::*;
     pub use crate::angles::*;
     pub use crate::circle_bresenham::*;
+    pub use crate::cone3d::*;
+    pub use crate::curves::*;
     pub use crate::distance::*;
+    pub use crate::ellipse_bresenham::*;
+    #[cfg(any(feature = "glam", feature = "nalgebra"))]
+    pub use crate::interop::*;
     pub use crate::line_bresenham::*;
+    pub use crate::line_bresenham3d::*;
+    pub use crate::line_supercover::*;
     pub use crate::line_vector::*;
     pub use crate::lines::*;
     pub use crate::point::*;
     pub use crate::point3::*;
+    pub use crate::polygon::*;
     pub use crate::rect::*;
+    pub use crate::rect3d::*;
     pub use crate::rectf::*;
+    pub use crate::sphere::*;
+    pub use crate::triangle::*;
 }
@@ -0,0 +1,147 @@
+use crate::prelude::Point3;
+
+/// An iterator over a 3D Bresenham line between two voxels, the `Point3`
+/// counterpart of `Bresenham`. Like its 2D sibling it includes `start` but
+/// not `end`. Steps along the dominant axis, so consecutive voxels always
+/// touch (possibly diagonally).
+pub struct Bresenham3D {
+    points: Vec<Point3>,
+    index: usize,
+}
+
+impl Bresenham3D {
+    /// Creates a new 3D line between two voxels.
+    pub fn new(start: Point3, end: Point3) -> Bresenham3D {
+        let (dx, dy, dz) = (
+            (end.x - start.x).abs(),
+            (end.y - start.y).abs(),
+            (end.z - start.z).abs(),
+        );
+        let (sx, sy, sz) = (
+            (end.x - start.x).signum(),
+            (end.y - start.y).signum(),
+            (end.z - start.z).signum(),
+        );
+        let mut current = start;
+        let mut points = Vec::with_capacity(dx.max(dy).max(dz) as usize + 1);
+
+        if dx >= dy && dx >= dz {
+            let mut err_y = 2 * dy - dx;
+            let mut err_z = 2 * dz - dx;
+            while current.x != end.x {
+                points.push(current);
+                if err_y >= 0 {
+                    current.y += sy;
+                    err_y -= 2 * dx;
+                }
+                if err_z >= 0 {
+                    current.z += sz;
+                    err_z -= 2 * dx;
+                }
+                err_y += 2 * dy;
+                err_z += 2 * dz;
+                current.x += sx;
+            }
+        } else if dy >= dx && dy >= dz {
+            let mut err_x = 2 * dx - dy;
+            let mut err_z = 2 * dz - dy;
+            while current.y != end.y {
+                points.push(current);
+                if err_x >= 0 {
+                    current.x += sx;
+                    err_x -= 2 * dy;
+                }
+                if err_z >= 0 {
+                    current.z += sz;
+                    err_z -= 2 * dy;
+                }
+                err_x += 2 * dx;
+                err_z += 2 * dz;
+                current.y += sy;
+            }
+        } else {
+            let mut err_x = 2 * dx - dz;
+            let mut err_y = 2 * dy - dz;
+            while current.z != end.z {
+                points.push(current);
+                if err_x >= 0 {
+                    current.x += sx;
+                    err_x -= 2 * dz;
+                }
+                if err_y >= 0 {
+                    current.y += sy;
+                    err_y -= 2 * dz;
+                }
+                err_x += 2 * dx;
+                err_y += 2 * dy;
+                current.z += sz;
+            }
+        }
+
+        Bresenham3D { points, index: 0 }
+    }
+}
+
+impl Iterator for Bresenham3D {
+    type Item = Point3;
+
+    #[inline]
+    fn next(&mut self) -> Option<Point3> {
+        if self.index < self.points.len() {
+            let result = self.points[self.index];
+            self.index += 1;
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Bresenham3D, Point3};
+
+    #[test]
+    fn test_axis_line() {
+        let line: Vec<Point3> =
+            Bresenham3D::new(Point3::new(0, 0, 0), Point3::new(3, 0, 0)).collect();
+        assert_eq!(
+            line,
+            vec![
+                Point3::new(0, 0, 0),
+                Point3::new(1, 0, 0),
+                Point3::new(2, 0, 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_grand_diagonal() {
+        let line: Vec<Point3> =
+            Bresenham3D::new(Point3::new(0, 0, 0), Point3::new(4, 4, 4)).collect();
+        assert_eq!(line.len(), 4);
+        for (i, point) in line.iter().enumerate() {
+            assert_eq!(*point, Point3::new(i as i32, i as i32, i as i32));
+        }
+    }
+
+    #[test]
+    fn test_steps_touch() {
+        let line: Vec<Point3> =
+            Bresenham3D::new(Point3::new(0, 0, 0), Point3::new(7, 3, -5)).collect();
+        assert_eq!(line[0], Point3::new(0, 0, 0));
+        assert_eq!(line.len(), 7);
+        for pair in line.windows(2) {
+            assert!((pair[1].x - pair[0].x).abs() <= 1);
+            assert!((pair[1].y - pair[0].y).abs() <= 1);
+            assert!((pair[1].z - pair[0].z).abs() <= 1);
+        }
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let line: Vec<Point3> =
+            Bresenham3D::new(Point3::new(2, 2, 2), Point3::new(2, 2, 2)).collect();
+        assert!(line.is_empty());
+    }
+}
@@ -1,9 +1,10 @@
-use crate::prelude::{Bresenham, DistanceAlg, Point};
+use crate::prelude::{Bresenham, DistanceAlg, Point, SuperCoverLine};
 
 /// Enumeration of available 2D Distance algorithms
 pub enum LineAlg {
     Bresenham,
     Vector,
+    SuperCover,
 }
 
 /// Plots a line between two 2D points and returns a vector of points along the line.
@@ -11,6 +12,7 @@ pub fn line2d(algorithm: LineAlg, start: Point, end: Point) -> Vec<Point> {
     match algorithm {
         LineAlg::Bresenham => line2d_bresenham(start, end),
         LineAlg::Vector => line2d_vector(start, end),
+        LineAlg::SuperCover => line2d_supercover(start, end),
     }
 }
 
@@ -21,6 +23,13 @@ pub fn line2d_bresenham(start: Point, end: Point) -> Vec<Point> {
     line.chain(std::iter::once(end)).collect()
 }
 
+/// Uses a supercover grid traversal to plot every cell the true segment touches,
+/// including both neighbors of any corner it clips through. See [`SuperCoverLine`]
+/// for the iterator form with per-cell entry sides.
+pub fn line2d_supercover(start: Point, end: Point) -> Vec<Point> {
+    SuperCoverLine::new(start, end).map(|cell| cell.pos).collect()
+}
+
 /// Uses a 2D vector algorithm to plot a line between two points. On some CPUs, this is faster
 /// than Bresenham.
 pub fn line2d_vector(start: Point, end: Point) -> Vec<Point> {
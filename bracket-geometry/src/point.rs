@@ -1,3 +1,4 @@
+use crate::prelude::Radians;
 use std::convert::{From, TryInto};
 use std::ops;
 use ultraviolet::Vec2;
@@ -100,6 +101,29 @@ impl Point {
         Self::new(v.x, v.y)
     }
     */
+
+    /// The angle from this point to another, using the same convention as
+    /// `project_angle`: 0 radians is north (negative Y), PI/2 is east (positive X).
+    pub fn angle_to(self, other: Point) -> Radians {
+        let dx = (other.x - self.x) as f32;
+        let dy = (other.y - self.y) as f32;
+        Radians(dx.atan2(-dy))
+    }
+
+    /// Rotates this point around a center, in the same north-towards-east sense
+    /// as `project_angle`, rounding to the nearest cell.
+    pub fn rotate_around<ANGLE>(self, center: Point, angle: ANGLE) -> Point
+    where
+        ANGLE: Into<Radians>,
+    {
+        let (sin, cos) = angle.into().0.sin_cos();
+        let dx = (self.x - center.x) as f32;
+        let dy = (self.y - center.y) as f32;
+        Point::new(
+            (center.x as f32 + dx * cos - dy * sin).round() as i32,
+            (center.y as f32 + dx * sin + dy * cos).round() as i32,
+        )
+    }
 }
 
 impl From<(i32, i32)> for Point {
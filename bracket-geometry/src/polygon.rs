@@ -0,0 +1,206 @@
+use crate::prelude::{Point, Rect};
+
+/// An arbitrary polygon, stored as a list of vertices in winding order (either
+/// direction). Supports point-in-polygon tests, bounding rectangles, area, and
+/// scanline rasterization into covered cells - useful for irregular zones such
+/// as spell templates or territory.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Debug)]
+pub struct Polygon {
+    pub vertices: Vec<Point>,
+}
+
+impl Polygon {
+    /// Creates a polygon from its vertices, in winding order. The closing edge
+    /// from the last vertex back to the first is implied.
+    pub fn new<I>(vertices: I) -> Self
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        Self {
+            vertices: vertices.into_iter().collect(),
+        }
+    }
+
+    /// Returns true if a point is inside the polygon, using the even-odd rule.
+    /// Polygons with fewer than three vertices contain nothing.
+    pub fn contains(&self, point: Point) -> bool {
+        if self.vertices.len() < 3 {
+            return false;
+        }
+        let (px, py) = (f64::from(point.x), f64::from(point.y));
+        let mut inside = false;
+        let mut j = self.vertices.len() - 1;
+        for i in 0..self.vertices.len() {
+            let (ix, iy) = (
+                f64::from(self.vertices[i].x),
+                f64::from(self.vertices[i].y),
+            );
+            let (jx, jy) = (
+                f64::from(self.vertices[j].x),
+                f64::from(self.vertices[j].y),
+            );
+            if ((iy > py) != (jy > py)) && (px < (jx - ix) * (py - iy) / (jy - iy) + ix) {
+                inside = !inside;
+            }
+            j = i;
+        }
+        inside
+    }
+
+    /// Returns the smallest rectangle containing every vertex. Exclusive of the
+    /// upper bound, as rectangles are, so every vertex passes `point_in_rect`.
+    /// An empty polygon yields a zero rectangle.
+    pub fn bounding_rect(&self) -> Rect {
+        if self.vertices.is_empty() {
+            return Rect::zero();
+        }
+        let min_x = self.vertices.iter().map(|v| v.x).min().unwrap();
+        let max_x = self.vertices.iter().map(|v| v.x).max().unwrap();
+        let min_y = self.vertices.iter().map(|v| v.y).min().unwrap();
+        let max_y = self.vertices.iter().map(|v| v.y).max().unwrap();
+        Rect::with_exact(min_x, min_y, max_x + 1, max_y + 1)
+    }
+
+    /// The polygon's area, by the shoelace formula. Always positive, regardless
+    /// of winding direction.
+    pub fn area(&self) -> f32 {
+        if self.vertices.len() < 3 {
+            return 0.0;
+        }
+        let mut sum = 0.0;
+        let mut j = self.vertices.len() - 1;
+        for i in 0..self.vertices.len() {
+            sum += f64::from(self.vertices[j].x) * f64::from(self.vertices[i].y)
+                - f64::from(self.vertices[i].x) * f64::from(self.vertices[j].y);
+            j = i;
+        }
+        (sum / 2.0).abs() as f32
+    }
+
+    /// Rasterizes the polygon with a scanline sweep, yielding every cell whose
+    /// center falls inside it.
+    pub fn rasterize(&self) -> PolygonRaster {
+        PolygonRaster::new(self)
+    }
+}
+
+/// Scanline rasterization of a [`Polygon`]: iterates every cell whose center
+/// falls inside the polygon, row by row.
+pub struct PolygonRaster {
+    spans: Vec<(i32, i32, i32)>,
+    span: usize,
+    x: i32,
+}
+
+impl PolygonRaster {
+    pub fn new(polygon: &Polygon) -> Self {
+        let mut spans: Vec<(i32, i32, i32)> = Vec::new();
+        if polygon.vertices.len() >= 3 {
+            let bounds = polygon.bounding_rect();
+            for y in bounds.y1..bounds.y2 {
+                // Sample at the row's cell centers, avoiding vertex-on-scanline
+                // degeneracy with integer vertices.
+                let yc = f64::from(y) + 0.5;
+                let mut crossings: Vec<f64> = Vec::new();
+                let mut j = polygon.vertices.len() - 1;
+                for i in 0..polygon.vertices.len() {
+                    let (ix, iy) = (
+                        f64::from(polygon.vertices[i].x),
+                        f64::from(polygon.vertices[i].y),
+                    );
+                    let (jx, jy) = (
+                        f64::from(polygon.vertices[j].x),
+                        f64::from(polygon.vertices[j].y),
+                    );
+                    if (iy > yc) != (jy > yc) {
+                        crossings.push((jx - ix) * (yc - iy) / (jy - iy) + ix);
+                    }
+                    j = i;
+                }
+                crossings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                for pair in crossings.chunks_exact(2) {
+                    // Cells whose center x+0.5 lies between the crossing pair.
+                    let start = (pair[0] - 0.5).ceil() as i32;
+                    let end = (pair[1] - 0.5).floor() as i32;
+                    if start <= end {
+                        spans.push((y, start, end));
+                    }
+                }
+            }
+        }
+        let x = spans.first().map_or(0, |s| s.1);
+        Self { spans, span: 0, x }
+    }
+}
+
+impl Iterator for PolygonRaster {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &(y, _, end) = self.spans.get(self.span)?;
+        let point = Point::new(self.x, y);
+        if self.x < end {
+            self.x += 1;
+        } else {
+            self.span += 1;
+            if let Some(s) = self.spans.get(self.span) {
+                self.x = s.1;
+            }
+        }
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Point, Polygon, Rect};
+
+    fn square() -> Polygon {
+        Polygon::new(vec![
+            Point::new(0, 0),
+            Point::new(4, 0),
+            Point::new(4, 4),
+            Point::new(0, 4),
+        ])
+    }
+
+    #[test]
+    fn contains_uses_even_odd() {
+        let poly = square();
+        assert!(poly.contains(Point::new(2, 2)));
+        assert!(!poly.contains(Point::new(5, 2)));
+        assert!(!poly.contains(Point::new(-1, 2)));
+    }
+
+    #[test]
+    fn bounding_rect_covers_vertices() {
+        let rect = square().bounding_rect();
+        assert_eq!(rect, Rect::with_exact(0, 0, 5, 5));
+        assert!(rect.point_in_rect(Point::new(4, 4)));
+    }
+
+    #[test]
+    fn shoelace_area() {
+        assert!((square().area() - 16.0).abs() < f32::EPSILON);
+        let triangle = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0), Point::new(0, 4)]);
+        assert!((triangle.area() - 8.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn rasterize_fills_a_square() {
+        let points: Vec<Point> = square().rasterize().collect();
+        assert_eq!(points.len(), 16);
+        assert!(points.contains(&Point::new(0, 0)));
+        assert!(points.contains(&Point::new(3, 3)));
+        assert!(!points.contains(&Point::new(4, 4)));
+    }
+
+    #[test]
+    fn degenerate_polygons_are_empty() {
+        let line = Polygon::new(vec![Point::new(0, 0), Point::new(4, 0)]);
+        assert!(!line.contains(Point::new(2, 0)));
+        assert_eq!(line.area(), 0.0);
+        assert_eq!(line.rasterize().count(), 0);
+    }
+}
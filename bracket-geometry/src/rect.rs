@@ -109,6 +109,71 @@ impl Rect {
     pub fn height(&self) -> i32 {
         i32::abs(self.y2 - self.y1)
     }
+
+    // Clips a line segment to the rectangle with Cohen-Sutherland, returning the
+    // clipped endpoints, or None if the segment lies entirely outside. Respects
+    // the exclusive upper bound: both returned points pass point_in_rect.
+    pub fn clip_line(&self, start: Point, end: Point) -> Option<(Point, Point)> {
+        if self.x1 >= self.x2 || self.y1 >= self.y2 {
+            return None;
+        }
+        const LEFT: u8 = 1;
+        const RIGHT: u8 = 2;
+        const BOTTOM: u8 = 4;
+        const TOP: u8 = 8;
+        let (min_x, min_y) = (f64::from(self.x1), f64::from(self.y1));
+        let (max_x, max_y) = (f64::from(self.x2 - 1), f64::from(self.y2 - 1));
+        let out_code = |x: f64, y: f64| {
+            let mut code = 0;
+            if x < min_x {
+                code |= LEFT;
+            } else if x > max_x {
+                code |= RIGHT;
+            }
+            if y < min_y {
+                code |= BOTTOM;
+            } else if y > max_y {
+                code |= TOP;
+            }
+            code
+        };
+        let (mut x0, mut y0) = (f64::from(start.x), f64::from(start.y));
+        let (mut x1, mut y1) = (f64::from(end.x), f64::from(end.y));
+        let (mut code0, mut code1) = (out_code(x0, y0), out_code(x1, y1));
+        loop {
+            if code0 | code1 == 0 {
+                // Both endpoints inside; done.
+                return Some((
+                    Point::new(x0.round() as i32, y0.round() as i32),
+                    Point::new(x1.round() as i32, y1.round() as i32),
+                ));
+            }
+            if code0 & code1 != 0 {
+                // Both endpoints share an outside half-plane; no intersection.
+                return None;
+            }
+            // Move the endpoint that is outside onto the boundary it crosses.
+            let outside = if code0 != 0 { code0 } else { code1 };
+            let (x, y) = if outside & TOP != 0 {
+                (x0 + (x1 - x0) * (max_y - y0) / (y1 - y0), max_y)
+            } else if outside & BOTTOM != 0 {
+                (x0 + (x1 - x0) * (min_y - y0) / (y1 - y0), min_y)
+            } else if outside & RIGHT != 0 {
+                (max_x, y0 + (y1 - y0) * (max_x - x0) / (x1 - x0))
+            } else {
+                (min_x, y0 + (y1 - y0) * (min_x - x0) / (x1 - x0))
+            };
+            if outside == code0 {
+                x0 = x;
+                y0 = y;
+                code0 = out_code(x0, y0);
+            } else {
+                x1 = x;
+                y1 = y;
+                code1 = out_code(x1, y1);
+            }
+        }
+    }
 }
 
 impl ops::Add<Rect> for Rect {
@@ -175,6 +240,29 @@ mod tests {
         assert!(!points.contains(&Point::new(1, 1)));
     }
 
+    #[test]
+    fn test_clip_line() {
+        let r1 = Rect::with_size(0, 0, 10, 10);
+        // Fully inside: unchanged.
+        assert_eq!(
+            r1.clip_line(Point::new(1, 1), Point::new(8, 8)),
+            Some((Point::new(1, 1), Point::new(8, 8)))
+        );
+        // Crossing horizontally: trimmed to the covered cells.
+        assert_eq!(
+            r1.clip_line(Point::new(-5, 5), Point::new(15, 5)),
+            Some((Point::new(0, 5), Point::new(9, 5)))
+        );
+        // Entirely outside.
+        assert_eq!(r1.clip_line(Point::new(-5, -1), Point::new(15, -1)), None);
+        // Clipped endpoints land inside the rectangle.
+        let (start, end) = r1
+            .clip_line(Point::new(-3, 2), Point::new(12, 7))
+            .unwrap();
+        assert!(r1.point_in_rect(start));
+        assert!(r1.point_in_rect(end));
+    }
+
     #[test]
     fn test_rect_callback() {
         use std::collections::HashSet;
@@ -0,0 +1,189 @@
+use crate::prelude::Point3;
+use std::convert::TryInto;
+
+/// An integer axis-aligned bounding box, the 3D counterpart of `Rect`.
+/// Exclusive of the upper bound on every axis, like `Rect`.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub struct Rect3D {
+    pub x1: i32,
+    pub x2: i32,
+    pub y1: i32,
+    pub y2: i32,
+    pub z1: i32,
+    pub z2: i32,
+}
+
+#[cfg(feature = "specs")]
+impl specs::prelude::Component for Rect3D {
+    type Storage = specs::prelude::VecStorage<Self>;
+}
+
+impl Default for Rect3D {
+    fn default() -> Rect3D {
+        Rect3D::zero()
+    }
+}
+
+impl Rect3D {
+    // Create a new box, specifying X/Y/Z Width/Height/Depth
+    pub fn with_size<T>(x: T, y: T, z: T, w: T, h: T, d: T) -> Rect3D
+    where
+        T: TryInto<i32>,
+    {
+        let x_i32: i32 = x.try_into().ok().unwrap();
+        let y_i32: i32 = y.try_into().ok().unwrap();
+        let z_i32: i32 = z.try_into().ok().unwrap();
+        Rect3D {
+            x1: x_i32,
+            y1: y_i32,
+            z1: z_i32,
+            x2: x_i32 + w.try_into().ok().unwrap(),
+            y2: y_i32 + h.try_into().ok().unwrap(),
+            z2: z_i32 + d.try_into().ok().unwrap(),
+        }
+    }
+
+    // Create a new box, specifying exact dimensions
+    pub fn with_exact<T>(x1: T, y1: T, z1: T, x2: T, y2: T, z2: T) -> Rect3D
+    where
+        T: TryInto<i32>,
+    {
+        Rect3D {
+            x1: x1.try_into().ok().unwrap(),
+            y1: y1.try_into().ok().unwrap(),
+            z1: z1.try_into().ok().unwrap(),
+            x2: x2.try_into().ok().unwrap(),
+            y2: y2.try_into().ok().unwrap(),
+            z2: z2.try_into().ok().unwrap(),
+        }
+    }
+
+    // Creates a zero box
+    pub fn zero() -> Rect3D {
+        Rect3D {
+            x1: 0,
+            y1: 0,
+            z1: 0,
+            x2: 0,
+            y2: 0,
+            z2: 0,
+        }
+    }
+
+    // Returns true if this overlaps with other
+    pub fn intersect(&self, other: &Rect3D) -> bool {
+        self.x1 <= other.x2
+            && self.x2 >= other.x1
+            && self.y1 <= other.y2
+            && self.y2 >= other.y1
+            && self.z1 <= other.z2
+            && self.z2 >= other.z1
+    }
+
+    // Returns the overlapping region of two boxes, or None if they don't overlap
+    pub fn intersection(&self, other: &Rect3D) -> Option<Rect3D> {
+        let result = Rect3D {
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+            z1: self.z1.max(other.z1),
+            x2: self.x2.min(other.x2),
+            y2: self.y2.min(other.y2),
+            z2: self.z2.min(other.z2),
+        };
+        if result.x1 >= result.x2 || result.y1 >= result.y2 || result.z1 >= result.z2 {
+            return None;
+        }
+        Some(result)
+    }
+
+    // Returns the center of the box
+    pub fn center(&self) -> Point3 {
+        Point3::new(
+            (self.x1 + self.x2) / 2,
+            (self.y1 + self.y2) / 2,
+            (self.z1 + self.z2) / 2,
+        )
+    }
+
+    // Returns true if a point is inside the box
+    pub fn point_in_rect(&self, point: Point3) -> bool {
+        point.x >= self.x1
+            && point.x < self.x2
+            && point.y >= self.y1
+            && point.y < self.y2
+            && point.z >= self.z1
+            && point.z < self.z2
+    }
+
+    // Calls a function for each x/y/z point in the box
+    pub fn for_each<F>(&self, mut f: F)
+    where
+        F: FnMut(Point3),
+    {
+        for z in self.z1..self.z2 {
+            for y in self.y1..self.y2 {
+                for x in self.x1..self.x2 {
+                    f(Point3::new(x, y, z));
+                }
+            }
+        }
+    }
+
+    // Returns the box's width
+    pub fn width(&self) -> i32 {
+        i32::abs(self.x2 - self.x1)
+    }
+
+    // Returns the box's height
+    pub fn height(&self) -> i32 {
+        i32::abs(self.y2 - self.y1)
+    }
+
+    // Returns the box's depth
+    pub fn depth(&self) -> i32 {
+        i32::abs(self.z2 - self.z1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Point3, Rect3D};
+
+    #[test]
+    fn test_dimensions() {
+        let b = Rect3D::with_size(0, 0, 0, 10, 10, 3);
+        assert!(b.width() == 10);
+        assert!(b.height() == 10);
+        assert!(b.depth() == 3);
+    }
+
+    #[test]
+    fn test_intersect() {
+        let b1 = Rect3D::with_size(0, 0, 0, 10, 10, 2);
+        let b2 = Rect3D::with_size(5, 5, 1, 10, 10, 2);
+        let b3 = Rect3D::with_size(100, 100, 100, 5, 5, 5);
+        assert!(b1.intersect(&b2));
+        assert!(!b1.intersect(&b3));
+        assert_eq!(
+            b1.intersection(&b2),
+            Some(Rect3D::with_exact(5, 5, 1, 10, 10, 2))
+        );
+        assert!(b1.intersection(&b3).is_none());
+    }
+
+    #[test]
+    fn test_point_in_rect() {
+        let b = Rect3D::with_size(0, 0, 0, 10, 10, 2);
+        assert!(b.point_in_rect(Point3::new(5, 5, 1)));
+        assert!(!b.point_in_rect(Point3::new(5, 5, 2)));
+    }
+
+    #[test]
+    fn test_rect_callback() {
+        let b = Rect3D::with_size(0, 0, 0, 2, 2, 2);
+        let mut count = 0;
+        b.for_each(|_| count += 1);
+        assert_eq!(count, 8);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::prelude::PointF;
+use crate::prelude::{PointF, Rect};
 use std::convert::TryInto;
 use std::ops;
 
@@ -88,6 +88,162 @@ impl RectF {
     pub fn height(&self) -> f32 {
         f32::abs(self.y2 - self.y1)
     }
+
+    // Returns true if the other rectangle is entirely inside this one
+    pub fn contains_rect(&self, other: &RectF) -> bool {
+        other.x1 >= self.x1 && other.x2 <= self.x2 && other.y1 >= self.y1 && other.y2 <= self.y2
+    }
+
+    // Returns the overlapping region of two rectangles, or None if they don't overlap
+    pub fn intersection(&self, other: &RectF) -> Option<RectF> {
+        if !self.intersect(other) {
+            return None;
+        }
+        Some(RectF {
+            x1: self.x1.max(other.x1),
+            y1: self.y1.max(other.y1),
+            x2: self.x2.min(other.x2),
+            y2: self.y2.min(other.y2),
+        })
+    }
+
+    // Returns the smallest rectangle containing both rectangles
+    pub fn union(&self, other: &RectF) -> RectF {
+        RectF {
+            x1: self.x1.min(other.x1),
+            y1: self.y1.min(other.y1),
+            x2: self.x2.max(other.x2),
+            y2: self.y2.max(other.y2),
+        }
+    }
+
+    // Returns the rectangle grown by delta on every side; a negative delta shrinks it
+    pub fn inflate(&self, delta: f32) -> RectF {
+        RectF {
+            x1: self.x1 - delta,
+            y1: self.y1 - delta,
+            x2: self.x2 + delta,
+            y2: self.y2 + delta,
+        }
+    }
+
+    // Clamps a point to lie within the rectangle
+    pub fn clamp_point(&self, point: PointF) -> PointF {
+        PointF {
+            x: point.x.max(self.x1).min(self.x2),
+            y: point.y.max(self.y1).min(self.y2),
+        }
+    }
+
+    // Slides the rectangle so it lies within bounds, preserving its size. If it
+    // is larger than bounds on an axis, it is pinned to the lower edge. Useful
+    // for keeping a camera viewport on the map.
+    pub fn clamped_within(&self, bounds: &RectF) -> RectF {
+        let mut result = *self;
+        if result.x2 > bounds.x2 {
+            let shift = result.x2 - bounds.x2;
+            result.x1 -= shift;
+            result.x2 -= shift;
+        }
+        if result.x1 < bounds.x1 {
+            let shift = bounds.x1 - result.x1;
+            result.x1 += shift;
+            result.x2 += shift;
+        }
+        if result.y2 > bounds.y2 {
+            let shift = result.y2 - bounds.y2;
+            result.y1 -= shift;
+            result.y2 -= shift;
+        }
+        if result.y1 < bounds.y1 {
+            let shift = bounds.y1 - result.y1;
+            result.y1 += shift;
+            result.y2 += shift;
+        }
+        result
+    }
+
+    // The smallest integer rectangle containing this one: floors the lower
+    // bound, ceilings the upper
+    pub fn enclosing_rect(&self) -> Rect {
+        Rect {
+            x1: self.x1.floor() as i32,
+            y1: self.y1.floor() as i32,
+            x2: self.x2.ceil() as i32,
+            y2: self.y2.ceil() as i32,
+        }
+    }
+
+    // The largest integer rectangle contained by this one: ceilings the lower
+    // bound, floors the upper
+    pub fn enclosed_rect(&self) -> Rect {
+        Rect {
+            x1: self.x1.ceil() as i32,
+            y1: self.y1.ceil() as i32,
+            x2: self.x2.floor() as i32,
+            y2: self.y2.floor() as i32,
+        }
+    }
+
+    // Clips a line segment to the rectangle with Cohen-Sutherland, returning the
+    // clipped endpoints, or None if the segment lies entirely outside. The
+    // boundary itself counts as inside.
+    pub fn clip_line(&self, start: PointF, end: PointF) -> Option<(PointF, PointF)> {
+        if self.x1 > self.x2 || self.y1 > self.y2 {
+            return None;
+        }
+        const LEFT: u8 = 1;
+        const RIGHT: u8 = 2;
+        const BOTTOM: u8 = 4;
+        const TOP: u8 = 8;
+        let out_code = |x: f32, y: f32| {
+            let mut code = 0;
+            if x < self.x1 {
+                code |= LEFT;
+            } else if x > self.x2 {
+                code |= RIGHT;
+            }
+            if y < self.y1 {
+                code |= BOTTOM;
+            } else if y > self.y2 {
+                code |= TOP;
+            }
+            code
+        };
+        let (mut x0, mut y0) = (start.x, start.y);
+        let (mut x1, mut y1) = (end.x, end.y);
+        let (mut code0, mut code1) = (out_code(x0, y0), out_code(x1, y1));
+        loop {
+            if code0 | code1 == 0 {
+                // Both endpoints inside; done.
+                return Some((PointF::new(x0, y0), PointF::new(x1, y1)));
+            }
+            if code0 & code1 != 0 {
+                // Both endpoints share an outside half-plane; no intersection.
+                return None;
+            }
+            // Move the endpoint that is outside onto the boundary it crosses.
+            let outside = if code0 != 0 { code0 } else { code1 };
+            let (x, y) = if outside & TOP != 0 {
+                (x0 + (x1 - x0) * (self.y2 - y0) / (y1 - y0), self.y2)
+            } else if outside & BOTTOM != 0 {
+                (x0 + (x1 - x0) * (self.y1 - y0) / (y1 - y0), self.y1)
+            } else if outside & RIGHT != 0 {
+                (self.x2, y0 + (y1 - y0) * (self.x2 - x0) / (x1 - x0))
+            } else {
+                (self.x1, y0 + (y1 - y0) * (self.x1 - x0) / (x1 - x0))
+            };
+            if outside == code0 {
+                x0 = x;
+                y0 = y;
+                code0 = out_code(x0, y0);
+            } else {
+                x1 = x;
+                y1 = y;
+                code1 = out_code(x1, y1);
+            }
+        }
+    }
 }
 
 impl ops::Add<RectF> for RectF {
@@ -102,3 +258,67 @@ impl ops::Add<RectF> for RectF {
         self
     }
 }
+
+impl From<Rect> for RectF {
+    fn from(rect: Rect) -> Self {
+        RectF {
+            x1: rect.x1 as f32,
+            y1: rect.y1 as f32,
+            x2: rect.x2 as f32,
+            y2: rect.y2 as f32,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{PointF, Rect, RectF};
+
+    #[test]
+    fn test_intersection() {
+        let r1 = RectF::with_size(0.0, 0.0, 10.0, 10.0);
+        let r2 = RectF::with_size(5.0, 5.0, 10.0, 10.0);
+        let overlap = r1.intersection(&r2).unwrap();
+        assert_eq!(overlap, RectF::with_exact(5.0, 5.0, 10.0, 10.0));
+        let r3 = RectF::with_size(100.0, 100.0, 5.0, 5.0);
+        assert!(r1.intersection(&r3).is_none());
+    }
+
+    #[test]
+    fn test_union() {
+        let r1 = RectF::with_size(0.0, 0.0, 5.0, 5.0);
+        let r2 = RectF::with_size(10.0, 10.0, 5.0, 5.0);
+        assert_eq!(r1.union(&r2), RectF::with_exact(0.0, 0.0, 15.0, 15.0));
+    }
+
+    #[test]
+    fn test_inflate_and_contains() {
+        let r1 = RectF::with_size(2.0, 2.0, 4.0, 4.0);
+        let grown = r1.inflate(1.0);
+        assert_eq!(grown, RectF::with_exact(1.0, 1.0, 7.0, 7.0));
+        assert!(grown.contains_rect(&r1));
+        assert!(!r1.contains_rect(&grown));
+        assert_eq!(r1.inflate(-2.0).width(), 0.0);
+    }
+
+    #[test]
+    fn test_clamping() {
+        let bounds = RectF::with_size(0.0, 0.0, 10.0, 10.0);
+        let clamped = bounds.clamp_point(PointF { x: -3.0, y: 15.0 });
+        assert_eq!(clamped.x, 0.0);
+        assert_eq!(clamped.y, 10.0);
+        // A camera viewport slides back inside the map bounds.
+        let camera = RectF::with_size(7.0, -2.0, 5.0, 5.0);
+        let clamped = camera.clamped_within(&bounds);
+        assert_eq!(clamped, RectF::with_exact(5.0, 0.0, 10.0, 5.0));
+    }
+
+    #[test]
+    fn test_conversion_rounding() {
+        let r = RectF::with_exact(0.25, 0.25, 4.75, 4.75);
+        assert_eq!(r.enclosing_rect(), Rect::with_exact(0, 0, 5, 5));
+        assert_eq!(r.enclosed_rect(), Rect::with_exact(1, 1, 4, 4));
+        let round_trip: RectF = Rect::with_exact(1, 2, 3, 4).into();
+        assert_eq!(round_trip, RectF::with_exact(1.0, 2.0, 3.0, 4.0));
+    }
+}
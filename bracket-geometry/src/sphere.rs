@@ -0,0 +1,139 @@
+use crate::prelude::Point3;
+
+/// Returns the even-odd ring bounds used by the circle and sphere fills:
+/// radius `r` covers cells with squared distance up to `r*(r+1)`, so
+/// successive radii nest without gaps.
+fn ring_limit(radius: i32) -> i32 {
+    radius * (radius + 1)
+}
+
+/// A filled sphere: yields every cell inside the sphere exactly once. Uses the
+/// same even-odd radius convention as `BresenhamCircleFilled` - cells with
+/// `dx*dx + dy*dy + dz*dz <= r*(r+1)` - so a sphere's z-slices match the 2D
+/// filled circles of the corresponding radii.
+pub struct SphereFill {
+    points: Vec<Point3>,
+    index: usize,
+}
+
+impl SphereFill {
+    #[allow(dead_code)]
+    pub fn new(center: Point3, radius: i32) -> Self {
+        let radius = radius.max(0);
+        let limit = ring_limit(radius);
+        let mut points = Vec::new();
+        for z in -radius..=radius {
+            for y in -radius..=radius {
+                for x in -radius..=radius {
+                    if x * x + y * y + z * z <= limit {
+                        points.push(Point3::new(center.x + x, center.y + y, center.z + z));
+                    }
+                }
+            }
+        }
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for SphereFill {
+    type Item = Point3;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+/// A sphere surface: yields the one-cell-thick shell of cells whose squared
+/// distance from the center falls in the even-odd ring of the given radius,
+/// `r*(r-1) < dx*dx + dy*dy + dz*dz <= r*(r+1)`. The shells of successive
+/// radii tile space with no gaps or overlaps.
+pub struct SphereSurface {
+    points: Vec<Point3>,
+    index: usize,
+}
+
+impl SphereSurface {
+    #[allow(dead_code)]
+    pub fn new(center: Point3, radius: i32) -> Self {
+        let radius = radius.max(0);
+        let inner = ring_limit(radius - 1);
+        let outer = ring_limit(radius);
+        let mut points = Vec::new();
+        for z in -radius..=radius {
+            for y in -radius..=radius {
+                for x in -radius..=radius {
+                    let d_sq = x * x + y * y + z * z;
+                    if d_sq > inner && d_sq <= outer {
+                        points.push(Point3::new(center.x + x, center.y + y, center.z + z));
+                    }
+                }
+            }
+        }
+        if points.is_empty() {
+            // Radius 0 is just the center cell.
+            points.push(center);
+        }
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for SphereSurface {
+    type Item = Point3;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Point3, SphereFill, SphereSurface};
+    use std::collections::HashSet;
+
+    #[test]
+    fn sphere_fill_covers_its_extremes_once() {
+        let fill: Vec<Point3> = SphereFill::new(Point3::new(0, 0, 0), 3).collect();
+        for extreme in [
+            Point3::new(3, 0, 0),
+            Point3::new(-3, 0, 0),
+            Point3::new(0, 3, 0),
+            Point3::new(0, 0, -3),
+        ] {
+            assert!(fill.contains(&extreme));
+        }
+        assert!(!fill.contains(&Point3::new(3, 3, 3)));
+        let unique: HashSet<Point3> = fill.iter().copied().collect();
+        assert_eq!(fill.len(), unique.len());
+    }
+
+    #[test]
+    fn sphere_surfaces_tile_the_fill() {
+        // The shells of radii 0..=3 together are exactly the radius-3 fill.
+        let mut shells: Vec<Point3> = Vec::new();
+        for radius in 0..=3 {
+            shells.extend(SphereSurface::new(Point3::new(0, 0, 0), radius));
+        }
+        let unique: HashSet<Point3> = shells.iter().copied().collect();
+        assert_eq!(shells.len(), unique.len());
+        let fill: HashSet<Point3> = SphereFill::new(Point3::new(0, 0, 0), 3).collect();
+        assert_eq!(unique, fill);
+    }
+
+    #[test]
+    fn sphere_radius0() {
+        assert_eq!(
+            SphereFill::new(Point3::new(5, 5, 5), 0).collect::<Vec<Point3>>(),
+            vec![Point3::new(5, 5, 5)]
+        );
+        assert_eq!(
+            SphereSurface::new(Point3::new(5, 5, 5), 0).collect::<Vec<Point3>>(),
+            vec![Point3::new(5, 5, 5)]
+        );
+    }
+}
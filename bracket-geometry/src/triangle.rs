@@ -0,0 +1,143 @@
+use crate::prelude::{Bresenham, Point, Polygon, PolygonRaster, Rect};
+
+/// A triangle, stored as its three corner vertices. Supports point-in-triangle
+/// tests, area, scanline rasterization into covered cells, and an outline
+/// walk - useful for cone attack templates and low-poly terrain rasterization.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl Triangle {
+    /// Creates a triangle from its three corners, in either winding order.
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        Self { a, b, c }
+    }
+
+    fn as_polygon(&self) -> Polygon {
+        Polygon::new([self.a, self.b, self.c])
+    }
+
+    /// Returns true if a point is inside the triangle.
+    pub fn contains(&self, point: Point) -> bool {
+        self.as_polygon().contains(point)
+    }
+
+    /// Returns the smallest rectangle containing every corner. Exclusive of the
+    /// upper bound, as rectangles are.
+    pub fn bounding_rect(&self) -> Rect {
+        self.as_polygon().bounding_rect()
+    }
+
+    /// The triangle's area. Always positive, regardless of winding direction.
+    pub fn area(&self) -> f32 {
+        self.as_polygon().area()
+    }
+
+    /// Rasterizes the triangle with a scanline sweep, yielding every cell whose
+    /// center falls inside it.
+    pub fn rasterize(&self) -> PolygonRaster {
+        self.as_polygon().rasterize()
+    }
+
+    /// Walks the triangle's outline: the three Bresenham edges in order, with
+    /// the shared corner cells emitted once.
+    pub fn outline(&self) -> TriangleOutline {
+        TriangleOutline::new(self)
+    }
+}
+
+/// Iterates the cells along a [`Triangle`]'s three edges, corner to corner,
+/// without repeating the corners where the edges join.
+pub struct TriangleOutline {
+    points: Vec<Point>,
+    index: usize,
+}
+
+impl TriangleOutline {
+    #[allow(dead_code)]
+    pub fn new(triangle: &Triangle) -> Self {
+        let mut points: Vec<Point> = Vec::new();
+        for (start, end) in [
+            (triangle.a, triangle.b),
+            (triangle.b, triangle.c),
+            (triangle.c, triangle.a),
+        ] {
+            // Bresenham excludes its endpoint, so each edge contributes its own
+            // starting corner and the next edge picks up where it stopped.
+            for cell in Bresenham::new(start, end) {
+                if points.first() != Some(&cell) {
+                    points.push(cell);
+                }
+            }
+        }
+        if points.is_empty() {
+            // All three corners coincide.
+            points.push(triangle.a);
+        }
+        Self { points, index: 0 }
+    }
+}
+
+impl Iterator for TriangleOutline {
+    type Item = Point;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.points.get(self.index).copied();
+        self.index += 1;
+        point
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{Point, Triangle};
+
+    fn right_triangle() -> Triangle {
+        Triangle::new(Point::new(0, 0), Point::new(6, 0), Point::new(0, 6))
+    }
+
+    #[test]
+    fn contains_and_area() {
+        let tri = right_triangle();
+        assert!(tri.contains(Point::new(1, 1)));
+        assert!(!tri.contains(Point::new(5, 5)));
+        assert!((tri.area() - 18.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn rasterize_stays_inside_the_bounds() {
+        let tri = right_triangle();
+        let bounds = tri.bounding_rect();
+        let cells: Vec<Point> = tri.rasterize().collect();
+        assert!(!cells.is_empty());
+        for cell in &cells {
+            assert!(bounds.point_in_rect(*cell));
+        }
+        assert!(cells.contains(&Point::new(1, 1)));
+        assert!(!cells.contains(&Point::new(5, 5)));
+    }
+
+    #[test]
+    fn outline_visits_every_corner_once() {
+        let tri = right_triangle();
+        let outline: Vec<Point> = tri.outline().collect();
+        for corner in [tri.a, tri.b, tri.c] {
+            assert_eq!(outline.iter().filter(|p| **p == corner).count(), 1);
+        }
+        let unique: std::collections::HashSet<Point> = outline.iter().copied().collect();
+        assert_eq!(outline.len(), unique.len());
+    }
+
+    #[test]
+    fn degenerate_triangles() {
+        let dot = Triangle::new(Point::new(2, 2), Point::new(2, 2), Point::new(2, 2));
+        assert_eq!(dot.outline().collect::<Vec<Point>>(), vec![Point::new(2, 2)]);
+        let sliver = Triangle::new(Point::new(0, 0), Point::new(4, 0), Point::new(2, 0));
+        assert_eq!(sliver.rasterize().count(), 0);
+    }
+}
@@ -14,6 +14,10 @@ pub enum NoiseType {
     PerlinFractal,
     Simplex,
     SimplexFractal,
+    OpenSimplex2,
+    OpenSimplex2Fractal,
+    OpenSimplex2S,
+    OpenSimplex2SFractal,
     Cellular,
     WhiteNoise,
     Cubic,
@@ -42,6 +46,7 @@ pub enum CellularDistanceFunction {
     Euclidean,
     Manhattan,
     Natural,
+    Chebyshev,
 }
 
 #[derive(PartialEq, Copy, Clone)]
@@ -56,6 +61,14 @@ pub enum CellularReturnType {
     Distance2Div,
 }
 
+#[derive(PartialEq, Copy, Clone)]
+/// Domain warp to apply to coordinates before the noise is sampled
+pub enum DomainWarpType {
+    None,
+    Gradient,
+    GradientFractal,
+}
+
 pub struct FastNoise {
     rng: RandomNumberGenerator,
     seed: u64,
@@ -70,10 +83,17 @@ pub struct FastNoise {
     cellular_return_type: CellularReturnType,
     cellular_distance_index: (i32, i32),
     cellular_jitter: f32,
+    cellular_custom_distance: Option<fn(f32, f32, f32) -> f32>,
     gradient_perturb_amp: f32,
+    domain_warp_type: DomainWarpType,
+    domain_warp_amp: f32,
+    domain_warp_frequency: f32,
+    domain_warp_octaves: i32,
+    tile_period: (i32, i32),
     perm: Vec<u8>,
     perm12: Vec<u8>,
     fractal_bounding: f32,
+    domain_warp_bounding: f32,
 }
 
 // Constants that used to be at the top
@@ -1672,6 +1692,13 @@ const G3: f32 = 1.0 / 6.0;
 const SQRT3: f32 = 1.7320508075688772935274463415059;
 const F2: f32 = 0.5 * (SQRT3 - 1.0);
 const G2: f32 = (3.0 - SQRT3) / 6.0;
+// OpenSimplex2 kernel radii (squared) and empirically-fitted output scaling.
+const OS2S_FALLOFF_2D: f32 = 2.0 / 3.0;
+const OS2_FALLOFF_3D: f32 = 0.6;
+const OS2S_FALLOFF_3D: f32 = 0.75;
+const OS2S_BOUNDING_2D: f32 = 13.5;
+const OS2_BOUNDING_3D: f32 = 32.5;
+const OS2S_BOUNDING_3D: f32 = 9.0;
 #[allow(dead_code)]
 #[allow(clippy::excessive_precision)]
 #[allow(clippy::unreadable_literal)]
@@ -1713,6 +1740,22 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + t * (b - a)
 }
 
+fn wrap_tile(coord: i32, period: i32) -> i32 {
+    if period > 0 {
+        coord.rem_euclid(period)
+    } else {
+        coord
+    }
+}
+
+// Maps a coordinate onto a circle whose circumference is `period` lattice cells,
+// so that travelling `period` along the axis returns to the starting point.
+fn tile_circle(coord: f32, period: i32) -> (f32, f32) {
+    let radius = period as f32 / std::f32::consts::TAU;
+    let angle = coord / radius;
+    (angle.cos() * radius, angle.sin() * radius)
+}
+
 fn interp_hermite_func(t: f32) -> f32 {
     t * t * (3. - 2. * t)
 }
@@ -1746,13 +1789,21 @@ impl FastNoise {
             cellular_return_type: CellularReturnType::CellValue,
             cellular_distance_index: (0, 1),
             cellular_jitter: 0.45,
+            cellular_custom_distance: None,
             gradient_perturb_amp: 1.0,
+            domain_warp_type: DomainWarpType::None,
+            domain_warp_amp: 1.0,
+            domain_warp_frequency: 0.01,
+            domain_warp_octaves: 3,
+            tile_period: (0, 0),
             perm: vec![0; 512],
             perm12: vec![0; 512],
             fractal_bounding: 0.0,
+            domain_warp_bounding: 0.0,
         };
         noise.set_seed(1337);
         noise.calculate_fractal_bounding();
+        noise.calculate_domain_warp_bounding();
         noise
     }
 
@@ -1772,13 +1823,21 @@ impl FastNoise {
             cellular_return_type: CellularReturnType::CellValue,
             cellular_distance_index: (0, 1),
             cellular_jitter: 0.45,
+            cellular_custom_distance: None,
             gradient_perturb_amp: 1.0,
+            domain_warp_type: DomainWarpType::None,
+            domain_warp_amp: 1.0,
+            domain_warp_frequency: 0.01,
+            domain_warp_octaves: 3,
+            tile_period: (0, 0),
             perm: vec![0; 512],
             perm12: vec![0; 512],
             fractal_bounding: 0.0,
+            domain_warp_bounding: 0.0,
         };
         noise.set_seed(seed);
         noise.calculate_fractal_bounding();
+        noise.calculate_domain_warp_bounding();
         noise
     }
 
@@ -1840,6 +1899,7 @@ impl FastNoise {
     pub fn set_fractal_gain(&mut self, gain: f32) {
         self.gain = gain;
         self.calculate_fractal_bounding();
+        self.calculate_domain_warp_bounding();
     }
     pub fn get_fractal_gain(&self) -> f32 {
         self.gain
@@ -1874,12 +1934,58 @@ impl FastNoise {
     pub fn get_cellular_jitter(&self) -> f32 {
         self.cellular_jitter
     }
+    /// Sets a custom cellular distance metric, overriding the configured
+    /// `CellularDistanceFunction`. The function receives the x/y/z components of
+    /// the offset to a candidate feature point (z is 0.0 in 2D) and returns its
+    /// distance measure. Pass `None` to return to the built-in metrics.
+    pub fn set_cellular_custom_distance(&mut self, metric: Option<fn(f32, f32, f32) -> f32>) {
+        self.cellular_custom_distance = metric
+    }
+    pub fn get_cellular_custom_distance(&self) -> Option<fn(f32, f32, f32) -> f32> {
+        self.cellular_custom_distance
+    }
     pub fn set_gradient_perterb_amp(&mut self, gradient_perturb_amp: f32) {
         self.gradient_perturb_amp = gradient_perturb_amp
     }
     pub fn get_gradient_perterb_amp(&self) -> f32 {
         self.gradient_perturb_amp
     }
+    pub fn set_domain_warp_type(&mut self, domain_warp_type: DomainWarpType) {
+        self.domain_warp_type = domain_warp_type
+    }
+    pub fn get_domain_warp_type(&self) -> DomainWarpType {
+        self.domain_warp_type
+    }
+    pub fn set_domain_warp_amp(&mut self, domain_warp_amp: f32) {
+        self.domain_warp_amp = domain_warp_amp
+    }
+    pub fn get_domain_warp_amp(&self) -> f32 {
+        self.domain_warp_amp
+    }
+    pub fn set_domain_warp_frequency(&mut self, frequency: f32) {
+        self.domain_warp_frequency = frequency
+    }
+    pub fn get_domain_warp_frequency(&self) -> f32 {
+        self.domain_warp_frequency
+    }
+    pub fn set_domain_warp_octaves(&mut self, octaves: i32) {
+        self.domain_warp_octaves = octaves;
+        self.calculate_domain_warp_bounding();
+    }
+    pub fn get_domain_warp_octaves(&self) -> i32 {
+        self.domain_warp_octaves
+    }
+    /// Sets the tiling period per axis, measured in noise lattice cells; the noise then
+    /// repeats every `period / frequency` world units along that axis. Zero disables
+    /// tiling on an axis. Applies to 2D value, perlin and simplex noise, plain and
+    /// fractal (fractals tile cleanly for integer lacunarity): value and perlin wrap
+    /// the lattice, simplex samples a circle in higher-dimensional noise.
+    pub fn set_tile_period(&mut self, x: i32, y: i32) {
+        self.tile_period = (i32::max(x, 0), i32::max(y, 0))
+    }
+    pub fn get_tile_period(&self) -> (i32, i32) {
+        self.tile_period
+    }
 
     fn calculate_fractal_bounding(&mut self) {
         let mut amp: f32 = self.gain;
@@ -1891,6 +1997,16 @@ impl FastNoise {
         self.fractal_bounding = 1.0 / amp_fractal;
     }
 
+    fn calculate_domain_warp_bounding(&mut self) {
+        let mut amp: f32 = self.gain;
+        let mut amp_fractal: f32 = 1.0;
+        for _ in 0..self.domain_warp_octaves {
+            amp_fractal += amp;
+            amp *= self.gain;
+        }
+        self.domain_warp_bounding = 1.0 / amp_fractal;
+    }
+
     pub fn set_cellular_distance_indices(&mut self, i1: i32, i2: i32) {
         self.cellular_distance_index.0 = i32::min(i1, i2);
         self.cellular_distance_index.1 = i32::max(i1, i2);
@@ -1964,7 +2080,6 @@ impl FastNoise {
         (n * n * n * Wrapping(60493i32)).0 as f32 / 2147483648.0
     }
 
-    #[allow(dead_code)]
     #[allow(clippy::many_single_char_names)]
     fn val_coord_4d(&self, seed: i32, x: i32, y: i32, z: i32, w: i32) -> f32 {
         use std::num::Wrapping;
@@ -1984,6 +2099,10 @@ impl FastNoise {
     fn val_coord_3d_fast(&self, offset: u8, x: i32, y: i32, z: i32) -> f32 {
         VAL_LUT[self.index3d_256(offset, x, y, z) as usize]
     }
+    #[allow(clippy::many_single_char_names)]
+    fn val_coord_4d_fast(&self, offset: u8, x: i32, y: i32, z: i32, w: i32) -> f32 {
+        VAL_LUT[self.index4d_256(offset, x, y, z, w) as usize]
+    }
 
     fn grad_coord_2d(&self, offset: u8, x: i32, y: i32, xd: f32, yd: f32) -> f32 {
         let lut_pos = self.index2d_12(offset, x, y) as usize;
@@ -1996,7 +2115,6 @@ impl FastNoise {
         xd * GRAD_X[lut_pos] + yd * GRAD_Y[lut_pos] + zd * GRAD_Z[lut_pos]
     }
 
-    #[allow(dead_code)]
     #[allow(clippy::too_many_arguments)]
     fn grad_coord_4d(
         &self,
@@ -2018,6 +2136,7 @@ impl FastNoise {
     }
 
     pub fn get_noise3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        self.apply_domain_warp3d(&mut x, &mut y, &mut z);
         x *= self.frequency;
         y *= self.frequency;
         z *= self.frequency;
@@ -2041,6 +2160,20 @@ impl FastNoise {
                 FractalType::Billow => self.single_simplex_fractal_billow3d(x, y, z),
                 FractalType::RigidMulti => self.single_simplex_fractal_rigid_multi3d(x, y, z),
             },
+            NoiseType::OpenSimplex2 => self.single_open_simplex2_3d(0, x, y, z),
+            NoiseType::OpenSimplex2Fractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2_fractal_fbm3d(x, y, z),
+                FractalType::Billow => self.single_open_simplex2_fractal_billow3d(x, y, z),
+                FractalType::RigidMulti => self.single_open_simplex2_fractal_rigid_multi3d(x, y, z),
+            },
+            NoiseType::OpenSimplex2S => self.single_open_simplex2s_3d(0, x, y, z),
+            NoiseType::OpenSimplex2SFractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2s_fractal_fbm3d(x, y, z),
+                FractalType::Billow => self.single_open_simplex2s_fractal_billow3d(x, y, z),
+                FractalType::RigidMulti => {
+                    self.single_open_simplex2s_fractal_rigid_multi3d(x, y, z)
+                }
+            },
             NoiseType::Cellular => match self.cellular_return_type {
                 CellularReturnType::CellValue => self.single_cellular3d(x, y, z),
                 CellularReturnType::Distance => self.single_cellular3d(x, y, z),
@@ -2057,6 +2190,7 @@ impl FastNoise {
     }
 
     pub fn get_noise(&self, mut x: f32, mut y: f32) -> f32 {
+        self.apply_domain_warp(&mut x, &mut y);
         x *= self.frequency;
         y *= self.frequency;
 
@@ -2079,6 +2213,18 @@ impl FastNoise {
                 FractalType::Billow => self.single_simplex_fractal_billow(x, y),
                 FractalType::RigidMulti => self.single_simplex_fractal_rigid_multi(x, y),
             },
+            NoiseType::OpenSimplex2 => self.single_open_simplex2(0, x, y),
+            NoiseType::OpenSimplex2Fractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2_fractal_fbm(x, y),
+                FractalType::Billow => self.single_open_simplex2_fractal_billow(x, y),
+                FractalType::RigidMulti => self.single_open_simplex2_fractal_rigid_multi(x, y),
+            },
+            NoiseType::OpenSimplex2S => self.single_open_simplex2s(0, x, y),
+            NoiseType::OpenSimplex2SFractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2s_fractal_fbm(x, y),
+                FractalType::Billow => self.single_open_simplex2s_fractal_billow(x, y),
+                FractalType::RigidMulti => self.single_open_simplex2s_fractal_rigid_multi(x, y),
+            },
             NoiseType::Cellular => match self.cellular_return_type {
                 CellularReturnType::CellValue => self.single_cellular(x, y),
                 CellularReturnType::Distance => self.single_cellular(x, y),
@@ -2094,7 +2240,108 @@ impl FastNoise {
         }
     }
 
-    #[allow(dead_code)]
+    /// As `get_noise3d`, with a fourth dimension - typically time, for animating
+    /// 3D noise. Supported for value, perlin, simplex and white noise, plain and
+    /// fractal; cellular, cubic and OpenSimplex2 noise have no 4D implementation
+    /// and sample their 3D variant at `(x, y, z)` instead. Domain warping is not
+    /// applied.
+    pub fn get_noise4d(&self, mut x: f32, mut y: f32, mut z: f32, mut w: f32) -> f32 {
+        x *= self.frequency;
+        y *= self.frequency;
+        z *= self.frequency;
+        w *= self.frequency;
+
+        match self.noise_type {
+            NoiseType::Value => self.single_value4d(0, x, y, z, w),
+            NoiseType::ValueFractal => match self.fractal_type {
+                FractalType::FBM => self.single_value_fractal_fbm4d(x, y, z, w),
+                FractalType::Billow => self.single_value_fractal_billow4d(x, y, z, w),
+                FractalType::RigidMulti => self.single_value_fractal_rigid_multi4d(x, y, z, w),
+            },
+            NoiseType::Perlin => self.single_perlin4d(0, x, y, z, w),
+            NoiseType::PerlinFractal => match self.fractal_type {
+                FractalType::FBM => self.single_perlin_fractal_fbm4d(x, y, z, w),
+                FractalType::Billow => self.single_perlin_fractal_billow4d(x, y, z, w),
+                FractalType::RigidMulti => self.single_perlin_fractal_rigid_multi4d(x, y, z, w),
+            },
+            NoiseType::Simplex => self.single_simplex4d(0, x, y, z, w),
+            NoiseType::SimplexFractal => match self.fractal_type {
+                FractalType::FBM => self.single_simplex_fractal_fbm4d(x, y, z, w),
+                FractalType::Billow => self.single_simplex_fractal_billow4d(x, y, z, w),
+                FractalType::RigidMulti => self.single_simplex_fractal_rigid_multi4d(x, y, z, w),
+            },
+            NoiseType::OpenSimplex2 => self.single_open_simplex2_3d(0, x, y, z),
+            NoiseType::OpenSimplex2Fractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2_fractal_fbm3d(x, y, z),
+                FractalType::Billow => self.single_open_simplex2_fractal_billow3d(x, y, z),
+                FractalType::RigidMulti => self.single_open_simplex2_fractal_rigid_multi3d(x, y, z),
+            },
+            NoiseType::OpenSimplex2S => self.single_open_simplex2s_3d(0, x, y, z),
+            NoiseType::OpenSimplex2SFractal => match self.fractal_type {
+                FractalType::FBM => self.single_open_simplex2s_fractal_fbm3d(x, y, z),
+                FractalType::Billow => self.single_open_simplex2s_fractal_billow3d(x, y, z),
+                FractalType::RigidMulti => {
+                    self.single_open_simplex2s_fractal_rigid_multi3d(x, y, z)
+                }
+            },
+            NoiseType::Cellular => match self.cellular_return_type {
+                CellularReturnType::CellValue => self.single_cellular3d(x, y, z),
+                CellularReturnType::Distance => self.single_cellular3d(x, y, z),
+                _ => self.single_cellular_2edge3d(x, y, z),
+            },
+            NoiseType::WhiteNoise => self.get_white_noise4d(x, y, z, w),
+            NoiseType::Cubic => self.single_cubic3d(0, x, y, z),
+            NoiseType::CubicFractal => match self.fractal_type {
+                FractalType::FBM => self.single_cubic_fractal_fbm3d(x, y, z),
+                FractalType::Billow => self.single_cubic_fractal_billow3d(x, y, z),
+                FractalType::RigidMulti => self.single_cubic_fractal_rigid_multi3d(x, y, z),
+            },
+        }
+    }
+
+    /// Fills `output` with `width * height` noise samples in row-major order;
+    /// cell `(column, row)` is sampled at `origin + (column, row) * step`. Much
+    /// faster than calling `get_noise` per cell for large maps, and with the
+    /// `threaded` feature enabled the rows are generated in parallel via `rayon`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `output.len()` is not `width * height`.
+    pub fn fill_2d(
+        &self,
+        output: &mut [f32],
+        width: usize,
+        height: usize,
+        origin: (f32, f32),
+        step: f32,
+    ) {
+        assert_eq!(
+            output.len(),
+            width * height,
+            "output slice must hold width * height samples"
+        );
+
+        #[cfg(feature = "threaded")]
+        {
+            use rayon::prelude::*;
+            output
+                .par_chunks_mut(width)
+                .enumerate()
+                .for_each(|(row, chunk)| self.fill_row(chunk, row, origin, step));
+        }
+        #[cfg(not(feature = "threaded"))]
+        for (row, chunk) in output.chunks_mut(width).enumerate() {
+            self.fill_row(chunk, row, origin, step);
+        }
+    }
+
+    fn fill_row(&self, chunk: &mut [f32], row: usize, origin: (f32, f32), step: f32) {
+        let y = origin.1 + row as f32 * step;
+        for (column, out) in chunk.iter_mut().enumerate() {
+            *out = self.get_noise(origin.0 + column as f32 * step, y);
+        }
+    }
+
     fn get_white_noise4d(&self, x: f32, y: f32, z: f32, w: f32) -> f32 {
         let xc: i32 = x.to_bits() as i32;
         let yc: i32 = y.to_bits() as i32;
@@ -2372,20 +2619,187 @@ impl FastNoise {
             }
         }
 
+        let (hx0, hx1) = (
+            wrap_tile(x0, self.tile_period.0),
+            wrap_tile(x1, self.tile_period.0),
+        );
+        let (hy0, hy1) = (
+            wrap_tile(y0, self.tile_period.1),
+            wrap_tile(y1, self.tile_period.1),
+        );
+
         let xf0 = lerp(
-            self.val_coord_2d_fast(offset, x0, y0),
-            self.val_coord_2d_fast(offset, x1, y0),
+            self.val_coord_2d_fast(offset, hx0, hy0),
+            self.val_coord_2d_fast(offset, hx1, hy0),
             xs,
         );
         let xf1 = lerp(
-            self.val_coord_2d_fast(offset, x0, y1),
-            self.val_coord_2d_fast(offset, x1, y1),
+            self.val_coord_2d_fast(offset, hx0, hy1),
+            self.val_coord_2d_fast(offset, hx1, hy1),
             xs,
         );
 
         lerp(xf0, xf1, ys)
     }
 
+    fn single_value_fractal_fbm4d(&self, mut x: f32, mut y: f32, mut z: f32, mut w: f32) -> f32 {
+        let mut sum: f32 = self.single_value4d(self.perm[0], x, y, z, w);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_value4d(self.perm[i as usize], x, y, z, w) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_value_fractal_billow4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = fast_abs_f(self.single_value4d(self.perm[0], x, y, z, w)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_value4d(self.perm[i as usize], x, y, z, w)) * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_value_fractal_rigid_multi4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_value4d(self.perm[0], x, y, z, w));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum -= (1.0 - fast_abs_f(self.single_value4d(self.perm[i as usize], x, y, z, w))) * amp;
+            i += 1;
+        }
+        sum
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn single_value4d(&self, offset: u8, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        let x0 = fast_floor(x);
+        let y0 = fast_floor(y);
+        let z0 = fast_floor(z);
+        let w0 = fast_floor(w);
+        let x1 = x0 + 1;
+        let y1 = y0 + 1;
+        let z1 = z0 + 1;
+        let w1 = w0 + 1;
+
+        let xs: f32;
+        let ys: f32;
+        let zs: f32;
+        let ws: f32;
+        match self.interp {
+            Interp::Linear => {
+                xs = x - x0 as f32;
+                ys = y - y0 as f32;
+                zs = z - z0 as f32;
+                ws = w - w0 as f32;
+            }
+            Interp::Hermite => {
+                xs = interp_hermite_func(x - x0 as f32);
+                ys = interp_hermite_func(y - y0 as f32);
+                zs = interp_hermite_func(z - z0 as f32);
+                ws = interp_hermite_func(w - w0 as f32);
+            }
+            Interp::Quintic => {
+                xs = interp_quintic_func(x - x0 as f32);
+                ys = interp_quintic_func(y - y0 as f32);
+                zs = interp_quintic_func(z - z0 as f32);
+                ws = interp_quintic_func(w - w0 as f32);
+            }
+        }
+
+        let xf000: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y0, z0, w0),
+            self.val_coord_4d_fast(offset, x1, y0, z0, w0),
+            xs,
+        );
+        let xf100: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y1, z0, w0),
+            self.val_coord_4d_fast(offset, x1, y1, z0, w0),
+            xs,
+        );
+        let xf010: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y0, z1, w0),
+            self.val_coord_4d_fast(offset, x1, y0, z1, w0),
+            xs,
+        );
+        let xf110: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y1, z1, w0),
+            self.val_coord_4d_fast(offset, x1, y1, z1, w0),
+            xs,
+        );
+        let xf001: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y0, z0, w1),
+            self.val_coord_4d_fast(offset, x1, y0, z0, w1),
+            xs,
+        );
+        let xf101: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y1, z0, w1),
+            self.val_coord_4d_fast(offset, x1, y1, z0, w1),
+            xs,
+        );
+        let xf011: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y0, z1, w1),
+            self.val_coord_4d_fast(offset, x1, y0, z1, w1),
+            xs,
+        );
+        let xf111: f32 = lerp(
+            self.val_coord_4d_fast(offset, x0, y1, z1, w1),
+            self.val_coord_4d_fast(offset, x1, y1, z1, w1),
+            xs,
+        );
+
+        let yf00: f32 = lerp(xf000, xf100, ys);
+        let yf10: f32 = lerp(xf010, xf110, ys);
+        let yf01: f32 = lerp(xf001, xf101, ys);
+        let yf11: f32 = lerp(xf011, xf111, ys);
+
+        let zf0: f32 = lerp(yf00, yf10, zs);
+        let zf1: f32 = lerp(yf01, yf11, zs);
+
+        lerp(zf0, zf1, ws)
+    }
+
     // Perlin noise
 
     #[allow(dead_code)]
@@ -2629,64 +3043,68 @@ impl FastNoise {
         let xd1 = xd0 - 1.0;
         let yd1 = yd0 - 1.0;
 
+        let (hx0, hx1) = (
+            wrap_tile(x0, self.tile_period.0),
+            wrap_tile(x1, self.tile_period.0),
+        );
+        let (hy0, hy1) = (
+            wrap_tile(y0, self.tile_period.1),
+            wrap_tile(y1, self.tile_period.1),
+        );
+
         let xf0 = lerp(
-            self.grad_coord_2d(offset, x0, y0, xd0, yd0),
-            self.grad_coord_2d(offset, x1, y0, xd1, yd0),
+            self.grad_coord_2d(offset, hx0, hy0, xd0, yd0),
+            self.grad_coord_2d(offset, hx1, hy0, xd1, yd0),
             xs,
         );
         let xf1 = lerp(
-            self.grad_coord_2d(offset, x0, y1, xd0, yd1),
-            self.grad_coord_2d(offset, x1, y1, xd1, yd1),
+            self.grad_coord_2d(offset, hx0, hy1, xd0, yd1),
+            self.grad_coord_2d(offset, hx1, hy1, xd1, yd1),
             xs,
         );
 
         lerp(xf0, xf1, ys)
     }
 
-    #[allow(dead_code)]
-    // Simplex noise
-    fn get_simplex_fractal3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
-        x *= self.frequency;
-        y *= self.frequency;
-        z *= self.frequency;
-
-        match self.fractal_type {
-            FractalType::FBM => self.single_simplex_fractal_fbm3d(x, y, z),
-            FractalType::Billow => self.single_simplex_fractal_billow3d(x, y, z),
-            FractalType::RigidMulti => self.single_simplex_fractal_rigid_multi3d(x, y, z),
-        }
-    }
-
-    fn single_simplex_fractal_fbm3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
-        let mut sum = self.single_simplex3d(self.perm[0], x, y, z);
-        let mut amp = 1.0;
+    fn single_perlin_fractal_fbm4d(&self, mut x: f32, mut y: f32, mut z: f32, mut w: f32) -> f32 {
+        let mut sum: f32 = self.single_perlin4d(self.perm[0], x, y, z, w);
+        let mut amp: f32 = 1.0;
         let mut i = 1;
 
         while i < self.octaves {
             x *= self.lacunarity;
             y *= self.lacunarity;
             z *= self.lacunarity;
+            w *= self.lacunarity;
 
             amp *= self.gain;
-            sum += self.single_simplex3d(self.perm[i as usize], x, y, z) * amp;
+            sum += self.single_perlin4d(self.perm[i as usize], x, y, z, w) * amp;
             i += 1;
         }
 
         sum * self.fractal_bounding
     }
 
-    fn single_simplex_fractal_billow3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
-        let mut sum = fast_abs_f(self.single_simplex3d(self.perm[0], x, y, z)) * 2.0 - 1.0;
-        let mut amp = 1.0;
+    fn single_perlin_fractal_billow4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = fast_abs_f(self.single_perlin4d(self.perm[0], x, y, z, w)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
         let mut i = 1;
 
         while i < self.octaves {
             x *= self.lacunarity;
             y *= self.lacunarity;
             z *= self.lacunarity;
+            w *= self.lacunarity;
 
             amp *= self.gain;
-            sum += (fast_abs_f(self.single_simplex3d(self.perm[i as usize], x, y, z)) * 2.0 - 1.0)
+            sum += (fast_abs_f(self.single_perlin4d(self.perm[i as usize], x, y, z, w)) * 2.0
+                - 1.0)
                 * amp;
             i += 1;
         }
@@ -2694,58 +3112,231 @@ impl FastNoise {
         sum * self.fractal_bounding
     }
 
-    fn single_simplex_fractal_rigid_multi3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
-        let mut sum = 1.0 - fast_abs_f(self.single_simplex3d(self.perm[0], x, y, z));
-        let mut amp = 1.0;
+    fn single_perlin_fractal_rigid_multi4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_perlin4d(self.perm[0], x, y, z, w));
+        let mut amp: f32 = 1.0;
         let mut i = 1;
 
         while i < self.octaves {
             x *= self.lacunarity;
             y *= self.lacunarity;
             z *= self.lacunarity;
+            w *= self.lacunarity;
 
             amp *= self.gain;
-            sum -= (1.0 - fast_abs_f(self.single_simplex3d(self.perm[i as usize], x, y, z))) * amp;
+            sum -=
+                (1.0 - fast_abs_f(self.single_perlin4d(self.perm[i as usize], x, y, z, w))) * amp;
             i += 1;
         }
-
         sum
     }
 
-    #[allow(dead_code)]
-    fn get_simplex3d(&self, x: f32, y: f32, z: f32) -> f32 {
-        self.single_simplex3d(
-            0,
-            x * self.frequency,
-            y * self.frequency,
-            z * self.frequency,
-        )
-    }
-
     #[allow(clippy::many_single_char_names)]
-    #[allow(clippy::collapsible_if)]
-    #[allow(clippy::suspicious_else_formatting)]
-    fn single_simplex3d(&self, offset: u8, x: f32, y: f32, z: f32) -> f32 {
-        let mut t: f32 = (x + y + z) * F3;
-        let i = fast_floor(x + t);
-        let j = fast_floor(y + t);
-        let k = fast_floor(z + t);
-
-        t = (i + j + k) as f32 * G3;
-        let x0 = i as f32 - t;
-        let y0 = j as f32 - t;
-        let z0 = k as f32 - t;
-
-        let x0 = x - x0;
-        let y0 = y - y0;
-        let z0 = z - z0;
-
-        let i1: f32;
-        let j1: f32;
-        let k1: f32;
-        let i2: f32;
-        let j2: f32;
-        let k2: f32;
+    fn single_perlin4d(&self, offset: u8, x: f32, y: f32, z: f32, w: f32) -> f32 {
+        let x0 = fast_floor(x);
+        let y0 = fast_floor(y);
+        let z0 = fast_floor(z);
+        let w0 = fast_floor(w);
+        let x1 = x0 + 1;
+        let y1 = y0 + 1;
+        let z1 = z0 + 1;
+        let w1 = w0 + 1;
+
+        let xs: f32;
+        let ys: f32;
+        let zs: f32;
+        let ws: f32;
+        match self.interp {
+            Interp::Linear => {
+                xs = x - x0 as f32;
+                ys = y - y0 as f32;
+                zs = z - z0 as f32;
+                ws = w - w0 as f32;
+            }
+            Interp::Hermite => {
+                xs = interp_hermite_func(x - x0 as f32);
+                ys = interp_hermite_func(y - y0 as f32);
+                zs = interp_hermite_func(z - z0 as f32);
+                ws = interp_hermite_func(w - w0 as f32);
+            }
+            Interp::Quintic => {
+                xs = interp_quintic_func(x - x0 as f32);
+                ys = interp_quintic_func(y - y0 as f32);
+                zs = interp_quintic_func(z - z0 as f32);
+                ws = interp_quintic_func(w - w0 as f32);
+            }
+        }
+
+        let xd0 = x - x0 as f32;
+        let yd0 = y - y0 as f32;
+        let zd0 = z - z0 as f32;
+        let wd0 = w - w0 as f32;
+        let xd1 = xd0 - 1.0;
+        let yd1 = yd0 - 1.0;
+        let zd1 = zd0 - 1.0;
+        let wd1 = wd0 - 1.0;
+
+        let xf000: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y0, z0, w0, xd0, yd0, zd0, wd0),
+            self.grad_coord_4d(offset, x1, y0, z0, w0, xd1, yd0, zd0, wd0),
+            xs,
+        );
+        let xf100: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y1, z0, w0, xd0, yd1, zd0, wd0),
+            self.grad_coord_4d(offset, x1, y1, z0, w0, xd1, yd1, zd0, wd0),
+            xs,
+        );
+        let xf010: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y0, z1, w0, xd0, yd0, zd1, wd0),
+            self.grad_coord_4d(offset, x1, y0, z1, w0, xd1, yd0, zd1, wd0),
+            xs,
+        );
+        let xf110: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y1, z1, w0, xd0, yd1, zd1, wd0),
+            self.grad_coord_4d(offset, x1, y1, z1, w0, xd1, yd1, zd1, wd0),
+            xs,
+        );
+        let xf001: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y0, z0, w1, xd0, yd0, zd0, wd1),
+            self.grad_coord_4d(offset, x1, y0, z0, w1, xd1, yd0, zd0, wd1),
+            xs,
+        );
+        let xf101: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y1, z0, w1, xd0, yd1, zd0, wd1),
+            self.grad_coord_4d(offset, x1, y1, z0, w1, xd1, yd1, zd0, wd1),
+            xs,
+        );
+        let xf011: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y0, z1, w1, xd0, yd0, zd1, wd1),
+            self.grad_coord_4d(offset, x1, y0, z1, w1, xd1, yd0, zd1, wd1),
+            xs,
+        );
+        let xf111: f32 = lerp(
+            self.grad_coord_4d(offset, x0, y1, z1, w1, xd0, yd1, zd1, wd1),
+            self.grad_coord_4d(offset, x1, y1, z1, w1, xd1, yd1, zd1, wd1),
+            xs,
+        );
+
+        let yf00: f32 = lerp(xf000, xf100, ys);
+        let yf10: f32 = lerp(xf010, xf110, ys);
+        let yf01: f32 = lerp(xf001, xf101, ys);
+        let yf11: f32 = lerp(xf011, xf111, ys);
+
+        let zf0: f32 = lerp(yf00, yf10, zs);
+        let zf1: f32 = lerp(yf01, yf11, zs);
+
+        lerp(zf0, zf1, ws)
+    }
+
+    #[allow(dead_code)]
+    // Simplex noise
+    fn get_simplex_fractal3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        x *= self.frequency;
+        y *= self.frequency;
+        z *= self.frequency;
+
+        match self.fractal_type {
+            FractalType::FBM => self.single_simplex_fractal_fbm3d(x, y, z),
+            FractalType::Billow => self.single_simplex_fractal_billow3d(x, y, z),
+            FractalType::RigidMulti => self.single_simplex_fractal_rigid_multi3d(x, y, z),
+        }
+    }
+
+    fn single_simplex_fractal_fbm3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum = self.single_simplex3d(self.perm[0], x, y, z);
+        let mut amp = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_simplex3d(self.perm[i as usize], x, y, z) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_simplex_fractal_billow3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum = fast_abs_f(self.single_simplex3d(self.perm[0], x, y, z)) * 2.0 - 1.0;
+        let mut amp = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_simplex3d(self.perm[i as usize], x, y, z)) * 2.0 - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_simplex_fractal_rigid_multi3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum = 1.0 - fast_abs_f(self.single_simplex3d(self.perm[0], x, y, z));
+        let mut amp = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+
+            amp *= self.gain;
+            sum -= (1.0 - fast_abs_f(self.single_simplex3d(self.perm[i as usize], x, y, z))) * amp;
+            i += 1;
+        }
+
+        sum
+    }
+
+    #[allow(dead_code)]
+    fn get_simplex3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.single_simplex3d(
+            0,
+            x * self.frequency,
+            y * self.frequency,
+            z * self.frequency,
+        )
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    #[allow(clippy::collapsible_if)]
+    #[allow(clippy::suspicious_else_formatting)]
+    fn single_simplex3d(&self, offset: u8, x: f32, y: f32, z: f32) -> f32 {
+        let mut t: f32 = (x + y + z) * F3;
+        let i = fast_floor(x + t);
+        let j = fast_floor(y + t);
+        let k = fast_floor(z + t);
+
+        t = (i + j + k) as f32 * G3;
+        let x0 = i as f32 - t;
+        let y0 = j as f32 - t;
+        let z0 = k as f32 - t;
+
+        let x0 = x - x0;
+        let y0 = y - y0;
+        let z0 = z - z0;
+
+        let i1: f32;
+        let j1: f32;
+        let k1: f32;
+        let i2: f32;
+        let j2: f32;
+        let k2: f32;
 
         if x0 >= y0 {
             if y0 >= z0 {
@@ -2959,6 +3550,25 @@ impl FastNoise {
 
     #[allow(clippy::many_single_char_names)]
     fn single_simplex(&self, offset: u8, x: f32, y: f32) -> f32 {
+        // The skewed simplex lattice can't be wrapped directly; tileable axes are
+        // mapped onto circles in higher-dimensional simplex noise instead.
+        match self.tile_period {
+            (0, 0) => {}
+            (px, 0) => {
+                let (cx, cy) = tile_circle(x, px);
+                return self.single_simplex3d(offset, cx, cy, y);
+            }
+            (0, py) => {
+                let (cx, cy) = tile_circle(y, py);
+                return self.single_simplex3d(offset, x, cx, cy);
+            }
+            (px, py) => {
+                let (cx, cy) = tile_circle(x, px);
+                let (cz, cw) = tile_circle(y, py);
+                return self.single_simplex4d(offset, cx, cy, cz, cw);
+            }
+        }
+
         let mut t: f32 = (x + y) * F2;
         let i = fast_floor(x + t);
         let j = fast_floor(y + t);
@@ -3028,7 +3638,6 @@ impl FastNoise {
         }
     }
 
-    #[allow(dead_code)]
     #[allow(clippy::many_single_char_names)]
     fn single_simplex4d(&self, offset: u8, x: f32, y: f32, z: f32, w: f32) -> f32 {
         let n0: f32;
@@ -3169,46 +3778,431 @@ impl FastNoise {
                     w2,
                 );
         }
-        t = 0.6 - x3 * x3 - y3 * y3 - z3 * z3 - w3 * w3;
-        if t < 0.0 {
-            n3 = 0.;
-        } else {
-            t *= t;
-            n3 = t
-                * t
-                * self.grad_coord_4d(
-                    offset,
-                    i as i32 + i3,
-                    j as i32 + j3,
-                    k as i32 + k3,
-                    l as i32 + l3,
-                    x3,
-                    y3,
-                    z3,
-                    w3,
-                );
+        t = 0.6 - x3 * x3 - y3 * y3 - z3 * z3 - w3 * w3;
+        if t < 0.0 {
+            n3 = 0.;
+        } else {
+            t *= t;
+            n3 = t
+                * t
+                * self.grad_coord_4d(
+                    offset,
+                    i as i32 + i3,
+                    j as i32 + j3,
+                    k as i32 + k3,
+                    l as i32 + l3,
+                    x3,
+                    y3,
+                    z3,
+                    w3,
+                );
+        }
+        t = 0.6 - x4 * x4 - y4 * y4 - z4 * z4 - w4 * w4;
+        if t < 0.0 {
+            n4 = 0.;
+        } else {
+            t *= t;
+            n4 = t
+                * t
+                * self.grad_coord_4d(
+                    offset,
+                    i as i32 + 1,
+                    j as i32 + 1,
+                    k as i32 + 1,
+                    l as i32 + 1,
+                    x4,
+                    y4,
+                    z4,
+                    w4,
+                );
+        }
+
+        27.0 * (n0 + n1 + n2 + n3 + n4) as f32
+    }
+
+    fn single_simplex_fractal_fbm4d(&self, mut x: f32, mut y: f32, mut z: f32, mut w: f32) -> f32 {
+        let mut sum: f32 = self.single_simplex4d(self.perm[0], x, y, z, w);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_simplex4d(self.perm[i as usize], x, y, z, w) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_simplex_fractal_billow4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = fast_abs_f(self.single_simplex4d(self.perm[0], x, y, z, w)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_simplex4d(self.perm[i as usize], x, y, z, w)) * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_simplex_fractal_rigid_multi4d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+        mut w: f32,
+    ) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_simplex4d(self.perm[0], x, y, z, w));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            w *= self.lacunarity;
+
+            amp *= self.gain;
+            sum -=
+                (1.0 - fast_abs_f(self.single_simplex4d(self.perm[i as usize], x, y, z, w))) * amp;
+            i += 1;
+        }
+        sum
+    }
+
+    // Open Simplex 2 noise
+
+    fn single_open_simplex2(&self, offset: u8, x: f32, y: f32) -> f32 {
+        // In 2D, OpenSimplex2 shares the classic simplex lattice; the scheme's
+        // improvements over simplex are the 3D domain rotation below.
+        self.single_simplex(offset, x, y)
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn single_open_simplex2s(&self, offset: u8, x: f32, y: f32) -> f32 {
+        // SuperSimplex: skew to the simplex lattice, then blend all four corners
+        // of the containing rhombus with a wider kernel than classic simplex.
+        let t: f32 = (x + y) * F2;
+        let i = fast_floor(x + t);
+        let j = fast_floor(y + t);
+
+        let t = (i + j) as f32 * G2;
+        let dx0 = x - (i as f32 - t);
+        let dy0 = y - (j as f32 - t);
+
+        let mut value = 0.0;
+        for &(di, dj) in &[(0, 0), (1, 0), (0, 1), (1, 1)] {
+            let dx = dx0 - di as f32 + (di + dj) as f32 * G2;
+            let dy = dy0 - dj as f32 + (di + dj) as f32 * G2;
+            let mut a = OS2S_FALLOFF_2D - dx * dx - dy * dy;
+            if a > 0.0 {
+                a *= a;
+                value += a * a * self.grad_coord_2d(offset, i + di, j + dj, dx, dy);
+            }
+        }
+
+        OS2S_BOUNDING_2D * value
+    }
+
+    fn single_open_simplex2_3d(&self, offset: u8, x: f32, y: f32, z: f32) -> f32 {
+        OS2_BOUNDING_3D * self.open_simplex2_bcc(offset, x, y, z, OS2_FALLOFF_3D)
+    }
+
+    fn single_open_simplex2s_3d(&self, offset: u8, x: f32, y: f32, z: f32) -> f32 {
+        OS2S_BOUNDING_3D * self.open_simplex2_bcc(offset, x, y, z, OS2S_FALLOFF_3D)
+    }
+
+    #[allow(clippy::many_single_char_names)]
+    fn open_simplex2_bcc(&self, offset: u8, x: f32, y: f32, z: f32, falloff: f32) -> f32 {
+        // Rotate the domain so the lattice's main diagonal lines up with an axis;
+        // this is what hides the axis-aligned artifacts classic simplex shows.
+        let r = (x + y + z) * (2.0 / 3.0);
+        let xr = r - x;
+        let yr = r - y;
+        let zr = r - z;
+
+        let mut value = 0.0;
+        // Two cubic lattices offset by half a cell form the body-centred cubic
+        // lattice OpenSimplex2 samples.
+        for l in 0..2 {
+            let half = l as f32 * 0.5;
+            let (xb, yb, zb) = (xr - half, yr - half, zr - half);
+            let x0 = fast_floor(xb);
+            let y0 = fast_floor(yb);
+            let z0 = fast_floor(zb);
+            let dx0 = xb - x0 as f32;
+            let dy0 = yb - y0 as f32;
+            let dz0 = zb - z0 as f32;
+
+            for corner in 0..8 {
+                let (ci, cj, ck) = (corner & 1, (corner >> 1) & 1, (corner >> 2) & 1);
+                let dx = dx0 - ci as f32;
+                let dy = dy0 - cj as f32;
+                let dz = dz0 - ck as f32;
+                let mut a = falloff - dx * dx - dy * dy - dz * dz;
+                if a > 0.0 {
+                    a *= a;
+                    value += a
+                        * a
+                        * self.grad_coord_3d(
+                            offset,
+                            (x0 + ci) * 2 + l,
+                            (y0 + cj) * 2 + l,
+                            (z0 + ck) * 2 + l,
+                            dx,
+                            dy,
+                            dz,
+                        );
+                }
+            }
+        }
+
+        value
+    }
+
+    fn single_open_simplex2_fractal_fbm(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = self.single_open_simplex2(self.perm[0], x, y);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_open_simplex2(self.perm[i as usize], x, y) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2_fractal_billow(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = fast_abs_f(self.single_open_simplex2(self.perm[0], x, y)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_open_simplex2(self.perm[i as usize], x, y)) * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2_fractal_rigid_multi(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_open_simplex2(self.perm[0], x, y));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            amp *= self.gain;
+            sum -= (1.0 - fast_abs_f(self.single_open_simplex2(self.perm[i as usize], x, y))) * amp;
+            i += 1;
+        }
+        sum
+    }
+
+    fn single_open_simplex2s_fractal_fbm(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = self.single_open_simplex2s(self.perm[0], x, y);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_open_simplex2s(self.perm[i as usize], x, y) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2s_fractal_billow(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = fast_abs_f(self.single_open_simplex2s(self.perm[0], x, y)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_open_simplex2s(self.perm[i as usize], x, y)) * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2s_fractal_rigid_multi(&self, mut x: f32, mut y: f32) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_open_simplex2s(self.perm[0], x, y));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            amp *= self.gain;
+            sum -=
+                (1.0 - fast_abs_f(self.single_open_simplex2s(self.perm[i as usize], x, y))) * amp;
+            i += 1;
+        }
+        sum
+    }
+
+    fn single_open_simplex2_fractal_fbm3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum: f32 = self.single_open_simplex2_3d(self.perm[0], x, y, z);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_open_simplex2_3d(self.perm[i as usize], x, y, z) * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2_fractal_billow3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum: f32 =
+            fast_abs_f(self.single_open_simplex2_3d(self.perm[0], x, y, z)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_open_simplex2_3d(self.perm[i as usize], x, y, z))
+                * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2_fractal_rigid_multi3d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+    ) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_open_simplex2_3d(self.perm[0], x, y, z));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            amp *= self.gain;
+            sum -= (1.0 - fast_abs_f(self.single_open_simplex2_3d(self.perm[i as usize], x, y, z)))
+                * amp;
+            i += 1;
         }
-        t = 0.6 - x4 * x4 - y4 * y4 - z4 * z4 - w4 * w4;
-        if t < 0.0 {
-            n4 = 0.;
-        } else {
-            t *= t;
-            n4 = t
-                * t
-                * self.grad_coord_4d(
-                    offset,
-                    i as i32 + 1,
-                    j as i32 + 1,
-                    k as i32 + 1,
-                    l as i32 + 1,
-                    x4,
-                    y4,
-                    z4,
-                    w4,
-                );
+        sum
+    }
+
+    fn single_open_simplex2s_fractal_fbm3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum: f32 = self.single_open_simplex2s_3d(self.perm[0], x, y, z);
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+
+            amp *= self.gain;
+            sum += self.single_open_simplex2s_3d(self.perm[i as usize], x, y, z) * amp;
+            i += 1;
         }
 
-        27.0 * (n0 + n1 + n2 + n3 + n4) as f32
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2s_fractal_billow3d(&self, mut x: f32, mut y: f32, mut z: f32) -> f32 {
+        let mut sum: f32 =
+            fast_abs_f(self.single_open_simplex2s_3d(self.perm[0], x, y, z)) * 2.0 - 1.0;
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            amp *= self.gain;
+            sum += (fast_abs_f(self.single_open_simplex2s_3d(self.perm[i as usize], x, y, z))
+                * 2.0
+                - 1.0)
+                * amp;
+            i += 1;
+        }
+
+        sum * self.fractal_bounding
+    }
+
+    fn single_open_simplex2s_fractal_rigid_multi3d(
+        &self,
+        mut x: f32,
+        mut y: f32,
+        mut z: f32,
+    ) -> f32 {
+        let mut sum: f32 = 1.0 - fast_abs_f(self.single_open_simplex2s_3d(self.perm[0], x, y, z));
+        let mut amp: f32 = 1.0;
+        let mut i = 1;
+
+        while i < self.octaves {
+            x *= self.lacunarity;
+            y *= self.lacunarity;
+            z *= self.lacunarity;
+            amp *= self.gain;
+            sum -= (1.0
+                - fast_abs_f(self.single_open_simplex2s_3d(self.perm[i as usize], x, y, z)))
+                * amp;
+            i += 1;
+        }
+        sum
     }
 
     #[allow(dead_code)]
@@ -3564,7 +4558,41 @@ impl FastNoise {
         }
     }
 
-    fn single_cellular3d(&self, x: f32, y: f32, z: f32) -> f32 {
+    fn cellular_distance2d(&self, vec_x: f32, vec_y: f32) -> f32 {
+        if let Some(metric) = self.cellular_custom_distance {
+            return metric(vec_x, vec_y, 0.0);
+        }
+        match self.cellular_distance_function {
+            CellularDistanceFunction::Euclidean => vec_x * vec_x + vec_y * vec_y,
+            CellularDistanceFunction::Manhattan => fast_abs_f(vec_x) + fast_abs_f(vec_y),
+            CellularDistanceFunction::Natural => {
+                (fast_abs_f(vec_x) + fast_abs_f(vec_y)) + (vec_x * vec_x + vec_y * vec_y)
+            }
+            CellularDistanceFunction::Chebyshev => f32::max(fast_abs_f(vec_x), fast_abs_f(vec_y)),
+        }
+    }
+
+    fn cellular_distance3d(&self, vec_x: f32, vec_y: f32, vec_z: f32) -> f32 {
+        if let Some(metric) = self.cellular_custom_distance {
+            return metric(vec_x, vec_y, vec_z);
+        }
+        match self.cellular_distance_function {
+            CellularDistanceFunction::Euclidean => vec_x * vec_x + vec_y * vec_y + vec_z * vec_z,
+            CellularDistanceFunction::Manhattan => {
+                fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z)
+            }
+            CellularDistanceFunction::Natural => {
+                (fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z))
+                    + (vec_x * vec_x + vec_y * vec_y + vec_z * vec_z)
+            }
+            CellularDistanceFunction::Chebyshev => f32::max(
+                f32::max(fast_abs_f(vec_x), fast_abs_f(vec_y)),
+                fast_abs_f(vec_z),
+            ),
+        }
+    }
+
+    fn cellular_nearest3d(&self, x: f32, y: f32, z: f32) -> (f32, i32, i32, i32) {
         let xr = fast_round(x);
         let yr = fast_round(y);
         let zr = fast_round(z);
@@ -3574,88 +4602,33 @@ impl FastNoise {
         let mut yc: i32 = 0;
         let mut zc: i32 = 0;
 
-        match self.cellular_distance_function {
-            CellularDistanceFunction::Euclidean => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance = vec_x * vec_x + vec_y * vec_y + vec_z * vec_z;
-
-                            if new_distance < distance {
-                                distance = new_distance;
-                                xc = xi;
-                                yc = yi;
-                                zc = zi;
-                            }
-                        }
-                    }
-                }
-            }
-            CellularDistanceFunction::Manhattan => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance =
-                                fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z);
-
-                            if new_distance < distance {
-                                distance = new_distance;
-                                xc = xi;
-                                yc = yi;
-                                zc = zi;
-                            }
-                        }
-                    }
-                }
-            }
-            CellularDistanceFunction::Natural => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance =
-                                (fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z))
-                                    + (vec_x * vec_x + vec_y * vec_y + vec_z * vec_z);
-
-                            if new_distance < distance {
-                                distance = new_distance;
-                                xc = xi;
-                                yc = yi;
-                                zc = zi;
-                            }
-                        }
+        for xi in xr - 1..xr + 2 {
+            for yi in yr - 1..yr + 2 {
+                for zi in zr - 1..zr + 2 {
+                    let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
+
+                    let vec_x = xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
+                    let vec_y = yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
+                    let vec_z = zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
+
+                    let new_distance = self.cellular_distance3d(vec_x, vec_y, vec_z);
+
+                    if new_distance < distance {
+                        distance = new_distance;
+                        xc = xi;
+                        yc = yi;
+                        zc = zi;
                     }
                 }
             }
         }
 
-        //let lut_pos : u8;
+        (distance, xc, yc, zc)
+    }
+
+    fn single_cellular3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (distance, xc, yc, zc) = self.cellular_nearest3d(x, y, z);
+
         match self.cellular_return_type {
             CellularReturnType::CellValue => self.val_coord_3d(self.seed as i32, xc, yc, zc),
             CellularReturnType::Distance => distance,
@@ -3669,94 +4642,31 @@ impl FastNoise {
         let zr = fast_round(z);
 
         let mut distance: Vec<f32> = vec![999999.0; FN_CELLULAR_INDEX_MAX + 1];
-        //FN_DECIMAL distance[FN_CELLULAR_INDEX_MAX+1] = { 999999,999999,999999,999999 };
 
-        match self.cellular_distance_function {
-            CellularDistanceFunction::Euclidean => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance = vec_x * vec_x + vec_y * vec_y + vec_z * vec_z;
-
-                            for i in (0..self.cellular_distance_index.1).rev() {
-                                distance[i as usize] = f32::max(
-                                    f32::min(distance[i as usize], new_distance),
-                                    distance[i as usize - 1],
-                                );
-                            }
-                            distance[0] = f32::min(distance[0], new_distance);
-                        }
-                    }
-                }
-            }
-            CellularDistanceFunction::Manhattan => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance =
-                                fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z);
-
-                            for i in (0..=self.cellular_distance_index.1).rev() {
-                                distance[i as usize] = f32::max(
-                                    f32::min(distance[i as usize], new_distance),
-                                    distance[i as usize - 1],
-                                );
-                            }
-                            distance[0] = f32::min(distance[0], new_distance);
-                        }
-                    }
-                }
-            }
-            CellularDistanceFunction::Natural => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        for zi in zr - 1..zr + 2 {
-                            let lut_pos = self.index3d_256(0, xi, yi, zi);
-
-                            let vec_x =
-                                xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
-                            let vec_y =
-                                yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
-                            let vec_z =
-                                zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
-
-                            let new_distance =
-                                (fast_abs_f(vec_x) + fast_abs_f(vec_y) + fast_abs_f(vec_z))
-                                    + (vec_x * vec_x + vec_y * vec_y + vec_z * vec_z);
-
-                            for i in (0..=self.cellular_distance_index.1).rev() {
-                                distance[i as usize] = f32::max(
-                                    f32::min(distance[i as usize], new_distance),
-                                    distance[i as usize - 1],
-                                );
-                            }
-                            distance[0] = f32::min(distance[0], new_distance);
-                        }
+        for xi in xr - 1..xr + 2 {
+            for yi in yr - 1..yr + 2 {
+                for zi in zr - 1..zr + 2 {
+                    let lut_pos: u8 = self.index3d_256(0, xi, yi, zi);
+
+                    let vec_x = xi as f32 - x + CELL_3D_X[lut_pos as usize] * self.cellular_jitter;
+                    let vec_y = yi as f32 - y + CELL_3D_Y[lut_pos as usize] * self.cellular_jitter;
+                    let vec_z = zi as f32 - z + CELL_3D_Z[lut_pos as usize] * self.cellular_jitter;
+
+                    let new_distance = self.cellular_distance3d(vec_x, vec_y, vec_z);
+
+                    for i in (1..=self.cellular_distance_index.1).rev() {
+                        distance[i as usize] = f32::max(
+                            f32::min(distance[i as usize], new_distance),
+                            distance[i as usize - 1],
+                        );
                     }
+                    distance[0] = f32::min(distance[0], new_distance);
                 }
             }
         }
 
         match self.cellular_return_type {
-            CellularReturnType::Distance2 => distance[self.cellular_distance_index.1 as usize],
+            CellularReturnType::Distance2 => distance[self.cellular_distance_index.0 as usize],
             CellularReturnType::Distance2Add => {
                 distance[self.cellular_distance_index.1 as usize]
                     + distance[self.cellular_distance_index.0 as usize]
@@ -3789,77 +4699,69 @@ impl FastNoise {
         }
     }
 
-    fn single_cellular(&self, x: f32, y: f32) -> f32 {
+    fn cellular_nearest(&self, x: f32, y: f32) -> (f32, i32, i32) {
         let xr = fast_round(x);
         let yr = fast_round(y);
 
         let mut distance: f32 = 999999.0;
+        let mut xc: i32 = 0;
+        let mut yc: i32 = 0;
 
-        match self.cellular_distance_function {
-            CellularDistanceFunction::Euclidean => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos: u8 = self.index2d_256(0, xi, yi);
+        for xi in xr - 1..xr + 2 {
+            for yi in yr - 1..yr + 2 {
+                let lut_pos: u8 = self.index2d_256(0, xi, yi);
 
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
+                let vec_x = xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
+                let vec_y = yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
 
-                        let new_distance = vec_x * vec_x + vec_y * vec_y;
+                let new_distance = self.cellular_distance2d(vec_x, vec_y);
 
-                        if new_distance < distance {
-                            distance = new_distance;
-                        }
-                    }
+                if new_distance < distance {
+                    distance = new_distance;
+                    xc = xi;
+                    yc = yi;
                 }
             }
-            CellularDistanceFunction::Manhattan => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos: u8 = self.index2d_256(0, xi, yi);
+        }
 
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
+        (distance, xc, yc)
+    }
 
-                        let new_distance = fast_abs_f(vec_x) + fast_abs_f(vec_y);
+    fn single_cellular(&self, x: f32, y: f32) -> f32 {
+        let (distance, xc, yc) = self.cellular_nearest(x, y);
 
-                        if new_distance < distance {
-                            distance = new_distance;
-                        }
-                    }
-                }
-            }
-            CellularDistanceFunction::Natural => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos: u8 = self.index2d_256(0, xi, yi);
+        match self.cellular_return_type {
+            CellularReturnType::CellValue => self.val_coord_2d(self.seed as i32, xc, yc),
+            CellularReturnType::Distance => distance,
+            _ => 0.0,
+        }
+    }
 
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
+    /// Returns a stable integer identifier of the cellular-noise feature cell
+    /// that `(x, y)` falls in, honouring the configured distance function,
+    /// custom metric and jitter. Pair it with `NoiseType::Cellular` to assign
+    /// biomes or regions without decoding the f32 cell value.
+    pub fn get_cellular_cell_id(&self, x: f32, y: f32) -> i32 {
+        use std::num::Wrapping;
 
-                        let new_distance = (fast_abs_f(vec_x) + fast_abs_f(vec_y))
-                            + (vec_x * vec_x + vec_y * vec_y);
+        let (_, xc, yc) = self.cellular_nearest(x * self.frequency, y * self.frequency);
+        let mut n = Wrapping(self.seed as i32);
+        n ^= Wrapping(X_PRIME) * Wrapping(xc);
+        n ^= Wrapping(Y_PRIME) * Wrapping(yc);
+        n.0
+    }
 
-                        if new_distance < distance {
-                            distance = new_distance;
-                        }
-                    }
-                }
-            }
-        }
+    /// As `get_cellular_cell_id`, for 3D cellular noise.
+    pub fn get_cellular_cell_id3d(&self, x: f32, y: f32, z: f32) -> i32 {
+        use std::num::Wrapping;
 
-        //let lut_pos : u8;
-        match self.cellular_return_type {
-            CellularReturnType::CellValue => {
-                self.val_coord_2d(self.seed as i32, x as i32, y as i32)
-            }
-            _ => 0.0,
-        }
+        let (_, xc, yc, zc) =
+            self.cellular_nearest3d(x * self.frequency, y * self.frequency, z * self.frequency);
+        let mut n = Wrapping(self.seed as i32);
+        n ^= Wrapping(X_PRIME) * Wrapping(xc);
+        n ^= Wrapping(Y_PRIME) * Wrapping(yc);
+        n ^= Wrapping(Z_PRIME) * Wrapping(zc);
+        n.0
     }
 
     fn single_cellular_2edge(&self, x: f32, y: f32) -> f32 {
@@ -3868,73 +4770,22 @@ impl FastNoise {
 
         let mut distance: Vec<f32> = vec![999999.0; FN_CELLULAR_INDEX_MAX + 1];
 
-        match self.cellular_distance_function {
-            CellularDistanceFunction::Euclidean => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos = self.index2d_256(0, xi, yi);
-
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
-
-                        let new_distance = vec_x * vec_x + vec_y * vec_y;
-
-                        for i in (0..=self.cellular_distance_index.1).rev() {
-                            distance[i as usize] = f32::max(
-                                f32::min(distance[i as usize], new_distance),
-                                distance[i as usize - 1],
-                            );
-                        }
-                        distance[0] = f32::min(distance[0], new_distance);
-                    }
-                }
-            }
-            CellularDistanceFunction::Manhattan => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos = self.index2d_256(0, xi, yi);
-
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
-
-                        let new_distance = fast_abs_f(vec_x) + fast_abs_f(vec_y);
-
-                        for i in (0..=self.cellular_distance_index.1).rev() {
-                            distance[i as usize] = f32::max(
-                                f32::min(distance[i as usize], new_distance),
-                                distance[i as usize - 1],
-                            );
-                        }
-                        distance[0] = f32::min(distance[0], new_distance);
-                    }
-                }
-            }
-            CellularDistanceFunction::Natural => {
-                for xi in xr - 1..xr + 2 {
-                    for yi in yr - 1..yr + 2 {
-                        let lut_pos = self.index2d_256(0, xi, yi);
-
-                        let vec_x =
-                            xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
-                        let vec_y =
-                            yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
-
-                        let new_distance = (fast_abs_f(vec_x) + fast_abs_f(vec_y))
-                            + (vec_x * vec_x + vec_y * vec_y);
-
-                        for i in (0..=self.cellular_distance_index.1).rev() {
-                            distance[i as usize] = f32::max(
-                                f32::min(distance[i as usize], new_distance),
-                                distance[i as usize - 1],
-                            );
-                        }
-                        distance[0] = f32::min(distance[0], new_distance);
-                    }
+        for xi in xr - 1..xr + 2 {
+            for yi in yr - 1..yr + 2 {
+                let lut_pos: u8 = self.index2d_256(0, xi, yi);
+
+                let vec_x = xi as f32 - x + CELL_2D_X[lut_pos as usize] * self.cellular_jitter;
+                let vec_y = yi as f32 - y + CELL_2D_Y[lut_pos as usize] * self.cellular_jitter;
+
+                let new_distance = self.cellular_distance2d(vec_x, vec_y);
+
+                for i in (1..=self.cellular_distance_index.1).rev() {
+                    distance[i as usize] = f32::max(
+                        f32::min(distance[i as usize], new_distance),
+                        distance[i as usize - 1],
+                    );
                 }
+                distance[0] = f32::min(distance[0], new_distance);
             }
         }
 
@@ -3960,6 +4811,42 @@ impl FastNoise {
         }
     }
 
+    fn apply_domain_warp3d(&self, x: &mut f32, y: &mut f32, z: &mut f32) {
+        match self.domain_warp_type {
+            DomainWarpType::None => {}
+            DomainWarpType::Gradient => self.single_gradient_perturb3d(
+                0,
+                self.domain_warp_amp,
+                self.domain_warp_frequency,
+                x,
+                y,
+                z,
+            ),
+            DomainWarpType::GradientFractal => {
+                let mut amp = self.domain_warp_amp * self.domain_warp_bounding;
+                let mut freq = self.domain_warp_frequency;
+                let mut i = 1;
+
+                self.single_gradient_perturb3d(
+                    self.perm[0],
+                    amp,
+                    self.domain_warp_frequency,
+                    x,
+                    y,
+                    z,
+                );
+
+                while i < self.domain_warp_octaves {
+                    freq *= self.lacunarity;
+                    amp *= self.gain;
+                    self.single_gradient_perturb3d(self.perm[i as usize], amp, freq, x, y, z);
+
+                    i += 1;
+                }
+            }
+        }
+    }
+
     #[allow(dead_code)]
     fn gradient_perturb3d(&self, x: &mut f32, y: &mut f32, z: &mut f32) {
         self.single_gradient_perturb3d(0, self.gradient_perturb_amp, self.frequency, x, y, z);
@@ -3982,7 +4869,6 @@ impl FastNoise {
         }
     }
 
-    #[allow(dead_code)]
     fn single_gradient_perturb3d(
         &self,
         offset: u8,
@@ -4109,6 +4995,33 @@ impl FastNoise {
         *z += lerp(lz0y, lerp(lz0x, lz1x, ys), zs) * warp_amp;
     }
 
+    fn apply_domain_warp(&self, x: &mut f32, y: &mut f32) {
+        match self.domain_warp_type {
+            DomainWarpType::None => {}
+            DomainWarpType::Gradient => self.single_gradient_perturb(
+                0,
+                self.domain_warp_amp,
+                self.domain_warp_frequency,
+                x,
+                y,
+            ),
+            DomainWarpType::GradientFractal => {
+                let mut amp = self.domain_warp_amp * self.domain_warp_bounding;
+                let mut freq = self.domain_warp_frequency;
+                let mut i = 1;
+
+                self.single_gradient_perturb(self.perm[0], amp, self.domain_warp_frequency, x, y);
+
+                while i < self.domain_warp_octaves {
+                    freq *= self.lacunarity;
+                    amp *= self.gain;
+                    self.single_gradient_perturb(self.perm[i as usize], amp, freq, x, y);
+                    i += 1;
+                }
+            }
+        }
+    }
+
     #[allow(dead_code)]
     fn gradient_perturb(&self, x: &mut f32, y: &mut f32) {
         self.single_gradient_perturb(0, self.gradient_perturb_amp, self.frequency, x, y);
@@ -4130,7 +5043,6 @@ impl FastNoise {
         }
     }
 
-    #[allow(dead_code)]
     fn single_gradient_perturb(
         &self,
         offset: u8,
@@ -4199,7 +5111,7 @@ impl FastNoise {
 
 #[cfg(test)]
 mod tests {
-    use super::{CellularDistanceFunction, FastNoise, NoiseType};
+    use super::{CellularDistanceFunction, DomainWarpType, FastNoise, NoiseType};
 
     #[test]
     // Tests that we make an RGB triplet at defaults and it is black.
@@ -4218,4 +5130,193 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    // Domain warping perturbs the sampled coordinates, so a warped instance must
+    // stay deterministic while disagreeing with an unwarped one somewhere.
+    fn test_domain_warp() {
+        let mut plain = FastNoise::seeded(42);
+        plain.set_frequency(0.02);
+        let mut warped = FastNoise::seeded(42);
+        warped.set_frequency(0.02);
+        warped.set_domain_warp_type(DomainWarpType::GradientFractal);
+        warped.set_domain_warp_amp(30.0);
+        warped.set_domain_warp_frequency(0.05);
+        warped.set_domain_warp_octaves(4);
+
+        let mut differs = false;
+        for i in 0..32 {
+            let (x, y) = (i as f32 * 0.37, i as f32 * 0.73);
+            let w = warped.get_noise(x, y);
+            assert!((w - warped.get_noise(x, y)).abs() < f32::EPSILON);
+            if (w - plain.get_noise(x, y)).abs() > f32::EPSILON {
+                differs = true;
+            }
+        }
+        assert!(differs);
+
+        let w3 = warped.get_noise3d(3.0, 4.0, 5.0);
+        assert!((w3 - warped.get_noise3d(3.0, 4.0, 5.0)).abs() < f32::EPSILON);
+        assert!((w3 - plain.get_noise3d(3.0, 4.0, 5.0)).abs() > f32::EPSILON);
+    }
+
+    #[test]
+    // A tile period of 8 lattice cells at frequency 0.125 repeats every 64 world units.
+    fn test_tileable_noise() {
+        for noise_type in [
+            NoiseType::Value,
+            NoiseType::Perlin,
+            NoiseType::Simplex,
+            NoiseType::SimplexFractal,
+        ] {
+            let mut noise = FastNoise::seeded(123);
+            noise.set_noise_type(noise_type);
+            noise.set_frequency(0.125);
+            noise.set_tile_period(8, 8);
+
+            let mut flat = true;
+            for i in 0..16 {
+                let (x, y) = (i as f32 * 3.7, i as f32 * 1.3);
+                let here = noise.get_noise(x, y);
+                assert!((here - noise.get_noise(x + 64.0, y)).abs() < 1.0e-3);
+                assert!((here - noise.get_noise(x, y + 64.0)).abs() < 1.0e-3);
+                if (here - noise.get_noise(0.0, 0.0)).abs() > 0.01 {
+                    flat = false;
+                }
+            }
+            assert!(!flat);
+        }
+    }
+
+    #[test]
+    // 4D sampling should stay in range, vary along the fourth axis, and be deterministic.
+    fn test_noise4d() {
+        for noise_type in [
+            NoiseType::Value,
+            NoiseType::ValueFractal,
+            NoiseType::Perlin,
+            NoiseType::PerlinFractal,
+            NoiseType::Simplex,
+            NoiseType::SimplexFractal,
+            NoiseType::WhiteNoise,
+        ] {
+            let mut noise = FastNoise::seeded(99);
+            noise.set_noise_type(noise_type);
+            noise.set_frequency(0.1);
+
+            let mut moves = false;
+            for i in 0..16 {
+                let (x, y, z) = (i as f32 * 1.7, i as f32 * 0.9, i as f32 * 0.4);
+                let a = noise.get_noise4d(x, y, z, 0.0);
+                let b = noise.get_noise4d(x, y, z, 7.3);
+                assert!(a.abs() <= 1.5 && b.abs() <= 1.5);
+                assert!((a - noise.get_noise4d(x, y, z, 0.0)).abs() < f32::EPSILON);
+                if (a - b).abs() > 0.01 {
+                    moves = true;
+                }
+            }
+            assert!(moves);
+        }
+    }
+
+    #[test]
+    // The OpenSimplex2 variants should stay close to the [-1, 1] range in 2D
+    // and 3D, vary, and be deterministic - plain and fractal alike.
+    fn test_open_simplex2() {
+        for noise_type in [
+            NoiseType::OpenSimplex2,
+            NoiseType::OpenSimplex2Fractal,
+            NoiseType::OpenSimplex2S,
+            NoiseType::OpenSimplex2SFractal,
+        ] {
+            let mut noise = FastNoise::seeded(4242);
+            noise.set_noise_type(noise_type);
+            noise.set_frequency(0.23);
+
+            let mut min = f32::MAX;
+            let mut max = f32::MIN;
+            for i in 0..400 {
+                let (x, y, z) = (i as f32 * 0.713, i as f32 * 0.447, i as f32 * 0.951);
+                let flat = noise.get_noise(x, y);
+                let solid = noise.get_noise3d(x, y, z);
+                assert!(flat.abs() <= 1.25 && solid.abs() <= 1.25);
+                assert!((flat - noise.get_noise(x, y)).abs() < f32::EPSILON);
+                assert!((solid - noise.get_noise3d(x, y, z)).abs() < f32::EPSILON);
+                min = min.min(flat).min(solid);
+                max = max.max(flat).max(solid);
+            }
+            assert!(max - min > 0.5);
+        }
+    }
+
+    #[test]
+    // Batch filling must agree exactly with per-call sampling.
+    fn test_fill_2d() {
+        let mut noise = FastNoise::seeded(77);
+        noise.set_noise_type(NoiseType::SimplexFractal);
+        noise.set_frequency(0.1);
+
+        let (width, height) = (33, 17);
+        let origin = (4.5, -2.25);
+        let step = 0.75;
+        let mut samples = vec![0.0; width * height];
+        noise.fill_2d(&mut samples, width, height, origin, step);
+
+        for row in 0..height {
+            for column in 0..width {
+                let expected = noise.get_noise(
+                    origin.0 + column as f32 * step,
+                    origin.1 + row as f32 * step,
+                );
+                assert!((samples[row * width + column] - expected).abs() < f32::EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    // Cell ids should be deterministic, vary across the map, and F2-F1 style
+    // return types plus the Chebyshev and custom metrics should all produce
+    // sane values.
+    fn test_cellular_regions_and_metrics() {
+        use super::{CellularReturnType, FastNoise};
+        use std::collections::HashSet;
+
+        let mut noise = FastNoise::seeded(31);
+        noise.set_noise_type(NoiseType::Cellular);
+        noise.set_frequency(0.05);
+
+        let mut ids = HashSet::new();
+        for i in 0..64 {
+            let (x, y) = (i as f32 * 17.3, i as f32 * 11.1);
+            let id = noise.get_cellular_cell_id(x, y);
+            assert_eq!(id, noise.get_cellular_cell_id(x, y));
+            ids.insert(id);
+            let id3d = noise.get_cellular_cell_id3d(x, y, i as f32 * 5.7);
+            assert_eq!(id3d, noise.get_cellular_cell_id3d(x, y, i as f32 * 5.7));
+        }
+        assert!(ids.len() > 4);
+
+        // F2-F1: the second-closest feature point is never closer than the first.
+        noise.set_cellular_return_type(CellularReturnType::Distance2Sub);
+        for i in 0..64 {
+            let (x, y) = (i as f32 * 17.3, i as f32 * 11.1);
+            assert!(noise.get_noise(x, y) >= 0.0);
+            assert!(noise.get_noise3d(x, y, i as f32 * 5.7) >= 0.0);
+        }
+
+        // A custom metric equal to Manhattan must agree with the built-in one.
+        noise.set_cellular_distance_function(CellularDistanceFunction::Manhattan);
+        let builtin = noise.get_noise(100.0, 200.0);
+        noise.set_cellular_distance_function(CellularDistanceFunction::Euclidean);
+        noise.set_cellular_custom_distance(Some(|x, y, z| x.abs() + y.abs() + z.abs()));
+        assert!((noise.get_noise(100.0, 200.0) - builtin).abs() < f32::EPSILON);
+        noise.set_cellular_custom_distance(None);
+
+        // Chebyshev runs and stays non-negative for distance returns.
+        noise.set_cellular_distance_function(CellularDistanceFunction::Chebyshev);
+        noise.set_cellular_return_type(CellularReturnType::Distance);
+        for i in 0..64 {
+            assert!(noise.get_noise(i as f32 * 17.3, i as f32 * 11.1) >= 0.0);
+        }
+    }
 }
@@ -1,5 +1,11 @@
+mod erosion;
 mod fastnoise;
+mod noise_graph;
+mod preview;
 
 pub mod prelude {
+    pub use crate::erosion::*;
     pub use crate::fastnoise::*;
+    pub use crate::noise_graph::*;
+    pub use crate::preview::*;
 }
@@ -0,0 +1,282 @@
+//! A small combinator layer over [`FastNoise`] for describing layered terrain
+//! declaratively: wrap sources in [`NoiseNode`]s, combine them with `add`,
+//! `multiply`, `min`/`max`, reshape them with `clamp`, `curve` or `terrace`,
+//! and blend them with `select`, then sample the composed graph like a single
+//! noise source.
+//!
+//! ```rust
+//! use bracket_noise::prelude::*;
+//!
+//! let mut continents = FastNoise::seeded(1);
+//! continents.set_noise_type(NoiseType::Perlin);
+//! continents.set_frequency(0.01);
+//!
+//! let mut mountains = FastNoise::seeded(2);
+//! mountains.set_noise_type(NoiseType::SimplexFractal);
+//! mountains.set_fractal_type(FractalType::RigidMulti);
+//! mountains.set_frequency(0.03);
+//!
+//! let mut mask = FastNoise::seeded(3);
+//! mask.set_frequency(0.005);
+//!
+//! // Continents of low-frequency perlin, masked over by ridged mountains.
+//! let terrain = NoiseNode::source(continents).select(
+//!     NoiseNode::source(mountains),
+//!     NoiseNode::source(mask),
+//!     0.4,
+//!     0.1,
+//! );
+//! let height = terrain.get_noise(10.5, 20.5);
+//! assert!(height.is_finite());
+//! ```
+
+use crate::fastnoise::FastNoise;
+
+/// One node of a composable noise graph. Build leaves with [`NoiseNode::source`]
+/// and [`NoiseNode::constant`], grow the graph with the combinator methods, and
+/// sample the root with [`NoiseNode::get_noise`] or [`NoiseNode::get_noise3d`];
+/// every [`FastNoise`] leaf is sampled at the same coordinates.
+pub enum NoiseNode {
+    /// A `FastNoise` leaf, sampled with its own type, frequency and seed.
+    Source(FastNoise),
+    /// A fixed value, regardless of coordinates.
+    Constant(f32),
+    /// The sum of both children.
+    Add(Box<NoiseNode>, Box<NoiseNode>),
+    /// The product of both children.
+    Multiply(Box<NoiseNode>, Box<NoiseNode>),
+    /// The smaller of both children.
+    Min(Box<NoiseNode>, Box<NoiseNode>),
+    /// The larger of both children.
+    Max(Box<NoiseNode>, Box<NoiseNode>),
+    /// The child, clamped to `[min, max]`.
+    Clamp(Box<NoiseNode>, f32, f32),
+    /// The child, remapped piecewise-linearly through `(input, output)` control
+    /// points; inputs outside the first/last point are clamped to them.
+    Curve(Box<NoiseNode>, Vec<(f32, f32)>),
+    /// The child, quantized into the given number of flat steps across `[-1, 1]`.
+    Terrace(Box<NoiseNode>, u32),
+    /// `a` where the mask reads below the threshold, `b` above it, blended
+    /// linearly across `falloff` on either side of the threshold.
+    Select {
+        a: Box<NoiseNode>,
+        b: Box<NoiseNode>,
+        mask: Box<NoiseNode>,
+        threshold: f32,
+        falloff: f32,
+    },
+}
+
+impl NoiseNode {
+    /// Wraps a configured `FastNoise` as a graph leaf.
+    pub fn source(noise: FastNoise) -> NoiseNode {
+        NoiseNode::Source(noise)
+    }
+
+    /// A leaf that always reads the given value.
+    pub fn constant(value: f32) -> NoiseNode {
+        NoiseNode::Constant(value)
+    }
+
+    /// Adds another node to this one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn add(self, other: NoiseNode) -> NoiseNode {
+        NoiseNode::Add(Box::new(self), Box::new(other))
+    }
+
+    /// Multiplies this node by another.
+    pub fn multiply(self, other: NoiseNode) -> NoiseNode {
+        NoiseNode::Multiply(Box::new(self), Box::new(other))
+    }
+
+    /// Takes the smaller of this node and another.
+    pub fn min(self, other: NoiseNode) -> NoiseNode {
+        NoiseNode::Min(Box::new(self), Box::new(other))
+    }
+
+    /// Takes the larger of this node and another.
+    pub fn max(self, other: NoiseNode) -> NoiseNode {
+        NoiseNode::Max(Box::new(self), Box::new(other))
+    }
+
+    /// Clamps this node's output to `[min, max]`.
+    pub fn clamp(self, min: f32, max: f32) -> NoiseNode {
+        NoiseNode::Clamp(Box::new(self), min, max)
+    }
+
+    /// Remaps this node's output through `(input, output)` control points,
+    /// sorted by input for you.
+    pub fn curve(self, mut points: Vec<(f32, f32)>) -> NoiseNode {
+        points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        NoiseNode::Curve(Box::new(self), points)
+    }
+
+    /// Quantizes this node's output into `steps` flat levels across `[-1, 1]`.
+    pub fn terrace(self, steps: u32) -> NoiseNode {
+        NoiseNode::Terrace(Box::new(self), steps)
+    }
+
+    /// Picks between this node and `other` by sampling `mask`: below the
+    /// threshold this node is used, above it `other`, blended linearly across
+    /// `falloff` on either side of the threshold.
+    pub fn select(self, other: NoiseNode, mask: NoiseNode, threshold: f32, falloff: f32) -> Self {
+        NoiseNode::Select {
+            a: Box::new(self),
+            b: Box::new(other),
+            mask: Box::new(mask),
+            threshold,
+            falloff,
+        }
+    }
+
+    /// Samples the composed graph in 2D.
+    pub fn get_noise(&self, x: f32, y: f32) -> f32 {
+        self.sample(&|noise| noise.get_noise(x, y))
+    }
+
+    /// Samples the composed graph in 3D.
+    pub fn get_noise3d(&self, x: f32, y: f32, z: f32) -> f32 {
+        self.sample(&|noise| noise.get_noise3d(x, y, z))
+    }
+
+    fn sample(&self, leaf: &dyn Fn(&FastNoise) -> f32) -> f32 {
+        match self {
+            NoiseNode::Source(noise) => leaf(noise),
+            NoiseNode::Constant(value) => *value,
+            NoiseNode::Add(a, b) => a.sample(leaf) + b.sample(leaf),
+            NoiseNode::Multiply(a, b) => a.sample(leaf) * b.sample(leaf),
+            NoiseNode::Min(a, b) => f32::min(a.sample(leaf), b.sample(leaf)),
+            NoiseNode::Max(a, b) => f32::max(a.sample(leaf), b.sample(leaf)),
+            NoiseNode::Clamp(inner, min, max) => f32::min(f32::max(inner.sample(leaf), *min), *max),
+            NoiseNode::Curve(inner, points) => curve_remap(inner.sample(leaf), points),
+            NoiseNode::Terrace(inner, steps) => terrace_quantize(inner.sample(leaf), *steps),
+            NoiseNode::Select {
+                a,
+                b,
+                mask,
+                threshold,
+                falloff,
+            } => {
+                let selector = mask.sample(leaf);
+                if selector < threshold - falloff {
+                    a.sample(leaf)
+                } else if selector > threshold + falloff {
+                    b.sample(leaf)
+                } else if *falloff > 0.0 {
+                    let t = (selector - (threshold - falloff)) / (2.0 * falloff);
+                    let low = a.sample(leaf);
+                    low + t * (b.sample(leaf) - low)
+                } else {
+                    b.sample(leaf)
+                }
+            }
+        }
+    }
+}
+
+fn curve_remap(value: f32, points: &[(f32, f32)]) -> f32 {
+    match points {
+        [] => value,
+        [only] => only.1,
+        [first, ..] if value <= first.0 => first.1,
+        [.., last] if value >= last.0 => last.1,
+        _ => {
+            let segment = points.windows(2).find(|pair| value <= pair[1].0).unwrap();
+            let (input0, output0) = segment[0];
+            let (input1, output1) = segment[1];
+            let t = (value - input0) / (input1 - input0);
+            output0 + t * (output1 - output0)
+        }
+    }
+}
+
+fn terrace_quantize(value: f32, steps: u32) -> f32 {
+    if steps < 2 {
+        return value;
+    }
+    let steps = steps as f32;
+    let t = ((value + 1.0) * 0.5).clamp(0.0, 1.0);
+    let level = f32::min((t * steps).floor(), steps - 1.0);
+    level / (steps - 1.0) * 2.0 - 1.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NoiseNode;
+    use crate::fastnoise::{FastNoise, NoiseType};
+
+    fn simplex(seed: u64) -> FastNoise {
+        let mut noise = FastNoise::seeded(seed);
+        noise.set_noise_type(NoiseType::Simplex);
+        noise.set_frequency(0.1);
+        noise
+    }
+
+    #[test]
+    fn arithmetic_nodes_match_direct_sampling() {
+        let graph = NoiseNode::source(simplex(1))
+            .add(NoiseNode::source(simplex(2)))
+            .multiply(NoiseNode::constant(0.5));
+        let direct = simplex(1);
+        let other = simplex(2);
+
+        for i in 0..32 {
+            let (x, y) = (i as f32 * 1.3, i as f32 * 0.7);
+            let expected = (direct.get_noise(x, y) + other.get_noise(x, y)) * 0.5;
+            assert!((graph.get_noise(x, y) - expected).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn clamp_and_terrace_restrict_outputs() {
+        let clamped = NoiseNode::source(simplex(3)).clamp(-0.25, 0.25);
+        let terraced = NoiseNode::source(simplex(3)).terrace(4);
+
+        for i in 0..64 {
+            let (x, y, z) = (i as f32 * 1.3, i as f32 * 0.7, i as f32 * 0.4);
+            let v = clamped.get_noise3d(x, y, z);
+            assert!((-0.25..=0.25).contains(&v));
+            let t = terraced.get_noise(x, y);
+            // Four steps means outputs land on one of four evenly spaced levels.
+            assert!((t * 1.5).fract().abs() < 1.0e-5 || ((t * 1.5).fract().abs() - 1.0) < 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn curve_remaps_through_control_points() {
+        let curved =
+            NoiseNode::constant(0.0).curve(vec![(-1.0, 0.0), (0.5, 0.75), (1.0, 1.0)]);
+        // 0.0 lies halfway between -1.0 and 0.5, so output is halfway to 0.75.
+        assert!((curved.get_noise(0.0, 0.0) - 0.5).abs() < 1.0e-6);
+
+        let clamped_ends = NoiseNode::constant(2.0).curve(vec![(-1.0, 0.0), (1.0, 1.0)]);
+        assert!((clamped_ends.get_noise(0.0, 0.0) - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn select_switches_on_the_mask() {
+        let picks_low = NoiseNode::constant(-1.0).select(
+            NoiseNode::constant(1.0),
+            NoiseNode::constant(0.0),
+            0.5,
+            0.1,
+        );
+        assert!((picks_low.get_noise(0.0, 0.0) + 1.0).abs() < f32::EPSILON);
+
+        let picks_high = NoiseNode::constant(-1.0).select(
+            NoiseNode::constant(1.0),
+            NoiseNode::constant(0.9),
+            0.5,
+            0.1,
+        );
+        assert!((picks_high.get_noise(0.0, 0.0) - 1.0).abs() < f32::EPSILON);
+
+        let blended = NoiseNode::constant(-1.0).select(
+            NoiseNode::constant(1.0),
+            NoiseNode::constant(0.5),
+            0.5,
+            0.1,
+        );
+        assert!(blended.get_noise(0.0, 0.0).abs() < f32::EPSILON);
+    }
+}
@@ -0,0 +1,186 @@
+//! Quick previews of noise settings, without per-project boilerplate: render a
+//! [`FastNoise`] or composed [`NoiseNode`] region to raw grayscale or RGBA
+//! pixel buffers (drop-in for `image::GrayImage::from_raw` /
+//! `image::RgbaImage::from_raw`), or to rows of shaded glyphs for printing
+//! straight onto a console:
+//!
+//! ```rust
+//! use bracket_noise::prelude::*;
+//!
+//! let mut noise = FastNoise::seeded(42);
+//! noise.set_noise_type(NoiseType::SimplexFractal);
+//! noise.set_frequency(0.1);
+//!
+//! for row in render_ascii(&noise, 16, 8, (0.0, 0.0), 1.0) {
+//!     println!("{}", row);
+//!     // ...or print it onto a bracket-terminal console: ctx.print(0, y, &row)
+//! }
+//! ```
+
+use crate::fastnoise::FastNoise;
+use crate::noise_graph::NoiseNode;
+
+/// A 2D noise source that the preview helpers can sample; implemented by both
+/// `FastNoise` and `NoiseNode` so either can be rendered.
+pub trait Noise2D {
+    /// Samples the source at a world coordinate, nominally in `[-1, 1]`.
+    fn sample(&self, x: f32, y: f32) -> f32;
+}
+
+impl Noise2D for FastNoise {
+    fn sample(&self, x: f32, y: f32) -> f32 {
+        self.get_noise(x, y)
+    }
+}
+
+impl Noise2D for NoiseNode {
+    fn sample(&self, x: f32, y: f32) -> f32 {
+        self.get_noise(x, y)
+    }
+}
+
+// Maps a noise value from [-1, 1] to [0, 1], clamped.
+fn unit(value: f32) -> f32 {
+    ((value + 1.0) * 0.5).clamp(0.0, 1.0)
+}
+
+/// Renders a `width * height` region to 8-bit grayscale pixels in row-major
+/// order, mapping noise values from `[-1, 1]` to `[0, 255]`. Cell `(column,
+/// row)` is sampled at `origin + (column, row) * step`. The buffer is laid out
+/// for `image::GrayImage::from_raw(width, height, buffer)`.
+pub fn render_gray(
+    noise: &dyn Noise2D,
+    width: usize,
+    height: usize,
+    origin: (f32, f32),
+    step: f32,
+) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for column in 0..width {
+            let value = noise.sample(origin.0 + column as f32 * step, origin.1 + row as f32 * step);
+            pixels.push((unit(value) * 255.0) as u8);
+        }
+    }
+    pixels
+}
+
+/// As `render_gray`, but produces RGBA pixels colored through a ramp: noise
+/// values from `[-1, 1]` are interpolated linearly across the given color
+/// stops, alpha is always 255. An empty ramp falls back to grayscale. The
+/// buffer is laid out for `image::RgbaImage::from_raw(width, height, buffer)`.
+pub fn render_rgba(
+    noise: &dyn Noise2D,
+    width: usize,
+    height: usize,
+    origin: (f32, f32),
+    step: f32,
+    ramp: &[(u8, u8, u8)],
+) -> Vec<u8> {
+    let mut pixels = Vec::with_capacity(width * height * 4);
+    for row in 0..height {
+        for column in 0..width {
+            let t = unit(noise.sample(
+                origin.0 + column as f32 * step,
+                origin.1 + row as f32 * step,
+            ));
+            let (r, g, b) = ramp_color(ramp, t);
+            pixels.extend_from_slice(&[r, g, b, 255]);
+        }
+    }
+    pixels
+}
+
+/// As `render_gray`, but produces one `String` of shaded glyphs per row, from
+/// lightest (space) to darkest (solid block) - ready to print line by line onto
+/// a terminal or a bracket-terminal console.
+pub fn render_ascii(
+    noise: &dyn Noise2D,
+    width: usize,
+    height: usize,
+    origin: (f32, f32),
+    step: f32,
+) -> Vec<String> {
+    const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+    let mut rows = Vec::with_capacity(height);
+    for row in 0..height {
+        let mut line = String::with_capacity(width);
+        for column in 0..width {
+            let t = unit(noise.sample(
+                origin.0 + column as f32 * step,
+                origin.1 + row as f32 * step,
+            ));
+            let shade = ((t * SHADES.len() as f32) as usize).min(SHADES.len() - 1);
+            line.push(SHADES[shade]);
+        }
+        rows.push(line);
+    }
+    rows
+}
+
+fn ramp_color(ramp: &[(u8, u8, u8)], t: f32) -> (u8, u8, u8) {
+    match ramp {
+        [] => {
+            let gray = (t * 255.0) as u8;
+            (gray, gray, gray)
+        }
+        [only] => *only,
+        _ => {
+            let scaled = t * (ramp.len() - 1) as f32;
+            let low = (scaled as usize).min(ramp.len() - 2);
+            let blend = scaled - low as f32;
+            let (r0, g0, b0) = ramp[low];
+            let (r1, g1, b1) = ramp[low + 1];
+            (
+                (r0 as f32 + blend * (r1 as f32 - r0 as f32)) as u8,
+                (g0 as f32 + blend * (g1 as f32 - g0 as f32)) as u8,
+                (b0 as f32 + blend * (b1 as f32 - b0 as f32)) as u8,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{render_ascii, render_gray, render_rgba};
+    use crate::fastnoise::{FastNoise, NoiseType};
+
+    fn noise() -> FastNoise {
+        let mut noise = FastNoise::seeded(7);
+        noise.set_noise_type(NoiseType::Simplex);
+        noise.set_frequency(0.2);
+        noise
+    }
+
+    #[test]
+    fn gray_buffer_matches_samples() {
+        let noise = noise();
+        let pixels = render_gray(&noise, 8, 4, (1.0, 2.0), 0.5);
+        assert_eq!(pixels.len(), 8 * 4);
+
+        let value = noise.get_noise(1.0 + 3.0 * 0.5, 2.0 + 2.0 * 0.5);
+        let expected = (((value + 1.0) * 0.5).clamp(0.0, 1.0) * 255.0) as u8;
+        assert_eq!(pixels[2 * 8 + 3], expected);
+    }
+
+    #[test]
+    fn rgba_buffer_uses_the_ramp() {
+        let pixels = render_rgba(&noise(), 4, 4, (0.0, 0.0), 1.0, &[(0, 0, 255), (255, 0, 0)]);
+        assert_eq!(pixels.len(), 4 * 4 * 4);
+        for pixel in pixels.chunks(4) {
+            // Blue-to-red ramp never produces green, and alpha is opaque.
+            assert_eq!(pixel[1], 0);
+            assert_eq!(pixel[3], 255);
+        }
+    }
+
+    #[test]
+    fn ascii_rows_have_the_requested_shape() {
+        let rows = render_ascii(&noise(), 10, 3, (0.0, 0.0), 1.0);
+        assert_eq!(rows.len(), 3);
+        for row in &rows {
+            assert_eq!(row.chars().count(), 10);
+        }
+    }
+}
@@ -19,6 +19,18 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             black_box(path);
         })
     });
+
+    c.bench_function("a_star_bidirectional_test_map", |b| {
+        b.iter(|| {
+            let map = Map::new();
+            let path = a_star_search_bidirectional(
+                map.point2d_to_index(START_POINT),
+                map.point2d_to_index(END_POINT),
+                &map,
+            );
+            black_box(path);
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
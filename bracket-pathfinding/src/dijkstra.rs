@@ -23,7 +23,7 @@ pub struct DijkstraMap {
 struct ParallelDm {
     map: Vec<f32>,
     max_depth: f32,
-    starts: Vec<usize>,
+    starts: Vec<(usize, f32)>,
 }
 
 // This is chosen arbitrarily. Whether it's better to
@@ -70,7 +70,9 @@ impl DijkstraMap {
     /// Construct a new Dijkstra map, ready to run. You must specify the map size, and link to an implementation
     /// of a BaseMap trait that can generate exits lists. It then builds the map, giving you a result.
     /// Starts is provided as a set of tuples, two per tile. The first is the tile index, the second the starting
-    /// weight (defaults to 0.0 on new)
+    /// weight (defaults to 0.0 on new). Weights may be negative (more desirable than a plain goal)
+    /// or positive (a goal to approach only when nothing better is in range), giving the classic
+    /// "desire map" behavior; `find_lowest_exit` and friends work on the result unchanged.
     pub fn new_weighted<T>(
         size_x: T,
         size_y: T,
@@ -94,6 +96,103 @@ impl DijkstraMap {
         d
     }
 
+    /// Builds one Dijkstra map per seed set - e.g. one per stimulus - in a single call.
+    /// The map's exits are gathered once and the floods then run on rayon's thread pool,
+    /// one per seed set, so a turn's worth of maps costs little more than the largest one.
+    /// Results are in the same order as the seed sets.
+    #[cfg(feature = "threaded")]
+    pub fn build_many<T>(
+        size_x: T,
+        size_y: T,
+        seed_sets: &[Vec<usize>],
+        map: &dyn BaseMap,
+        max_depth: f32,
+    ) -> Vec<DijkstraMap>
+    where
+        T: TryInto<usize>,
+    {
+        let sz_x: usize = size_x.try_into().ok().unwrap();
+        let sz_y: usize = size_y.try_into().ok().unwrap();
+        let mapsize = sz_x * sz_y;
+        let exits: Vec<SmallVec<[(usize, f32); 10]>> = (0..mapsize)
+            .map(|idx| map.get_available_exits(idx))
+            .collect();
+
+        seed_sets
+            .par_iter()
+            .map(|starts| {
+                let mut dm = DijkstraMap::new_empty(sz_x, sz_y, max_depth);
+                let mut open_list: VecDeque<(usize, f32)> = VecDeque::with_capacity(mapsize);
+                for start in starts {
+                    dm.map[*start] = 0.0;
+                    open_list.push_back((*start, 0.0));
+                }
+                while let Some((tile_idx, depth)) = open_list.pop_front() {
+                    for (new_idx, add_depth) in &exits[tile_idx] {
+                        let new_idx = *new_idx;
+                        let new_depth = depth + add_depth;
+                        if new_depth >= dm.map[new_idx] {
+                            continue;
+                        }
+                        if new_depth >= dm.max_depth {
+                            continue;
+                        }
+                        dm.map[new_idx] = new_depth;
+                        open_list.push_back((new_idx, new_depth));
+                    }
+                }
+                dm
+            })
+            .collect()
+    }
+
+    /// Sequential fallback for `build_many` when the `threaded` feature is disabled:
+    /// builds each map in turn with `build`.
+    #[cfg(not(feature = "threaded"))]
+    pub fn build_many<T>(
+        size_x: T,
+        size_y: T,
+        seed_sets: &[Vec<usize>],
+        map: &dyn BaseMap,
+        max_depth: f32,
+    ) -> Vec<DijkstraMap>
+    where
+        T: TryInto<usize> + Copy,
+    {
+        seed_sets
+            .iter()
+            .map(|starts| DijkstraMap::new(size_x, size_y, starts, map, max_depth))
+            .collect()
+    }
+
+    /// The classic "coward's map" trick in one call: every reachable tile is re-seeded at its
+    /// current value multiplied by `-coefficient` (the folklore value is 1.2) and the map is
+    /// rescanned. The result is a safety map: rolling downhill leads away from the original
+    /// goals, but around corners and through side passages rather than straight into dead ends.
+    pub fn flee_map(&self, coefficient: f32, map: &dyn BaseMap) -> DijkstraMap {
+        let starts: Vec<(usize, f32)> = self
+            .map
+            .iter()
+            .enumerate()
+            .filter(|(_idx, depth)| **depth < MAX)
+            .map(|(idx, depth)| (idx, depth * -coefficient))
+            .collect();
+        let mut result = DijkstraMap::new_empty(self.size_x, self.size_y, self.max_depth);
+        DijkstraMap::build_weighted(&mut result, &starts, map);
+        result
+    }
+
+    /// Coward pathing: the exit that best retreats from the original goals. This is simply
+    /// `find_lowest_exit` on a map produced by `flee_map`, named for discoverability.
+    pub fn find_flee_exit(flee: &DijkstraMap, position: usize, map: &dyn BaseMap) -> Option<usize> {
+        DijkstraMap::find_lowest_exit(flee, position, map)
+    }
+
+    /// The dimensions the map was built with, as (x, y).
+    pub fn size(&self) -> (usize, usize) {
+        (self.size_x, self.size_y)
+    }
+
     /// Creates an empty Dijkstra map node.
     pub fn new_empty<T>(size_x: T, size_y: T, max_depth: f32) -> DijkstraMap
     where
@@ -136,7 +235,11 @@ impl DijkstraMap {
     }
 
     #[cfg(feature = "threaded")]
-    fn build_helper_weighted(dm: &mut DijkstraMap, starts: &[(usize, f32)], map: &dyn BaseMap) -> RunThreaded {
+    fn build_helper_weighted(
+        dm: &mut DijkstraMap,
+        starts: &[(usize, f32)],
+        map: &dyn BaseMap,
+    ) -> RunThreaded {
         if starts.len() >= THREADED_REQUIRED_STARTS {
             DijkstraMap::build_parallel_weighted(dm, starts, map);
             return RunThreaded::True;
@@ -144,6 +247,15 @@ impl DijkstraMap {
         RunThreaded::False
     }
 
+    #[cfg(not(feature = "threaded"))]
+    fn build_helper_weighted(
+        _dm: &mut DijkstraMap,
+        _starts: &[(usize, f32)],
+        _map: &dyn BaseMap,
+    ) -> RunThreaded {
+        RunThreaded::False
+    }
+
     /// Builds the Dijkstra map: iterate from each starting point, to each exit provided by BaseMap's
     /// exits implementation. Each step adds cost to the current depth, and is discarded if the new
     /// depth is further than the current depth.
@@ -159,6 +271,7 @@ impl DijkstraMap {
         let mut open_list: VecDeque<(usize, f32)> = VecDeque::with_capacity(mapsize);
 
         for start in starts {
+            dm.map[*start] = 0.0;
             open_list.push_back((*start, 0.0));
         }
 
@@ -179,18 +292,23 @@ impl DijkstraMap {
         }
     }
 
-    /// Builds the Dijkstra map: iterate from each starting point, to each exit provided by BaseMap's
-    /// exits implementation. Each step adds cost to the current depth, and is discarded if the new
-    /// depth is further than the current depth.
+    /// Builds the Dijkstra map from weighted starting points: each seed is written into the map
+    /// at its initial weight, then flooded outwards as in `build`. Negative weights make a seed
+    /// more attractive, positive weights less so; competing seeds each claim the tiles they win.
     /// WARNING: Will give incorrect results when used with non-uniform exit costs. Much slower
     /// algorithm required to support that.
     /// Automatically branches to a parallel version if you provide more than 4 starting points
     pub fn build_weighted(dm: &mut DijkstraMap, starts: &[(usize, f32)], map: &dyn BaseMap) {
+        let threaded = DijkstraMap::build_helper_weighted(dm, starts, map);
+        if threaded == RunThreaded::True {
+            return;
+        }
         let mapsize: usize = (dm.size_x * dm.size_y) as usize;
         let mut open_list: VecDeque<(usize, f32)> = VecDeque::with_capacity(mapsize);
 
-        for start in starts {
-            open_list.push_back(*start);
+        for (start, weight) in starts {
+            dm.map[*start] = f32::min(dm.map[*start], *weight);
+            open_list.push_back((*start, *weight));
         }
 
         while let Some((tile_idx, depth)) = open_list.pop_front() {
@@ -223,7 +341,7 @@ impl DijkstraMap {
             };
             layer
                 .starts
-                .extend(start_chunk.iter().copied().map(|x| x as usize));
+                .extend(start_chunk.iter().copied().map(|x| (x as usize, 0.0)));
             layers.push(layer);
         }
 
@@ -235,8 +353,9 @@ impl DijkstraMap {
         layers.par_iter_mut().for_each(|l| {
             let mut open_list: VecDeque<(usize, f32)> = VecDeque::with_capacity(mapsize);
 
-            for start in l.starts.iter().copied() {
-                open_list.push_back((start, 0.0));
+            for (start, weight) in l.starts.iter().copied() {
+                l.map[start] = f32::min(l.map[start], weight);
+                open_list.push_back((start, weight));
             }
 
             while let Some((tile_idx, depth)) = open_list.pop_front() {
@@ -265,8 +384,9 @@ impl DijkstraMap {
         }
     }
 
-        #[cfg(feature = "threaded")]
-    fn build_parallel(dm: &mut DijkstraMap, starts: &[usize], map: &dyn BaseMap) {
+    /// Implementation of Parallel Dijkstra with weighted starting points.
+    #[cfg(feature = "threaded")]
+    fn build_parallel_weighted(dm: &mut DijkstraMap, starts: &[(usize, f32)], map: &dyn BaseMap) {
         let mapsize: usize = (dm.size_x * dm.size_y) as usize;
         let mut layers: Vec<ParallelDm> = Vec::with_capacity(starts.len());
         for start_chunk in starts.chunks(rayon::current_num_threads()) {
@@ -275,9 +395,7 @@ impl DijkstraMap {
                 max_depth: dm.max_depth,
                 starts: Vec::new(),
             };
-            layer
-                .starts
-                .extend(start_chunk.iter().copied().map(|x| x as usize));
+            layer.starts.extend(start_chunk.iter().copied());
             layers.push(layer);
         }
 
@@ -289,8 +407,9 @@ impl DijkstraMap {
         layers.par_iter_mut().for_each(|l| {
             let mut open_list: VecDeque<(usize, f32)> = VecDeque::with_capacity(mapsize);
 
-            for start in l.starts.iter().copied() {
-                open_list.push_back((start, 0.0));
+            for (start, weight) in l.starts.iter().copied() {
+                l.map[start] = f32::min(l.map[start], weight);
+                open_list.push_back((start, weight));
             }
 
             while let Some((tile_idx, depth)) = open_list.pop_front() {
@@ -418,6 +537,53 @@ mod test {
             }
         }
     }
+    // 1 by 5 stripe of tiles, uniform cost in both directions
+    struct Strip;
+    impl BaseMap for Strip {
+        fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+            match idx {
+                0 => smallvec![(1, 1.)],
+                4 => smallvec![(3, 1.)],
+                _ => smallvec![(idx - 1, 1.), (idx + 1, 1.)],
+            }
+        }
+    }
+
+    #[test]
+    fn test_build_many() {
+        let map = Strip {};
+        let seed_sets = vec![vec![0], vec![4], vec![0, 4]];
+        let many = DijkstraMap::build_many(5, 1, &seed_sets, &map, 10.);
+        assert_eq!(many.len(), 3);
+        for (seeds, dm) in seed_sets.iter().zip(many.iter()) {
+            let single = DijkstraMap::new(5, 1, seeds, &map, 10.);
+            assert_eq!(dm.map, single.map);
+        }
+    }
+
+    #[test]
+    fn test_flee_map() {
+        let map = Strip {};
+        let danger = DijkstraMap::new(5, 1, &[0], &map, 10.);
+        let safety = danger.flee_map(1.2, &map);
+        // Safety increases with distance from the threat...
+        for pair in safety.map.windows(2) {
+            assert!(pair[1] < pair[0]);
+        }
+        // ...and a coward standing mid-strip retreats away from it.
+        assert_eq!(DijkstraMap::find_flee_exit(&safety, 2, &map), Some(3));
+    }
+
+    #[test]
+    fn test_weighted_starts() {
+        let map = Strip {};
+        // An ordinary goal at one end, a much more desirable one at the other.
+        let desire = DijkstraMap::new_weighted(5, 1, &[(0, 0.0), (4, -3.0)], &map, 10.);
+        assert_eq!(desire.map, vec![0.0, 0.0, -1.0, -2.0, -3.0]);
+        // Rolling downhill from the middle heads for the weighted seed.
+        assert_eq!(DijkstraMap::find_lowest_exit(&desire, 1, &map), Some(2));
+    }
+
     #[test]
     fn test_highest_exit() {
         let map = MiniMap {};
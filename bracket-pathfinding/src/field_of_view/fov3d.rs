@@ -0,0 +1,129 @@
+use bracket_algorithm_traits::prelude::Algorithm3D;
+use bracket_geometry::prelude::{Bresenham3D, Point3};
+
+use std::collections::HashSet;
+
+/// Calculates field-of-view across Z-levels for a map that supports Algorithm3D, returning a
+/// HashSet of visible `Point3`s. Voxel opacity comes from `BaseMap::is_opaque` with
+/// `point3d_to_index` indices. Rays are cast with `Bresenham3D` to every voxel on the sight
+/// sphere's surface; an opaque voxel is itself visible but blocks everything beyond it, so
+/// floors hide the level below except through openings.
+pub fn field_of_view_3d_set(
+    center: Point3,
+    range: i32,
+    fov_check: &dyn Algorithm3D,
+) -> HashSet<Point3> {
+    let mut visible_points: HashSet<Point3> =
+        HashSet::with_capacity((6 * range * range) as usize);
+    visible_points.insert(center);
+
+    let limit = range * (range + 1);
+    let mut cast = |target: Point3| {
+        for point in Bresenham3D::new(center, target).chain(std::iter::once(target)) {
+            let offset = point - center;
+            if offset.x * offset.x + offset.y * offset.y + offset.z * offset.z > limit {
+                break;
+            }
+            if !fov_check.in_bounds(point) {
+                break;
+            }
+            visible_points.insert(point);
+            if point != center && fov_check.is_opaque(fov_check.point3d_to_index(point)) {
+                break;
+            }
+        }
+    };
+
+    // Target every voxel on the surface of the range cube; the radial limit
+    // above trims the corners down to the sight sphere.
+    for a in -range..=range {
+        for b in -range..=range {
+            for &face in &[-range, range] {
+                cast(Point3::new(center.x + a, center.y + b, center.z + face));
+                cast(Point3::new(center.x + a, center.y + face, center.z + b));
+                cast(Point3::new(center.x + face, center.y + a, center.z + b));
+            }
+        }
+    }
+
+    visible_points
+}
+
+/// Calculates field-of-view across Z-levels for a map that supports Algorithm3D.
+pub fn field_of_view_3d(center: Point3, range: i32, fov_check: &dyn Algorithm3D) -> Vec<Point3> {
+    field_of_view_3d_set(center, range, fov_check)
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{field_of_view_3d, field_of_view_3d_set};
+    use bracket_algorithm_traits::prelude::{Algorithm3D, BaseMap};
+    use bracket_geometry::prelude::Point3;
+
+    const W: i32 = 11;
+    const H: i32 = 11;
+    const D: i32 = 5;
+
+    // An 11x11x5 map with a solid floor slab at z=2, pierced at (5, 5, 2).
+    struct TestMap {
+        solid: Vec<bool>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut solid = vec![false; (W * H * D) as usize];
+            for y in 0..H {
+                for x in 0..W {
+                    if x != 5 || y != 5 {
+                        solid[((2 * W * H) + (y * W) + x) as usize] = true;
+                    }
+                }
+            }
+            TestMap { solid }
+        }
+    }
+
+    impl BaseMap for TestMap {
+        fn is_opaque(&self, idx: usize) -> bool {
+            self.solid[idx]
+        }
+    }
+
+    impl Algorithm3D for TestMap {
+        fn dimensions(&self) -> Point3 {
+            Point3::new(W, H, D)
+        }
+    }
+
+    #[test]
+    fn sees_own_level_and_through_the_opening() {
+        let map = TestMap::new();
+        let viewer = Point3::new(5, 5, 3);
+        let visible = field_of_view_3d_set(viewer, 3, &map);
+
+        assert!(visible.contains(&viewer));
+        assert!(visible.contains(&Point3::new(7, 5, 3)));
+        // Straight down through the hole in the floor...
+        assert!(visible.contains(&Point3::new(5, 5, 2)));
+        assert!(visible.contains(&Point3::new(5, 5, 1)));
+        // ...but the slab hides the rest of the level below.
+        assert!(!visible.contains(&Point3::new(2, 5, 1)));
+        // The slab's own tiles are visible from above.
+        assert!(visible.contains(&Point3::new(6, 5, 2)));
+    }
+
+    #[test]
+    fn respects_range() {
+        let map = TestMap {
+            solid: vec![false; (W * H * D) as usize],
+        };
+        let viewer = Point3::new(5, 5, 2);
+        for point in field_of_view_3d(viewer, 2, &map) {
+            let offset = point - viewer;
+            let d2 = offset.x * offset.x + offset.y * offset.y + offset.z * offset.z;
+            assert!(d2 <= 2 * 3);
+        }
+    }
+}
@@ -1,19 +1,31 @@
 use bracket_algorithm_traits::prelude::Algorithm2D;
-use bracket_geometry::prelude::Point;
+use bracket_geometry::prelude::{Bresenham, DistanceAlg, Point, Radians};
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::PI;
 
 mod recursive_shadowcasting;
 // Default algorithm / backwards compatibility
 pub use recursive_shadowcasting::{field_of_view, field_of_view_set};
+mod fov3d;
+pub use fov3d::{field_of_view_3d, field_of_view_3d_set};
+mod permissive;
 mod symmetric_shadowcasting;
 
 /// Enumeration of available FOV algorithms
 #[derive(Clone, Copy)]
 #[non_exhaustive] // Other algorithms may be added in the future
 pub enum FieldOfViewAlg {
+    /// Classic recursive shadowcasting: fast and the long-standing default, but sight
+    /// between two tiles is not always mutual around pillar corners.
     RecursiveShadowcasting,
+    /// Albert Ford's symmetric shadowcasting: for any two transparent tiles a and b,
+    /// `field_of_view_set(a, ..)` contains b if and only if the reverse holds. Use this
+    /// when player-sees-monster asymmetry would cause gameplay bugs.
     SymmetricShadowcasting,
+    /// Line-of-sight permissive FOV: also symmetric, and more generous around corners
+    /// than either shadowcaster, at a higher cost per call.
+    Permissive,
 }
 
 impl FieldOfViewAlg {
@@ -30,6 +42,7 @@ impl FieldOfViewAlg {
             FieldOfViewAlg::SymmetricShadowcasting => {
                 symmetric_shadowcasting::field_of_view_set(center, range, fov_check)
             }
+            FieldOfViewAlg::Permissive => permissive::field_of_view_set(center, range, fov_check),
         }
     }
     pub fn field_of_view(
@@ -45,10 +58,69 @@ impl FieldOfViewAlg {
             FieldOfViewAlg::SymmetricShadowcasting => {
                 symmetric_shadowcasting::field_of_view(start, range, fov_check)
             }
+            FieldOfViewAlg::Permissive => permissive::field_of_view(start, range, fov_check),
         }
     }
 }
 
+/// Calculates a directional vision cone for a map that supports Algorithm2D: the default
+/// shadowcasting field of view, restricted to the wedge of `half_angle` either side of
+/// `facing`. Angles follow the bracket-geometry convention (0 is north, clockwise positive)
+/// and accept `Radians` or `Degrees`. The center is always visible.
+pub fn field_of_view_cone<ANGLE, HALF>(
+    center: Point,
+    facing: ANGLE,
+    half_angle: HALF,
+    range: i32,
+    fov_check: &dyn Algorithm2D,
+) -> HashSet<Point>
+where
+    ANGLE: Into<Radians>,
+    HALF: Into<Radians>,
+{
+    let facing = facing.into().0;
+    let half_angle = half_angle.into().0;
+    field_of_view_set(center, range, fov_check)
+        .into_iter()
+        .filter(|point| {
+            if *point == center {
+                return true;
+            }
+            let angle = center.angle_to(*point).0;
+            let diff = (angle - facing + PI).rem_euclid(2.0 * PI) - PI;
+            // Small tolerance so cells exactly on the cone's edge (e.g. the
+            // diagonal of a 90-degree cone) aren't lost to rounding.
+            diff.abs() <= half_angle + 1.0e-4
+        })
+        .collect()
+}
+
+/// Calculates field-of-view with per-cell light intensity, ready to feed a lighting overlay
+/// or color lerp without a second pass. Each visible cell maps to an intensity in 0.0..=1.0:
+/// linear distance falloff (1.0 at the center, fading to 0.0 at `range`), further attenuated
+/// by the `BaseMap::get_opacity` of the tiles the light passes through - so partial occluders
+/// like smoke dim what lies beyond them instead of hiding it.
+pub fn field_of_view_attenuated(
+    center: Point,
+    range: i32,
+    fov_check: &dyn Algorithm2D,
+) -> HashMap<Point, f32> {
+    let radius = range as f32 + 0.5;
+    field_of_view_set(center, range, fov_check)
+        .into_iter()
+        .map(|point| {
+            let falloff = 1.0 - DistanceAlg::Pythagoras.distance2d(center, point) / radius;
+            // Light is dimmed by everything strictly between the center and
+            // the cell; the cell's own opacity doesn't darken itself.
+            let transmission: f32 = Bresenham::new(center, point)
+                .skip(1)
+                .map(|p| 1.0 - fov_check.get_opacity(fov_check.point2d_to_index(p)))
+                .product();
+            (point, (falloff * transmission).clamp(0.0, 1.0))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -62,9 +134,10 @@ mod tests {
     const TESTMAP_W: usize = 20;
     const TESTMAP_H: usize = 20;
     const TESTMAP_TILES: usize = (TESTMAP_W * TESTMAP_H) as usize;
-    const ALGORITHS: [FieldOfViewAlg; 2] = [
+    const ALGORITHS: [FieldOfViewAlg; 3] = [
         FieldOfViewAlg::RecursiveShadowcasting,
         FieldOfViewAlg::SymmetricShadowcasting,
+        FieldOfViewAlg::Permissive,
     ];
 
     struct Map {
@@ -199,6 +272,97 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fov_attenuated() {
+        use crate::prelude::field_of_view_attenuated;
+
+        let map = Map::new();
+        let c = Point::new(10, 10);
+        let lit = field_of_view_attenuated(c, 8, &map);
+        // Full brightness at the center, fading with distance.
+        assert!((lit[&c] - 1.0).abs() < f32::EPSILON);
+        assert!(lit[&Point::new(11, 10)] > lit[&Point::new(14, 10)]);
+        assert!(lit[&Point::new(14, 10)] > 0.0);
+
+        // A half-opacity smoke cloud dims, but doesn't hide, what's behind it.
+        struct SmokeMap {
+            opacity: Vec<f32>,
+        }
+        impl BaseMap for SmokeMap {
+            fn is_opaque(&self, idx: usize) -> bool {
+                self.opacity[idx] >= 1.0
+            }
+            fn get_opacity(&self, idx: usize) -> f32 {
+                self.opacity[idx]
+            }
+        }
+        impl Algorithm2D for SmokeMap {
+            fn dimensions(&self) -> Point {
+                Point::new(TESTMAP_W, TESTMAP_H)
+            }
+        }
+        let mut smoke = SmokeMap {
+            opacity: vec![0.0; TESTMAP_TILES],
+        };
+        smoke.opacity[Point::new(12, 10).to_index(TESTMAP_W)] = 0.5;
+        let dimmed = field_of_view_attenuated(c, 8, &smoke);
+        let behind = Point::new(14, 10);
+        assert!((dimmed[&behind] - lit[&behind] * 0.5).abs() < 0.01);
+        // The smoke cell itself is not darkened by its own opacity.
+        let at_smoke = Point::new(12, 10);
+        assert!((dimmed[&at_smoke] - lit[&at_smoke]).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn fov_cone() {
+        use crate::prelude::field_of_view_cone;
+        use bracket_geometry::prelude::Degrees;
+
+        let map = Map::new();
+        let c = Point::new(10, 10);
+        // Facing east with a 90-degree total aperture.
+        let cone = field_of_view_cone(c, Degrees::new(90.0), Degrees::new(45.0), 8, &map);
+        assert!(cone.contains(&c));
+        assert!(cone.contains(&Point::new(14, 10)));
+        assert!(cone.contains(&Point::new(13, 7)));
+        assert!(!cone.contains(&Point::new(6, 10)));
+        assert!(!cone.contains(&Point::new(10, 14)));
+        // The wedge is a subset of the full field of view.
+        let full = FieldOfViewAlg::RecursiveShadowcasting.field_of_view_set(c, 8, &map);
+        assert!(cone.iter().all(|p| full.contains(p)));
+
+        // Facing north, the wedge spans the 0-angle wrap-around.
+        let north = field_of_view_cone(c, Degrees::new(0.0), Degrees::new(45.0), 8, &map);
+        assert!(north.contains(&Point::new(9, 6)));
+        assert!(north.contains(&Point::new(11, 6)));
+        assert!(!north.contains(&Point::new(10, 14)));
+    }
+
+    // A lone pillar must not create one-way sight lines around its corners;
+    // permissive FOV is symmetric for every transparent pair by construction.
+    #[test]
+    fn fov_permissive_pillar_symmetry() {
+        let mut map = Map::new();
+        let pillar = Point::new(10, 10);
+        map.tiles[pillar.to_index(TESTMAP_W)] = true;
+        let radius: i32 = 4;
+
+        for viewer in [Point::new(8, 10), Point::new(9, 9), Point::new(11, 12)] {
+            for point in FieldOfViewAlg::Permissive.field_of_view_set(viewer, radius, &map) {
+                if !map.tiles[point.to_index(TESTMAP_W)] {
+                    assert!(
+                        FieldOfViewAlg::Permissive
+                            .field_of_view_set(point, radius, &map)
+                            .contains(&viewer),
+                        "{:?} sees {:?} but not vice versa",
+                        viewer,
+                        point
+                    );
+                }
+            }
+        }
+    }
+
     // NOTE: Symmetry applies to FieldOfViewAlg::SymmetricShadowcasting only
     #[test]
     fn fov_symmetric() {
@@ -0,0 +1,62 @@
+use bracket_algorithm_traits::prelude::Algorithm2D;
+use bracket_geometry::prelude::{Bresenham, Point};
+
+use std::collections::HashSet;
+
+/// True if every tile strictly between `from` and `to` is transparent.
+fn line_is_clear<T: Algorithm2D + ?Sized>(from: Point, to: Point, fov_check: &T) -> bool {
+    Bresenham::new(from, to)
+        .skip(1)
+        .all(|point| !fov_check.is_opaque(fov_check.point2d_to_index(point)))
+}
+
+/// Calculates field-of-view (permissive version) for a map that supports Algorithm2D, returning a HashSet.
+/// A tile is visible if a sight line in either direction between it and the origin is unobstructed,
+/// so the result is symmetric by construction and avoids shadowcasting's pillar-corner artifacts,
+/// at the cost of scaling with the cube of the radius.
+pub fn field_of_view_set<T: Algorithm2D + ?Sized>(
+    origin: Point,
+    radius: i32,
+    fov_check: &T,
+) -> HashSet<Point> {
+    let mut visible_points: HashSet<Point> =
+        HashSet::with_capacity((4 * radius * radius) as usize);
+    visible_points.insert(origin);
+
+    // The same disc as the shadowcasting implementations: r * (r + 1) rounds
+    // the boundary outwards by half a tile.
+    let limit = radius * (radius + 1);
+    for y in -radius..=radius {
+        for x in -radius..=radius {
+            if x * x + y * y > limit {
+                continue;
+            }
+            let target = Point::new(origin.x + x, origin.y + y);
+            if !fov_check.in_bounds(target) {
+                continue;
+            }
+            if line_is_clear(origin, target, fov_check)
+                || line_is_clear(target, origin, fov_check)
+            {
+                visible_points.insert(target);
+            }
+        }
+    }
+
+    visible_points
+        .iter()
+        .copied()
+        .filter(|p| fov_check.in_bounds(*p))
+        .collect()
+}
+
+/// Calculates field-of-view (permissive version) for a map that supports Algorithm2D.
+pub fn field_of_view<T: Algorithm2D + ?Sized>(
+    start: Point,
+    range: i32,
+    fov_check: &T,
+) -> Vec<Point> {
+    field_of_view_set(start, range, fov_check)
+        .into_iter()
+        .collect()
+}
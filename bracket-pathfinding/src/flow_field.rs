@@ -0,0 +1,147 @@
+use crate::prelude::DijkstraMap;
+use bracket_geometry::prelude::{Point, Rect};
+
+/// A per-cell best-direction field derived from a `DijkstraMap`. Build one
+/// field per goal set and hundreds of agents can steer by a single lookup
+/// each, instead of running a path search per entity.
+/// Each cell holds an 8-way unit step (e.g. `(1, -1)`); goals, walls and
+/// unreachable cells hold `(0, 0)`.
+pub struct FlowField {
+    directions: Vec<Point>,
+    size_x: usize,
+    size_y: usize,
+}
+
+impl FlowField {
+    /// Builds a flow field from a Dijkstra map: every cell points at its
+    /// lowest-valued neighbor, i.e. downhill towards the nearest goal.
+    pub fn new(dm: &DijkstraMap) -> FlowField {
+        let (size_x, size_y) = dm.size();
+        let mut field = FlowField {
+            directions: vec![Point::zero(); size_x * size_y],
+            size_x,
+            size_y,
+        };
+        field.rebuild_region(
+            dm,
+            Rect::with_size(0, 0, size_x as i32, size_y as i32),
+        );
+        field
+    }
+
+    /// The direction to travel from a cell, as an 8-way unit step. Returns
+    /// `(0, 0)` for goals, walls, unreachable cells and out-of-bounds queries.
+    pub fn direction(&self, cell: Point) -> Point {
+        if cell.x < 0
+            || cell.y < 0
+            || cell.x >= self.size_x as i32
+            || cell.y >= self.size_y as i32
+        {
+            return Point::zero();
+        }
+        self.directions[(cell.y as usize * self.size_x) + cell.x as usize]
+    }
+
+    /// Recomputes the field for just the cells inside `region` (clipped to the
+    /// map), for cheap partial rebuilds after a localized cost change. Rebuild
+    /// the Dijkstra map first; the field reads whatever values it holds now.
+    pub fn rebuild_region(&mut self, dm: &DijkstraMap, region: Rect) {
+        let bounds = Rect::with_size(0, 0, self.size_x as i32, self.size_y as i32);
+        for y in region.y1.max(bounds.y1)..region.y2.min(bounds.y2) {
+            for x in region.x1.max(bounds.x1)..region.x2.min(bounds.x2) {
+                let idx = (y as usize * self.size_x) + x as usize;
+                self.directions[idx] = self.best_direction(dm, x, y);
+            }
+        }
+    }
+
+    /// The step from a cell to its lowest-valued neighbor, or zero if no
+    /// neighbor improves on the cell's own value.
+    fn best_direction(&self, dm: &DijkstraMap, x: i32, y: i32) -> Point {
+        let here = dm.map[(y as usize * self.size_x) + x as usize];
+        let mut best = here;
+        let mut dir = Point::zero();
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let (nx, ny) = (x + dx, y + dy);
+                if (dx == 0 && dy == 0)
+                    || nx < 0
+                    || ny < 0
+                    || nx >= self.size_x as i32
+                    || ny >= self.size_y as i32
+                {
+                    continue;
+                }
+                let value = dm.map[(ny as usize * self.size_x) + nx as usize];
+                if value < best {
+                    best = value;
+                    dir = Point::new(dx, dy);
+                }
+            }
+        }
+        dir
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FlowField;
+    use crate::prelude::DijkstraMap;
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{Point, Rect};
+    use smallvec::SmallVec;
+
+    // A fully open 5x5 map with 8-way movement.
+    struct OpenMap;
+
+    impl BaseMap for OpenMap {
+        fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+            let mut exits = SmallVec::new();
+            let pos = self.index_to_point2d(idx);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let next = Point::new(pos.x + dx, pos.y + dy);
+                    if (dx != 0 || dy != 0) && self.in_bounds(next) {
+                        exits.push((
+                            self.point2d_to_index(next),
+                            if dx != 0 && dy != 0 { 1.4 } else { 1.0 },
+                        ));
+                    }
+                }
+            }
+            exits
+        }
+    }
+
+    impl Algorithm2D for OpenMap {
+        fn dimensions(&self) -> Point {
+            Point::new(5, 5)
+        }
+    }
+
+    #[test]
+    fn field_points_downhill_to_the_goal() {
+        let map = OpenMap {};
+        let goal = map.point2d_to_index(Point::new(2, 2));
+        let dm = DijkstraMap::new(5, 5, &[goal], &map, 100.0);
+        let field = FlowField::new(&dm);
+        assert_eq!(field.direction(Point::new(0, 0)), Point::new(1, 1));
+        assert_eq!(field.direction(Point::new(4, 2)), Point::new(-1, 0));
+        assert_eq!(field.direction(Point::new(2, 4)), Point::new(0, -1));
+        // The goal itself, and out-of-bounds queries, have no direction.
+        assert_eq!(field.direction(Point::new(2, 2)), Point::zero());
+        assert_eq!(field.direction(Point::new(-1, 7)), Point::zero());
+    }
+
+    #[test]
+    fn partial_rebuild_only_touches_the_region() {
+        let map = OpenMap {};
+        let dm_left = DijkstraMap::new(5, 5, &[map.point2d_to_index(Point::new(0, 2))], &map, 100.0);
+        let dm_right = DijkstraMap::new(5, 5, &[map.point2d_to_index(Point::new(4, 2))], &map, 100.0);
+        let mut field = FlowField::new(&dm_left);
+        // Re-point the right column at the new goal, leaving the rest alone.
+        field.rebuild_region(&dm_right, Rect::with_size(4, 0, 1, 5));
+        assert_eq!(field.direction(Point::new(4, 0)), Point::new(0, 1));
+        assert_eq!(field.direction(Point::new(1, 2)), Point::new(-1, 0));
+    }
+}
@@ -0,0 +1,548 @@
+use crate::prelude::{JumpMap, NavigationPath};
+use bracket_geometry::prelude::{DistanceAlg, Point, Rect};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Spans of walkable border cells wider than this get an entrance at each end
+/// instead of one in the middle, following the original HPA* paper.
+const WIDE_ENTRANCE: i32 = 6;
+
+/// A hierarchical (HPA*-style) pathfinding overlay. The map is partitioned
+/// into square clusters; walkable entrances between adjacent clusters and the
+/// internal paths linking them are precomputed into an abstract graph. Queries
+/// search the abstract graph and refine the result to concrete cells on
+/// demand, which is far cheaper than raw A* when many agents share one large
+/// map. Paths are near-optimal rather than optimal - the abstract graph only
+/// routes through entrances.
+///
+/// When the map changes, call [`HpaMap::tile_changed`] to rebuild just the
+/// affected clusters rather than the whole overlay.
+pub struct HpaMap {
+    cluster_size: i32,
+    clusters_x: i32,
+    clusters_y: i32,
+    clusters: Vec<Cluster>,
+    /// Pairs of entrance cells facing each other across a cluster border.
+    portals: Vec<(usize, usize)>,
+}
+
+/// One square cluster of the map: its region, the entrance cells on its
+/// borders, and precomputed paths between them.
+struct Cluster {
+    region: Rect,
+    entrances: Vec<usize>,
+    /// (from, to, cost) for each connected pair of this cluster's entrances.
+    internal_edges: Vec<(usize, usize, f32)>,
+}
+
+impl HpaMap {
+    /// Builds the abstract graph for a map, using clusters of
+    /// `cluster_size` x `cluster_size` tiles.
+    pub fn new(map: &dyn JumpMap, cluster_size: i32) -> HpaMap {
+        let bounds = map.dimensions();
+        let clusters_x = (bounds.x + cluster_size - 1) / cluster_size;
+        let clusters_y = (bounds.y + cluster_size - 1) / cluster_size;
+        let mut clusters = Vec::with_capacity((clusters_x * clusters_y) as usize);
+        for cy in 0..clusters_y {
+            for cx in 0..clusters_x {
+                clusters.push(Cluster {
+                    region: Rect::with_exact(
+                        cx * cluster_size,
+                        cy * cluster_size,
+                        ((cx + 1) * cluster_size).min(bounds.x),
+                        ((cy + 1) * cluster_size).min(bounds.y),
+                    ),
+                    entrances: Vec::new(),
+                    internal_edges: Vec::new(),
+                });
+            }
+        }
+
+        let mut hpa = HpaMap {
+            cluster_size,
+            clusters_x,
+            clusters_y,
+            clusters,
+            portals: Vec::new(),
+        };
+        for cluster in 0..hpa.clusters.len() {
+            hpa.scan_border_east(cluster, map);
+            hpa.scan_border_south(cluster, map);
+        }
+        for cluster in 0..hpa.clusters.len() {
+            hpa.link_entrances(cluster, map);
+        }
+        hpa
+    }
+
+    /// The cluster a tile index belongs to.
+    fn cluster_of(&self, pos: Point) -> usize {
+        ((pos.y / self.cluster_size) * self.clusters_x + (pos.x / self.cluster_size)) as usize
+    }
+
+    /// Rebuilds the clusters affected by a change to one tile: the tile's own
+    /// cluster and its four neighbors, whose shared borders and internal paths
+    /// may have changed. Much cheaper than rebuilding the whole overlay.
+    pub fn tile_changed(&mut self, idx: usize, map: &dyn JumpMap) {
+        let home = self.cluster_of(map.index_to_point2d(idx));
+        let home_region = self.clusters[home].region;
+        let cx = home as i32 % self.clusters_x;
+        let cy = home as i32 / self.clusters_x;
+
+        // Drop the portals crossing the home cluster's borders, and their
+        // entrance cells. Borders between two unaffected clusters are untouched.
+        let mut removed: Vec<(usize, usize)> = Vec::new();
+        self.portals.retain(|(a, b)| {
+            if home_region.point_in_rect(map.index_to_point2d(*a))
+                || home_region.point_in_rect(map.index_to_point2d(*b))
+            {
+                removed.push((*a, *b));
+                return false;
+            }
+            true
+        });
+        for (a, b) in removed {
+            for endpoint in [a, b] {
+                let cluster = self.cluster_of(map.index_to_point2d(endpoint));
+                if let Some(i) = self.clusters[cluster]
+                    .entrances
+                    .iter()
+                    .position(|e| *e == endpoint)
+                {
+                    self.clusters[cluster].entrances.remove(i);
+                }
+            }
+        }
+
+        // Rescan the home cluster's four borders; west and north belong to the
+        // neighbors' east/south scans.
+        self.scan_border_east(home, map);
+        self.scan_border_south(home, map);
+        if cx > 0 {
+            self.scan_border_east(home - 1, map);
+        }
+        if cy > 0 {
+            self.scan_border_south(home - self.clusters_x as usize, map);
+        }
+
+        // Entrance sets changed for home and its neighbors; relink them.
+        let mut affected = vec![home];
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1)] {
+            let (nx, ny) = (cx + dx, cy + dy);
+            if nx >= 0 && nx < self.clusters_x && ny >= 0 && ny < self.clusters_y {
+                affected.push((ny * self.clusters_x + nx) as usize);
+            }
+        }
+        for cluster in affected {
+            self.link_entrances(cluster, map);
+        }
+    }
+
+    /// Scans the east border of a cluster - cells (x2-1, y) facing (x2, y) -
+    /// for walkable spans, creating entrance pairs and portal edges.
+    fn scan_border_east(&mut self, cluster: usize, map: &dyn JumpMap) {
+        let cx = cluster as i32 % self.clusters_x;
+        if cx + 1 >= self.clusters_x {
+            return;
+        }
+        let region = self.clusters[cluster].region;
+        let spans = Self::walkable_spans(map, region.y1..region.y2, |y| {
+            (Point::new(region.x2 - 1, y), Point::new(region.x2, y))
+        });
+        self.add_entrances(cluster, cluster + 1, &spans, map);
+    }
+
+    /// Scans the south border of a cluster - cells (x, y2-1) facing (x, y2) -
+    /// for walkable spans, creating entrance pairs and portal edges.
+    fn scan_border_south(&mut self, cluster: usize, map: &dyn JumpMap) {
+        let cy = cluster as i32 / self.clusters_x;
+        if cy + 1 >= self.clusters_y {
+            return;
+        }
+        let region = self.clusters[cluster].region;
+        let spans = Self::walkable_spans(map, region.x1..region.x2, |x| {
+            (Point::new(x, region.y2 - 1), Point::new(x, region.y2))
+        });
+        self.add_entrances(cluster, cluster + self.clusters_x as usize, &spans, map);
+    }
+
+    /// Finds the contiguous runs along a border where both facing cells are
+    /// walkable. Returns (start, end) inclusive coordinate ranges.
+    fn walkable_spans<F>(
+        map: &dyn JumpMap,
+        range: std::ops::Range<i32>,
+        facing: F,
+    ) -> Vec<(i32, i32)>
+    where
+        F: Fn(i32) -> (Point, Point),
+    {
+        let mut spans = Vec::new();
+        let mut current: Option<(i32, i32)> = None;
+        for coord in range {
+            let (near, far) = facing(coord);
+            let open = passable(map, near) && passable(map, far);
+            match (open, current) {
+                (true, None) => current = Some((coord, coord)),
+                (true, Some((start, _))) => current = Some((start, coord)),
+                (false, Some(span)) => {
+                    spans.push(span);
+                    current = None;
+                }
+                (false, None) => {}
+            }
+        }
+        if let Some(span) = current {
+            spans.push(span);
+        }
+        spans
+    }
+
+    /// Creates entrances for each span: one in the middle of narrow spans, one
+    /// at each end of wide ones.
+    fn add_entrances(
+        &mut self,
+        cluster: usize,
+        neighbor: usize,
+        spans: &[(i32, i32)],
+        map: &dyn JumpMap,
+    ) {
+        let region = self.clusters[cluster].region;
+        for &(start, end) in spans {
+            let coords = if end - start + 1 >= WIDE_ENTRANCE {
+                vec![start, end]
+            } else {
+                vec![(start + end) / 2]
+            };
+            for coord in coords {
+                // The span runs along whichever axis the border does.
+                let (near, far) = if region.x2 == self.clusters[neighbor].region.x1 {
+                    (Point::new(region.x2 - 1, coord), Point::new(region.x2, coord))
+                } else {
+                    (Point::new(coord, region.y2 - 1), Point::new(coord, region.y2))
+                };
+                let near_idx = map.point2d_to_index(near);
+                let far_idx = map.point2d_to_index(far);
+                self.clusters[cluster].entrances.push(near_idx);
+                self.clusters[neighbor].entrances.push(far_idx);
+                self.portals.push((near_idx, far_idx));
+            }
+        }
+    }
+
+    /// Recomputes the internal paths between a cluster's entrances.
+    fn link_entrances(&mut self, cluster: usize, map: &dyn JumpMap) {
+        let region = self.clusters[cluster].region;
+        let entrances = self.clusters[cluster].entrances.clone();
+        let mut edges = Vec::new();
+        for (i, &a) in entrances.iter().enumerate() {
+            for &b in entrances.iter().skip(i + 1) {
+                if let Some((_, cost)) = local_path(
+                    map,
+                    region,
+                    map.index_to_point2d(a),
+                    map.index_to_point2d(b),
+                ) {
+                    edges.push((a, b, cost));
+                }
+            }
+        }
+        self.clusters[cluster].internal_edges = edges;
+    }
+
+    /// Finds a path from start to end (as tile indices), searching the
+    /// abstract graph and refining the result to a contiguous cell path.
+    /// Returns the same `NavigationPath` shape as `a_star_search`.
+    pub fn find_path(&self, start: usize, end: usize, map: &dyn JumpMap) -> NavigationPath {
+        let mut result = NavigationPath::new();
+        if !map.is_passable(start) || !map.is_passable(end) {
+            return result;
+        }
+        let start_pos = map.index_to_point2d(start);
+        let end_pos = map.index_to_point2d(end);
+        let start_cluster = self.cluster_of(start_pos);
+        let end_cluster = self.cluster_of(end_pos);
+
+        // Within one cluster, a direct local search is both faster and exact.
+        if start_cluster == end_cluster {
+            if let Some((steps, _)) =
+                local_path(map, self.clusters[start_cluster].region, start_pos, end_pos)
+            {
+                result.success = true;
+                result.destination = end;
+                result.steps = steps;
+                return result;
+            }
+        }
+
+        // Assemble the abstract graph, with the endpoints linked to their
+        // clusters' entrances as temporary nodes.
+        let mut graph: HashMap<usize, Vec<(usize, f32)>> = HashMap::new();
+        for cluster in &self.clusters {
+            for &(a, b, cost) in &cluster.internal_edges {
+                graph.entry(a).or_default().push((b, cost));
+                graph.entry(b).or_default().push((a, cost));
+            }
+        }
+        for &(a, b) in &self.portals {
+            graph.entry(a).or_default().push((b, 1.0));
+            graph.entry(b).or_default().push((a, 1.0));
+        }
+        for (node, cluster) in [(start, start_cluster), (end, end_cluster)] {
+            let region = self.clusters[cluster].region;
+            let pos = map.index_to_point2d(node);
+            for &entrance in &self.clusters[cluster].entrances {
+                if let Some((_, cost)) =
+                    local_path(map, region, pos, map.index_to_point2d(entrance))
+                {
+                    graph.entry(node).or_default().push((entrance, cost));
+                    graph.entry(entrance).or_default().push((node, cost));
+                }
+            }
+        }
+
+        // Dijkstra over the abstract graph.
+        let mut open: BinaryHeap<GraphNode> = BinaryHeap::new();
+        let mut best: HashMap<usize, f32> = HashMap::new();
+        let mut parents: HashMap<usize, usize> = HashMap::new();
+        open.push(GraphNode { idx: start, f: 0.0 });
+        best.insert(start, 0.0);
+        while let Some(q) = open.pop() {
+            if q.idx == end {
+                break;
+            }
+            if q.f > best[&q.idx] {
+                continue;
+            }
+            if let Some(exits) = graph.get(&q.idx) {
+                for &(next, cost) in exits {
+                    let f = q.f + cost;
+                    if f < *best.get(&next).unwrap_or(&f32::MAX) {
+                        best.insert(next, f);
+                        parents.insert(next, q.idx);
+                        open.push(GraphNode { idx: next, f });
+                    }
+                }
+            }
+        }
+        if !parents.contains_key(&end) {
+            return result;
+        }
+
+        // Refine: expand each abstract hop into concrete steps.
+        let mut abstract_path = vec![end];
+        let mut current = end;
+        while current != start {
+            current = parents[&current];
+            abstract_path.insert(0, current);
+        }
+        result.steps.push(start);
+        for pair in abstract_path.windows(2) {
+            let a = map.index_to_point2d(pair[0]);
+            let b = map.index_to_point2d(pair[1]);
+            if DistanceAlg::Chebyshev.distance2d(a, b) <= 1.0 {
+                // A portal hop between adjacent cells.
+                result.steps.push(pair[1]);
+                continue;
+            }
+            // An internal hop; both ends lie in the same cluster.
+            let region = self.clusters[self.cluster_of(a)].region;
+            match local_path(map, region, a, b) {
+                Some((steps, _)) => result.steps.extend(steps.into_iter().skip(1)),
+                None => return NavigationPath::new(),
+            }
+        }
+        result.success = true;
+        result.destination = end;
+        result
+    }
+}
+
+/// True if the point is on the map and can be entered.
+fn passable(map: &dyn JumpMap, pos: Point) -> bool {
+    map.in_bounds(pos) && map.is_passable(map.point2d_to_index(pos))
+}
+
+/// A uniform-cost, 8-way A* restricted to one cluster region. Returns the
+/// step indices (including the start) and the path cost.
+fn local_path(
+    map: &dyn JumpMap,
+    region: Rect,
+    start: Point,
+    end: Point,
+) -> Option<(Vec<usize>, f32)> {
+    let mut open: BinaryHeap<GraphNode> = BinaryHeap::new();
+    let mut best: HashMap<usize, f32> = HashMap::new();
+    let mut parents: HashMap<usize, usize> = HashMap::new();
+    let start_idx = map.point2d_to_index(start);
+    let end_idx = map.point2d_to_index(end);
+    open.push(GraphNode {
+        idx: start_idx,
+        f: 0.0,
+    });
+    best.insert(start_idx, 0.0);
+    while let Some(q) = open.pop() {
+        if q.idx == end_idx {
+            let mut steps = vec![end_idx];
+            let mut current = end_idx;
+            while current != start_idx {
+                current = parents[&current];
+                steps.insert(0, current);
+            }
+            return Some((steps, best[&end_idx]));
+        }
+        let pos = map.index_to_point2d(q.idx);
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dy == 0 {
+                    continue;
+                }
+                let next = Point::new(pos.x + dx, pos.y + dy);
+                if !region.point_in_rect(next) || !passable(map, next) {
+                    continue;
+                }
+                let next_idx = map.point2d_to_index(next);
+                let g = best[&q.idx] + DistanceAlg::Pythagoras.distance2d(pos, next);
+                if g < *best.get(&next_idx).unwrap_or(&f32::MAX) {
+                    best.insert(next_idx, g);
+                    parents.insert(next_idx, q.idx);
+                    open.push(GraphNode {
+                        idx: next_idx,
+                        f: g + DistanceAlg::Pythagoras.distance2d(next, end),
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+#[derive(Copy, Clone)]
+/// A node in the abstract-graph and local searches, ordered as a min-heap on f.
+struct GraphNode {
+    idx: usize,
+    f: f32,
+}
+
+impl PartialEq for GraphNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.f == other.f
+    }
+}
+
+impl Eq for GraphNode {}
+
+impl Ord for GraphNode {
+    fn cmp(&self, b: &Self) -> Ordering {
+        b.f.partial_cmp(&self.f).unwrap()
+    }
+}
+
+impl PartialOrd for GraphNode {
+    fn partial_cmp(&self, b: &Self) -> Option<Ordering> {
+        Some(self.cmp(b))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HpaMap;
+    use crate::prelude::JumpMap;
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{DistanceAlg, Point};
+
+    // A 20x20 map with a vertical wall down x=10, pierced at y=15.
+    struct TestMap {
+        walls: Vec<bool>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut walls = vec![false; 400];
+            for y in 0..20 {
+                if y != 15 {
+                    walls[(y * 20 + 10) as usize] = true;
+                }
+            }
+            TestMap { walls }
+        }
+    }
+
+    impl BaseMap for TestMap {
+        fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+            DistanceAlg::Pythagoras
+                .distance2d(self.index_to_point2d(idx1), self.index_to_point2d(idx2))
+        }
+    }
+
+    impl Algorithm2D for TestMap {
+        fn dimensions(&self) -> Point {
+            Point::new(20, 20)
+        }
+    }
+
+    impl JumpMap for TestMap {
+        fn is_passable(&self, idx: usize) -> bool {
+            !self.walls[idx]
+        }
+    }
+
+    fn assert_valid_path(map: &TestMap, path: &crate::prelude::NavigationPath) {
+        for pair in path.steps.windows(2) {
+            let a = map.index_to_point2d(pair[0]);
+            let b = map.index_to_point2d(pair[1]);
+            assert!((a.x - b.x).abs() <= 1 && (a.y - b.y).abs() <= 1 && a != b);
+        }
+        for step in &path.steps {
+            assert!(map.is_passable(*step));
+        }
+    }
+
+    #[test]
+    fn hpa_routes_through_the_gap() {
+        let map = TestMap::new();
+        let hpa = HpaMap::new(&map, 5);
+        let start = map.point2d_to_index(Point::new(2, 2));
+        let end = map.point2d_to_index(Point::new(17, 2));
+        let path = hpa.find_path(start, end, &map);
+        assert!(path.success);
+        assert_eq!(path.steps[0], start);
+        assert_eq!(*path.steps.last().unwrap(), end);
+        assert_valid_path(&map, &path);
+        assert!(path.steps.contains(&map.point2d_to_index(Point::new(10, 15))));
+    }
+
+    #[test]
+    fn hpa_same_cluster_is_direct() {
+        let map = TestMap::new();
+        let hpa = HpaMap::new(&map, 5);
+        let start = map.point2d_to_index(Point::new(1, 1));
+        let end = map.point2d_to_index(Point::new(3, 4));
+        let path = hpa.find_path(start, end, &map);
+        assert!(path.success);
+        assert_valid_path(&map, &path);
+    }
+
+    #[test]
+    fn hpa_tile_changes_rebuild_incrementally() {
+        let mut map = TestMap::new();
+        let mut hpa = HpaMap::new(&map, 5);
+        let start = map.point2d_to_index(Point::new(2, 2));
+        let end = map.point2d_to_index(Point::new(17, 2));
+        assert!(hpa.find_path(start, end, &map).success);
+
+        // Close the gap and tell the overlay.
+        let gap = map.point2d_to_index(Point::new(10, 15));
+        map.walls[gap] = true;
+        hpa.tile_changed(gap, &map);
+        assert!(!hpa.find_path(start, end, &map).success);
+
+        // Open a new gap at the top.
+        let new_gap = map.point2d_to_index(Point::new(10, 1));
+        map.walls[new_gap] = false;
+        hpa.tile_changed(new_gap, &map);
+        let path = hpa.find_path(start, end, &map);
+        assert!(path.success);
+        assert_valid_path(&map, &path);
+        assert!(path.steps.contains(&new_gap));
+    }
+}
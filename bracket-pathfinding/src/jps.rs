@@ -0,0 +1,338 @@
+use crate::prelude::NavigationPath;
+use bracket_algorithm_traits::prelude::Algorithm2D;
+use bracket_geometry::prelude::{DistanceAlg, Point};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::convert::TryInto;
+
+/// Bail out if the JPS search expands this many jump points.
+const MAX_JPS_STEPS: usize = 65536;
+
+/// Map support for Jump Point Search. JPS assumes a uniform-cost, 8-way grid,
+/// so on top of `Algorithm2D`'s coordinate mapping it needs a cheap passability
+/// check - the fast path it probes many times per jump - instead of
+/// enumerating `get_available_exits`.
+pub trait JumpMap: Algorithm2D {
+    /// True if the tile can be entered.
+    fn is_passable(&self, idx: usize) -> bool;
+}
+
+/// Request a Jump Point Search. The start and end are specified as index numbers
+/// (compatible with your `Algorithm2D` implementation). JPS finds the same paths
+/// as `a_star_search` on uniform-cost grids while expanding far fewer nodes on
+/// open maps; movement is 8-way, and diagonal moves may pass blocked corners, as
+/// in the original algorithm. Returns the same `NavigationPath` as A*.
+pub fn jps_search<T>(start: T, end: T, map: &dyn JumpMap) -> NavigationPath
+where
+    T: TryInto<usize>,
+{
+    Jps::new(start.try_into().ok().unwrap(), end.try_into().ok().unwrap()).search(map)
+}
+
+#[derive(Copy, Clone)]
+/// Node is an internal jump point on the open list; idx is the cell, f the
+/// total cost, g the cost from the start.
+struct Node {
+    idx: usize,
+    f: f32,
+    g: f32,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.f == other.f
+    }
+}
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    fn cmp(&self, b: &Self) -> Ordering {
+        b.f.partial_cmp(&self.f).unwrap()
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, b: &Self) -> Option<Ordering> {
+        Some(self.cmp(b))
+    }
+}
+
+/// Private structure for calculating a Jump Point Search path.
+struct Jps {
+    start: usize,
+    end: usize,
+    open_list: BinaryHeap<Node>,
+    closed_list: HashSet<usize>,
+    best_g: HashMap<usize, f32>,
+    parents: HashMap<usize, usize>,
+    step_counter: usize,
+}
+
+impl Jps {
+    /// Creates a new search, with specified starting and ending indices.
+    fn new(start: usize, end: usize) -> Jps {
+        let mut open_list: BinaryHeap<Node> = BinaryHeap::new();
+        open_list.push(Node {
+            idx: start,
+            f: 0.0,
+            g: 0.0,
+        });
+
+        Jps {
+            start,
+            end,
+            open_list,
+            closed_list: HashSet::new(),
+            best_g: HashMap::new(),
+            parents: HashMap::new(),
+            step_counter: 0,
+        }
+    }
+
+    /// True if the point is on the map and can be entered.
+    fn passable(map: &dyn JumpMap, pos: Point) -> bool {
+        map.in_bounds(pos) && map.is_passable(map.point2d_to_index(pos))
+    }
+
+    /// True if a cell reached while travelling in a direction has a forced
+    /// neighbor - an obstacle adjacent to the path that makes this cell a
+    /// mandatory turning point.
+    fn has_forced_neighbor(map: &dyn JumpMap, pos: Point, dx: i32, dy: i32) -> bool {
+        let blocked = |x: i32, y: i32| !Self::passable(map, Point::new(x, y));
+        let open = |x: i32, y: i32| Self::passable(map, Point::new(x, y));
+        if dx != 0 && dy != 0 {
+            (blocked(pos.x - dx, pos.y) && open(pos.x - dx, pos.y + dy))
+                || (blocked(pos.x, pos.y - dy) && open(pos.x + dx, pos.y - dy))
+        } else if dx != 0 {
+            (blocked(pos.x, pos.y + 1) && open(pos.x + dx, pos.y + 1))
+                || (blocked(pos.x, pos.y - 1) && open(pos.x + dx, pos.y - 1))
+        } else {
+            (blocked(pos.x + 1, pos.y) && open(pos.x + 1, pos.y + dy))
+                || (blocked(pos.x - 1, pos.y) && open(pos.x - 1, pos.y + dy))
+        }
+    }
+
+    /// Travels from a cell in a direction until it finds a jump point: the
+    /// target, a cell with a forced neighbor, or (diagonally) a cell from which
+    /// a cardinal jump succeeds. Returns None if it runs off the map or into a
+    /// wall first.
+    fn jump(&self, map: &dyn JumpMap, from: Point, dx: i32, dy: i32) -> Option<Point> {
+        let mut current = from;
+        loop {
+            current = Point::new(current.x + dx, current.y + dy);
+            if !Self::passable(map, current) {
+                return None;
+            }
+            if map.point2d_to_index(current) == self.end
+                || Self::has_forced_neighbor(map, current, dx, dy)
+            {
+                return Some(current);
+            }
+            if dx != 0
+                && dy != 0
+                && (self.jump(map, current, dx, 0).is_some()
+                    || self.jump(map, current, 0, dy).is_some())
+            {
+                return Some(current);
+            }
+        }
+    }
+
+    /// The pruned set of directions worth leaving a cell in, given the
+    /// direction of travel that reached it. The start has no direction and
+    /// considers all eight.
+    fn pruned_directions(map: &dyn JumpMap, pos: Point, dir: Option<(i32, i32)>) -> Vec<(i32, i32)> {
+        let Some((dx, dy)) = dir else {
+            return vec![
+                (1, 0),
+                (-1, 0),
+                (0, 1),
+                (0, -1),
+                (1, 1),
+                (1, -1),
+                (-1, 1),
+                (-1, -1),
+            ];
+        };
+        let blocked = |x: i32, y: i32| !Self::passable(map, Point::new(x, y));
+        let mut dirs = Vec::new();
+        if dx != 0 && dy != 0 {
+            dirs.push((dx, 0));
+            dirs.push((0, dy));
+            dirs.push((dx, dy));
+            if blocked(pos.x - dx, pos.y) {
+                dirs.push((-dx, dy));
+            }
+            if blocked(pos.x, pos.y - dy) {
+                dirs.push((dx, -dy));
+            }
+        } else if dx != 0 {
+            dirs.push((dx, 0));
+            if blocked(pos.x, pos.y + 1) {
+                dirs.push((dx, 1));
+            }
+            if blocked(pos.x, pos.y - 1) {
+                dirs.push((dx, -1));
+            }
+        } else {
+            dirs.push((0, dy));
+            if blocked(pos.x + 1, pos.y) {
+                dirs.push((1, dy));
+            }
+            if blocked(pos.x - 1, pos.y) {
+                dirs.push((-1, dy));
+            }
+        }
+        dirs
+    }
+
+    /// Helper function to unwrap a path once we've found the end-point. Jump
+    /// points can be many cells apart, so the straight runs between them are
+    /// filled in to give contiguous steps like `a_star_search`.
+    fn found_it(&self, map: &dyn JumpMap) -> NavigationPath {
+        let mut result = NavigationPath::new();
+        result.success = true;
+        result.destination = self.end;
+
+        let mut jump_points = vec![self.end];
+        let mut current = self.end;
+        while current != self.start {
+            current = self.parents[&current];
+            jump_points.insert(0, current);
+        }
+
+        result.steps.push(self.start);
+        for pair in jump_points.windows(2) {
+            let mut pos = map.index_to_point2d(pair[0]);
+            let target = map.index_to_point2d(pair[1]);
+            while pos != target {
+                pos = Point::new(
+                    pos.x + (target.x - pos.x).signum(),
+                    pos.y + (target.y - pos.y).signum(),
+                );
+                result.steps.push(map.point2d_to_index(pos));
+            }
+        }
+
+        result
+    }
+
+    /// Performs the Jump Point Search.
+    fn search(&mut self, map: &dyn JumpMap) -> NavigationPath {
+        let result = NavigationPath::new();
+        if !map.is_passable(self.start) || !map.is_passable(self.end) {
+            return result;
+        }
+        while !self.open_list.is_empty() && self.step_counter < MAX_JPS_STEPS {
+            self.step_counter += 1;
+
+            let q = self.open_list.pop().unwrap();
+            if q.idx == self.end {
+                return self.found_it(map);
+            }
+            if !self.closed_list.insert(q.idx) {
+                continue;
+            }
+
+            let pos = map.index_to_point2d(q.idx);
+            let dir = self.parents.get(&q.idx).map(|parent| {
+                let from = map.index_to_point2d(*parent);
+                ((pos.x - from.x).signum(), (pos.y - from.y).signum())
+            });
+
+            for (dx, dy) in Self::pruned_directions(map, pos, dir) {
+                if let Some(jump_point) = self.jump(map, pos, dx, dy) {
+                    let idx = map.point2d_to_index(jump_point);
+                    let g = q.g + DistanceAlg::Pythagoras.distance2d(pos, jump_point);
+                    if g < *self.best_g.get(&idx).unwrap_or(&f32::MAX) {
+                        self.best_g.insert(idx, g);
+                        self.parents.insert(idx, q.idx);
+                        self.open_list.push(Node {
+                            idx,
+                            f: g + map.get_pathing_distance(idx, self.end),
+                            g,
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{jps_search, JumpMap};
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{DistanceAlg, Point};
+
+    // A 10x10 map with a vertical wall down x=5, pierced at y=8.
+    struct TestMap {
+        walls: Vec<bool>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut walls = vec![false; 100];
+            for y in 0..10 {
+                if y != 8 {
+                    walls[(y * 10 + 5) as usize] = true;
+                }
+            }
+            TestMap { walls }
+        }
+    }
+
+    impl BaseMap for TestMap {
+        fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+            DistanceAlg::Pythagoras.distance2d(self.index_to_point2d(idx1), self.index_to_point2d(idx2))
+        }
+    }
+
+    impl Algorithm2D for TestMap {
+        fn dimensions(&self) -> Point {
+            Point::new(10, 10)
+        }
+    }
+
+    impl JumpMap for TestMap {
+        fn is_passable(&self, idx: usize) -> bool {
+            !self.walls[idx]
+        }
+    }
+
+    #[test]
+    fn jps_finds_a_contiguous_path() {
+        let map = TestMap::new();
+        let start = map.point2d_to_index(Point::new(1, 1));
+        let end = map.point2d_to_index(Point::new(8, 1));
+        let path = jps_search(start, end, &map);
+        assert!(path.success);
+        assert_eq!(path.steps[0], start);
+        assert_eq!(*path.steps.last().unwrap(), end);
+        for pair in path.steps.windows(2) {
+            let a = map.index_to_point2d(pair[0]);
+            let b = map.index_to_point2d(pair[1]);
+            assert!((a.x - b.x).abs() <= 1 && (a.y - b.y).abs() <= 1 && a != b);
+        }
+        for step in &path.steps {
+            assert!(map.is_passable(*step));
+        }
+        // The only way through the wall is the gap at (5, 8).
+        assert!(path.steps.contains(&map.point2d_to_index(Point::new(5, 8))));
+    }
+
+    #[test]
+    fn jps_fails_when_walled_off() {
+        let mut map = TestMap::new();
+        map.walls[85] = true; // close the gap
+        let path = jps_search(
+            map.point2d_to_index(Point::new(1, 1)),
+            map.point2d_to_index(Point::new(8, 1)),
+            &map,
+        );
+        assert!(!path.success);
+        assert!(path.steps.is_empty());
+    }
+}
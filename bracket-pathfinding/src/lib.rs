@@ -1,11 +1,23 @@
 mod astar;
 mod dijkstra;
 mod field_of_view;
+mod flow_field;
+mod hpa;
+mod jps;
+mod navmesh;
+mod path_cache;
+mod thetastar;
 
 pub mod prelude {
     pub use crate::astar::*;
     pub use crate::dijkstra::*;
     pub use crate::field_of_view::*;
+    pub use crate::flow_field::*;
+    pub use crate::hpa::*;
+    pub use crate::jps::*;
+    pub use crate::navmesh::*;
+    pub use crate::path_cache::*;
+    pub use crate::thetastar::*;
     pub use bracket_algorithm_traits::prelude::*;
     pub use bracket_geometry::prelude::*;
 
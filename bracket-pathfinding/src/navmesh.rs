@@ -0,0 +1,461 @@
+use crate::prelude::JumpMap;
+use bracket_geometry::prelude::{Point, PointF};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+/// Tolerance used when matching shared polygon edges.
+const EDGE_EPSILON: f32 = 1e-4;
+
+/// A navigation mesh for free-form (non-grid) maps: walkable space is covered
+/// by convex polygons, paths are found with A* over the polygon adjacency
+/// graph, and the funnel algorithm pulls the crossing into a tight point path.
+/// Polygons use `PointF` throughout, so the result plugs straight into
+/// floating-point movement.
+pub struct NavMesh {
+    polygons: Vec<Vec<PointF>>,
+    centroids: Vec<PointF>,
+    adjacency: Vec<Vec<Portal>>,
+}
+
+/// An edge shared between two polygons, crossable by a path.
+#[derive(Copy, Clone)]
+struct Portal {
+    to: usize,
+    a: PointF,
+    b: PointF,
+}
+
+/// Signed double area of the triangle a-b-c; the sign gives the winding.
+fn triarea2(a: PointF, b: PointF, c: PointF) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (c.x - a.x) * (b.y - a.y)
+}
+
+/// Euclidean distance between two points.
+fn dist(a: PointF, b: PointF) -> f32 {
+    ((b.x - a.x).powi(2) + (b.y - a.y).powi(2)).sqrt()
+}
+
+/// Node on the polygon-graph open list, ordered as a min-heap on f.
+#[derive(Copy, Clone)]
+struct MeshNode {
+    poly: usize,
+    f: f32,
+    g: f32,
+}
+
+impl PartialEq for MeshNode {
+    fn eq(&self, other: &Self) -> bool {
+        self.f == other.f
+    }
+}
+
+impl Eq for MeshNode {}
+
+impl Ord for MeshNode {
+    fn cmp(&self, b: &Self) -> Ordering {
+        b.f.partial_cmp(&self.f).unwrap()
+    }
+}
+
+impl PartialOrd for MeshNode {
+    fn partial_cmp(&self, b: &Self) -> Option<Ordering> {
+        Some(self.cmp(b))
+    }
+}
+
+impl NavMesh {
+    /// Builds a mesh from a set of convex polygons (each a list of vertices,
+    /// either winding). Polygons become neighbors wherever their edges
+    /// overlap along a shared segment, so tessellations from any source work -
+    /// including ones whose vertices don't line up exactly.
+    pub fn new(polygons: Vec<Vec<PointF>>) -> NavMesh {
+        let centroids = polygons
+            .iter()
+            .map(|poly| {
+                let mut c = PointF::new(0.0, 0.0);
+                for v in poly {
+                    c.x += v.x;
+                    c.y += v.y;
+                }
+                c.x /= poly.len() as f32;
+                c.y /= poly.len() as f32;
+                c
+            })
+            .collect();
+
+        let mut adjacency: Vec<Vec<Portal>> = vec![Vec::new(); polygons.len()];
+        for i in 0..polygons.len() {
+            for j in i + 1..polygons.len() {
+                if let Some((a, b)) = shared_edge(&polygons[i], &polygons[j]) {
+                    adjacency[i].push(Portal { to: j, a, b });
+                    adjacency[j].push(Portal { to: i, a, b });
+                }
+            }
+        }
+
+        NavMesh {
+            polygons,
+            centroids,
+            adjacency,
+        }
+    }
+
+    /// Builds a mesh from a grid map by greedily merging walkable tiles into
+    /// maximal rectangles. Useful as a bridge while migrating a grid game to
+    /// free-form movement, and as a cheap convex decomposition.
+    pub fn from_grid(map: &dyn JumpMap) -> NavMesh {
+        let bounds = map.dimensions();
+        let walkable = |x: i32, y: i32| {
+            let pos = Point::new(x, y);
+            map.in_bounds(pos) && map.is_passable(map.point2d_to_index(pos))
+        };
+
+        let mut claimed = vec![false; (bounds.x * bounds.y) as usize];
+        let mut polygons: Vec<Vec<PointF>> = Vec::new();
+        for y in 0..bounds.y {
+            for x in 0..bounds.x {
+                if claimed[(y * bounds.x + x) as usize] || !walkable(x, y) {
+                    continue;
+                }
+                // Grow a rectangle right, then down.
+                let mut w = 1;
+                while walkable(x + w, y) && !claimed[(y * bounds.x + x + w) as usize] {
+                    w += 1;
+                }
+                let mut h = 1;
+                'rows: while y + h < bounds.y {
+                    for col in x..x + w {
+                        if !walkable(col, y + h) || claimed[((y + h) * bounds.x + col) as usize] {
+                            break 'rows;
+                        }
+                    }
+                    h += 1;
+                }
+                for cy in y..y + h {
+                    for cx in x..x + w {
+                        claimed[(cy * bounds.x + cx) as usize] = true;
+                    }
+                }
+                polygons.push(vec![
+                    PointF::new(x as f32, y as f32),
+                    PointF::new((x + w) as f32, y as f32),
+                    PointF::new((x + w) as f32, (y + h) as f32),
+                    PointF::new(x as f32, (y + h) as f32),
+                ]);
+            }
+        }
+        NavMesh::new(polygons)
+    }
+
+    /// How many polygons the mesh holds.
+    pub fn len(&self) -> usize {
+        self.polygons.len()
+    }
+
+    /// True if the mesh holds no polygons.
+    pub fn is_empty(&self) -> bool {
+        self.polygons.is_empty()
+    }
+
+    /// The index of the polygon containing a point, or None if it's outside
+    /// the mesh. Points on a shared edge belong to the first polygon found.
+    pub fn polygon_at(&self, pos: PointF) -> Option<usize> {
+        self.polygons.iter().position(|poly| {
+            let mut sign = 0.0f32;
+            for i in 0..poly.len() {
+                let area = triarea2(poly[i], poly[(i + 1) % poly.len()], pos);
+                if area.abs() <= EDGE_EPSILON {
+                    continue;
+                }
+                if sign == 0.0 {
+                    sign = area.signum();
+                } else if area.signum() != sign {
+                    return false;
+                }
+            }
+            true
+        })
+    }
+
+    /// Finds a point path from start to end: A* across the polygon graph,
+    /// then the funnel algorithm to pull the rope tight through the portals.
+    /// Returns None if either point is off the mesh or no route exists. The
+    /// result includes both endpoints.
+    pub fn find_path(&self, start: PointF, end: PointF) -> Option<Vec<PointF>> {
+        let start_poly = self.polygon_at(start)?;
+        let end_poly = self.polygon_at(end)?;
+        if start_poly == end_poly {
+            return Some(vec![start, end]);
+        }
+
+        let crossing = self.polygon_path(start_poly, end_poly, end)?;
+        let mut portals: Vec<(PointF, PointF)> = Vec::new();
+        let mut reference = start;
+        for pair in crossing.windows(2) {
+            let portal = self.adjacency[pair[0]]
+                .iter()
+                .find(|p| p.to == pair[1])
+                .unwrap();
+            // Orient the portal left/right relative to the direction of
+            // travel, as the funnel expects.
+            let (left, right) = if triarea2(reference, portal.a, portal.b) > 0.0 {
+                (portal.a, portal.b)
+            } else {
+                (portal.b, portal.a)
+            };
+            portals.push((left, right));
+            reference = PointF::new((left.x + right.x) * 0.5, (left.y + right.y) * 0.5);
+        }
+        portals.push((end, end));
+        Some(string_pull(&portals, start, end))
+    }
+
+    /// A* over the polygon adjacency graph. Edge costs and the heuristic use
+    /// polygon centroids; returns the polygon indices crossed, in order.
+    fn polygon_path(&self, start: usize, end: usize, end_point: PointF) -> Option<Vec<usize>> {
+        let mut open_list: BinaryHeap<MeshNode> = BinaryHeap::new();
+        let mut best_g: HashMap<usize, f32> = HashMap::new();
+        let mut parents: HashMap<usize, usize> = HashMap::new();
+        open_list.push(MeshNode {
+            poly: start,
+            f: 0.0,
+            g: 0.0,
+        });
+        best_g.insert(start, 0.0);
+
+        while let Some(q) = open_list.pop() {
+            if q.poly == end {
+                let mut crossing = vec![end];
+                let mut current = end;
+                while current != start {
+                    current = parents[&current];
+                    crossing.insert(0, current);
+                }
+                return Some(crossing);
+            }
+            for portal in &self.adjacency[q.poly] {
+                let g = q.g + dist(self.centroids[q.poly], self.centroids[portal.to]);
+                if g < *best_g.get(&portal.to).unwrap_or(&f32::MAX) {
+                    best_g.insert(portal.to, g);
+                    parents.insert(portal.to, q.poly);
+                    open_list.push(MeshNode {
+                        poly: portal.to,
+                        f: g + dist(self.centroids[portal.to], end_point),
+                        g,
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The overlapping segment of two polygons' boundaries, if they share one of
+/// positive length.
+fn shared_edge(a: &[PointF], b: &[PointF]) -> Option<(PointF, PointF)> {
+    for i in 0..a.len() {
+        let (p1, p2) = (a[i], a[(i + 1) % a.len()]);
+        for j in 0..b.len() {
+            let (q1, q2) = (b[j], b[(j + 1) % b.len()]);
+            if let Some(overlap) = segment_overlap(p1, p2, q1, q2) {
+                return Some(overlap);
+            }
+        }
+    }
+    None
+}
+
+/// The overlap of two collinear segments, or None if they aren't collinear or
+/// barely touch.
+fn segment_overlap(
+    p1: PointF,
+    p2: PointF,
+    q1: PointF,
+    q2: PointF,
+) -> Option<(PointF, PointF)> {
+    let d = PointF::new(p2.x - p1.x, p2.y - p1.y);
+    let len = dist(p1, p2);
+    if len <= EDGE_EPSILON
+        || triarea2(p1, p2, q1).abs() > EDGE_EPSILON
+        || triarea2(p1, p2, q2).abs() > EDGE_EPSILON
+    {
+        return None;
+    }
+    // Project everything onto p1->p2 and intersect the parameter ranges.
+    let project = |p: PointF| ((p.x - p1.x) * d.x + (p.y - p1.y) * d.y) / (len * len);
+    let (t1, t2) = (project(q1), project(q2));
+    let (lo, hi) = (t1.min(t2).max(0.0), t1.max(t2).min(1.0));
+    if hi - lo <= EDGE_EPSILON {
+        return None;
+    }
+    let at = |t: f32| PointF::new(p1.x + d.x * t, p1.y + d.y * t);
+    Some((at(lo), at(hi)))
+}
+
+/// The "simple stupid funnel" algorithm: walks the portal edges, keeping a
+/// funnel from the current apex, and emits a corner point whenever the funnel
+/// closes. The final portal is the degenerate (end, end).
+fn string_pull(portals: &[(PointF, PointF)], start: PointF, end: PointF) -> Vec<PointF> {
+    let mut path = vec![start];
+    let (mut apex, mut left, mut right) = (start, portals[0].0, portals[0].1);
+    let (mut left_i, mut right_i) = (0usize, 0usize);
+
+    let mut i = 1;
+    while i < portals.len() {
+        let (next_left, next_right) = portals[i];
+
+        // Tighten the right side of the funnel.
+        if triarea2(apex, right, next_right) <= 0.0 {
+            if apex == right || triarea2(apex, left, next_right) > 0.0 {
+                right = next_right;
+                right_i = i;
+            } else {
+                // Right crossed over left: the left corner is a path point.
+                path.push(left);
+                apex = left;
+                right = apex;
+                right_i = left_i;
+                i = left_i + 1;
+                continue;
+            }
+        }
+
+        // Tighten the left side of the funnel.
+        if triarea2(apex, left, next_left) >= 0.0 {
+            if apex == left || triarea2(apex, right, next_left) < 0.0 {
+                left = next_left;
+                left_i = i;
+            } else {
+                path.push(right);
+                apex = right;
+                left = apex;
+                left_i = right_i;
+                i = right_i + 1;
+                continue;
+            }
+        }
+
+        i += 1;
+    }
+
+    if *path.last().unwrap() != end {
+        path.push(end);
+    }
+    path
+}
+
+#[cfg(test)]
+mod test {
+    use super::NavMesh;
+    use crate::prelude::JumpMap;
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{Point, PointF};
+
+    #[test]
+    fn two_squares_share_an_edge() {
+        let mesh = NavMesh::new(vec![
+            vec![
+                PointF::new(0.0, 0.0),
+                PointF::new(5.0, 0.0),
+                PointF::new(5.0, 5.0),
+                PointF::new(0.0, 5.0),
+            ],
+            vec![
+                PointF::new(5.0, 0.0),
+                PointF::new(10.0, 0.0),
+                PointF::new(10.0, 5.0),
+                PointF::new(5.0, 5.0),
+            ],
+        ]);
+        assert_eq!(mesh.len(), 2);
+        assert_eq!(mesh.polygon_at(PointF::new(1.0, 1.0)), Some(0));
+        assert_eq!(mesh.polygon_at(PointF::new(9.0, 1.0)), Some(1));
+        assert_eq!(mesh.polygon_at(PointF::new(20.0, 1.0)), None);
+
+        // Straight across open ground: the rope pulls fully taut.
+        let path = mesh
+            .find_path(PointF::new(1.0, 2.5), PointF::new(9.0, 2.5))
+            .unwrap();
+        assert_eq!(path, vec![PointF::new(1.0, 2.5), PointF::new(9.0, 2.5)]);
+    }
+
+    #[test]
+    fn funnel_bends_around_an_l_corner() {
+        // An L-shaped corridor: vertical arm, elbow, horizontal arm.
+        let mesh = NavMesh::new(vec![
+            vec![
+                PointF::new(0.0, 0.0),
+                PointF::new(2.0, 0.0),
+                PointF::new(2.0, 8.0),
+                PointF::new(0.0, 8.0),
+            ],
+            vec![
+                PointF::new(0.0, 8.0),
+                PointF::new(2.0, 8.0),
+                PointF::new(2.0, 10.0),
+                PointF::new(0.0, 10.0),
+            ],
+            vec![
+                PointF::new(2.0, 8.0),
+                PointF::new(10.0, 8.0),
+                PointF::new(10.0, 10.0),
+                PointF::new(2.0, 10.0),
+            ],
+        ]);
+        let path = mesh
+            .find_path(PointF::new(1.0, 1.0), PointF::new(9.0, 9.0))
+            .unwrap();
+        assert_eq!(path.first(), Some(&PointF::new(1.0, 1.0)));
+        assert_eq!(path.last(), Some(&PointF::new(9.0, 9.0)));
+        // The rope catches on the elbow's inner corner at (2, 8).
+        assert!(path.contains(&PointF::new(2.0, 8.0)));
+        assert_eq!(path.len(), 3);
+    }
+
+    // A 10x10 grid map with a vertical wall down x=5, pierced at y=8.
+    struct TestMap {
+        walls: Vec<bool>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut walls = vec![false; 100];
+            for y in 0..10 {
+                if y != 8 {
+                    walls[(y * 10 + 5) as usize] = true;
+                }
+            }
+            TestMap { walls }
+        }
+    }
+
+    impl BaseMap for TestMap {}
+
+    impl Algorithm2D for TestMap {
+        fn dimensions(&self) -> Point {
+            Point::new(10, 10)
+        }
+    }
+
+    impl JumpMap for TestMap {
+        fn is_passable(&self, idx: usize) -> bool {
+            !self.walls[idx]
+        }
+    }
+
+    #[test]
+    fn grid_decomposition_routes_through_the_gap() {
+        let map = TestMap::new();
+        let mesh = NavMesh::from_grid(&map);
+        let path = mesh
+            .find_path(PointF::new(1.5, 1.5), PointF::new(8.5, 1.5))
+            .unwrap();
+        assert_eq!(path.first(), Some(&PointF::new(1.5, 1.5)));
+        assert_eq!(path.last(), Some(&PointF::new(8.5, 1.5)));
+        // Every point sits on the mesh, and the route dips through the gap row.
+        for point in &path {
+            assert!(mesh.polygon_at(*point).is_some());
+        }
+        assert!(path.iter().any(|p| p.y >= 8.0));
+    }
+}
@@ -0,0 +1,217 @@
+use crate::prelude::{a_star_search, NavigationPath};
+use bracket_algorithm_traits::prelude::Algorithm2D;
+use std::collections::{HashMap, VecDeque};
+
+/// An optional cache for `a_star_search` results, for games where many agents
+/// request near-identical paths. Entries are keyed by the goal tile and the
+/// *region* containing the start (the map carved into `region_size` squares),
+/// so agents clustered together share one stored path.
+///
+/// A shared hit may therefore begin a few tiles away from the asking agent:
+/// walk to `steps[0]` first, or pass a `region_size` of 1 for exact-start
+/// caching only.
+///
+/// Stored paths are validated against `BaseMap::get_generation()`; bump that
+/// counter whenever the map changes and stale entries fall back to a fresh
+/// search on their next use.
+pub struct PathCache {
+    region_size: i32,
+    capacity: usize,
+    entries: HashMap<(i32, i32, usize), CachedPath>,
+    order: VecDeque<(i32, i32, usize)>,
+}
+
+/// A stored path, and the map generation it was computed against.
+struct CachedPath {
+    path: NavigationPath,
+    generation: u64,
+}
+
+impl PathCache {
+    /// Creates a cache. `region_size` is the side of the start-region squares
+    /// (in tiles); `capacity` is the number of paths retained, oldest first
+    /// out.
+    pub fn new(region_size: i32, capacity: usize) -> PathCache {
+        PathCache {
+            region_size: region_size.max(1),
+            capacity: capacity.max(1),
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    /// The cache key for a start/end pair.
+    fn key(&self, start: usize, end: usize, map: &dyn Algorithm2D) -> (i32, i32, usize) {
+        let start_pos = map.index_to_point2d(start);
+        (
+            start_pos.x / self.region_size,
+            start_pos.y / self.region_size,
+            end,
+        )
+    }
+
+    /// Returns a path from start to end, reusing a cached one when an agent in
+    /// the same start-region already asked for this goal on the current map
+    /// generation. Falls back to a fresh `a_star_search` (and stores the
+    /// result) otherwise.
+    pub fn find_path(&mut self, start: usize, end: usize, map: &dyn Algorithm2D) -> NavigationPath {
+        let key = self.key(start, end, map);
+        if let Some(cached) = self.entries.get(&key) {
+            if cached.generation == map.get_generation() {
+                return cached.path.clone();
+            }
+        }
+
+        let path = a_star_search(start, end, map);
+        if !self.entries.contains_key(&key) {
+            if self.order.len() >= self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.order.push_back(key);
+        }
+        self.entries.insert(
+            key,
+            CachedPath {
+                path: path.clone(),
+                generation: map.get_generation(),
+            },
+        );
+        path
+    }
+
+    /// Drops every stored path.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PathCache;
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{DistanceAlg, Point};
+    use smallvec::SmallVec;
+    use std::cell::Cell;
+
+    // A 10x10 map with a vertical wall down x=5, pierced at y=8, which counts
+    // how often its exits are queried so tests can see cache hits.
+    struct TestMap {
+        walls: Vec<bool>,
+        generation: u64,
+        exit_queries: Cell<usize>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut walls = vec![false; 100];
+            for y in 0..10 {
+                if y != 8 {
+                    walls[(y * 10 + 5) as usize] = true;
+                }
+            }
+            TestMap {
+                walls,
+                generation: 0,
+                exit_queries: Cell::new(0),
+            }
+        }
+    }
+
+    impl BaseMap for TestMap {
+        fn get_available_exits(&self, idx: usize) -> SmallVec<[(usize, f32); 10]> {
+            self.exit_queries.set(self.exit_queries.get() + 1);
+            let mut exits = SmallVec::new();
+            let pos = self.index_to_point2d(idx);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let next = Point::new(pos.x + dx, pos.y + dy);
+                    if (dx != 0 || dy != 0) && self.in_bounds(next) {
+                        let next_idx = self.point2d_to_index(next);
+                        if !self.walls[next_idx] {
+                            exits.push((next_idx, if dx != 0 && dy != 0 { 1.4 } else { 1.0 }));
+                        }
+                    }
+                }
+            }
+            exits
+        }
+
+        fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+            DistanceAlg::Pythagoras
+                .distance2d(self.index_to_point2d(idx1), self.index_to_point2d(idx2))
+        }
+
+        fn get_generation(&self) -> u64 {
+            self.generation
+        }
+    }
+
+    impl Algorithm2D for TestMap {
+        fn dimensions(&self) -> Point {
+            Point::new(10, 10)
+        }
+    }
+
+    #[test]
+    fn nearby_agents_share_a_cached_path() {
+        let map = TestMap::new();
+        let mut cache = PathCache::new(3, 16);
+        let end = map.point2d_to_index(Point::new(8, 1));
+
+        let first = cache.find_path(map.point2d_to_index(Point::new(1, 1)), end, &map);
+        assert!(first.success);
+        assert!(map.exit_queries.get() > 0);
+
+        // A neighbor in the same 3x3 region hits the cache - no search runs.
+        map.exit_queries.set(0);
+        let second = cache.find_path(map.point2d_to_index(Point::new(2, 2)), end, &map);
+        assert_eq!(map.exit_queries.get(), 0);
+        assert_eq!(second.steps, first.steps);
+    }
+
+    #[test]
+    fn generation_bump_invalidates_cached_paths() {
+        let mut map = TestMap::new();
+        let mut cache = PathCache::new(3, 16);
+        let start = map.point2d_to_index(Point::new(1, 1));
+        let end = map.point2d_to_index(Point::new(8, 1));
+
+        let through_gap = cache.find_path(start, end, &map);
+        assert!(through_gap
+            .steps
+            .contains(&map.point2d_to_index(Point::new(5, 8))));
+
+        // Open a shortcut and bump the generation: the stale path is rebuilt.
+        map.walls[15] = false; // (5, 1)
+        map.generation += 1;
+        let shortcut = cache.find_path(start, end, &map);
+        assert!(shortcut.success);
+        assert!(shortcut.steps.len() < through_gap.steps.len());
+
+        // The rebuilt path is cached against the new generation.
+        map.exit_queries.set(0);
+        cache.find_path(start, end, &map);
+        assert_eq!(map.exit_queries.get(), 0);
+    }
+
+    #[test]
+    fn capacity_evicts_the_oldest_entry() {
+        let map = TestMap::new();
+        let mut cache = PathCache::new(1, 2);
+        let goals = [Point::new(8, 1), Point::new(8, 3), Point::new(8, 5)];
+        let start = map.point2d_to_index(Point::new(1, 1));
+        for goal in goals {
+            cache.find_path(start, map.point2d_to_index(goal), &map);
+        }
+
+        // The first goal was evicted, the last is still warm.
+        map.exit_queries.set(0);
+        cache.find_path(start, map.point2d_to_index(goals[2]), &map);
+        assert_eq!(map.exit_queries.get(), 0);
+        cache.find_path(start, map.point2d_to_index(goals[0]), &map);
+        assert!(map.exit_queries.get() > 0);
+    }
+}
@@ -0,0 +1,366 @@
+use crate::prelude::{JumpMap, NavigationPath};
+use bracket_geometry::prelude::{DistanceAlg, Point, SuperCoverLine};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::convert::TryInto;
+
+/// Bail out if the Theta* search exceeds this many steps.
+const MAX_THETA_STEPS: usize = 65536;
+
+/// Request a Theta* any-angle search. The start and end are specified as index
+/// numbers (compatible with your `Algorithm2D` implementation). Theta* relaxes
+/// A*'s grid-locked parents with line-of-sight shortcuts, so projectiles and
+/// flying units get natural straight runs instead of zig-zags.
+///
+/// Unlike `a_star_search`, the returned `steps` are *waypoints*: each
+/// consecutive pair has line of sight between them, but they are generally not
+/// adjacent cells. The start and destination are included.
+pub fn theta_star_search<T>(start: T, end: T, map: &dyn JumpMap) -> NavigationPath
+where
+    T: TryInto<usize>,
+{
+    ThetaStar::new(start.try_into().ok().unwrap(), end.try_into().ok().unwrap()).search(map)
+}
+
+/// String-pulling post-process for grid paths: walks an existing
+/// `NavigationPath` (e.g. from `a_star_search`) and drops every waypoint that
+/// line of sight makes redundant, so the result no longer hugs walls. The
+/// returned steps are waypoints suitable for any-angle movement, like
+/// `theta_star_search` output; the destination and success flag are preserved.
+pub fn smooth_path(path: &NavigationPath, map: &dyn JumpMap) -> NavigationPath {
+    let mut result = NavigationPath::new();
+    result.destination = path.destination;
+    result.success = path.success;
+    if path.steps.is_empty() {
+        return result;
+    }
+
+    let mut anchor = 0;
+    result.steps.push(path.steps[0]);
+    while anchor < path.steps.len() - 1 {
+        let anchor_pos = map.index_to_point2d(path.steps[anchor]);
+        let mut furthest = anchor + 1;
+        for (candidate, step) in path.steps.iter().enumerate().skip(anchor + 2) {
+            if line_of_sight(map, anchor_pos, map.index_to_point2d(*step)) {
+                furthest = candidate;
+            }
+        }
+        result.steps.push(path.steps[furthest]);
+        anchor = furthest;
+    }
+    result
+}
+
+/// True if the point is on the map and can be entered.
+fn is_walkable(map: &dyn JumpMap, pos: Point) -> bool {
+    map.in_bounds(pos) && map.is_passable(map.point2d_to_index(pos))
+}
+
+/// Line-of-sight check between two cells: every cell the segment touches must
+/// be walkable. Uses the supercover traversal rather than plain Bresenham so
+/// the result is symmetric and never slips through clipped corners.
+fn line_of_sight(map: &dyn JumpMap, from: Point, to: Point) -> bool {
+    SuperCoverLine::new(from, to).all(|cell| is_walkable(map, cell.pos))
+}
+
+#[derive(Copy, Clone)]
+/// Node is an internal step on the open list; idx is the cell, f the total
+/// cost, g the cost from the start.
+struct Node {
+    idx: usize,
+    f: f32,
+    g: f32,
+}
+
+impl PartialEq for Node {
+    fn eq(&self, other: &Self) -> bool {
+        self.f == other.f
+    }
+}
+
+impl Eq for Node {}
+
+impl Ord for Node {
+    fn cmp(&self, b: &Self) -> Ordering {
+        b.f.partial_cmp(&self.f).unwrap()
+    }
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, b: &Self) -> Option<Ordering> {
+        Some(self.cmp(b))
+    }
+}
+
+/// Private structure for calculating a Theta* path.
+struct ThetaStar {
+    start: usize,
+    end: usize,
+    open_list: BinaryHeap<Node>,
+    closed_list: HashSet<usize>,
+    best_g: HashMap<usize, f32>,
+    parents: HashMap<usize, usize>,
+    step_counter: usize,
+}
+
+impl ThetaStar {
+    /// Creates a new search, with specified starting and ending indices.
+    fn new(start: usize, end: usize) -> ThetaStar {
+        let mut open_list: BinaryHeap<Node> = BinaryHeap::new();
+        open_list.push(Node {
+            idx: start,
+            f: 0.0,
+            g: 0.0,
+        });
+        let mut best_g = HashMap::new();
+        best_g.insert(start, 0.0);
+
+        ThetaStar {
+            start,
+            end,
+            open_list,
+            closed_list: HashSet::new(),
+            best_g,
+            parents: HashMap::new(),
+            step_counter: 0,
+        }
+    }
+
+    /// Helper function to unwrap the waypoint chain once we've found the
+    /// end-point. Each parent link already has line of sight to its child, so
+    /// the chain is the any-angle path.
+    fn found_it(&self) -> NavigationPath {
+        let mut result = NavigationPath::new();
+        result.success = true;
+        result.destination = self.end;
+
+        result.steps.push(self.end);
+        let mut current = self.end;
+        while current != self.start {
+            current = self.parents[&current];
+            result.steps.insert(0, current);
+        }
+
+        result
+    }
+
+    /// Performs the Theta* search.
+    fn search(&mut self, map: &dyn JumpMap) -> NavigationPath {
+        let result = NavigationPath::new();
+        if !map.is_passable(self.start) || !map.is_passable(self.end) {
+            return result;
+        }
+        while !self.open_list.is_empty() && self.step_counter < MAX_THETA_STEPS {
+            self.step_counter += 1;
+
+            let q = self.open_list.pop().unwrap();
+            if q.idx == self.end {
+                return self.found_it();
+            }
+            if !self.closed_list.insert(q.idx) {
+                continue;
+            }
+
+            let pos = map.index_to_point2d(q.idx);
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    if dx == 0 && dy == 0 {
+                        continue;
+                    }
+                    let next = Point::new(pos.x + dx, pos.y + dy);
+                    if !is_walkable(map, next) {
+                        continue;
+                    }
+                    // No corner cutting: diagonal steps must match the
+                    // supercover line-of-sight rule, or the waypoints would
+                    // clip walls the shortcut check forbids.
+                    if dx != 0
+                        && dy != 0
+                        && (!is_walkable(map, Point::new(pos.x + dx, pos.y))
+                            || !is_walkable(map, Point::new(pos.x, pos.y + dy)))
+                    {
+                        continue;
+                    }
+                    let next_idx = map.point2d_to_index(next);
+                    if self.closed_list.contains(&next_idx) {
+                        continue;
+                    }
+
+                    // Path 2: if the grandparent can see this neighbor, skip
+                    // the intermediate cell entirely. Path 1: step from q.
+                    let grandparent = self.parents.get(&q.idx).copied();
+                    let (parent, g) = match grandparent {
+                        Some(gp) => {
+                            let gp_pos = map.index_to_point2d(gp);
+                            if line_of_sight(map, gp_pos, next) {
+                                (
+                                    gp,
+                                    self.best_g[&gp]
+                                        + DistanceAlg::Pythagoras.distance2d(gp_pos, next),
+                                )
+                            } else {
+                                (q.idx, q.g + DistanceAlg::Pythagoras.distance2d(pos, next))
+                            }
+                        }
+                        None => (q.idx, q.g + DistanceAlg::Pythagoras.distance2d(pos, next)),
+                    };
+
+                    if g < *self.best_g.get(&next_idx).unwrap_or(&f32::MAX) {
+                        self.best_g.insert(next_idx, g);
+                        self.parents.insert(next_idx, parent);
+                        self.open_list.push(Node {
+                            idx: next_idx,
+                            f: g + map.get_pathing_distance(next_idx, self.end),
+                            g,
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{line_of_sight, smooth_path, theta_star_search};
+    use crate::prelude::{JumpMap, NavigationPath};
+    use bracket_algorithm_traits::prelude::{Algorithm2D, BaseMap};
+    use bracket_geometry::prelude::{DistanceAlg, Point};
+
+    // A 10x10 map with a vertical wall down x=5, pierced at y=8.
+    struct TestMap {
+        walls: Vec<bool>,
+    }
+
+    impl TestMap {
+        fn new() -> Self {
+            let mut walls = vec![false; 100];
+            for y in 0..10 {
+                if y != 8 {
+                    walls[(y * 10 + 5) as usize] = true;
+                }
+            }
+            TestMap { walls }
+        }
+    }
+
+    impl BaseMap for TestMap {
+        fn get_pathing_distance(&self, idx1: usize, idx2: usize) -> f32 {
+            DistanceAlg::Pythagoras
+                .distance2d(self.index_to_point2d(idx1), self.index_to_point2d(idx2))
+        }
+    }
+
+    impl Algorithm2D for TestMap {
+        fn dimensions(&self) -> Point {
+            Point::new(10, 10)
+        }
+    }
+
+    impl JumpMap for TestMap {
+        fn is_passable(&self, idx: usize) -> bool {
+            !self.walls[idx]
+        }
+    }
+
+    #[test]
+    fn open_ground_is_a_single_straight_run() {
+        let map = TestMap {
+            walls: vec![false; 100],
+        };
+        let start = map.point2d_to_index(Point::new(1, 1));
+        let end = map.point2d_to_index(Point::new(8, 6));
+        let path = theta_star_search(start, end, &map);
+        assert!(path.success);
+        assert_eq!(path.steps, vec![start, end]);
+    }
+
+    #[test]
+    fn waypoints_have_line_of_sight() {
+        let map = TestMap::new();
+        let start = map.point2d_to_index(Point::new(1, 1));
+        let end = map.point2d_to_index(Point::new(8, 1));
+        let path = theta_star_search(start, end, &map);
+        assert!(path.success);
+        assert_eq!(path.steps[0], start);
+        assert_eq!(*path.steps.last().unwrap(), end);
+        for pair in path.steps.windows(2) {
+            assert!(line_of_sight(
+                &map,
+                map.index_to_point2d(pair[0]),
+                map.index_to_point2d(pair[1])
+            ));
+        }
+        // The detour through (5, 8) needs only a few turning points.
+        assert!(path.steps.len() <= 5);
+    }
+
+    #[test]
+    fn smoothing_collapses_a_staircase() {
+        let map = TestMap {
+            walls: vec![false; 100],
+        };
+        // The staircase a grid A* produces between (1, 1) and (6, 4).
+        let mut jagged = NavigationPath::new();
+        jagged.success = true;
+        for step in [(1, 1), (2, 2), (3, 3), (4, 4), (5, 4), (6, 4)] {
+            jagged.steps.push(map.point2d_to_index(Point::new(step.0, step.1)));
+        }
+        jagged.destination = *jagged.steps.last().unwrap();
+
+        let smooth = smooth_path(&jagged, &map);
+        assert!(smooth.success);
+        assert_eq!(smooth.steps, vec![jagged.steps[0], jagged.destination]);
+    }
+
+    #[test]
+    fn smoothing_keeps_necessary_corners() {
+        let map = TestMap::new();
+        // A wall-hugging route through the gap at (5, 8).
+        let route = [
+            (4, 1),
+            (4, 2),
+            (4, 3),
+            (4, 4),
+            (4, 5),
+            (4, 6),
+            (4, 7),
+            (4, 8),
+            (5, 8),
+            (6, 8),
+            (6, 7),
+        ];
+        let mut jagged = NavigationPath::new();
+        jagged.success = true;
+        for step in route {
+            jagged.steps.push(map.point2d_to_index(Point::new(step.0, step.1)));
+        }
+        jagged.destination = *jagged.steps.last().unwrap();
+
+        let smooth = smooth_path(&jagged, &map);
+        assert!(smooth.steps.len() < jagged.steps.len());
+        assert_eq!(smooth.steps[0], jagged.steps[0]);
+        assert_eq!(*smooth.steps.last().unwrap(), jagged.destination);
+        for pair in smooth.steps.windows(2) {
+            assert!(line_of_sight(
+                &map,
+                map.index_to_point2d(pair[0]),
+                map.index_to_point2d(pair[1])
+            ));
+        }
+    }
+
+    #[test]
+    fn theta_star_fails_when_walled_off() {
+        let mut map = TestMap::new();
+        map.walls[85] = true; // close the gap
+        let path = theta_star_search(
+            map.point2d_to_index(Point::new(1, 1)),
+            map.point2d_to_index(Point::new(8, 1)),
+            &map,
+        );
+        assert!(!path.success);
+        assert!(path.steps.is_empty());
+    }
+}
@@ -0,0 +1,168 @@
+#[cfg(feature = "parsing")]
+use crate::prelude::DiceParseError;
+use crate::prelude::{RandomNumberGenerator, RngAlgorithm};
+use std::sync::{Mutex, MutexGuard};
+
+/// A thread-safe handle around a [`RandomNumberGenerator`], for ECS systems
+/// and worker threads that need to share one source of randomness. The shared
+/// generator sits behind a lock, with the usual convenience methods locking
+/// per call; parallel systems that must not contend (or must stay reproducible
+/// regardless of scheduling) should instead take their own stream with
+/// [`GlobalRng::stream`], which derives an independent generator from the
+/// master seed.
+pub struct GlobalRng {
+    master_seed: u64,
+    rng: Mutex<RandomNumberGenerator>,
+}
+
+impl Default for GlobalRng {
+    fn default() -> Self {
+        GlobalRng::new()
+    }
+}
+
+impl GlobalRng {
+    /// Creates a new shared xorshift RNG from a randomly generated master seed
+    pub fn new() -> GlobalRng {
+        let mut seed_source = RandomNumberGenerator::new();
+        GlobalRng::seeded(seed_source.next_u64())
+    }
+
+    /// Creates a new shared xorshift RNG from a specific master seed; the
+    /// same seed reproduces both the shared sequence and every stream
+    pub fn seeded(seed: u64) -> GlobalRng {
+        GlobalRng {
+            master_seed: seed,
+            rng: Mutex::new(RandomNumberGenerator::seeded(seed)),
+        }
+    }
+
+    /// The master seed this handle (and its streams) derive from
+    pub fn master_seed(&self) -> u64 {
+        self.master_seed
+    }
+
+    /// Derives an independent generator for one system or thread. Streams are
+    /// keyed only by the master seed and the stream number, so stream `n` is
+    /// reproducible however threads are scheduled - hand each parallel system
+    /// its own number and let it roll without touching the lock.
+    pub fn stream(&self, stream: u64) -> RandomNumberGenerator {
+        RandomNumberGenerator::seeded(split_mix(self.master_seed ^ split_mix(stream)))
+    }
+
+    /// As `stream`, but running the derived generator on the chosen algorithm
+    pub fn stream_with_algorithm(
+        &self,
+        algorithm: RngAlgorithm,
+        stream: u64,
+    ) -> RandomNumberGenerator {
+        RandomNumberGenerator::with_algorithm_seeded(
+            algorithm,
+            split_mix(self.master_seed ^ split_mix(stream)),
+        )
+    }
+
+    /// Locks the shared generator for several calls in a row - cheaper than
+    /// relocking per call, and gives access to the full API
+    pub fn lock(&self) -> MutexGuard<'_, RandomNumberGenerator> {
+        self.rng.lock().unwrap()
+    }
+
+    /// Returns a random value of whatever type you specify
+    pub fn rand<T>(&self) -> T
+    where
+        rand::distributions::Standard: rand::distributions::Distribution<T>,
+    {
+        self.lock().rand::<T>()
+    }
+
+    /// Returns a random value in the specified range, of type specified at the
+    /// call site. This is INCLUSIVE of the first parameter, and EXCLUSIVE of
+    /// the second.
+    pub fn range<T>(&self, min: T, max: T) -> T
+    where
+        T: rand::distributions::uniform::SampleUniform + PartialOrd,
+    {
+        self.lock().range(min, max)
+    }
+
+    /// Rolls dice, using the classic 3d6 type of format: n is the number of
+    /// dice, die_type is the size of the dice.
+    pub fn roll_dice(&self, n: i32, die_type: i32) -> i32 {
+        self.lock().roll_dice(n, die_type)
+    }
+
+    /// Returns the RNG's next unsigned-64 type
+    pub fn next_u64(&self) -> u64 {
+        self.lock().next_u64()
+    }
+
+    /// Rolls dice based on passing in a string, such as roll_str("1d12")
+    #[cfg(feature = "parsing")]
+    pub fn roll_str<S: ToString>(&self, dice: S) -> Result<i32, DiceParseError> {
+        self.lock().roll_str(dice)
+    }
+
+    /// Shuffles a slice in place (Fisher-Yates)
+    pub fn shuffle<T>(&self, slice: &mut [T]) {
+        self.lock().shuffle(slice)
+    }
+
+    /// Returns a random entry from a slice (or None if empty)
+    pub fn choose<'a, T>(&self, slice: &'a [T]) -> Option<&'a T> {
+        self.lock().choose(slice)
+    }
+}
+
+// SplitMix64's finalizer: spreads a seed/stream number over all 64 bits so
+// neighbouring stream numbers produce unrelated sequences.
+fn split_mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GlobalRng;
+    use std::sync::Arc;
+
+    #[test]
+    fn shared_handle_works_across_threads() {
+        let rng = Arc::new(GlobalRng::seeded(1));
+        let mut handles = Vec::new();
+        for _ in 0..4 {
+            let rng = rng.clone();
+            handles.push(std::thread::spawn(move || {
+                for _ in 0..100 {
+                    let n = rng.roll_dice(3, 6);
+                    assert!((3..=18).contains(&n));
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+    }
+
+    #[test]
+    fn streams_are_reproducible_and_independent() {
+        let first = GlobalRng::seeded(99);
+        let second = GlobalRng::seeded(99);
+        assert_eq!(first.stream(7).next_u64(), second.stream(7).next_u64());
+        assert_ne!(first.stream(7).next_u64(), first.stream(8).next_u64());
+        // Streams don't advance the shared generator.
+        assert_eq!(first.next_u64(), second.next_u64());
+    }
+
+    #[test]
+    fn shared_sequence_matches_a_plain_generator() {
+        use crate::prelude::RandomNumberGenerator;
+        let shared = GlobalRng::seeded(5);
+        let mut plain = RandomNumberGenerator::seeded(5);
+        for _ in 0..10 {
+            assert_eq!(shared.next_u64(), plain.next_u64());
+        }
+    }
+}
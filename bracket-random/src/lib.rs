@@ -12,6 +12,12 @@ mod js_seed;
 
 mod iterators;
 
+mod weighted;
+
+mod poisson;
+
+mod global;
+
 pub mod prelude {
     pub use crate::random::*;
 
@@ -19,4 +25,10 @@ pub mod prelude {
     pub use crate::parsing::*;
 
     pub use crate::iterators::*;
+
+    pub use crate::weighted::*;
+
+    pub use crate::poisson::*;
+
+    pub use crate::global::*;
 }
@@ -38,6 +38,64 @@ impl Default for DiceType {
     }
 }
 
+/// Which rolled dice count towards the total, from the `kh`/`kl`/`dh`/`dl`
+/// suffixes (e.g. `4d6kh3` keeps the highest three of four dice).
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum KeepRule {
+    /// Every die counts.
+    All,
+    /// Only the highest n dice count (`khN`).
+    KeepHighest(i32),
+    /// Only the lowest n dice count (`klN`).
+    KeepLowest(i32),
+    /// The highest n dice are discarded (`dhN`).
+    DropHighest(i32),
+    /// The lowest n dice are discarded (`dlN`).
+    DropLowest(i32),
+}
+
+/// A dice roll in extended tabletop notation: a base [`DiceType`] plus
+/// exploding dice (`!`), rerolls (`rN` rerolls anything at or below N, once)
+/// and a [`KeepRule`]. Parse one with [`parse_extended_dice_string`], roll it
+/// with `RandomNumberGenerator::roll_extended`.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ExtendedDiceType {
+    pub dice: DiceType,
+    pub keep: KeepRule,
+    pub exploding: bool,
+    pub reroll_below: Option<i32>,
+}
+
+impl Default for ExtendedDiceType {
+    fn default() -> ExtendedDiceType {
+        ExtendedDiceType {
+            dice: DiceType::default(),
+            keep: KeepRule::All,
+            exploding: false,
+            reroll_below: None,
+        }
+    }
+}
+
+impl From<DiceType> for ExtendedDiceType {
+    fn from(dice: DiceType) -> ExtendedDiceType {
+        ExtendedDiceType {
+            dice,
+            ..Default::default()
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DiceParseError;
 
@@ -95,9 +153,77 @@ pub fn parse_dice_string(dice: &str) -> Result<DiceType, DiceParseError> {
     Ok(result)
 }
 
+#[allow(dead_code)]
+// Parses an extended dice string, of the type "4d6kh3", "d6!", "3d6r1+2" or
+// "2d20dl1-1". Modifiers come in the order: explosion, reroll, keep/drop, bonus.
+#[cfg(feature = "parsing")]
+pub fn parse_extended_dice_string(dice: &str) -> Result<ExtendedDiceType, DiceParseError> {
+    let dice = &dice.split_whitespace().collect::<Vec<_>>().join("");
+    lazy_static! {
+        static ref EXTENDED_DICE_RE: Regex =
+            Regex::new(r"^(\d+)?d(\d+)(!)?(?:r(\d+))?(?:(kh|kl|dh|dl)(\d+))?([\+\-]\d+)?$")
+                .unwrap();
+    }
+    let cap = match EXTENDED_DICE_RE.captures(dice) {
+        Some(cap) => cap,
+        None => return Err(DiceParseError {}),
+    };
+
+    let n_dice = match cap.get(1) {
+        Some(group) => match group.as_str().parse::<i32>() {
+            Ok(number) => number,
+            Err(_) => return Err(DiceParseError {}),
+        },
+        None => 1,
+    };
+    let die_type = match cap.get(2) {
+        Some(group) => match group.as_str().parse::<i32>() {
+            Ok(number) => number,
+            Err(_) => return Err(DiceParseError {}),
+        },
+        None => return Err(DiceParseError {}),
+    };
+    let reroll_below = match cap.get(4) {
+        Some(group) => match group.as_str().parse::<i32>() {
+            Ok(number) => Some(number),
+            Err(_) => return Err(DiceParseError {}),
+        },
+        None => None,
+    };
+    let keep = if let (Some(rule), Some(count)) = (cap.get(5), cap.get(6)) {
+        let count = match count.as_str().parse::<i32>() {
+            Ok(number) => number,
+            Err(_) => return Err(DiceParseError {}),
+        };
+        match rule.as_str() {
+            "kh" => KeepRule::KeepHighest(count),
+            "kl" => KeepRule::KeepLowest(count),
+            "dh" => KeepRule::DropHighest(count),
+            "dl" => KeepRule::DropLowest(count),
+            _ => return Err(DiceParseError {}),
+        }
+    } else {
+        KeepRule::All
+    };
+    let bonus = match cap.get(7) {
+        Some(group) => match group.as_str().parse::<i32>() {
+            Ok(number) => number,
+            Err(_) => return Err(DiceParseError {}),
+        },
+        None => 0,
+    };
+
+    Ok(ExtendedDiceType {
+        dice: DiceType::new(n_dice, die_type, bonus),
+        keep,
+        exploding: cap.get(3).is_some(),
+        reroll_below,
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{parse_dice_string, DiceType};
+    use super::{parse_dice_string, parse_extended_dice_string, DiceType, KeepRule};
 
     #[test]
     fn parse_1d6() {
@@ -138,6 +264,38 @@ mod tests {
         assert!(parse_dice_string("blah").is_err());
     }
 
+    #[test]
+    fn parse_keep_highest() {
+        let parsed = parse_extended_dice_string("4d6kh3").unwrap();
+        assert_eq!(parsed.dice, DiceType::new(4, 6, 0));
+        assert_eq!(parsed.keep, KeepRule::KeepHighest(3));
+        assert!(!parsed.exploding);
+        assert!(parsed.reroll_below.is_none());
+    }
+
+    #[test]
+    fn parse_exploding_reroll_and_bonus() {
+        let parsed = parse_extended_dice_string("3d6!r1dl1+2").unwrap();
+        assert_eq!(parsed.dice, DiceType::new(3, 6, 2));
+        assert_eq!(parsed.keep, KeepRule::DropLowest(1));
+        assert!(parsed.exploding);
+        assert_eq!(parsed.reroll_below, Some(1));
+    }
+
+    #[test]
+    fn parse_bare_exploding_die() {
+        let parsed = parse_extended_dice_string("d6!").unwrap();
+        assert_eq!(parsed.dice, DiceType::new(1, 6, 0));
+        assert!(parsed.exploding);
+    }
+
+    #[test]
+    fn fail_extended_parsing() {
+        assert!(parse_extended_dice_string("4d6kx3").is_err());
+        assert!(parse_extended_dice_string("blah").is_err());
+        assert!(parse_extended_dice_string("4d6kh3!").is_err());
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize_parsing() {
@@ -0,0 +1,165 @@
+use crate::prelude::RandomNumberGenerator;
+use bracket_geometry::prelude::{Point, PointF, Rect};
+
+/// Fills a rectangle with points using Poisson-disk sampling (Bridson's
+/// algorithm): points land evenly but without a visible grid, and no two come
+/// closer than `radius`. Handy for scattering trees, monsters or stars with a
+/// minimum spacing. Deterministic for a given RNG state.
+pub fn poisson_disk_sample(
+    area: Rect,
+    radius: f32,
+    rng: &mut RandomNumberGenerator,
+) -> Vec<PointF> {
+    // How many candidates to try around each point before retiring it;
+    // Bridson's recommended constant.
+    const ATTEMPTS: u32 = 30;
+
+    let width = (area.x2 - area.x1) as f32;
+    let height = (area.y2 - area.y1) as f32;
+    if width <= 0.0 || height <= 0.0 || radius <= 0.0 {
+        return Vec::new();
+    }
+
+    // A grid with cells small enough that each can hold at most one point.
+    let cell_size = radius / std::f32::consts::SQRT_2;
+    let cols = (width / cell_size).ceil() as usize + 1;
+    let rows = (height / cell_size).ceil() as usize + 1;
+    let mut grid: Vec<Option<usize>> = vec![None; cols * rows];
+    let cell_of = |point: PointF| -> usize {
+        let col = ((point.x - area.x1 as f32) / cell_size) as usize;
+        let row = ((point.y - area.y1 as f32) / cell_size) as usize;
+        row.min(rows - 1) * cols + col.min(cols - 1)
+    };
+
+    let mut points: Vec<PointF> = Vec::new();
+    let mut active: Vec<usize> = Vec::new();
+
+    let first = PointF::new(
+        area.x1 as f32 + rng.rand::<f32>() * width,
+        area.y1 as f32 + rng.rand::<f32>() * height,
+    );
+    grid[cell_of(first)] = Some(0);
+    points.push(first);
+    active.push(0);
+
+    while !active.is_empty() {
+        let slot = rng.range(0, active.len());
+        let center = points[active[slot]];
+
+        let mut placed = false;
+        for _ in 0..ATTEMPTS {
+            // A candidate in the annulus between radius and 2 * radius.
+            let angle = rng.rand::<f32>() * std::f32::consts::TAU;
+            let distance = radius * (1.0 + rng.rand::<f32>());
+            let candidate = PointF::new(
+                center.x + angle.cos() * distance,
+                center.y + angle.sin() * distance,
+            );
+            if candidate.x < area.x1 as f32
+                || candidate.y < area.y1 as f32
+                || candidate.x >= area.x2 as f32
+                || candidate.y >= area.y2 as f32
+            {
+                continue;
+            }
+
+            let col = ((candidate.x - area.x1 as f32) / cell_size) as i32;
+            let row = ((candidate.y - area.y1 as f32) / cell_size) as i32;
+            let mut too_close = false;
+            'neighbors: for gy in (row - 2).max(0)..=(row + 2).min(rows as i32 - 1) {
+                for gx in (col - 2).max(0)..=(col + 2).min(cols as i32 - 1) {
+                    if let Some(index) = grid[gy as usize * cols + gx as usize] {
+                        let delta = points[index] - candidate;
+                        if delta.mag() < radius {
+                            too_close = true;
+                            break 'neighbors;
+                        }
+                    }
+                }
+            }
+            if too_close {
+                continue;
+            }
+
+            let index = points.len();
+            grid[cell_of(candidate)] = Some(index);
+            points.push(candidate);
+            active.push(index);
+            placed = true;
+            break;
+        }
+
+        if !placed {
+            active.swap_remove(slot);
+        }
+    }
+
+    points
+}
+
+/// As [`poisson_disk_sample`], but truncates the samples onto integer tile
+/// coordinates. With a radius below about 1.5 tiles, distinct samples can
+/// truncate onto the same tile; duplicates are removed, keeping the first.
+pub fn poisson_disk_sample_points(
+    area: Rect,
+    radius: f32,
+    rng: &mut RandomNumberGenerator,
+) -> Vec<Point> {
+    let mut points: Vec<Point> = poisson_disk_sample(area, radius, rng)
+        .into_iter()
+        .map(|point| Point::new(point.x as i32, point.y as i32))
+        .collect();
+    let mut seen = std::collections::HashSet::new();
+    points.retain(|point| seen.insert(*point));
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{poisson_disk_sample, poisson_disk_sample_points};
+    use crate::prelude::RandomNumberGenerator;
+    use bracket_geometry::prelude::Rect;
+
+    #[test]
+    fn samples_respect_bounds_and_spacing() {
+        let area = Rect::with_size(10, 20, 60, 40);
+        let mut rng = RandomNumberGenerator::seeded(31);
+        let points = poisson_disk_sample(area, 4.0, &mut rng);
+        assert!(!points.is_empty());
+
+        for point in &points {
+            assert!(point.x >= 10.0 && point.x < 70.0);
+            assert!(point.y >= 20.0 && point.y < 60.0);
+        }
+        for (i, a) in points.iter().enumerate() {
+            for b in points.iter().skip(i + 1) {
+                assert!((*a - *b).mag() >= 4.0);
+            }
+        }
+    }
+
+    #[test]
+    fn sampling_is_deterministic_per_seed() {
+        let area = Rect::with_size(0, 0, 50, 50);
+        let mut first_rng = RandomNumberGenerator::seeded(32);
+        let mut second_rng = RandomNumberGenerator::seeded(32);
+        let first = poisson_disk_sample(area, 3.0, &mut first_rng);
+        let second = poisson_disk_sample(area, 3.0, &mut second_rng);
+        assert_eq!(first.len(), second.len());
+        for (a, b) in first.iter().zip(second.iter()) {
+            assert_eq!((a.x, a.y), (b.x, b.y));
+        }
+    }
+
+    #[test]
+    fn tile_samples_are_unique() {
+        let area = Rect::with_size(0, 0, 30, 30);
+        let mut rng = RandomNumberGenerator::seeded(33);
+        let points = poisson_disk_sample_points(area, 1.2, &mut rng);
+        let mut deduped = points.clone();
+        deduped.sort_by_key(|point| (point.x, point.y));
+        deduped.dedup();
+        assert_eq!(points.len(), deduped.len());
+        assert!(points.iter().all(|point| area.point_in_rect(*point)));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::prelude::RandomNumberGenerator;
+use rand::Rng;
+
+#[cfg(feature = "serde")]
+use serde_crate::{Deserialize, Serialize};
+
+/// A weighted random table, for loot drops, encounters and similar
+/// data-driven picks. Entries carry integer or floating-point weights; rolls
+/// use the alias method, so sampling stays O(1) however large the table grows.
+/// Rebuilding the alias table is O(n) and happens on every mutation - build
+/// large tables with [`WeightedTable::from_entries`] rather than repeated
+/// [`WeightedTable::add`] calls.
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+#[derive(Clone, Debug)]
+pub struct WeightedTable<T> {
+    entries: Vec<(T, f32)>,
+    alias: Vec<usize>,
+    probability: Vec<f32>,
+    total_weight: f32,
+}
+
+impl<T> Default for WeightedTable<T> {
+    fn default() -> Self {
+        WeightedTable::new()
+    }
+}
+
+impl<T> WeightedTable<T> {
+    /// Creates an empty table
+    pub fn new() -> Self {
+        WeightedTable {
+            entries: Vec::new(),
+            alias: Vec::new(),
+            probability: Vec::new(),
+            total_weight: 0.0,
+        }
+    }
+
+    /// Creates a table from `(item, weight)` pairs in one pass. Entries with a
+    /// zero or negative weight never come up.
+    pub fn from_entries<W: Into<f64>>(entries: Vec<(T, W)>) -> Self {
+        let mut table = WeightedTable::new();
+        table.entries = entries
+            .into_iter()
+            .map(|(item, weight)| (item, weight.into() as f32))
+            .collect();
+        table.rebuild();
+        table
+    }
+
+    /// Adds an entry with the given weight, rebuilding the sampling table
+    pub fn add<W: Into<f64>>(&mut self, item: T, weight: W) {
+        self.entries.push((item, weight.into() as f32));
+        self.rebuild();
+    }
+
+    /// The number of entries in the table
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// True if the table has no entries
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Picks one entry at random, weighted; `None` if the table is empty or
+    /// all weights are zero
+    pub fn roll<'a>(&'a self, rng: &mut RandomNumberGenerator) -> Option<&'a T> {
+        if self.entries.is_empty() || self.total_weight <= 0.0 {
+            return None;
+        }
+        let column = rng.get_rng().gen_range(0..self.entries.len());
+        let index = if rng.rand::<f32>() < self.probability[column] {
+            column
+        } else {
+            self.alias[column]
+        };
+        Some(&self.entries[index].0)
+    }
+
+    /// Picks up to `n` distinct entries, weighted, without replacement - each
+    /// entry can come up at most once. Returns fewer than `n` if the table
+    /// runs out of (positively weighted) entries first.
+    pub fn roll_without_replacement<'a>(
+        &'a self,
+        rng: &mut RandomNumberGenerator,
+        n: usize,
+    ) -> Vec<&'a T> {
+        let mut remaining: Vec<usize> = (0..self.entries.len())
+            .filter(|&i| self.entries[i].1 > 0.0)
+            .collect();
+        let mut picks = Vec::with_capacity(n.min(remaining.len()));
+        while picks.len() < n && !remaining.is_empty() {
+            let total: f32 = remaining.iter().map(|&i| self.entries[i].1).sum();
+            let mut target = rng.rand::<f32>() * total;
+            let mut chosen = remaining.len() - 1;
+            for (slot, &i) in remaining.iter().enumerate() {
+                target -= self.entries[i].1;
+                if target <= 0.0 {
+                    chosen = slot;
+                    break;
+                }
+            }
+            picks.push(&self.entries[remaining.swap_remove(chosen)].0);
+        }
+        picks
+    }
+
+    // Rebuilds the alias/probability columns (Vose's alias method) after a
+    // change to the entries.
+    fn rebuild(&mut self) {
+        let n = self.entries.len();
+        self.alias = vec![0; n];
+        self.probability = vec![1.0; n];
+        self.total_weight = self.entries.iter().map(|(_, weight)| weight.max(0.0)).sum();
+        if n == 0 || self.total_weight <= 0.0 {
+            return;
+        }
+
+        let scale = n as f32 / self.total_weight;
+        let mut scaled: Vec<f32> = self
+            .entries
+            .iter()
+            .map(|(_, weight)| weight.max(0.0) * scale)
+            .collect();
+        let mut small: Vec<usize> = (0..n).filter(|&i| scaled[i] < 1.0).collect();
+        let mut large: Vec<usize> = (0..n).filter(|&i| scaled[i] >= 1.0).collect();
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            self.probability[less] = scaled[less];
+            self.alias[less] = more;
+            scaled[more] = (scaled[more] + scaled[less]) - 1.0;
+            if scaled[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+        // Anything left in either stack sits at probability 1.0 (set above),
+        // absorbing the floating-point residue.
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::{RandomNumberGenerator, WeightedTable};
+
+    #[test]
+    fn empty_table_rolls_nothing() {
+        let mut rng = RandomNumberGenerator::new();
+        let table: WeightedTable<i32> = WeightedTable::new();
+        assert!(table.roll(&mut rng).is_none());
+        assert!(table.roll_without_replacement(&mut rng, 3).is_empty());
+    }
+
+    #[test]
+    fn weights_bias_the_rolls() {
+        let table = WeightedTable::from_entries(vec![("common", 90), ("rare", 10)]);
+        let mut rng = RandomNumberGenerator::seeded(12345);
+        let mut commons = 0;
+        for _ in 0..10_000 {
+            if *table.roll(&mut rng).unwrap() == "common" {
+                commons += 1;
+            }
+        }
+        // 90% weight should dominate; leave wide statistical slack.
+        assert!(commons > 8_000);
+    }
+
+    #[test]
+    fn zero_weights_never_come_up() {
+        let mut table = WeightedTable::new();
+        table.add("always", 1.0);
+        table.add("never", 0.0);
+        let mut rng = RandomNumberGenerator::seeded(1);
+        for _ in 0..1_000 {
+            assert_eq!(*table.roll(&mut rng).unwrap(), "always");
+        }
+    }
+
+    #[test]
+    fn without_replacement_rolls_are_distinct() {
+        let table = WeightedTable::from_entries(vec![(1, 5), (2, 3), (3, 2), (4, 1)]);
+        let mut rng = RandomNumberGenerator::seeded(7);
+        for _ in 0..100 {
+            let mut picks: Vec<i32> = table
+                .roll_without_replacement(&mut rng, 3)
+                .into_iter()
+                .copied()
+                .collect();
+            assert_eq!(picks.len(), 3);
+            picks.sort_unstable();
+            picks.dedup();
+            assert_eq!(picks.len(), 3);
+        }
+        // Asking for more than the table holds returns everything once.
+        assert_eq!(table.roll_without_replacement(&mut rng, 10).len(), 4);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_weighted_table() {
+        let table = WeightedTable::from_entries(vec![(1, 3), (2, 1)]);
+        let serialized = serde_json::to_string(&table).unwrap();
+        let deserialized: WeightedTable<i32> = serde_json::from_str(&serialized).unwrap();
+        let mut rng = RandomNumberGenerator::seeded(5);
+        assert!(deserialized.roll(&mut rng).is_some());
+        assert_eq!(deserialized.len(), 2);
+    }
+}
@@ -0,0 +1,56 @@
+// Demonstrates the `bracket-egui` feature: an egui window floating over a
+// bracket console. Build your UI from `tick` through `ctx.egui_ctx()`; egui
+// claims clicks and keystrokes aimed at its widgets, everything else still
+// reaches the game.
+//
+// Run with: cargo run --example egui --features bracket-egui
+use bracket_terminal::prelude::*;
+
+struct State {
+    name: String,
+    bounce: bool,
+}
+
+impl GameState for State {
+    fn tick(&mut self, ctx: &mut BTerm) {
+        ctx.cls();
+        let greeting = if self.name.is_empty() {
+            "Hello Bracket World".to_string()
+        } else {
+            format!("Hello {}", self.name)
+        };
+        let y = if self.bounce {
+            25 + (ctx.frame_time_ms as i32 % 5)
+        } else {
+            25
+        };
+        ctx.print_centered(y, &greeting);
+
+        if let Some(egui) = ctx.egui_ctx() {
+            egui::Window::new("Debug Tools").show(&egui, |ui| {
+                ui.label("This UI is egui; the text behind it is a console.");
+                ui.horizontal(|ui| {
+                    ui.label("Name:");
+                    ui.text_edit_singleline(&mut self.name);
+                });
+                ui.checkbox(&mut self.bounce, "Bounce the greeting");
+                if ui.button("Quit").clicked() {
+                    ctx.quit();
+                }
+            });
+        }
+    }
+}
+
+fn main() -> BError {
+    let context = BTermBuilder::simple80x50()
+        .with_title("bracket-terminal + egui")
+        .build()?;
+    main_loop(
+        context,
+        State {
+            name: String::new(),
+            bounce: false,
+        },
+    )
+}
@@ -0,0 +1,158 @@
+//! AngelCode BMFont (`.fnt`, text format) import. Parses per-glyph metrics, texture
+//! pages and kerning pairs, for proportional text rendering on top of a sprite or
+//! fancy console.
+
+use crate::prelude::embedding;
+use crate::BResult;
+use std::collections::HashMap;
+
+/// Placement and advance metrics for one glyph, in pixels, straight from the `char`
+/// records of the `.fnt` file.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct BitmapGlyph {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub x_offset: i32,
+    pub y_offset: i32,
+    pub x_advance: i32,
+    pub page: usize,
+}
+
+/// A parsed BMFont: texture page filenames, glyph metrics keyed by character, and
+/// kerning adjustments keyed by character pair.
+#[derive(Clone, Debug, Default)]
+pub struct BitmapFont {
+    pub line_height: i32,
+    pub base: i32,
+    pub pages: Vec<String>,
+    pub glyphs: HashMap<char, BitmapGlyph>,
+    pub kerning: HashMap<(char, char), i32>,
+}
+
+impl BitmapFont {
+    /// Loads and parses a `.fnt` file through the resource system, so it works with
+    /// both embedded resources and the filesystem.
+    pub fn load<S: ToString>(filename: S) -> BResult<BitmapFont> {
+        let filename = filename.to_string();
+        let resource = embedding::EMBED.lock().get_resource(filename.clone());
+        let text = match resource {
+            Some(bytes) => String::from_utf8(bytes.to_vec())?,
+            None => std::fs::read_to_string(&filename)?,
+        };
+        BitmapFont::parse(&text)
+    }
+
+    /// Parses BMFont text-format data that is already in memory.
+    pub fn parse(text: &str) -> BResult<BitmapFont> {
+        let mut font = BitmapFont::default();
+        for line in text.lines() {
+            let mut parts = line.split_whitespace();
+            let tag = match parts.next() {
+                Some(tag) => tag,
+                None => continue,
+            };
+            let fields: HashMap<&str, &str> = parts
+                .filter_map(|p| {
+                    let mut kv = p.splitn(2, '=');
+                    Some((kv.next()?, kv.next()?))
+                })
+                .collect();
+            let num =
+                |key: &str| -> i32 { fields.get(key).and_then(|v| v.parse().ok()).unwrap_or(0) };
+            match tag {
+                "common" => {
+                    font.line_height = num("lineHeight");
+                    font.base = num("base");
+                }
+                "page" => {
+                    if let Some(file) = fields.get("file") {
+                        font.pages.push(file.trim_matches('"').to_string());
+                    }
+                }
+                "char" => {
+                    if let Some(ch) = char::from_u32(num("id") as u32) {
+                        font.glyphs.insert(
+                            ch,
+                            BitmapGlyph {
+                                x: num("x"),
+                                y: num("y"),
+                                width: num("width"),
+                                height: num("height"),
+                                x_offset: num("xoffset"),
+                                y_offset: num("yoffset"),
+                                x_advance: num("xadvance"),
+                                page: num("page") as usize,
+                            },
+                        );
+                    }
+                }
+                "kerning" => {
+                    if let (Some(first), Some(second)) = (
+                        char::from_u32(num("first") as u32),
+                        char::from_u32(num("second") as u32),
+                    ) {
+                        font.kerning.insert((first, second), num("amount"));
+                    }
+                }
+                _ => {}
+            }
+        }
+        if font.glyphs.is_empty() {
+            return Err("No char records found; is this a BMFont text-format file?".into());
+        }
+        Ok(font)
+    }
+
+    /// The metrics for a single character, if the font defines it.
+    pub fn glyph(&self, ch: char) -> Option<&BitmapGlyph> {
+        self.glyphs.get(&ch)
+    }
+
+    /// The kerning adjustment between a pair of characters (0 if none is defined).
+    pub fn kerning(&self, first: char, second: char) -> i32 {
+        self.kerning.get(&(first, second)).copied().unwrap_or(0)
+    }
+
+    /// Measures a string's rendered width in pixels, honoring advances and kerning.
+    pub fn text_width(&self, text: &str) -> i32 {
+        let mut width = 0;
+        let mut prev: Option<char> = None;
+        for ch in text.chars() {
+            if let Some(glyph) = self.glyph(ch) {
+                if let Some(p) = prev {
+                    width += self.kerning(p, ch);
+                }
+                width += glyph.x_advance;
+            }
+            prev = Some(ch);
+        }
+        width
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BitmapFont;
+
+    const SAMPLE: &str = r#"info face="Test" size=16
+common lineHeight=18 base=14 scaleW=256 scaleH=256 pages=1
+page id=0 file="test_0.png"
+chars count=2
+char id=65 x=0 y=0 width=10 height=14 xoffset=0 yoffset=2 xadvance=11 page=0 chnl=15
+char id=86 x=10 y=0 width=10 height=14 xoffset=0 yoffset=2 xadvance=11 page=0 chnl=15
+kernings count=1
+kerning first=65 second=86 amount=-2
+"#;
+
+    #[test]
+    fn parse_metrics_and_kerning() {
+        let font = BitmapFont::parse(SAMPLE).unwrap();
+        assert_eq!(font.line_height, 18);
+        assert_eq!(font.pages, vec!["test_0.png".to_string()]);
+        assert_eq!(font.glyph('A').unwrap().x_advance, 11);
+        assert_eq!(font.kerning('A', 'V'), -2);
+        assert_eq!(font.text_width("AV"), 20);
+    }
+}
@@ -2,9 +2,10 @@
 #[allow(unused_imports)]
 use crate::{
     prelude::{
-        init_raw, BEvent, CharacterTranslationMode, Console, FlexiConsole, Font, FontCharType,
-        GameState, InitHints, Radians, RenderSprite, Shader, SimpleConsole, SpriteConsole,
-        SpriteSheet, TextAlign, VirtualKeyCode, XpFile, XpLayer, BACKEND, INPUT,
+        init_raw, BEvent, CharacterTranslationMode, Console, DrawBatch, FlexiConsole, Font,
+        FontCharType, GameState, GlyphRun, GlyphStyle, InitHints, Radians, RenderSprite, Shader,
+        SimpleConsole, SpriteConsole, SpriteSheet, TextAlign, VirtualKeyCode, XpFile, XpLayer,
+        BACKEND, INPUT,
     },
     BResult,
 };
@@ -18,7 +19,24 @@ use std::convert::*;
 pub struct DisplayConsole {
     pub console: Box<dyn Console>,
     pub shader_index: usize,
+    /// The shader the console was registered with; `shader_index` returns here when a
+    /// per-console override is removed.
+    pub base_shader_index: usize,
     pub font_index: usize,
+    /// How this layer composites over the layers beneath it.
+    pub blend_mode: BlendMode,
+    /// An optional per-cell lighting overlay, multiplied into the console's colors at
+    /// render time.
+    pub lighting: Option<LightingOverlay>,
+    /// A render-time offset/rotation/scale for the layer.
+    pub transform: ConsoleTransform,
+    /// A color multiplied over the whole layer at render time; the alpha channel
+    /// acts as the layer's opacity. Opaque white leaves the layer unchanged.
+    pub tint: RGBA,
+    /// Whether the layer renders at all; hidden layers keep their contents.
+    pub visible: bool,
+    /// An optional camera restricting rendering to a window of the console's cells.
+    pub camera: Option<ConsoleCamera>,
 }
 
 pub struct BTermInternal {
@@ -26,6 +44,7 @@ pub struct BTermInternal {
     pub shaders: Vec<Shader>,
     pub consoles: Vec<DisplayConsole>,
     pub sprite_sheets: Vec<SpriteSheet>,
+    pub post_shader: Option<PostProcessShader>,
 }
 
 impl BTermInternal {
@@ -35,6 +54,7 @@ impl BTermInternal {
             shaders: Vec::new(),
             consoles: Vec::new(),
             sprite_sheets: Vec::new(),
+            post_shader: None,
         }
     }
 }
@@ -46,6 +66,7 @@ impl Default for BTermInternal {
             shaders: Vec::new(),
             consoles: Vec::new(),
             sprite_sheets: Vec::new(),
+            post_shader: None,
         }
     }
 }
@@ -53,10 +74,159 @@ impl Default for BTermInternal {
 unsafe impl Send for BTermInternal {}
 unsafe impl Sync for BTermInternal {}
 
+/// A per-cell lighting overlay for a console layer. The game fills it with light colors,
+/// directly or by adding point lights with falloff, and the renderer multiplies them
+/// into the console's colors, so torchlight does not require rewriting every `set` call.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LightingOverlay {
+    pub width: u32,
+    pub height: u32,
+    /// One light color per cell, row-major from the top-left, multiplied into the cell's
+    /// foreground and background at render time.
+    pub cells: Vec<bracket_color::prelude::RGB>,
+}
+
+impl LightingOverlay {
+    /// Creates an overlay of the specified size, fully lit (white).
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![
+                bracket_color::prelude::RGB::from_f32(1.0, 1.0, 1.0);
+                (width * height) as usize
+            ],
+        }
+    }
+
+    /// Resets every cell to the specified ambient light level.
+    pub fn clear(&mut self, ambient: bracket_color::prelude::RGB) {
+        self.cells.iter_mut().for_each(|c| *c = ambient);
+    }
+
+    /// Sets the light color of a single cell.
+    pub fn set_light(&mut self, x: i32, y: i32, color: bracket_color::prelude::RGB) {
+        if x >= 0 && y >= 0 && (x as u32) < self.width && (y as u32) < self.height {
+            self.cells[(y as u32 * self.width + x as u32) as usize] = color;
+        }
+    }
+
+    /// Adds a point light at `center`, with linear falloff out to `radius` (in cells).
+    /// Light is additive, so overlapping torches brighten each other.
+    pub fn add_point_light(
+        &mut self,
+        center: Point,
+        radius: f32,
+        color: bracket_color::prelude::RGB,
+    ) {
+        for y in 0..self.height as i32 {
+            for x in 0..self.width as i32 {
+                let distance = bracket_geometry::prelude::DistanceAlg::Pythagoras
+                    .distance2d(center, Point::new(x, y));
+                if distance < radius {
+                    let intensity = 1.0 - (distance / radius);
+                    let cell = &mut self.cells[(y as u32 * self.width + x as u32) as usize];
+                    *cell = *cell + (color * intensity);
+                }
+            }
+        }
+    }
+
+    /// The light color for a tile-array index, converting from the console's
+    /// bottom-up row order to the overlay's top-down one.
+    pub(crate) fn light_for_tile_index(&self, idx: usize) -> bracket_color::prelude::RGB {
+        let row = idx as u32 / self.width;
+        let col = idx as u32 % self.width;
+        let y = self.height - 1 - row;
+        self.cells[(y * self.width + col) as usize]
+    }
+}
+
+/// A camera/viewport over an oversized console. Only the `view_size` window of cells,
+/// starting at `origin`, is rendered - stretched across the console's screen area - so a
+/// large logical map console can scroll without copying cells every frame.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConsoleCamera {
+    /// The top-left visible cell, in console cells.
+    pub origin: (u32, u32),
+    /// The size of the visible window, in cells.
+    pub view_size: (u32, u32),
+    /// A smooth-scrolling offset in fractional cells; positive values scroll the view
+    /// right and down.
+    pub offset: (f32, f32),
+}
+
+/// A render-time transform for a console layer: pixel offset, rotation about the screen
+/// center, and scale. Applied in the vertex stage, so screen-shake and recoil effects do
+/// not require re-printing anything at shifted coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConsoleTransform {
+    /// Offset in physical pixels; positive y moves the layer down the screen.
+    pub offset_pixels: (f32, f32),
+    /// Rotation about the screen center, in radians.
+    pub rotation_radians: f32,
+    /// Uniform scale about the screen center.
+    pub scale: f32,
+}
+
+impl Default for ConsoleTransform {
+    fn default() -> Self {
+        Self {
+            offset_pixels: (0.0, 0.0),
+            rotation_radians: 0.0,
+            scale: 1.0,
+        }
+    }
+}
+
+/// How a console layer is composited over the layers beneath it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BlendMode {
+    /// Standard alpha blending (the default).
+    Alpha,
+    /// Adds the layer's color to what is underneath - good for glows and lighting.
+    Additive,
+    /// Multiplies the underlying color by the layer's - good for shadows and tinting.
+    Multiply,
+    /// Inverted multiply, brightening what is underneath.
+    Screen,
+}
+
+/// A uniform value for a custom post-processing shader.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PostShaderUniform {
+    Float(f32),
+    Vec3(f32, f32, f32),
+    Bool(bool),
+}
+
+/// A custom post-processing shader, applied to the backing-buffer pass in place of the
+/// built-in scanlines effect. Registered with `BTerm::set_post_shader`.
+pub struct PostProcessShader {
+    pub shader: Shader,
+    pub uniforms: Vec<(String, PostShaderUniform)>,
+}
+
 lazy_static! {
     pub static ref BACKEND_INTERNAL: Mutex<BTermInternal> = Mutex::new(BTermInternal::new());
 }
 
+/// Mouse cursor appearance, set at runtime with `BTerm::set_cursor_icon`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CursorIcon {
+    Default,
+    Crosshair,
+    Hand,
+    Text,
+    Wait,
+    Help,
+    NotAllowed,
+    Move,
+    /// A custom cursor image, given as a URL or data URI. Honored by the wasm
+    /// back-end via canvas CSS; native back-ends fall back to the default cursor.
+    Custom(String),
+}
+
 /// A BTerm context.
 #[derive(Clone, Debug)]
 pub struct BTerm {
@@ -77,7 +247,50 @@ pub struct BTerm {
     pub quitting: bool,
     pub post_scanlines: bool,
     pub post_screenburn: bool,
+    /// When a fixed time-step is configured, the fraction (0..1) of the current fixed step
+    /// that had elapsed when this frame rendered. Use it to interpolate between the last
+    /// two simulation states for smooth rendering.
+    pub interpolation_alpha: f32,
+    /// Simulation updates (`fixed_tick` calls) per second, when a fixed time-step is
+    /// configured. The render rate is still reported through `fps`.
+    pub ups: f32,
+    /// The configured simulation step length in milliseconds, 0.0 when not using a fixed
+    /// time-step. The render frame time is still reported through `frame_time_ms`.
+    pub update_time_ms: f32,
     pub screen_burn_color: bracket_color::prelude::RGB,
+    /// Tunable CRT post-process parameters, applied when scanlines are active.
+    pub crt_params: CrtParams,
+    /// When set, the whole frame is run through a colorblindness simulation
+    /// as a post-process - for previewing accessibility, or shipping a
+    /// colorblind-friendly mode. `None` renders normally.
+    pub colorblind_mode: Option<bracket_color::prelude::ColorblindMode>,
+}
+
+/// Tunable parameters for the CRT (scanline) post-process pass. The defaults
+/// reproduce the classic `with_post_scanlines` look; adjust per-game with
+/// `BTerm::set_crt_params`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CrtParams {
+    /// How strongly alternate scan lines are darkened. 0.0 disables the lines,
+    /// 0.25 is the classic look.
+    pub scanline_intensity: f32,
+    /// Strength of the aperture-grille phosphor mask (0.0 disables it).
+    pub mask_intensity: f32,
+    /// Barrel-distortion amount simulating a curved tube (0.0 is flat).
+    pub curvature: f32,
+    /// How strongly the corners darken (0.0 disables the vignette).
+    pub vignette: f32,
+}
+
+impl Default for CrtParams {
+    fn default() -> Self {
+        Self {
+            scanline_intensity: 0.25,
+            mask_intensity: 0.0,
+            curvature: 0.0,
+            vignette: 0.0,
+        }
+    }
 }
 
 impl BTerm {
@@ -147,13 +360,38 @@ impl BTerm {
         context
     }
 
-    /// Registers a font, and returns its handle number. Do not use after initialization!
-    pub(crate) fn register_font(&mut self, font: Font) -> BResult<usize> {
+    /// Registers a font, and returns its handle number. When called during
+    /// initialization the texture is uploaded with the rest of the GL resources; when
+    /// called later (e.g. from an options menu), it is uploaded on the spot, so fonts
+    /// can be registered at runtime.
+    pub fn register_font(&mut self, font: Font) -> BResult<usize> {
+        #[cfg(feature = "opengl")]
+        let font = {
+            let mut font = font;
+            let be = crate::hal::BACKEND.lock();
+            if let Some(gl) = be.gl.as_ref() {
+                font.setup_gl_texture(gl)?;
+            }
+            font
+        };
         let mut bi = BACKEND_INTERNAL.lock();
         bi.fonts.push(font);
         Ok(bi.fonts.len() - 1)
     }
 
+    /// Switches a console layer to a different registered font, taking effect on the
+    /// next frame. The font must use the same glyph layout conventions, but may be a
+    /// different size - handy for letting players change tilesets from a menu. Does
+    /// nothing if the layer or font index is out of range.
+    pub fn set_console_font(&mut self, console: usize, font_index: usize) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        if console < bi.consoles.len() && font_index < bi.fonts.len() {
+            let cons = &mut bi.consoles[console];
+            cons.font_index = font_index;
+            force_rebuild(&mut cons.console);
+        }
+    }
+
     /// Registers a new console terminal for output, and returns its handle number.
     pub fn register_console(&mut self, new_console: Box<dyn Console>, font_index: usize) -> usize {
         let mut bi = BACKEND_INTERNAL.lock();
@@ -161,10 +399,162 @@ impl BTerm {
             console: new_console,
             font_index,
             shader_index: 0,
+            base_shader_index: 0,
+            blend_mode: BlendMode::Alpha,
+            visible: true,
+            lighting: None,
+            transform: ConsoleTransform::default(),
+            tint: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+            camera: None,
         });
         bi.consoles.len() - 1
     }
 
+    /// Adds a console layer at runtime, returning its handle number. The rendering
+    /// backing is created on the next frame, so tools can open extra views after
+    /// initialization.
+    pub fn add_console(&mut self, new_console: Box<dyn Console>, font_index: usize) -> usize {
+        self.register_console(new_console, font_index)
+    }
+
+    /// Removes a console layer at runtime. Higher layers shift down by one, so any
+    /// stored layer handles (including DrawBatch targets) above the removed layer must
+    /// be decremented by the caller. The active console is clamped back into range.
+    /// Does nothing if the layer is out of range.
+    pub fn remove_console(&mut self, layer: usize) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        if layer >= bi.consoles.len() {
+            return;
+        }
+        bi.consoles.remove(layer);
+        #[cfg(any(feature = "opengl", feature = "webgpu"))]
+        {
+            let mut backing = crate::hal::CONSOLE_BACKING.lock();
+            if layer < backing.len() {
+                backing.remove(layer);
+            }
+        }
+        if self.active_console >= bi.consoles.len() {
+            self.active_console = bi.consoles.len().saturating_sub(1);
+        }
+    }
+
+    /// Shows or hides a console layer. Hidden layers are skipped by the renderer but
+    /// keep their contents, so overlays can be toggled cheaply. Does nothing if the
+    /// layer is out of range.
+    pub fn set_console_visible(&mut self, layer: usize, visible: bool) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        if layer < bi.consoles.len() {
+            bi.consoles[layer].visible = visible;
+        }
+    }
+
+    /// Whether a console layer is currently visible. Out-of-range layers report false.
+    pub fn is_console_visible(&self, layer: usize) -> bool {
+        let bi = BACKEND_INTERNAL.lock();
+        bi.consoles.get(layer).map_or(false, |c| c.visible)
+    }
+
+    /// Captures a console layer's cell buffer as a serializable snapshot (for save
+    /// thumbnails or replays). Returns None for out-of-range layers and console
+    /// types without snapshot support.
+    #[cfg(feature = "serde")]
+    pub fn snapshot_console(&self, layer: usize) -> Option<crate::prelude::ConsoleSnapshot> {
+        let bi = BACKEND_INTERNAL.lock();
+        bi.consoles
+            .get(layer)
+            .and_then(|c| crate::prelude::ConsoleSnapshot::capture(c.console.as_ref()))
+    }
+
+    /// Restores a previously captured snapshot onto a console layer. Returns false
+    /// if the layer is out of range or its console type does not match the
+    /// snapshot.
+    #[cfg(feature = "serde")]
+    pub fn restore_console(
+        &mut self,
+        layer: usize,
+        snapshot: &crate::prelude::ConsoleSnapshot,
+    ) -> bool {
+        let mut bi = BACKEND_INTERNAL.lock();
+        match bi.consoles.get_mut(layer) {
+            Some(c) => snapshot.restore(c.console.as_mut()),
+            None => false,
+        }
+    }
+
+    /// Binds a callback to a developer-console command. The callback receives the
+    /// whitespace-split arguments and may return a line of output:
+    ///
+    /// ```ignore
+    /// bterm.register_command("spawn", Box::new(|args| {
+    ///     Some(format!("Spawning {}", args.join(" ")))
+    /// }));
+    /// ```
+    pub fn register_command<S: ToString>(
+        &mut self,
+        name: S,
+        callback: crate::debug_console::CommandCallback,
+    ) {
+        crate::debug_console::DEBUG_CONSOLE
+            .lock()
+            .register_command(name, callback);
+    }
+
+    /// Opens the developer console if it is closed, and vice versa. The backquote
+    /// key does the same through `debug_console_event`.
+    pub fn toggle_debug_console(&mut self) {
+        let mut console = crate::debug_console::DEBUG_CONSOLE.lock();
+        console.open = !console.open;
+    }
+
+    /// Whether the developer console is currently open (and capturing the keyboard).
+    pub fn debug_console_open(&self) -> bool {
+        crate::debug_console::DEBUG_CONSOLE.lock().open
+    }
+
+    /// Feeds an input event to the developer console. While the console is open it
+    /// consumes all keyboard input (returning true); while it is closed only the
+    /// backquote toggle is consumed, so feed every event through this and skip your
+    /// own handling when it returns true.
+    pub fn debug_console_event(&mut self, event: &BEvent) -> bool {
+        crate::debug_console::DEBUG_CONSOLE
+            .lock()
+            .handle_event(event)
+    }
+
+    /// Draws the developer console overlay into `region` on the batch's current
+    /// target. Does nothing while the console is closed, so it is safe to call
+    /// unconditionally at the end of each tick.
+    pub fn render_debug_console(&mut self, batch: &mut DrawBatch, region: Rect) {
+        crate::debug_console::DEBUG_CONSOLE
+            .lock()
+            .render(batch, region);
+    }
+
+    /// Shows or hides the built-in performance overlay: FPS, per-phase timing
+    /// bars, a frame-time graph, and console rebuild/draw counts, drawn onto the
+    /// topmost console each frame.
+    pub fn show_perf_overlay(&mut self, show: bool) {
+        crate::perf_overlay::set_enabled(show);
+    }
+
+    /// Resizes a console layer's character grid at runtime, preserving the overlapping
+    /// region of its contents and reallocating the rendering backing on the next frame.
+    /// Emits `BEvent::ConsoleResized` so the game can re-lay out its UI. Does nothing
+    /// if the layer is out of range.
+    pub fn set_console_size(&mut self, layer: usize, width: u32, height: u32) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        if layer >= bi.consoles.len() {
+            return;
+        }
+        bi.consoles[layer].console.set_char_size(width, height);
+        mark_dirty(&mut bi.consoles[layer].console);
+        INPUT.lock().push_event(BEvent::ConsoleResized {
+            layer,
+            new_size: Point::new(width as i32, height as i32),
+        });
+    }
+
     /// Registers a new console terminal for output, and returns its handle number. This variant requests
     /// that the new console not render background colors, so it can be layered on top of other consoles.
     pub fn register_console_no_bg(
@@ -177,6 +567,13 @@ impl BTerm {
             console: new_console,
             font_index,
             shader_index: 1,
+            base_shader_index: 1,
+            blend_mode: BlendMode::Alpha,
+            visible: true,
+            lighting: None,
+            transform: ConsoleTransform::default(),
+            tint: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+            camera: None,
         });
         bi.consoles.len() - 1
     }
@@ -193,6 +590,13 @@ impl BTerm {
             console: new_console,
             font_index,
             shader_index: 4,
+            base_shader_index: 4,
+            blend_mode: BlendMode::Alpha,
+            visible: true,
+            lighting: None,
+            transform: ConsoleTransform::default(),
+            tint: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+            camera: None,
         });
         bi.consoles.len() - 1
     }
@@ -204,6 +608,13 @@ impl BTerm {
             console: new_console,
             font_index: 0,
             shader_index: 5,
+            base_shader_index: 5,
+            blend_mode: BlendMode::Alpha,
+            visible: true,
+            lighting: None,
+            transform: ConsoleTransform::default(),
+            tint: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+            camera: None,
         });
         bi.consoles.len() - 1
     }
@@ -221,6 +632,17 @@ impl BTerm {
         }
     }
 
+    /// Sets the currently active console number if it is in range, returning
+    /// whether it was. The non-panicking variant of `set_active_console`.
+    pub fn set_active_console_checked(&mut self, id: usize) -> bool {
+        if id < BACKEND_INTERNAL.lock().consoles.len() {
+            self.active_console = id;
+            true
+        } else {
+            false
+        }
+    }
+
     /// Applies the current physical mouse position to the active console, and translates the coordinates into that console's coordinate space.
     #[cfg(feature = "curses")]
     pub fn mouse_pos(&self) -> (i32, i32) {
@@ -228,12 +650,12 @@ impl BTerm {
     }
 
     ///
-    #[cfg(feature = "curses")]
+    #[cfg(any(feature = "curses", feature = "cross_term"))]
     fn pixel_to_char_pos(&self, pos: (i32, i32), _console: &Box<dyn Console>) -> (i32, i32) {
         pos
     }
 
-    #[cfg(not(feature = "curses"))]
+    #[cfg(not(any(feature = "curses", feature = "cross_term")))]
     fn pixel_to_char_pos(&self, pos: (i32, i32), console: &Box<dyn Console>) -> (i32, i32) {
         let max_sizes = console.get_char_size();
         let (scale, center_x, center_y) = console.get_scale();
@@ -249,15 +671,16 @@ impl BTerm {
             center_y as f32 * font_size.1 * (scale - 1.0),
         );
 
-        let w : f32;
-        let h : f32;
+        let w: f32;
+        let h: f32;
 
         {
             let be = crate::hal::BACKEND.lock();
             w = be.screen_scaler.available_width as f32;
             h = be.screen_scaler.available_height as f32;
-            offsets.0 -= be.screen_scaler.gutter_left as f32;
-            offsets.1 -= be.screen_scaler.gutter_top as f32;
+            offsets.0 -=
+                be.screen_scaler.gutter_left as f32 + be.screen_scaler.mode_offset.0 as f32;
+            offsets.1 -= be.screen_scaler.gutter_top as f32 + be.screen_scaler.mode_offset.1 as f32;
         }
 
         let extent_x = (pos.0 as f32 + offsets.0) / w;
@@ -329,6 +752,19 @@ impl BTerm {
         self.screen_burn_color = color;
     }
 
+    /// Adjusts the CRT post-process: scanline intensity, aperture-grille mask,
+    /// curvature and vignette. Takes effect while scanlines are active
+    /// (`with_post_scanlines`).
+    pub fn set_crt_params(&mut self, params: CrtParams) {
+        self.crt_params = params;
+    }
+
+    /// Enable (or with `None`, disable) whole-frame colorblindness
+    /// simulation. Applies on the GPU backends; terminal backends ignore it.
+    pub fn with_colorblind_mode(&mut self, mode: Option<bracket_color::prelude::ColorblindMode>) {
+        self.colorblind_mode = mode;
+    }
+
     /// Internal: mark a key press
     pub(crate) fn on_key(&mut self, key: VirtualKeyCode, scan_code: u32, pressed: bool) {
         let mut input = INPUT.lock();
@@ -363,6 +799,47 @@ impl BTerm {
         });
     }
 
+    /// Internal: mark a touch contact starting. Touches drive the mouse position, and
+    /// (unless disabled in `INPUT`) are also reported as left mouse clicks.
+    pub(crate) fn on_touch_start(&mut self, x: f64, y: f64) {
+        self.on_mouse_position(x, y);
+        let tile = self.mouse_point();
+        self.on_event(BEvent::TouchStarted {
+            pixel: Point::new(x as i32, y as i32),
+            tile,
+        });
+        if INPUT.lock().touch_emulates_mouse {
+            self.on_mouse_button(0, true);
+        }
+    }
+
+    /// Internal: mark a touch contact moving.
+    pub(crate) fn on_touch_move(&mut self, x: f64, y: f64) {
+        self.on_mouse_position(x, y);
+        let tile = self.mouse_point();
+        self.on_event(BEvent::TouchMoved {
+            pixel: Point::new(x as i32, y as i32),
+            tile,
+        });
+    }
+
+    /// Internal: mark a touch contact ending.
+    pub(crate) fn on_touch_end(&mut self, x: f64, y: f64) {
+        let tile = self.mouse_point();
+        self.on_event(BEvent::TouchEnded {
+            pixel: Point::new(x as i32, y as i32),
+            tile,
+        });
+        if INPUT.lock().touch_emulates_mouse {
+            self.on_mouse_button(0, false);
+        }
+    }
+
+    /// Internal: mark mouse wheel movement
+    pub(crate) fn on_mouse_wheel(&mut self, x: f32, y: f32) {
+        INPUT.lock().on_mouse_wheel(x, y);
+    }
+
     /// Internal: mark mouse position changes
     pub(crate) fn on_mouse_position(&mut self, x: f64, y: f64) {
         let bi = BACKEND_INTERNAL.lock();
@@ -533,6 +1010,30 @@ impl BTerm {
         }
     }
 
+    /// Sets the z-order assigned to subsequent plain `Console` drawing calls
+    /// (`print`, `set`, etc.) on the active console. Fancy console tiles are
+    /// sorted by z before rendering, lowest first, so this lets text and
+    /// effects layer deterministically against `set_fancy` glyphs. Ignored by
+    /// other console types.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn set_fancy_z_order(&mut self, z_order: i32) {
+        let mut be = BACKEND_INTERNAL.lock();
+        let cons_any = be.consoles[self.active_console].console.as_any_mut();
+        if let Some(fc) = cons_any.downcast_mut::<FlexiConsole>() {
+            fc.default_z_order = z_order;
+        }
+    }
+
+    /// Sets the z-order assigned to subsequent plain `Console` drawing calls
+    /// (`print`, `set`, etc.) on the active console. Fancy console tiles are
+    /// sorted by z before rendering, lowest first, so this lets text and
+    /// effects layer deterministically against `set_fancy` glyphs. Ignored by
+    /// other console types.
+    #[cfg(not(any(feature = "opengl", feature = "webgpu")))]
+    pub fn set_fancy_z_order(&mut self, _z_order: i32) {
+        // Does nothing
+    }
+
     /// Set a tile with "fancy" additional attributes
     #[cfg(not(any(feature = "opengl", feature = "webgpu")))]
     pub fn set_fancy<COLOR, COLOR2, GLYPH, ANGLE>(
@@ -569,6 +1070,149 @@ impl BTerm {
             );
     }
 
+    /// Marks (or unmarks) a cell on the active console as blinking. Blinking cells
+    /// flash at the rate set with `set_blink_rate`. Simple and sparse consoles only;
+    /// other console types ignore it.
+    pub fn set_blink<X, Y>(&mut self, x: X, y: Y, blinking: bool)
+    where
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .set_blink(
+                x.try_into().ok().expect("Must be i32 convertible"),
+                y.try_into().ok().expect("Must be i32 convertible"),
+                blinking,
+            );
+    }
+
+    /// Clears every blink mark on the active console.
+    pub fn clear_blink(&mut self) {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .clear_blink();
+    }
+
+    /// Sets the global blink rate: the time, in milliseconds, between blink phase
+    /// flips. The default of 530ms approximates the classic hardware cursor rate.
+    pub fn set_blink_rate(&mut self, interval_ms: f32) {
+        crate::consoles::BLINK.lock().interval_ms = interval_ms;
+    }
+
+    /// Registers a color-cycle group: a set of colors that rotate at the given
+    /// rate, stepping to the next color every `interval_ms` milliseconds. Returns
+    /// a handle to pass to `set_color_cycle`. Groups are global, shared by every
+    /// console layer, and last for the lifetime of the program.
+    pub fn register_color_cycle<COLOR: Into<RGBA>>(
+        &mut self,
+        colors: Vec<COLOR>,
+        interval_ms: f32,
+    ) -> usize {
+        crate::consoles::register_color_cycle(
+            colors.into_iter().map(|c| c.into()).collect(),
+            interval_ms,
+        )
+    }
+
+    /// Marks a cell on the active console as a member of a color-cycle group
+    /// (or clears the mark with `None`). The renderer replaces the cell's
+    /// foreground with the group's current color each frame, so water/lava
+    /// effects animate without re-printing. Simple and sparse consoles only;
+    /// other console types ignore it.
+    pub fn set_color_cycle<X, Y>(&mut self, x: X, y: Y, cycle: Option<usize>)
+    where
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .set_color_cycle(
+                x.try_into().ok().expect("Must be i32 convertible"),
+                y.try_into().ok().expect("Must be i32 convertible"),
+                cycle,
+            );
+    }
+
+    /// Clears every color-cycle mark on the active console.
+    pub fn clear_color_cycles(&mut self) {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .clear_color_cycles();
+    }
+
+    /// Sets (or clears, with `GlyphStyle::None`) the glyph style of a cell on the
+    /// active console. Styled cells are drawn with a 1px outline or drop shadow by
+    /// the OpenGL backends, keeping text readable over busy tiles. Simple, sparse
+    /// and fancy consoles only; other console types ignore it.
+    pub fn set_glyph_style<X, Y>(&mut self, x: X, y: Y, style: GlyphStyle)
+    where
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .set_glyph_style(
+                x.try_into().ok().expect("Must be i32 convertible"),
+                y.try_into().ok().expect("Must be i32 convertible"),
+                style,
+            );
+    }
+
+    /// Clears every glyph style mark on the active console.
+    pub fn clear_glyph_styles(&mut self) {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .clear_glyph_styles();
+    }
+
+    /// Prints a string at x/y, styling every cell of the run with the specified
+    /// glyph style.
+    pub fn print_styled<S, X, Y>(&mut self, x: X, y: Y, style: GlyphStyle, output: S)
+    where
+        S: ToString,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let text = output.to_string();
+        let mut be = BACKEND_INTERNAL.lock();
+        let console = &mut be.consoles[self.active_console].console;
+        console.print(x, y, &text);
+        for i in 0..text.chars().count() as i32 {
+            console.set_glyph_style(x + i, y, style);
+        }
+    }
+
+    /// Prints a string at x/y, with foreground and background colors, styling every
+    /// cell of the run with the specified glyph style.
+    pub fn print_color_styled<S, COLOR, COLOR2, X, Y>(
+        &mut self,
+        x: X,
+        y: Y,
+        fg: COLOR,
+        bg: COLOR2,
+        style: GlyphStyle,
+        output: S,
+    ) where
+        S: ToString,
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        let x = x.try_into().ok().expect("Must be i32 convertible");
+        let y = y.try_into().ok().expect("Must be i32 convertible");
+        let text = output.to_string();
+        let mut be = BACKEND_INTERNAL.lock();
+        let console = &mut be.consoles[self.active_console].console;
+        console.print_color(x, y, fg.into(), bg.into(), &text);
+        for i in 0..text.chars().count() as i32 {
+            console.set_glyph_style(x + i, y, style);
+        }
+    }
+
     /// Draws a filled box, with single line characters.
     pub fn draw_box<COLOR, COLOR2, X, Y, W, H>(
         &mut self,
@@ -768,6 +1412,116 @@ impl BTerm {
             .fill_region(target, glyph.try_into().ok().unwrap(), fg.into(), bg.into());
     }
 
+    /// Flood-fills the active console from a starting point, spreading through the
+    /// four cardinal neighbors of every cell whose current glyph satisfies
+    /// `predicate`, and painting each reached cell with the given glyph and colors.
+    /// Only console types that can report cell contents (simple consoles) fill.
+    pub fn flood_fill<COLOR, COLOR2, GLYPH>(
+        &mut self,
+        start: Point,
+        glyph: GLYPH,
+        fg: COLOR,
+        bg: COLOR2,
+        predicate: &dyn Fn(FontCharType) -> bool,
+    ) where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        GLYPH: TryInto<FontCharType>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .flood_fill(
+                start,
+                glyph.try_into().ok().expect("Must be u16 convertible"),
+                fg.into(),
+                bg.into(),
+                predicate,
+            );
+    }
+
+    /// Paints every cell yielded by a point iterator on the active console - pairs
+    /// with the bracket-geometry iterators (lines, circles) for procedural painting
+    /// without manual loops.
+    pub fn paint_region<I, COLOR, COLOR2, GLYPH>(
+        &mut self,
+        region: I,
+        glyph: GLYPH,
+        fg: COLOR,
+        bg: COLOR2,
+    ) where
+        I: IntoIterator<Item = Point>,
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        GLYPH: TryInto<FontCharType>,
+    {
+        crate::prelude::paint_region(
+            BACKEND_INTERNAL.lock().consoles[self.active_console]
+                .console
+                .as_mut(),
+            region,
+            glyph.try_into().ok().expect("Must be u16 convertible"),
+            fg.into(),
+            bg.into(),
+        );
+    }
+
+    /// Draws a single-line wall/box glyph at x/y, selecting the CP437 junction
+    /// character from the line glyphs already in the neighboring cells and
+    /// re-joining those neighbors.
+    pub fn draw_smart_wall<COLOR, COLOR2, X, Y>(&mut self, x: X, y: Y, fg: COLOR, bg: COLOR2)
+    where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        crate::prelude::draw_smart_wall(
+            BACKEND_INTERNAL.lock().consoles[self.active_console]
+                .console
+                .as_mut(),
+            x.try_into().ok().expect("Must be i32 convertible"),
+            y.try_into().ok().expect("Must be i32 convertible"),
+            fg.into(),
+            bg.into(),
+        );
+    }
+
+    /// `draw_smart_wall`, using the CP437 double-line glyphs.
+    pub fn draw_smart_wall_double<COLOR, COLOR2, X, Y>(&mut self, x: X, y: Y, fg: COLOR, bg: COLOR2)
+    where
+        COLOR: Into<RGBA>,
+        COLOR2: Into<RGBA>,
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        crate::prelude::draw_smart_wall_double(
+            BACKEND_INTERNAL.lock().consoles[self.active_console]
+                .console
+                .as_mut(),
+            x.try_into().ok().expect("Must be i32 convertible"),
+            y.try_into().ok().expect("Must be i32 convertible"),
+            fg.into(),
+            bg.into(),
+        );
+    }
+
+    /// Prints a pre-encoded glyph run on the active console, starting at x/y.
+    /// Consoles with contiguous row storage copy the run in one slice operation,
+    /// making this much cheaper than per-cell printing for heavy text UIs.
+    pub fn print_run<X, Y>(&mut self, x: X, y: Y, run: &GlyphRun)
+    where
+        X: TryInto<i32>,
+        Y: TryInto<i32>,
+    {
+        BACKEND_INTERNAL.lock().consoles[self.active_console]
+            .console
+            .print_run(
+                x.try_into().ok().expect("Must be i32 convertible"),
+                y.try_into().ok().expect("Must be i32 convertible"),
+                run.tiles(),
+            );
+    }
+
     /// Prints centered text, centered across the whole line
     pub fn print_centered<S, Y>(&mut self, y: Y, text: S)
     where
@@ -1035,6 +1789,459 @@ impl BTerm {
         //panic!("This will be supported when `winit` stops crashing on resize request.");
     }
 
+    /// Change the window title at runtime - for example to show the current save name or
+    /// turn counter. On wasm this updates the document title instead. OpenGL only for now.
+    #[cfg(feature = "opengl")]
+    pub fn set_window_title<S: ToString>(&mut self, title: S) {
+        BACKEND.lock().request_window_title = Some(title.to_string());
+    }
+
+    /// Change the window title at runtime. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_window_title<S: ToString>(&mut self, _title: S) {
+        // Do nothing
+    }
+
+    /// Show or hide the OS mouse cursor over the window - hide it to draw a glyph
+    /// cursor in the console instead. On wasm this sets `cursor: none` on the canvas.
+    /// OpenGL only for now.
+    #[cfg(feature = "opengl")]
+    pub fn set_cursor_visible(&mut self, visible: bool) {
+        BACKEND.lock().request_cursor_visible = Some(visible);
+    }
+
+    /// Show or hide the OS mouse cursor. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_cursor_visible(&mut self, _visible: bool) {
+        // Do nothing
+    }
+
+    /// Change the mouse cursor shown over the window. `CursorIcon::Custom` takes a
+    /// URL or data URI and is honored by the wasm back-end via canvas CSS; native
+    /// back-ends fall back to the default cursor for it. OpenGL only for now.
+    #[cfg(feature = "opengl")]
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) {
+        BACKEND.lock().request_cursor_icon = Some(icon);
+    }
+
+    /// Change the mouse cursor. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_cursor_icon(&mut self, _icon: CursorIcon) {
+        // Do nothing
+    }
+
+    /// Change the window icon at runtime, from any `image` type. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_window_icon(&mut self, image: image::DynamicImage) {
+        let rgba = image.to_rgba8();
+        let (width, height) = rgba.dimensions();
+        BACKEND.lock().request_window_icon = Some((rgba.into_raw(), width, height));
+    }
+
+    /// Change the window icon at runtime. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn set_window_icon(&mut self, _image: ()) {
+        // Do nothing
+    }
+
+    /// Move the window to the specified desktop pixel position at runtime. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn set_window_position<T>(&mut self, x: T, y: T)
+    where
+        T: TryInto<i32>,
+    {
+        BACKEND.lock().request_window_position = Some((
+            x.try_into().ok().expect("Must be convertible to an i32"),
+            y.try_into().ok().expect("Must be convertible to an i32"),
+        ));
+    }
+
+    /// Move the window at runtime. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn set_window_position<T>(&mut self, _x: T, _y: T)
+    where
+        T: TryInto<i32>,
+    {
+        // Do nothing
+    }
+
+    /// Switch between fullscreen and windowed mode at runtime. The resize path re-runs
+    /// once the mode change lands, so consoles re-scale correctly. OpenGL only for now.
+    #[cfg(feature = "opengl")]
+    pub fn set_fullscreen(&mut self, fullscreen: bool) {
+        BACKEND.lock().request_fullscreen = Some(fullscreen);
+    }
+
+    /// Switch between fullscreen and windowed mode. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_fullscreen(&mut self, _fullscreen: bool) {
+        // Do nothing
+    }
+
+    /// Toggle between fullscreen and windowed mode at runtime.
+    #[cfg(feature = "opengl")]
+    pub fn toggle_fullscreen(&mut self) {
+        let mut be = BACKEND.lock();
+        let fullscreen = be.request_fullscreen.unwrap_or(be.fullscreen);
+        be.request_fullscreen = Some(!fullscreen);
+    }
+
+    /// Toggle between fullscreen and windowed mode. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn toggle_fullscreen(&mut self) {
+        // Do nothing
+    }
+
+    /// Summons (or dismisses) the device's soft keyboard by focusing a hidden
+    /// input element; typed characters arrive as `BEvent::Character`. Web only.
+    #[cfg(all(feature = "opengl", target_arch = "wasm32"))]
+    pub fn show_virtual_keyboard(&mut self, show: bool) {
+        BACKEND.lock().request_virtual_keyboard = Some(show);
+    }
+
+    /// Summons or dismisses the soft keyboard. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", target_arch = "wasm32")))]
+    pub fn show_virtual_keyboard(&mut self, _show: bool) {
+        // Do nothing
+    }
+
+    /// Shows or hides an on-screen directional pad overlay whose buttons
+    /// synthesize cursor-key presses - touch-friendly movement. Web only.
+    #[cfg(all(feature = "opengl", target_arch = "wasm32"))]
+    pub fn show_virtual_dpad(&mut self, show: bool) {
+        BACKEND.lock().request_virtual_dpad = Some(show);
+    }
+
+    /// Shows or hides the directional pad overlay. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", target_arch = "wasm32")))]
+    pub fn show_virtual_dpad(&mut self, _show: bool) {
+        // Do nothing
+    }
+
+    /// Install a custom post-processing GLSL fragment shader, applied full-screen to the
+    /// rendered frame in place of the built-in scanlines effect. The shader samples the
+    /// frame via the same interface as the scanlines shader and receives `screenSize`
+    /// automatically; `uniforms` are (name, value) pairs uploaded every frame and
+    /// adjustable later with `set_post_uniform`. OpenGL only.
+    #[cfg(feature = "opengl")]
+    pub fn set_post_shader(
+        &mut self,
+        fragment_source: &str,
+        uniforms: Vec<(String, PostShaderUniform)>,
+    ) {
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        let shader = Shader::new(
+            gl,
+            crate::hal::shader_strings::SCANLINES_VS,
+            fragment_source,
+        );
+        BACKEND_INTERNAL.lock().post_shader = Some(PostProcessShader { shader, uniforms });
+    }
+
+    /// Install a custom post-processing shader. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_post_shader(
+        &mut self,
+        _fragment_source: &str,
+        _uniforms: Vec<(String, PostShaderUniform)>,
+    ) {
+        // Do nothing
+    }
+
+    /// Attach a custom fragment shader to a single console layer - e.g. water
+    /// distortion on the map console without touching the UI. The override is compiled
+    /// against the vertex stage the console already uses and bound by the renderer in
+    /// place of the stock console shader. OpenGL only.
+    #[cfg(feature = "opengl")]
+    pub fn set_console_shader(&mut self, console: usize, fragment_source: &str) {
+        use crate::hal::shader_strings;
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        let mut bi = BACKEND_INTERNAL.lock();
+        let vertex_source = match bi.consoles[console].base_shader_index {
+            0 => shader_strings::CONSOLE_WITH_BG_VS,
+            1 => shader_strings::CONSOLE_NO_BG_VS,
+            4 => shader_strings::FANCY_CONSOLE_VS,
+            5 => shader_strings::SPRITE_CONSOLE_VS,
+            _ => shader_strings::CONSOLE_WITH_BG_VS,
+        };
+        let shader = Shader::new(gl, vertex_source, fragment_source);
+        let index = bi.shaders.len();
+        bi.shaders.push(shader);
+        bi.consoles[console].shader_index = index;
+    }
+
+    /// Attach a custom fragment shader to a console. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_console_shader(&mut self, _console: usize, _fragment_source: &str) {
+        // Do nothing
+    }
+
+    /// Remove a per-console shader override, restoring the shader the console was
+    /// registered with.
+    pub fn reset_console_shader(&mut self, console: usize) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        let base = bi.consoles[console].base_shader_index;
+        bi.consoles[console].shader_index = base;
+    }
+
+    /// Attaches a camera to a simple console layer: only a `view_width` x `view_height`
+    /// window of cells, starting at the specified origin, is rendered. Combine with
+    /// `set_camera_offset` for smooth sub-cell scrolling. An existing camera's smooth
+    /// offset is preserved.
+    pub fn set_camera(
+        &mut self,
+        console: usize,
+        origin_x: u32,
+        origin_y: u32,
+        view_width: u32,
+        view_height: u32,
+    ) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        let offset = bi.consoles[console]
+            .camera
+            .map(|cam| cam.offset)
+            .unwrap_or((0.0, 0.0));
+        bi.consoles[console].camera = Some(ConsoleCamera {
+            origin: (origin_x, origin_y),
+            view_size: (view_width, view_height),
+            offset,
+        });
+        force_rebuild(&mut bi.consoles[console].console);
+    }
+
+    /// Sets the camera's smooth-scrolling offset, in fractional cells. Positive values
+    /// scroll the view right and down. Does nothing without a camera attached.
+    pub fn set_camera_offset(&mut self, console: usize, x: f32, y: f32) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        if let Some(cam) = bi.consoles[console].camera.as_mut() {
+            cam.offset = (x, y);
+            force_rebuild(&mut bi.consoles[console].console);
+        }
+    }
+
+    /// Detaches the camera from a console layer, rendering all of its cells again.
+    pub fn clear_camera(&mut self, console: usize) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        bi.consoles[console].camera = None;
+        force_rebuild(&mut bi.consoles[console].console);
+    }
+
+    /// Sets a render-time transform for a console layer: an offset in pixels, a rotation
+    /// about the screen center in radians, and a uniform scale. Ideal for screen shake:
+    /// jitter the offset for a few frames and reset it, with no re-printing needed.
+    pub fn set_console_transform(
+        &mut self,
+        console: usize,
+        offset_x: f32,
+        offset_y: f32,
+        rotation_radians: f32,
+        scale: f32,
+    ) {
+        BACKEND_INTERNAL.lock().consoles[console].transform = ConsoleTransform {
+            offset_pixels: (offset_x, offset_y),
+            rotation_radians,
+            scale,
+        };
+    }
+
+    /// Restores a console layer's render transform to the identity.
+    pub fn reset_console_transform(&mut self, console: usize) {
+        BACKEND_INTERNAL.lock().consoles[console].transform = ConsoleTransform::default();
+    }
+
+    /// Attaches (or replaces) a per-cell lighting overlay on a console layer. The
+    /// renderer multiplies the light colors into the console's colors; honored for
+    /// simple and sparse consoles.
+    pub fn set_lighting(&mut self, console: usize, lighting: LightingOverlay) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        bi.consoles[console].lighting = Some(lighting);
+        mark_dirty(&mut bi.consoles[console].console);
+    }
+
+    /// Removes the lighting overlay from a console layer.
+    pub fn clear_lighting(&mut self, console: usize) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        bi.consoles[console].lighting = None;
+        mark_dirty(&mut bi.consoles[console].console);
+    }
+
+    /// Sets the default background alpha for a console layer, so e.g. a simple console
+    /// can be layered over a map without blanking it out. Cells keep their individual
+    /// RGBA backgrounds; the default applies when the console is cleared.
+    pub fn set_default_bg_alpha(&mut self, console: usize, alpha: f32) {
+        BACKEND_INTERNAL.lock().consoles[console]
+            .console
+            .set_default_bg_alpha(alpha)
+    }
+
+    /// Sets how a console layer composites over the layers beneath it - e.g. an additive
+    /// layer for light effects, or multiply for shadow.
+    pub fn set_console_blend_mode(&mut self, console: usize, blend_mode: BlendMode) {
+        BACKEND_INTERNAL.lock().consoles[console].blend_mode = blend_mode;
+    }
+
+    /// Sets the opacity of a console layer: 1.0 is fully opaque, 0.0 invisible.
+    /// Applied at render time, so panels can fade in and out without touching
+    /// their cells. Leaves the layer's tint color unchanged.
+    pub fn set_console_opacity(&mut self, console: usize, opacity: f32) {
+        BACKEND_INTERNAL.lock().consoles[console].tint.a = opacity;
+    }
+
+    /// Sets a color multiplied over an entire console layer at render time -
+    /// e.g. a dark gray to dim the map behind a modal dialog. The tint's alpha
+    /// is the layer's opacity.
+    pub fn set_console_tint<COLOR: Into<RGBA>>(&mut self, console: usize, tint: COLOR) {
+        BACKEND_INTERNAL.lock().consoles[console].tint = tint.into();
+    }
+
+    /// Removes any tint and opacity from a console layer, restoring it to
+    /// opaque, unmodified colors.
+    pub fn clear_console_tint(&mut self, console: usize) {
+        BACKEND_INTERNAL.lock().consoles[console].tint = RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+    }
+
+    /// Constrains final output to a palette: every framebuffer pixel is snapped to the
+    /// nearest of the registered colors (up to 64) - handy for a strict 16-color
+    /// aesthetic. Implemented as a built-in post-processing shader, so it replaces any
+    /// shader set with `set_post_shader`; call again with a new palette for day/night
+    /// switches, or `clear_output_palette` to restore normal output. OpenGL only.
+    #[cfg(feature = "opengl")]
+    pub fn set_output_palette(&mut self, palette: &[bracket_color::prelude::RGB]) {
+        let count = palette.len().min(64);
+        let mut uniforms = vec![(
+            "paletteCount".to_string(),
+            PostShaderUniform::Float(count as f32),
+        )];
+        for (i, color) in palette.iter().take(count).enumerate() {
+            uniforms.push((
+                format!("palette[{}]", i),
+                PostShaderUniform::Vec3(color.r, color.g, color.b),
+            ));
+        }
+        self.set_post_shader(crate::hal::shader_strings::PALETTE_FS, uniforms);
+    }
+
+    /// Constrain output to a palette. Not supported on this back-end.
+    #[cfg(not(feature = "opengl"))]
+    pub fn set_output_palette(&mut self, _palette: &[bracket_color::prelude::RGB]) {
+        // Do nothing
+    }
+
+    /// Removes the palette constraint set with `set_output_palette`.
+    pub fn clear_output_palette(&mut self) {
+        self.clear_post_shader();
+    }
+
+    /// Update (or add) a single uniform on the active post-processing shader. Does
+    /// nothing if no custom post shader is installed.
+    pub fn set_post_uniform<S: ToString>(&mut self, name: S, value: PostShaderUniform) {
+        if let Some(post) = BACKEND_INTERNAL.lock().post_shader.as_mut() {
+            let name = name.to_string();
+            if let Some(existing) = post.uniforms.iter_mut().find(|(n, _)| *n == name) {
+                existing.1 = value;
+            } else {
+                post.uniforms.push((name, value));
+            }
+        }
+    }
+
+    /// Remove any custom post-processing shader, restoring the built-in pipeline.
+    pub fn clear_post_shader(&mut self) {
+        BACKEND_INTERNAL.lock().post_shader = None;
+    }
+
+    /// Take a screenshot of just the specified pixel rectangle of the frame, with the
+    /// origin at the top-left of the window. Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn screenshot_region<S: ToString>(&mut self, filename: S, region: Rect) {
+        BACKEND.lock().request_screenshot_region = Some((filename.to_string(), region));
+    }
+
+    /// Take a region screenshot. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn screenshot_region<S: ToString>(&mut self, _filename: S, _region: Rect) {
+        // Do nothing
+    }
+
+    /// Take a screenshot of a single console layer - e.g. the map console without the UI
+    /// on top - by rendering it alone to an offscreen buffer. Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn screenshot_console<S: ToString>(&mut self, filename: S, console: usize) {
+        BACKEND.lock().request_screenshot_console = Some((filename.to_string(), console));
+    }
+
+    /// Take a per-console screenshot. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn screenshot_console<S: ToString>(&mut self, _filename: S, _console: usize) {
+        // Do nothing
+    }
+
+    /// Request a screenshot captured to memory rather than disk. The frame is read back
+    /// at the end of the current render pass; collect it with `get_screenshot_image` on a
+    /// later tick. Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn screenshot_to_image(&mut self) {
+        BACKEND.lock().request_screenshot_image = true;
+    }
+
+    /// Request an in-memory screenshot. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn screenshot_to_image(&mut self) {
+        // Do nothing
+    }
+
+    /// Collect a screenshot previously requested with `screenshot_to_image`. Returns
+    /// `None` until the capture has happened; the buffer is handed over exactly once.
+    /// Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn get_screenshot_image(&mut self) -> Option<image::RgbaImage> {
+        BACKEND.lock().screenshot_image.take()
+    }
+
+    /// Collect an in-memory screenshot. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn get_screenshot_image(&mut self) -> Option<()> {
+        None
+    }
+
+    /// Start recording frames from the back buffer at the requested rate (in frames per
+    /// second). Call `stop_recording` to encode them as an animated GIF at `filename`.
+    /// Frames accumulate in memory until the recording is stopped. Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn start_recording<S: ToString>(&mut self, filename: S, fps: f32) {
+        BACKEND.lock().recording = Some(crate::hal::Recording {
+            filename: filename.to_string(),
+            frame_interval_ms: 1000.0 / fps,
+            time_since_capture_ms: 0.0,
+            frames: Vec::new(),
+        });
+    }
+
+    /// Start recording frames. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn start_recording<S: ToString>(&mut self, _filename: S, _fps: f32) {
+        // Do nothing
+    }
+
+    /// Stop a recording started with `start_recording`, encoding the captured frames as
+    /// an animated GIF. Does nothing if no recording is in progress. Native only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn stop_recording(&mut self) -> BResult<()> {
+        let recording = BACKEND.lock().recording.take();
+        if let Some(recording) = recording {
+            recording.save()?;
+        }
+        Ok(())
+    }
+
+    /// Stop a recording. Not supported on this back-end.
+    #[cfg(not(all(feature = "opengl", not(target_arch = "wasm32"))))]
+    pub fn stop_recording(&mut self) -> BResult<()> {
+        Ok(())
+    }
+
     /// Take a screenshot - Native only
     #[cfg(all(
         any(feature = "opengl", feature = "webgpu"),
@@ -1053,6 +2260,34 @@ impl BTerm {
         // Do nothing
     }
 
+    /// Changes how the console maps onto a mismatched-aspect window: stretch,
+    /// letterbox, or crop. Takes effect on the next frame.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn set_scaling_mode(&mut self, scaling_mode: crate::hal::ScalingMode) {
+        BACKEND.lock().screen_scaler.set_scaling_mode(scaling_mode);
+    }
+
+    /// How much larger the rendered console is than its base pixel size - a
+    /// whole number in `PixelPerfect` mode. Useful for positioning UI around
+    /// the letterbox bars.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn effective_scale(&self) -> f32 {
+        BACKEND.lock().screen_scaler.effective_scale
+    }
+
+    /// Returns a snapshot of the recent frame-time history, broken down by
+    /// phase. See [`crate::frame_stats::FrameStats`].
+    pub fn frame_stats(&self) -> crate::frame_stats::FrameStats {
+        crate::frame_stats::FRAME_STATS.lock().clone()
+    }
+
+    /// Changes how many frames of timing history are retained (default 240).
+    pub fn set_frame_stats_capacity(&mut self, capacity: usize) {
+        crate::frame_stats::FRAME_STATS
+            .lock()
+            .set_capacity(capacity);
+    }
+
     /// Register a sprite sheet (OpenGL - native or WASM - only)
     #[cfg(any(feature = "opengl", feature = "webgpu"))]
     pub fn register_spritesheet(&mut self, ss: SpriteSheet) -> usize {
@@ -1076,6 +2311,65 @@ impl BTerm {
             });
         }
     }
+
+    /// Add a sprite to the current console, looked up by its registered name in the
+    /// console's sprite sheet. Does nothing if the name is unknown.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn add_sprite_named<S: AsRef<str>>(
+        &mut self,
+        destination: Rect,
+        z_order: i32,
+        tint: RGBA,
+        name: S,
+    ) {
+        let mut bi = BACKEND_INTERNAL.lock();
+        let sheet = match bi.consoles[self.active_console]
+            .console
+            .as_any()
+            .downcast_ref::<SpriteConsole>()
+        {
+            Some(cons) => cons.sprite_sheet,
+            None => return,
+        };
+        let index = match bi.sprite_sheets[sheet].sprite_index(name) {
+            Some(index) => index,
+            None => return,
+        };
+        let as_any = bi.consoles[self.active_console].console.as_any_mut();
+        if let Some(cons) = as_any.downcast_mut::<SpriteConsole>() {
+            cons.render_sprite(RenderSprite {
+                destination,
+                z_order,
+                tint,
+                index,
+            });
+        }
+    }
+}
+
+#[cfg(all(feature = "bracket-egui", not(target_arch = "wasm32")))]
+impl BTerm {
+    /// The egui context, for building UI from `GameState::tick`. `None` until
+    /// the main loop has started. Native OpenGL with the `bracket-egui`
+    /// feature only.
+    pub fn egui_ctx(&self) -> Option<egui::CtxRef> {
+        crate::hal::EGUI_CTX.lock().clone()
+    }
+}
+
+/// Lets a `BTerm` be handed directly to libraries that expect a
+/// `HasRawWindowHandle` - native file dialogs, audio device pickers, custom
+/// renderers. Display handles arrived in later `raw-window-handle` releases
+/// than the one the windowing stack pins, so only the window handle is
+/// exposed. Native OpenGL only.
+#[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+unsafe impl raw_window_handle::HasRawWindowHandle for BTerm {
+    fn raw_window_handle(&self) -> raw_window_handle::RawWindowHandle {
+        BACKEND
+            .lock()
+            .raw_window_handle
+            .expect("Window has not been created yet")
+    }
 }
 
 /// Runs the BTerm application, calling into the provided gamestate handler every tick.
@@ -1122,6 +2416,28 @@ fn iclamp(val: i32, min: i32, max: i32) -> i32 {
     i32::max(min, i32::min(val, max))
 }
 
+/// Forces a console to rebuild its vertex backing on the next frame, e.g. after its
+/// lighting overlay changed without any tiles being touched.
+fn mark_dirty(console: &mut Box<dyn Console>) {
+    let cons_any = console.as_any_mut();
+    if let Some(sc) = cons_any.downcast_mut::<SimpleConsole>() {
+        sc.is_dirty = true;
+    } else if let Some(sp) = cons_any.downcast_mut::<crate::prelude::SparseConsole>() {
+        sp.is_dirty = true;
+    }
+}
+
+/// As `mark_dirty`, but also resizes the vertex backing - needed when a camera change
+/// alters how many cells are rendered, or when only sub-cell offsets moved (the plain
+/// dirty path skips rebuilds when no tile changed).
+fn force_rebuild(console: &mut Box<dyn Console>) {
+    let cons_any = console.as_any_mut();
+    if let Some(sc) = cons_any.downcast_mut::<SimpleConsole>() {
+        sc.is_dirty = true;
+        sc.needs_resize_internal = true;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::iclamp;
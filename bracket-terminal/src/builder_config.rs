@@ -0,0 +1,220 @@
+//! Data-driven display configuration. `BTermBuilder::from_config_file` reads a
+//! RON, TOML or JSON document describing the window, fonts and console layers,
+//! so players and modders can change display settings without a recompile.
+
+use crate::prelude::BTermBuilder;
+use crate::BResult;
+use bracket_color::prelude::RGB;
+use serde::Deserialize;
+
+/// A display configuration document. Every field is optional; anything omitted
+/// keeps the builder's default.
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct TerminalConfig {
+    /// Window width, in characters.
+    pub width: Option<u32>,
+    /// Window height, in characters.
+    pub height: Option<u32>,
+    /// Window title.
+    pub title: Option<String>,
+    /// Directory holding the font files.
+    pub resource_path: Option<String>,
+    /// Reference tile width in pixels, used to size the window.
+    pub tile_width: Option<u32>,
+    /// Reference tile height in pixels, used to size the window.
+    pub tile_height: Option<u32>,
+    /// Frame-rate cap, in frames per second.
+    pub fps_cap: Option<f32>,
+    pub vsync: Option<bool>,
+    pub fullscreen: Option<bool>,
+    /// Enables the scanline post-processing effect.
+    pub scanlines: Option<bool>,
+    /// Adds the screen-burn effect to the scanline pass.
+    pub screen_burn: Option<bool>,
+    /// Fonts to register, in order.
+    pub fonts: Vec<FontConfig>,
+    /// Console layers to create, bottom-most first.
+    pub consoles: Vec<ConsoleConfig>,
+}
+
+/// One font registration in a [`TerminalConfig`].
+#[derive(Deserialize, Debug)]
+pub struct FontConfig {
+    /// File name, relative to the resource path.
+    pub path: String,
+    /// Glyph width in pixels.
+    pub width: u32,
+    /// Glyph height in pixels.
+    pub height: u32,
+    /// Optional background color (0.0-1.0 RGB) to treat as transparent.
+    pub background: Option<(f32, f32, f32)>,
+}
+
+/// One console layer in a [`TerminalConfig`].
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+pub struct ConsoleConfig {
+    pub kind: ConsoleKind,
+    /// Width in characters; defaults to the window width.
+    pub width: Option<u32>,
+    /// Height in characters; defaults to the window height.
+    pub height: Option<u32>,
+    /// Font file name; defaults to the baked-in 8x8 terminal font.
+    pub font: Option<String>,
+}
+
+/// The console types a configuration document can request.
+#[derive(Deserialize, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsoleKind {
+    #[default]
+    Simple,
+    SimpleNoBg,
+    Sparse,
+    SparseNoBg,
+    Fancy,
+}
+
+impl TerminalConfig {
+    /// Parses a configuration document. The format is chosen by extension:
+    /// `ron` and `toml` are what they say; anything else is treated as JSON.
+    pub fn parse(text: &str, extension: &str) -> BResult<TerminalConfig> {
+        let config = match extension {
+            "ron" => ron::de::from_str(text)?,
+            "toml" => toml::from_str(text)?,
+            _ => serde_json::from_str(text)?,
+        };
+        Ok(config)
+    }
+
+    /// Applies the document to a fresh builder, using only the public builder
+    /// methods - so a config file can do exactly what code can.
+    pub fn into_builder(self) -> BResult<BTermBuilder> {
+        let width = self.width.unwrap_or(80);
+        let height = self.height.unwrap_or(50);
+        let mut builder = BTermBuilder::new()
+            .with_dimensions(width, height)
+            .with_tile_dimensions(self.tile_width.unwrap_or(8), self.tile_height.unwrap_or(8));
+        if let Some(title) = self.title {
+            builder = builder.with_title(title);
+        }
+        if let Some(path) = self.resource_path {
+            builder = builder.with_resource_path(path);
+        }
+        if let Some(fps) = self.fps_cap {
+            builder = builder.with_fps_cap(fps);
+        }
+        if let Some(vsync) = self.vsync {
+            builder = builder.with_vsync(vsync);
+        }
+        if let Some(fullscreen) = self.fullscreen {
+            builder = builder.with_fullscreen(fullscreen);
+        }
+        if self.scanlines.unwrap_or(false) {
+            builder = builder.with_post_scanlines(self.screen_burn.unwrap_or(false));
+        }
+
+        for font in self.fonts {
+            builder = match font.background {
+                Some((r, g, b)) => builder.with_font_bg(
+                    &font.path,
+                    font.width,
+                    font.height,
+                    RGB::from_f32(r, g, b),
+                ),
+                None => builder.with_font(&font.path, font.width, font.height),
+            };
+        }
+
+        for console in self.consoles {
+            let w = console.width.unwrap_or(width);
+            let h = console.height.unwrap_or(height);
+            let font = console
+                .font
+                .unwrap_or_else(|| "terminal8x8.png".to_string());
+            builder = match console.kind {
+                ConsoleKind::Simple => builder.with_simple_console(w, h, font),
+                ConsoleKind::SimpleNoBg => builder.with_simple_console_no_bg(w, h, font),
+                ConsoleKind::Sparse => builder.with_sparse_console(w, h, font),
+                ConsoleKind::SparseNoBg => builder.with_sparse_console_no_bg(w, h, font),
+                #[cfg(any(feature = "opengl", feature = "webgpu"))]
+                ConsoleKind::Fancy => builder.with_fancy_console(w, h, font),
+                #[cfg(not(any(feature = "opengl", feature = "webgpu")))]
+                ConsoleKind::Fancy => return Err("Fancy consoles require a GPU back-end".into()),
+            };
+        }
+
+        Ok(builder)
+    }
+}
+
+impl BTermBuilder {
+    /// Builds the console stack from a configuration file instead of chained
+    /// builder calls. The format is chosen by the file extension: `.ron`,
+    /// `.toml`, or JSON for anything else. Further `with_*` calls may still be
+    /// chained afterwards to layer in settings the file format doesn't cover.
+    pub fn from_config_file<P: AsRef<std::path::Path>>(path: P) -> BResult<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)?;
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        TerminalConfig::parse(&text, extension)?.into_builder()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ron_documents() {
+        let config = TerminalConfig::parse(
+            r#"(
+                width: Some(100),
+                height: Some(60),
+                title: Some("Configured"),
+                fps_cap: Some(30.0),
+                scanlines: Some(true),
+                fonts: [(path: "vga8x16.png", width: 8, height: 16)],
+                consoles: [(kind: sparse, font: Some("vga8x16.png"))],
+            )"#,
+            "ron",
+        )
+        .unwrap();
+        assert_eq!(config.width, Some(100));
+        assert_eq!(config.fonts.len(), 1);
+        assert_eq!(config.consoles[0].kind, ConsoleKind::Sparse);
+        assert!(config.into_builder().is_ok());
+    }
+
+    #[test]
+    fn parses_toml_documents() {
+        let config = TerminalConfig::parse(
+            r#"
+                width = 100
+                height = 60
+                fullscreen = true
+
+                [[fonts]]
+                path = "terminal8x8.png"
+                width = 8
+                height = 8
+
+                [[consoles]]
+                kind = "simple_no_bg"
+            "#,
+            "toml",
+        )
+        .unwrap();
+        assert_eq!(config.fullscreen, Some(true));
+        assert_eq!(config.consoles[0].kind, ConsoleKind::SimpleNoBg);
+    }
+
+    #[test]
+    fn unknown_console_kinds_are_rejected() {
+        assert!(
+            TerminalConfig::parse(r#"{ "consoles": [ { "kind": "holographic" } ] }"#, "json")
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,86 @@
+//! Per-cell blink support. Cells are marked with `Console::set_blink`; a single
+//! global phase clock (advanced once per frame from the main loops) decides
+//! whether blinking cells are currently shown or hidden. The GL back-ends blank
+//! the foreground of hidden cells when rebuilding vertices; the terminal
+//! back-ends emit the terminal's own blink attribute instead.
+
+use crate::prelude::{SimpleConsole, SparseConsole, BACKEND_INTERNAL};
+use parking_lot::Mutex;
+
+pub(crate) struct BlinkState {
+    /// Time between phase flips, in milliseconds.
+    pub interval_ms: f32,
+    elapsed_ms: f32,
+    visible: bool,
+}
+
+impl BlinkState {
+    /// Advances the phase clock. Returns true if the phase flipped, meaning
+    /// blinking consoles need a redraw.
+    fn tick(&mut self, frame_time_ms: f32) -> bool {
+        let interval = self.interval_ms.max(1.0);
+        self.elapsed_ms += frame_time_ms;
+        let mut flipped = false;
+        while self.elapsed_ms >= interval {
+            self.elapsed_ms -= interval;
+            self.visible = !self.visible;
+            flipped = true;
+        }
+        flipped
+    }
+}
+
+lazy_static! {
+    pub(crate) static ref BLINK: Mutex<BlinkState> = Mutex::new(BlinkState {
+        interval_ms: 530.0,
+        elapsed_ms: 0.0,
+        visible: true,
+    });
+}
+
+/// Internal: true while blinking cells should be drawn. Consulted by the GL
+/// vertex rebuild.
+pub(crate) fn blink_visible() -> bool {
+    BLINK.lock().visible
+}
+
+/// Internal: advances the blink clock by one frame and, if the phase flipped,
+/// marks every console with blinking cells dirty so it redraws.
+pub(crate) fn tick_blink(frame_time_ms: f32) {
+    if !BLINK.lock().tick(frame_time_ms) {
+        return;
+    }
+    let mut bi = BACKEND_INTERNAL.lock();
+    for cons in bi.consoles.iter_mut() {
+        let cons_any = cons.console.as_any_mut();
+        if let Some(sc) = cons_any.downcast_mut::<SimpleConsole>() {
+            if !sc.blink_cells.is_empty() {
+                sc.is_dirty = true;
+            }
+        } else if let Some(sc) = cons_any.downcast_mut::<SparseConsole>() {
+            if !sc.blink_cells.is_empty() {
+                sc.is_dirty = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlinkState;
+
+    #[test]
+    fn phase_flips_at_the_configured_interval() {
+        let mut blink = BlinkState {
+            interval_ms: 500.0,
+            elapsed_ms: 0.0,
+            visible: true,
+        };
+        assert!(!blink.tick(250.0));
+        assert!(blink.visible);
+        assert!(blink.tick(300.0)); // 550ms - first flip
+        assert!(!blink.visible);
+        assert!(blink.tick(1000.0)); // two intervals - flips twice, ending hidden again
+        assert!(!blink.visible);
+    }
+}
@@ -0,0 +1,128 @@
+//! Palette-cycling support, for old-school water/lava effects. A cycle group is
+//! a set of colors that rotate at a fixed rate; cells are marked as members with
+//! `Console::set_color_cycle`. A global clock (advanced once per frame from the
+//! main loops) steps each group; the GL back-ends substitute the group's current
+//! color for the foreground of member cells when rebuilding vertices, so the
+//! game never has to re-print cycling cells.
+
+use crate::prelude::{SimpleConsole, SparseConsole, BACKEND_INTERNAL};
+use bracket_color::prelude::RGBA;
+use parking_lot::Mutex;
+
+pub(crate) struct CycleGroup {
+    /// The colors the group rotates through, in order.
+    colors: Vec<RGBA>,
+    /// Time between color steps, in milliseconds.
+    interval_ms: f32,
+    elapsed_ms: f32,
+    current: usize,
+}
+
+impl CycleGroup {
+    /// Advances the group's clock. Returns true if the group stepped to a new
+    /// color, meaning member cells need a redraw.
+    fn tick(&mut self, frame_time_ms: f32) -> bool {
+        if self.colors.len() < 2 {
+            return false;
+        }
+        let interval = self.interval_ms.max(1.0);
+        self.elapsed_ms += frame_time_ms;
+        let mut stepped = false;
+        while self.elapsed_ms >= interval {
+            self.elapsed_ms -= interval;
+            self.current = (self.current + 1) % self.colors.len();
+            stepped = true;
+        }
+        stepped
+    }
+}
+
+lazy_static! {
+    pub(crate) static ref COLOR_CYCLES: Mutex<Vec<CycleGroup>> = Mutex::new(Vec::new());
+}
+
+/// Internal: registers a cycle group and returns its handle. Exposed through
+/// `BTerm::register_color_cycle`.
+pub(crate) fn register_color_cycle(colors: Vec<RGBA>, interval_ms: f32) -> usize {
+    let mut cycles = COLOR_CYCLES.lock();
+    cycles.push(CycleGroup {
+        colors,
+        interval_ms,
+        elapsed_ms: 0.0,
+        current: 0,
+    });
+    cycles.len() - 1
+}
+
+/// Internal: the current color of a cycle group, `None` for an unknown handle
+/// or an empty group. Consulted by the GL vertex rebuild.
+pub(crate) fn cycle_color(cycle: usize) -> Option<RGBA> {
+    let cycles = COLOR_CYCLES.lock();
+    let group = cycles.get(cycle)?;
+    group.colors.get(group.current).copied()
+}
+
+/// Internal: advances every cycle group by one frame and, if any stepped, marks
+/// every console with cycling cells dirty so it redraws.
+pub(crate) fn tick_color_cycles(frame_time_ms: f32) {
+    let mut stepped = false;
+    for group in COLOR_CYCLES.lock().iter_mut() {
+        if group.tick(frame_time_ms) {
+            stepped = true;
+        }
+    }
+    if !stepped {
+        return;
+    }
+    let mut bi = BACKEND_INTERNAL.lock();
+    for cons in bi.consoles.iter_mut() {
+        let cons_any = cons.console.as_any_mut();
+        if let Some(sc) = cons_any.downcast_mut::<SimpleConsole>() {
+            if !sc.cycle_cells.is_empty() {
+                sc.is_dirty = true;
+            }
+        } else if let Some(sc) = cons_any.downcast_mut::<SparseConsole>() {
+            if !sc.cycle_cells.is_empty() {
+                sc.is_dirty = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CycleGroup;
+    use bracket_color::prelude::RGBA;
+
+    #[test]
+    fn groups_step_at_the_configured_interval() {
+        let mut group = CycleGroup {
+            colors: vec![
+                RGBA::from_f32(1.0, 0.0, 0.0, 1.0),
+                RGBA::from_f32(0.0, 1.0, 0.0, 1.0),
+                RGBA::from_f32(0.0, 0.0, 1.0, 1.0),
+            ],
+            interval_ms: 100.0,
+            elapsed_ms: 0.0,
+            current: 0,
+        };
+        assert!(!group.tick(50.0));
+        assert_eq!(group.current, 0);
+        assert!(group.tick(60.0)); // 110ms - first step
+        assert_eq!(group.current, 1);
+        assert!(group.tick(200.0)); // two intervals - wraps back to the start
+        assert_eq!(group.current, 0);
+    }
+
+    #[test]
+    fn single_color_groups_never_step() {
+        let mut group = CycleGroup {
+            colors: vec![RGBA::from_f32(1.0, 1.0, 1.0, 1.0)],
+            interval_ms: 100.0,
+            elapsed_ms: 0.0,
+            current: 0,
+        };
+        assert!(!group.tick(1000.0));
+        assert_eq!(group.current, 0);
+    }
+}
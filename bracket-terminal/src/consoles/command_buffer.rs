@@ -2,7 +2,7 @@
 // designed to be used safely from within ECS systems in a potentially
 // multi-threaded environment.
 
-use crate::prelude::{BTerm, FontCharType, TextAlign};
+use crate::prelude::{wrap_text, BTerm, FontCharType, GlyphRun, TextAlign, WrapAlign};
 use crate::BResult;
 use bracket_color::prelude::{ColorPair, RGBA};
 use bracket_geometry::prelude::{Point, PointF, Radians, Rect};
@@ -12,8 +12,15 @@ use std::convert::TryInto;
 use std::sync::Arc;
 
 lazy_static! {
-    static ref COMMAND_BUFFER: Mutex<Vec<(usize, Vec<DrawCommand>)>> =
-        Mutex::new(Vec::with_capacity(100));
+    static ref COMMAND_BUFFER: Mutex<Vec<BatchEntry>> = Mutex::new(Vec::with_capacity(100));
+}
+
+/// A submitted batch awaiting replay: the sort domain (console layer) it belongs to,
+/// its z-order within that domain, and the buffered commands.
+struct BatchEntry {
+    layer: Option<usize>,
+    z_order: usize,
+    commands: Vec<DrawCommand>,
 }
 
 lazy_static! {
@@ -58,6 +65,10 @@ pub enum DrawCommand {
         text: String,
         color: ColorPair,
     },
+    PrintRun {
+        pos: Point,
+        run: GlyphRun,
+    },
     PrintRight {
         pos: Point,
         text: String,
@@ -147,6 +158,18 @@ pub enum DrawCommand {
         glyph: FontCharType,
         scale: PointF,
     },
+    AddSprite {
+        destination: Rect,
+        z_order: i32,
+        tint: RGBA,
+        index: usize,
+    },
+    AddSpriteNamed {
+        destination: Rect,
+        z_order: i32,
+        tint: RGBA,
+        name: String,
+    },
 }
 
 /// Represents a batch of drawing commands, designed to be submitted together.
@@ -160,11 +183,33 @@ impl DrawBatch {
         BUFFER_POOL.pull(|| panic!("No pooling!"))
     }
 
-    /// Submits a batch to the global drawing buffer, and empties the batch.
+    /// Submits a batch to the global drawing buffer, and empties the batch. Batches
+    /// submitted this way share a single global sort domain, ordered by `z_order`;
+    /// batches with equal `z_order` replay in submission order.
     pub fn submit(&mut self, z_order: usize) -> BResult<()> {
         let mut new_batch = Vec::with_capacity(self.batch.len());
         new_batch.append(&mut self.batch);
-        COMMAND_BUFFER.lock().push((z_order, new_batch));
+        COMMAND_BUFFER.lock().push(BatchEntry {
+            layer: None,
+            z_order,
+            commands: new_batch,
+        });
+        Ok(())
+    }
+
+    /// Submits a batch to the drawing buffer for a specific console layer, and empties
+    /// the batch. Each layer is its own sort domain: batches replay grouped by layer
+    /// (lowest first), ordered by `z_order` within the layer, with equal `z_order`
+    /// resolving to submission order. The target console is set to `layer` before the
+    /// batch replays, so UI code does not need a leading `target()` call.
+    pub fn submit_to(&mut self, layer: usize, z_order: usize) -> BResult<()> {
+        let mut new_batch = Vec::with_capacity(self.batch.len());
+        new_batch.append(&mut self.batch);
+        COMMAND_BUFFER.lock().push(BatchEntry {
+            layer: Some(layer),
+            z_order,
+            commands: new_batch,
+        });
         Ok(())
     }
 
@@ -274,6 +319,48 @@ impl DrawBatch {
         self
     }
 
+    /// Prints a pre-encoded glyph run starting at the given position.
+    pub fn print_run(&mut self, pos: Point, run: GlyphRun) -> &mut Self {
+        self.batch.push(DrawCommand::PrintRun { pos, run });
+        self
+    }
+
+    /// Prints text word-wrapped into `region` in the default colors, aligned per
+    /// `align`, clipping lines that do not fit. Unlike the other print calls this
+    /// returns the total number of wrapped lines rather than `&mut Self`, so UIs
+    /// can size panels around the text.
+    pub fn print_wrapped<S: ToString>(&mut self, region: Rect, text: S, align: WrapAlign) -> u32 {
+        let lines = wrap_text(&text.to_string(), region.width().max(1) as usize, align);
+        for (i, line) in lines
+            .iter()
+            .take(region.height().max(0) as usize)
+            .enumerate()
+        {
+            self.print(Point::new(region.x1, region.y1 + i as i32), line);
+        }
+        lines.len() as u32
+    }
+
+    /// Prints text word-wrapped into `region` in the given colors, aligned per
+    /// `align`. Returns the total number of wrapped lines, as `print_wrapped` does.
+    pub fn print_color_wrapped<S: ToString>(
+        &mut self,
+        region: Rect,
+        text: S,
+        align: WrapAlign,
+        color: ColorPair,
+    ) -> u32 {
+        let lines = wrap_text(&text.to_string(), region.width().max(1) as usize, align);
+        for (i, line) in lines
+            .iter()
+            .take(region.height().max(0) as usize)
+            .enumerate()
+        {
+            self.print_color(Point::new(region.x1, region.y1 + i as i32), line, color);
+        }
+        lines.len() as u32
+    }
+
     /// Prints text, centered to the whole console width, at vertical location y.
     pub fn print_centered<S: ToString, Y: TryInto<i32>>(&mut self, y: Y, text: S) -> &mut Self {
         self.batch.push(DrawCommand::PrintCentered {
@@ -431,6 +518,47 @@ impl DrawBatch {
         self
     }
 
+    /// Adds a sprite to the current (sprite) console, by sprite sheet index.
+    pub fn add_sprite<TINT>(
+        &mut self,
+        destination: Rect,
+        z_order: i32,
+        tint: TINT,
+        index: usize,
+    ) -> &mut Self
+    where
+        TINT: Into<RGBA>,
+    {
+        self.batch.push(DrawCommand::AddSprite {
+            destination,
+            z_order,
+            tint: tint.into(),
+            index,
+        });
+        self
+    }
+
+    /// Adds a sprite to the current (sprite) console, looked up by its registered name
+    /// in the console's sprite sheet.
+    pub fn add_sprite_named<S: ToString, TINT>(
+        &mut self,
+        destination: Rect,
+        z_order: i32,
+        tint: TINT,
+        name: S,
+    ) -> &mut Self
+    where
+        TINT: Into<RGBA>,
+    {
+        self.batch.push(DrawCommand::AddSpriteNamed {
+            destination,
+            z_order,
+            tint: tint.into(),
+            name: name.to_string(),
+        });
+        self
+    }
+
     /// Sets a clipping rectangle for the current console
     pub fn set_clipping(&mut self, clip: Option<Rect>) -> &mut Self {
         self.batch.push(DrawCommand::SetClipping { clip });
@@ -459,9 +587,15 @@ impl DrawBatch {
 /// Submits the current batch to the BTerm buffer and empties it
 pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
     let mut buffer = COMMAND_BUFFER.lock();
-    buffer.sort_unstable_by(|a, b| a.0.cmp(&b.0));
-    buffer.iter().for_each(|(_, batch)| {
-        batch.iter().for_each(|cmd| match cmd {
+    // Stable sort: batches group by layer domain, order by z within it, and keep
+    // submission order when z ties.
+    buffer
+        .sort_by(|a, b| (a.layer.unwrap_or(0), a.z_order).cmp(&(b.layer.unwrap_or(0), b.z_order)));
+    buffer.iter().for_each(|entry| {
+        if let Some(layer) = entry.layer {
+            bterm.set_active_console(layer);
+        }
+        entry.commands.iter().for_each(|cmd| match cmd {
             DrawCommand::ClearScreen => bterm.cls(),
             DrawCommand::ClearToColor { color } => bterm.cls_bg(*color),
             DrawCommand::SetTarget { console } => bterm.set_active_console(*console),
@@ -473,6 +607,7 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
             DrawCommand::PrintColor { pos, text, color } => {
                 bterm.print_color(pos.x, pos.y, color.fg, color.bg, &text)
             }
+            DrawCommand::PrintRun { pos, run } => bterm.print_run(pos.x, pos.y, run),
             DrawCommand::PrintCentered { y, text } => bterm.print_centered(*y, &text),
             DrawCommand::PrintColorCentered { y, text, color } => {
                 bterm.print_color_centered(*y, color.fg, color.bg, &text)
@@ -558,6 +693,22 @@ pub fn render_draw_buffer(bterm: &mut BTerm) -> BResult<()> {
                     *position, *z_order, *rotation, *scale, color.fg, color.bg, *glyph,
                 );
             }
+            #[cfg(any(feature = "opengl", feature = "webgpu"))]
+            DrawCommand::AddSprite {
+                destination,
+                z_order,
+                tint,
+                index,
+            } => bterm.add_sprite(*destination, *z_order, *tint, *index),
+            #[cfg(any(feature = "opengl", feature = "webgpu"))]
+            DrawCommand::AddSpriteNamed {
+                destination,
+                z_order,
+                tint,
+                name,
+            } => bterm.add_sprite_named(*destination, *z_order, *tint, name),
+            #[cfg(not(any(feature = "opengl", feature = "webgpu")))]
+            DrawCommand::AddSprite { .. } | DrawCommand::AddSpriteNamed { .. } => {}
         })
     });
     buffer.clear();
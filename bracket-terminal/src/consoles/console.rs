@@ -5,6 +5,7 @@ use std::any::Any;
 
 /// The internal storage type for tiles in a simple console.
 #[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Tile {
     pub glyph: FontCharType,
     pub fg: RGBA,
@@ -24,6 +25,31 @@ pub enum CharacterTranslationMode {
     Unicode,
 }
 
+/// Per-cell text decoration, rendered by the OpenGL backends in the console
+/// fragment shader by sampling neighboring glyph texels. Useful for keeping
+/// text readable over busy map tiles.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GlyphStyle {
+    /// No decoration; the glyph renders as normal.
+    None,
+    /// A one-pixel black outline around the glyph.
+    Outline,
+    /// A one-pixel black drop shadow below and to the right of the glyph.
+    DropShadow,
+}
+
+impl GlyphStyle {
+    /// The flag value passed to the fragment shader for this style.
+    pub(crate) fn shader_flag(self) -> f32 {
+        match self {
+            GlyphStyle::None => 0.0,
+            GlyphStyle::Outline => 1.0,
+            GlyphStyle::DropShadow => 2.0,
+        }
+    }
+}
+
 /// Trait that must be implemented by console types.
 pub trait Console {
     /// Gets the dimensions of the console in characters
@@ -38,6 +64,11 @@ pub trait Console {
     /// Clear the console.
     fn cls(&mut self);
 
+    /// Sets the default background alpha applied when the console is cleared. Only
+    /// meaningful for consoles that paint a background in every cell; the default
+    /// implementation does nothing.
+    fn set_default_bg_alpha(&mut self, _alpha: f32) {}
+
     /// Clear the console to a set background color, if supported.
     fn cls_bg(&mut self, background: RGBA);
 
@@ -53,12 +84,73 @@ pub trait Console {
     /// Thanks to doryen_rs for the idea.
     fn printer(&mut self, x: i32, y: i32, output: &str, align: TextAlign, background: Option<RGBA>);
 
+    /// Prints a string word-wrapped into `region`, aligned per `align`, clipping
+    /// lines that do not fit. Returns the total number of wrapped lines (including
+    /// clipped ones), so UIs can size panels to fit.
+    fn print_wrapped(
+        &mut self,
+        region: Rect,
+        output: &str,
+        align: crate::prelude::WrapAlign,
+    ) -> u32 {
+        let lines = crate::prelude::wrap_text(output, region.width().max(1) as usize, align);
+        for (i, line) in lines
+            .iter()
+            .take(region.height().max(0) as usize)
+            .enumerate()
+        {
+            self.print(region.x1, region.y1 + i as i32, line);
+        }
+        lines.len() as u32
+    }
+
     /// Sets a single cell to a color/glyph combination.
     fn set(&mut self, x: i32, y: i32, fg: RGBA, bg: RGBA, glyph: FontCharType);
 
     /// Sets a single cell's background color.
     fn set_bg(&mut self, x: i32, y: i32, bg: RGBA);
 
+    /// Prints a pre-encoded run of cells starting at x/y, continuing along the
+    /// row. Consoles with contiguous row storage copy the run in one slice
+    /// operation; the default writes cell-by-cell.
+    fn print_run(&mut self, x: i32, y: i32, run: &[Tile]) {
+        for (i, t) in run.iter().enumerate() {
+            self.set(x + i as i32, y, t.fg, t.bg, t.glyph);
+        }
+    }
+
+    /// Marks (or unmarks) a single cell as blinking. Blinking cells flash at the
+    /// rate set with `BTerm::set_blink_rate`. Supported by simple and sparse
+    /// consoles; other console types ignore it.
+    fn set_blink(&mut self, _x: i32, _y: i32, _blinking: bool) {}
+
+    /// Clears every blink mark on the console.
+    fn clear_blink(&mut self) {}
+
+    /// True if any cell on the console is marked as blinking.
+    fn has_blinking_cells(&self) -> bool {
+        false
+    }
+
+    /// Marks a single cell as a member of a color-cycle group (registered with
+    /// `BTerm::register_color_cycle`), or clears the mark with `None`. Member
+    /// cells have their foreground replaced by the group's current color each
+    /// frame. Supported by simple and sparse consoles; other console types
+    /// ignore it.
+    fn set_color_cycle(&mut self, _x: i32, _y: i32, _cycle: Option<usize>) {}
+
+    /// Clears every color-cycle mark on the console.
+    fn clear_color_cycles(&mut self) {}
+
+    /// Sets (or clears, with `GlyphStyle::None`) the glyph style of a single cell.
+    /// Styled cells are drawn with an outline or drop shadow by the OpenGL
+    /// backends. Supported by simple, sparse and fancy consoles; other console
+    /// types ignore it.
+    fn set_glyph_style(&mut self, _x: i32, _y: i32, _style: GlyphStyle) {}
+
+    /// Clears every glyph style mark on the console.
+    fn clear_glyph_styles(&mut self) {}
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, x: i32, y: i32, width: i32, height: i32, fg: RGBA, bg: RGBA);
 
@@ -84,6 +176,43 @@ pub trait Console {
     /// Fills a rectangle-defined region with a given glyph
     fn fill_region(&mut self, target: Rect, glyph: FontCharType, fg: RGBA, bg: RGBA);
 
+    /// Reads the glyph currently stored in a cell, if the console type can report
+    /// cell contents. Returns `None` out of bounds, or on console types without
+    /// readable per-cell storage.
+    fn get_glyph(&self, _x: i32, _y: i32) -> Option<FontCharType> {
+        None
+    }
+
+    /// Flood-fills from a starting point, spreading through the four cardinal
+    /// neighbors of every cell whose current glyph satisfies `predicate`, and
+    /// painting each reached cell with the given glyph and colors. Does nothing
+    /// on console types that cannot report cell contents (see [`Console::get_glyph`]).
+    fn flood_fill(
+        &mut self,
+        start: Point,
+        glyph: FontCharType,
+        fg: RGBA,
+        bg: RGBA,
+        predicate: &dyn Fn(FontCharType) -> bool,
+    ) {
+        let mut visited = std::collections::HashSet::new();
+        let mut open = vec![start];
+        while let Some(p) = open.pop() {
+            if !visited.insert((p.x, p.y)) {
+                continue;
+            }
+            match self.get_glyph(p.x, p.y) {
+                Some(g) if predicate(g) => {}
+                _ => continue,
+            }
+            self.set(p.x, p.y, fg, bg, glyph);
+            open.push(Point::new(p.x + 1, p.y));
+            open.push(Point::new(p.x - 1, p.y));
+            open.push(Point::new(p.x, p.y + 1));
+            open.push(Point::new(p.x, p.y - 1));
+        }
+    }
+
     /// Draws a horizontal progress bar.
     #[allow(clippy::too_many_arguments)]
     fn draw_bar_horizontal(
@@ -1,12 +1,14 @@
 use crate::prelude::{
     string_to_cp437, to_cp437, CharacterTranslationMode, ColoredTextSpans, Console, FontCharType,
-    TextAlign, XpLayer,
+    GlyphStyle, TextAlign, XpLayer,
 };
 use bracket_color::prelude::{XpColor, RGBA};
 use bracket_geometry::prelude::{PointF, Rect};
 use std::any::Any;
+use std::collections::HashMap;
 
 /// Internal storage structure for sparse tiles.
+#[derive(Clone)]
 pub struct FlexiTile {
     pub position: PointF,
     pub z_order: i32,
@@ -17,6 +19,60 @@ pub struct FlexiTile {
     pub scale: PointF,
 }
 
+// PointF is an ultraviolet Vec2 without serde support, so FlexiTile round-trips
+// through a plain-float mirror rather than deriving directly.
+#[cfg(feature = "serde")]
+mod flexi_tile_serde {
+    use super::FlexiTile;
+    use bracket_geometry::prelude::PointF;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Mirror {
+        x: f32,
+        y: f32,
+        z_order: i32,
+        glyph: crate::FontCharType,
+        fg: bracket_color::prelude::RGBA,
+        bg: bracket_color::prelude::RGBA,
+        rotation: f32,
+        scale_x: f32,
+        scale_y: f32,
+    }
+
+    impl Serialize for FlexiTile {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            Mirror {
+                x: self.position.x,
+                y: self.position.y,
+                z_order: self.z_order,
+                glyph: self.glyph,
+                fg: self.fg,
+                bg: self.bg,
+                rotation: self.rotation,
+                scale_x: self.scale.x,
+                scale_y: self.scale.y,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for FlexiTile {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let mirror = Mirror::deserialize(deserializer)?;
+            Ok(FlexiTile {
+                position: PointF::new(mirror.x, mirror.y),
+                z_order: mirror.z_order,
+                glyph: mirror.glyph,
+                fg: mirror.fg,
+                bg: mirror.bg,
+                rotation: mirror.rotation,
+                scale: PointF::new(mirror.scale_x, mirror.scale_y),
+            })
+        }
+    }
+}
+
 /// A sparse console. Rather than storing every cell on the screen, it stores just cells that have
 /// data.
 pub struct FlexiConsole {
@@ -33,8 +89,15 @@ pub struct FlexiConsole {
     pub scale: f32,
     pub scale_center: (i32, i32),
 
+    /// The z-order assigned to tiles emitted by the plain `Console` drawing calls
+    /// (`print`, `set`, etc.), which have no z parameter of their own. Tiles are
+    /// sorted by z before rendering, lowest first; equal z renders in submission
+    /// order.
+    pub default_z_order: i32,
+
     pub extra_clipping: Option<Rect>,
     pub translation: CharacterTranslationMode,
+    pub(crate) styled_cells: HashMap<usize, GlyphStyle>,
     pub(crate) needs_resize_internal: bool,
 }
 
@@ -51,8 +114,10 @@ impl FlexiConsole {
             offset_y: 0.0,
             scale: 1.0,
             scale_center: (width as i32 / 2, height as i32 / 2),
+            default_z_order: 0,
             extra_clipping: None,
             translation: CharacterTranslationMode::Codepage437,
+            styled_cells: HashMap::new(),
             needs_resize_internal: false,
         };
 
@@ -126,13 +191,14 @@ impl Console for FlexiConsole {
         };
 
         let h = (self.height - 1) as f32;
+        let z_order = self.default_z_order;
         self.tiles
             .extend(bytes.into_iter().enumerate().map(|(i, glyph)| FlexiTile {
                 position: PointF {
                     x: i as f32 + x as f32,
                     y: h - y as f32,
                 },
-                z_order: 0,
+                z_order,
                 glyph,
                 fg: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
                 bg: RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
@@ -152,9 +218,10 @@ impl Console for FlexiConsole {
             }
         };
         let h = (self.height - 1) as f32;
+        let z_order = self.default_z_order;
         self.tiles
             .extend(bytes.into_iter().enumerate().map(|(i, glyph)| FlexiTile {
-                z_order: 0,
+                z_order,
                 position: PointF {
                     x: i as f32 + x as f32,
                     y: h - y as f32,
@@ -177,7 +244,7 @@ impl Console for FlexiConsole {
                     x: x as f32,
                     y: h - y as f32,
                 },
-                z_order: 0,
+                z_order: self.default_z_order,
                 glyph,
                 fg,
                 bg,
@@ -426,6 +493,26 @@ impl Console for FlexiConsole {
         });
     }
 
+    /// Sets (or clears) the glyph style of a single cell. Styles apply to whichever
+    /// tiles occupy the cell at render time, including fractionally-positioned ones
+    /// rounded down into it.
+    fn set_glyph_style(&mut self, x: i32, y: i32, style: GlyphStyle) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if style == GlyphStyle::None {
+                self.styled_cells.remove(&idx);
+            } else {
+                self.styled_cells.insert(idx, style);
+            }
+        }
+    }
+
+    /// Clears every glyph style mark on the console.
+    fn clear_glyph_styles(&mut self) {
+        self.is_dirty = true;
+        self.styled_cells.clear();
+    }
+
     /// Sets the character translation mode
     fn set_translation_mode(&mut self, mode: CharacterTranslationMode) {
         self.translation = mode;
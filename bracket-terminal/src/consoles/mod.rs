@@ -1,17 +1,27 @@
+mod blink;
+mod color_cycle;
 mod command_buffer;
 pub mod console;
 mod flexible_console;
+mod particles;
 mod simple_console;
+#[cfg(feature = "serde")]
+mod snapshot;
 mod sparse_console;
 mod sprite_console;
 mod sprites;
 mod text;
 mod virtual_console;
 
+pub(crate) use blink::*;
+pub(crate) use color_cycle::*;
 pub use command_buffer::*;
 pub use console::*;
 pub use flexible_console::*;
+pub use particles::*;
 pub use simple_console::*;
+#[cfg(feature = "serde")]
+pub use snapshot::*;
 pub use sparse_console::*;
 pub use sprite_console::*;
 pub use sprites::*;
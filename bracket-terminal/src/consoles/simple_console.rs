@@ -1,10 +1,11 @@
 use crate::prelude::{
     string_to_cp437, to_cp437, CharacterTranslationMode, ColoredTextSpans, Console, FontCharType,
-    TextAlign, Tile, XpLayer,
+    GlyphStyle, TextAlign, Tile, XpLayer,
 };
 use bracket_color::prelude::*;
 use bracket_geometry::prelude::Rect;
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 
 /// A simple console with background color.
 pub struct SimpleConsole {
@@ -23,7 +24,13 @@ pub struct SimpleConsole {
 
     pub extra_clipping: Option<Rect>,
     pub translation: CharacterTranslationMode,
+    /// The background alpha applied when the console is cleared, allowing a whole layer
+    /// to be see-through without setting every cell's background individually.
+    pub default_bg_alpha: f32,
     pub(crate) needs_resize_internal: bool,
+    pub(crate) blink_cells: HashSet<usize>,
+    pub(crate) styled_cells: HashMap<usize, GlyphStyle>,
+    pub(crate) cycle_cells: HashMap<usize, usize>,
 }
 
 impl SimpleConsole {
@@ -51,7 +58,11 @@ impl SimpleConsole {
             scale_center: (width as i32 / 2, height as i32 / 2),
             extra_clipping: None,
             translation: CharacterTranslationMode::Codepage437,
+            default_bg_alpha: 1.0,
             needs_resize_internal: false,
+            blink_cells: HashSet::new(),
+            styled_cells: HashMap::new(),
+            cycle_cells: HashMap::new(),
         };
 
         Box::new(new_console)
@@ -78,10 +89,15 @@ impl Console for SimpleConsole {
         for tile in &mut self.tiles {
             tile.glyph = 32;
             tile.fg = RGBA::from_u8(255, 255, 255, 255);
-            tile.bg = RGBA::from_u8(0, 0, 0, 255);
+            tile.bg = RGBA::from_f32(0.0, 0.0, 0.0, self.default_bg_alpha);
         }
     }
 
+    /// Sets the default background alpha used by `cls`.
+    fn set_default_bg_alpha(&mut self, alpha: f32) {
+        self.default_bg_alpha = alpha;
+    }
+
     /// Clears the screen with a background color.
     fn cls_bg(&mut self, background: RGBA) {
         self.is_dirty = true;
@@ -147,6 +163,99 @@ impl Console for SimpleConsole {
         }
     }
 
+    /// Reads the glyph currently stored in a cell.
+    fn get_glyph(&self, x: i32, y: i32) -> Option<FontCharType> {
+        self.try_at(x, y).map(|idx| self.tiles[idx].glyph)
+    }
+
+    /// Prints a pre-encoded run of cells with a single slice copy.
+    fn print_run(&mut self, x: i32, y: i32, run: &[Tile]) {
+        // The clipped path keeps per-cell checks; the common case is one memcpy.
+        if self.extra_clipping.is_some() {
+            for (i, t) in run.iter().enumerate() {
+                if let Some(idx) = self.try_at(x + i as i32, y) {
+                    self.tiles[idx] = *t;
+                }
+            }
+            self.is_dirty = true;
+            return;
+        }
+        if y < 0 || y >= self.height as i32 {
+            return;
+        }
+        let skip = (-x).max(0) as usize;
+        if skip >= run.len() {
+            return;
+        }
+        let start_x = x + skip as i32;
+        if start_x >= self.width as i32 {
+            return;
+        }
+        let count = (run.len() - skip).min((self.width as i32 - start_x) as usize);
+        let idx = self.at(start_x, y);
+        self.tiles[idx..idx + count].copy_from_slice(&run[skip..skip + count]);
+        self.is_dirty = true;
+    }
+
+    /// Marks (or unmarks) a single cell as blinking.
+    fn set_blink(&mut self, x: i32, y: i32, blinking: bool) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if blinking {
+                self.blink_cells.insert(idx);
+            } else {
+                self.blink_cells.remove(&idx);
+            }
+        }
+    }
+
+    /// Clears every blink mark on the console.
+    fn clear_blink(&mut self) {
+        self.is_dirty = true;
+        self.blink_cells.clear();
+    }
+
+    /// True if any cell on the console is marked as blinking.
+    fn has_blinking_cells(&self) -> bool {
+        !self.blink_cells.is_empty()
+    }
+
+    /// Marks a single cell as a member of a color-cycle group, or clears the mark.
+    fn set_color_cycle(&mut self, x: i32, y: i32, cycle: Option<usize>) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if let Some(cycle) = cycle {
+                self.cycle_cells.insert(idx, cycle);
+            } else {
+                self.cycle_cells.remove(&idx);
+            }
+        }
+    }
+
+    /// Clears every color-cycle mark on the console.
+    fn clear_color_cycles(&mut self) {
+        self.is_dirty = true;
+        self.cycle_cells.clear();
+    }
+
+    /// Sets (or clears) the glyph style of a single cell.
+    fn set_glyph_style(&mut self, x: i32, y: i32, style: GlyphStyle) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if style == GlyphStyle::None {
+                self.styled_cells.remove(&idx);
+            } else {
+                self.styled_cells.insert(idx, style);
+            }
+        }
+    }
+
+    /// Clears every glyph style mark on the console.
+    fn clear_glyph_styles(&mut self) {
+        self.is_dirty = true;
+        self.styled_cells.clear();
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, sx: i32, sy: i32, width: i32, height: i32, fg: RGBA, bg: RGBA) {
         crate::prelude::draw_box(self, sx, sy, width, height, fg, bg);
@@ -0,0 +1,131 @@
+//! Serializable snapshots of console contents, for save-game thumbnails and
+//! replays. Only compiled with the `serde` feature.
+
+use crate::prelude::{
+    Console, FlexiConsole, FlexiTile, SimpleConsole, SparseConsole, SparseTile, Tile,
+};
+use serde::{Deserialize, Serialize};
+
+/// The captured cell buffer of one console layer. Capture with
+/// [`ConsoleSnapshot::capture`] (or `BTerm::snapshot_console`), serialize it with
+/// any serde format, and put it back with [`ConsoleSnapshot::restore`] (or
+/// `BTerm::restore_console`).
+#[derive(Clone, Serialize, Deserialize)]
+pub enum ConsoleSnapshot {
+    Simple {
+        width: u32,
+        height: u32,
+        tiles: Vec<Tile>,
+    },
+    Sparse {
+        width: u32,
+        height: u32,
+        tiles: Vec<SparseTile>,
+    },
+    Fancy {
+        width: u32,
+        height: u32,
+        tiles: Vec<FlexiTile>,
+    },
+}
+
+impl ConsoleSnapshot {
+    /// Captures a console's cell buffer. Returns None for console types without
+    /// snapshot support (sprite and virtual consoles).
+    pub fn capture(console: &dyn Console) -> Option<ConsoleSnapshot> {
+        if let Some(sc) = console.as_any().downcast_ref::<SimpleConsole>() {
+            return Some(ConsoleSnapshot::Simple {
+                width: sc.width,
+                height: sc.height,
+                tiles: sc.tiles.clone(),
+            });
+        }
+        if let Some(sc) = console.as_any().downcast_ref::<SparseConsole>() {
+            return Some(ConsoleSnapshot::Sparse {
+                width: sc.width,
+                height: sc.height,
+                tiles: sc.tiles.clone(),
+            });
+        }
+        if let Some(fc) = console.as_any().downcast_ref::<FlexiConsole>() {
+            return Some(ConsoleSnapshot::Fancy {
+                width: fc.width,
+                height: fc.height,
+                tiles: fc.tiles.clone(),
+            });
+        }
+        None
+    }
+
+    /// Restores a snapshot onto a console of the matching type, resizing it to
+    /// the captured dimensions. Returns false if the console type does not match
+    /// the snapshot variant.
+    pub fn restore(&self, console: &mut dyn Console) -> bool {
+        match self {
+            ConsoleSnapshot::Simple {
+                width,
+                height,
+                tiles,
+            } => {
+                if let Some(sc) = console.as_any_mut().downcast_mut::<SimpleConsole>() {
+                    if (sc.width, sc.height) != (*width, *height) {
+                        sc.set_char_size(*width, *height);
+                    }
+                    sc.tiles = tiles.clone();
+                    sc.is_dirty = true;
+                    return true;
+                }
+                false
+            }
+            ConsoleSnapshot::Sparse {
+                width,
+                height,
+                tiles,
+            } => {
+                if let Some(sc) = console.as_any_mut().downcast_mut::<SparseConsole>() {
+                    if (sc.width, sc.height) != (*width, *height) {
+                        sc.set_char_size(*width, *height);
+                    }
+                    sc.tiles = tiles.clone();
+                    sc.is_dirty = true;
+                    return true;
+                }
+                false
+            }
+            ConsoleSnapshot::Fancy {
+                width,
+                height,
+                tiles,
+            } => {
+                if let Some(fc) = console.as_any_mut().downcast_mut::<FlexiConsole>() {
+                    if (fc.width, fc.height) != (*width, *height) {
+                        fc.set_char_size(*width, *height);
+                    }
+                    fc.tiles = tiles.clone();
+                    fc.is_dirty = true;
+                    return true;
+                }
+                false
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConsoleSnapshot;
+    use crate::prelude::{Console, SimpleConsole};
+
+    #[test]
+    fn simple_console_round_trips() {
+        let mut console = SimpleConsole::init(4, 3);
+        console.print(0, 0, "Hi");
+        let snapshot = ConsoleSnapshot::capture(&*console).unwrap();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let restored: ConsoleSnapshot = serde_json::from_str(&json).unwrap();
+
+        let mut other = SimpleConsole::init(4, 3);
+        assert!(restored.restore(&mut *other));
+        assert_eq!(other.tiles, console.tiles);
+    }
+}
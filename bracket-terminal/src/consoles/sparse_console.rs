@@ -1,13 +1,15 @@
 use crate::prelude::{
     string_to_cp437, to_cp437, CharacterTranslationMode, ColoredTextSpans, Console, FontCharType,
-    TextAlign, XpLayer,
+    GlyphStyle, TextAlign, XpLayer,
 };
 use bracket_color::prelude::{XpColor, RGBA};
 use bracket_geometry::prelude::Rect;
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 
 /// Internal storage structure for sparse tiles.
 #[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SparseTile {
     pub idx: usize,
     pub glyph: FontCharType,
@@ -34,6 +36,9 @@ pub struct SparseConsole {
     pub extra_clipping: Option<Rect>,
     pub translation: CharacterTranslationMode,
     pub(crate) needs_resize_internal: bool,
+    pub(crate) blink_cells: HashSet<usize>,
+    pub(crate) styled_cells: HashMap<usize, GlyphStyle>,
+    pub(crate) cycle_cells: HashMap<usize, usize>,
 }
 
 impl SparseConsole {
@@ -52,6 +57,9 @@ impl SparseConsole {
             extra_clipping: None,
             translation: CharacterTranslationMode::Codepage437,
             needs_resize_internal: false,
+            blink_cells: HashSet::new(),
+            styled_cells: HashMap::new(),
+            cycle_cells: HashMap::new(),
         };
 
         Box::new(new_console)
@@ -172,6 +180,72 @@ impl Console for SparseConsole {
         }
     }
 
+    /// Reads the glyph most recently stored in a cell, if any tile occupies it.
+    fn get_glyph(&self, x: i32, y: i32) -> Option<FontCharType> {
+        self.try_at(x, y)
+            .and_then(|idx| self.tiles.iter().rev().find(|t| t.idx == idx))
+            .map(|t| t.glyph)
+    }
+
+    /// Marks (or unmarks) a single cell as blinking.
+    fn set_blink(&mut self, x: i32, y: i32, blinking: bool) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if blinking {
+                self.blink_cells.insert(idx);
+            } else {
+                self.blink_cells.remove(&idx);
+            }
+        }
+    }
+
+    /// Clears every blink mark on the console.
+    fn clear_blink(&mut self) {
+        self.is_dirty = true;
+        self.blink_cells.clear();
+    }
+
+    /// True if any cell on the console is marked as blinking.
+    fn has_blinking_cells(&self) -> bool {
+        !self.blink_cells.is_empty()
+    }
+
+    /// Marks a single cell as a member of a color-cycle group, or clears the mark.
+    fn set_color_cycle(&mut self, x: i32, y: i32, cycle: Option<usize>) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if let Some(cycle) = cycle {
+                self.cycle_cells.insert(idx, cycle);
+            } else {
+                self.cycle_cells.remove(&idx);
+            }
+        }
+    }
+
+    /// Clears every color-cycle mark on the console.
+    fn clear_color_cycles(&mut self) {
+        self.is_dirty = true;
+        self.cycle_cells.clear();
+    }
+
+    /// Sets (or clears) the glyph style of a single cell.
+    fn set_glyph_style(&mut self, x: i32, y: i32, style: GlyphStyle) {
+        if let Some(idx) = self.try_at(x, y) {
+            self.is_dirty = true;
+            if style == GlyphStyle::None {
+                self.styled_cells.remove(&idx);
+            } else {
+                self.styled_cells.insert(idx, style);
+            }
+        }
+    }
+
+    /// Clears every glyph style mark on the console.
+    fn clear_glyph_styles(&mut self) {
+        self.is_dirty = true;
+        self.styled_cells.clear();
+    }
+
     /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
     fn draw_box(&mut self, sx: i32, sy: i32, width: i32, height: i32, fg: RGBA, bg: RGBA) {
         crate::prelude::draw_box(self, sx, sy, width, height, fg, bg);
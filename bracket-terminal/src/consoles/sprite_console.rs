@@ -4,6 +4,7 @@ use bracket_geometry::prelude::Rect;
 use std::any::Any;
 
 /// Internal storage structure for sparse tiles.
+#[derive(Clone)]
 pub struct RenderSprite {
     pub destination: Rect,
     pub z_order: i32,
@@ -0,0 +1,150 @@
+use crate::prelude::SpriteSheet;
+
+/// How an animation clip behaves when it reaches its final frame.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LoopMode {
+    /// Restart from the first frame.
+    Loop,
+    /// Stop on the final frame.
+    Once,
+    /// Play backwards to the first frame, then forwards again.
+    PingPong,
+}
+
+/// A single frame of an animation clip: the sprite sheet index to display and how long
+/// to hold it, in milliseconds.
+#[derive(Copy, Clone, Debug)]
+pub struct AnimationFrame {
+    pub sprite_index: usize,
+    pub duration_ms: f32,
+}
+
+/// A named animation clip: an ordered list of sprite frames with durations and a loop
+/// mode. Clips are stored on the sprite sheet and referenced by index from
+/// `AnimationController`.
+#[derive(Clone, Debug)]
+pub struct AnimationClip {
+    pub name: String,
+    pub frames: Vec<AnimationFrame>,
+    pub loop_mode: LoopMode,
+}
+
+impl AnimationClip {
+    /// Creates a clip holding each listed sprite for the same duration.
+    pub fn new<S: ToString>(
+        name: S,
+        sprite_indices: &[usize],
+        frame_duration_ms: f32,
+        loop_mode: LoopMode,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            frames: sprite_indices
+                .iter()
+                .map(|i| AnimationFrame {
+                    sprite_index: *i,
+                    duration_ms: frame_duration_ms,
+                })
+                .collect(),
+            loop_mode,
+        }
+    }
+}
+
+/// Playback state for one animated entity. Store one of these per monster/effect, call
+/// `tick` with `BTerm::frame_time_ms` each frame, and render the sprite index it
+/// returns - no hand-rolled timing required.
+#[derive(Clone, Debug)]
+pub struct AnimationController {
+    clip: usize,
+    current_frame: usize,
+    time_in_frame_ms: f32,
+    playing: bool,
+    reversed: bool,
+}
+
+impl AnimationController {
+    /// Starts playing the specified clip (an index into the sheet's `animations`).
+    pub fn new(clip: usize) -> Self {
+        Self {
+            clip,
+            current_frame: 0,
+            time_in_frame_ms: 0.0,
+            playing: true,
+            reversed: false,
+        }
+    }
+
+    /// Switches to a different clip, restarting playback from its first frame. Does
+    /// nothing if the clip is already playing.
+    pub fn play(&mut self, clip: usize) {
+        if self.clip != clip || !self.playing {
+            self.clip = clip;
+            self.current_frame = 0;
+            self.time_in_frame_ms = 0.0;
+            self.playing = true;
+            self.reversed = false;
+        }
+    }
+
+    /// The clip currently playing.
+    pub fn current_clip(&self) -> usize {
+        self.clip
+    }
+
+    /// True once a `LoopMode::Once` clip has reached its final frame.
+    pub fn is_finished(&self) -> bool {
+        !self.playing
+    }
+
+    /// Advances playback by `frame_time_ms` milliseconds against the sheet's clip data,
+    /// and returns the sprite sheet index to render this frame.
+    pub fn tick(&mut self, sheet: &SpriteSheet, frame_time_ms: f32) -> usize {
+        let clip = &sheet.animations[self.clip];
+        if clip.frames.is_empty() {
+            return 0;
+        }
+        if !self.playing {
+            return clip.frames[self.current_frame].sprite_index;
+        }
+
+        self.time_in_frame_ms += frame_time_ms;
+        while self.time_in_frame_ms >= clip.frames[self.current_frame].duration_ms {
+            self.time_in_frame_ms -= clip.frames[self.current_frame].duration_ms;
+            let last = clip.frames.len() - 1;
+            match clip.loop_mode {
+                LoopMode::Loop => {
+                    self.current_frame = if self.current_frame == last {
+                        0
+                    } else {
+                        self.current_frame + 1
+                    };
+                }
+                LoopMode::Once => {
+                    if self.current_frame == last {
+                        self.playing = false;
+                        self.time_in_frame_ms = 0.0;
+                        break;
+                    }
+                    self.current_frame += 1;
+                }
+                LoopMode::PingPong => {
+                    if self.reversed {
+                        if self.current_frame == 0 {
+                            self.reversed = false;
+                            self.current_frame = usize::min(1, last);
+                        } else {
+                            self.current_frame -= 1;
+                        }
+                    } else if self.current_frame == last {
+                        self.reversed = true;
+                        self.current_frame = last.saturating_sub(1);
+                    } else {
+                        self.current_frame += 1;
+                    }
+                }
+            }
+        }
+        clip.frames[self.current_frame].sprite_index
+    }
+}
@@ -0,0 +1,76 @@
+//! Aseprite sprite sheet import. Reads the JSON data emitted by Aseprite's
+//! "Export Sprite Sheet" (frames, frame tags and durations), filling both the sprite
+//! and animation registries. Requires the `atlas` feature.
+
+use crate::prelude::{embedding, AnimationClip, AnimationFrame, LoopMode, SpriteSheet};
+use crate::BResult;
+use serde_json::Value;
+
+impl SpriteSheet {
+    /// Loads an Aseprite JSON export, registering every frame as a named sprite and
+    /// every frame tag as an animation clip (with Aseprite's per-frame durations and
+    /// loop direction). The JSON is loaded through the resource system, so it works
+    /// with both embedded resources and the filesystem - on native and wasm alike.
+    /// Export with the *array* frame layout; the hash layout does not guarantee frame
+    /// ordering, which frame tags rely on.
+    pub fn from_aseprite<S: ToString>(json_filename: S) -> BResult<SpriteSheet> {
+        let filename = json_filename.to_string();
+        let resource = embedding::EMBED.lock().get_resource(filename.clone());
+        let json = match resource {
+            Some(bytes) => String::from_utf8(bytes.to_vec())?,
+            None => std::fs::read_to_string(&filename)?,
+        };
+        SpriteSheet::from_aseprite_json(&json)
+    }
+
+    /// Parses Aseprite JSON that has already been loaded into memory.
+    pub fn from_aseprite_json(json: &str) -> BResult<SpriteSheet> {
+        let root: Value = serde_json::from_str(json)?;
+        let mut sheet = SpriteSheet::from_texture_packer_json(json, None)?;
+
+        // Per-frame durations, in frame order. The array layout preserves it; the
+        // hash layout falls back to map order.
+        let durations: Vec<f32> = match &root["frames"] {
+            Value::Array(list) => list
+                .iter()
+                .map(|f| f["duration"].as_f64().unwrap_or(100.0) as f32)
+                .collect(),
+            Value::Object(map) => map
+                .values()
+                .map(|f| f["duration"].as_f64().unwrap_or(100.0) as f32)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        if let Value::Array(tags) = &root["meta"]["frameTags"] {
+            for tag in tags {
+                let name = tag["name"].as_str().unwrap_or("");
+                let from = tag["from"].as_u64().unwrap_or(0) as usize;
+                let to = tag["to"].as_u64().unwrap_or(0) as usize;
+                if to >= sheet.sprites.len() || from > to {
+                    continue;
+                }
+                let mut frames: Vec<AnimationFrame> = (from..=to)
+                    .map(|i| AnimationFrame {
+                        sprite_index: i,
+                        duration_ms: durations.get(i).copied().unwrap_or(100.0),
+                    })
+                    .collect();
+                let loop_mode = match tag["direction"].as_str() {
+                    Some("pingpong") => LoopMode::PingPong,
+                    Some("reverse") => {
+                        frames.reverse();
+                        LoopMode::Loop
+                    }
+                    _ => LoopMode::Loop,
+                };
+                sheet.animations.push(AnimationClip {
+                    name: name.to_string(),
+                    frames,
+                    loop_mode,
+                });
+            }
+        }
+        Ok(sheet)
+    }
+}
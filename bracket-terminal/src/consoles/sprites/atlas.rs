@@ -0,0 +1,66 @@
+//! TexturePacker / free-tex-packer atlas import. Requires the `atlas` feature.
+
+use crate::prelude::SpriteSheet;
+use crate::BResult;
+use bracket_geometry::prelude::Rect;
+use serde_json::Value;
+
+impl SpriteSheet {
+    /// Builds a sprite sheet from TexturePacker (or free-tex-packer) JSON. Both the
+    /// hash (`"frames": { "name": ... }`) and array (`"frames": [ ... ]`) layouts are
+    /// supported. Every frame is registered as a named sprite, so it can be looked up
+    /// with `sprite_index` by its atlas name. The texture filename is read from
+    /// `meta.image`; pass `texture_filename` to override it (e.g. to prefix a
+    /// resource path).
+    pub fn from_texture_packer_json(
+        json: &str,
+        texture_filename: Option<&str>,
+    ) -> BResult<SpriteSheet> {
+        let root: Value = serde_json::from_str(json)?;
+
+        let filename = match texture_filename {
+            Some(f) => f.to_string(),
+            None => root["meta"]["image"]
+                .as_str()
+                .ok_or("Atlas JSON has no meta.image; specify a texture filename")?
+                .to_string(),
+        };
+        let mut sheet = SpriteSheet::new(filename);
+
+        let frames = &root["frames"];
+        match frames {
+            Value::Object(map) => {
+                for (name, frame) in map {
+                    sheet = sheet.add_named_sprite(name, parse_frame_rect(frame)?);
+                }
+            }
+            Value::Array(list) => {
+                for frame in list {
+                    let name = frame["filename"]
+                        .as_str()
+                        .ok_or("Atlas frame has no filename")?;
+                    sheet = sheet.add_named_sprite(name, parse_frame_rect(frame)?);
+                }
+            }
+            _ => return Err("Atlas JSON has no frames section".into()),
+        }
+        Ok(sheet)
+    }
+}
+
+/// Reads the `frame` rectangle (x/y/w/h, in pixels) from a single atlas entry.
+fn parse_frame_rect(frame: &Value) -> BResult<Rect> {
+    let f = &frame["frame"];
+    let field = |name: &str| -> BResult<i32> {
+        f[name]
+            .as_i64()
+            .map(|n| n as i32)
+            .ok_or_else(|| format!("Atlas frame is missing {}", name).into())
+    };
+    Ok(Rect::with_size(
+        field("x")?,
+        field("y")?,
+        field("w")?,
+        field("h")?,
+    ))
+}
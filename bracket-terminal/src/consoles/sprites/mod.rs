@@ -1,5 +1,13 @@
+mod animation;
+#[cfg(feature = "atlas")]
+mod aseprite;
+#[cfg(feature = "atlas")]
+mod atlas;
+#[cfg(any(feature = "opengl", feature = "webgpu"))]
+mod gif;
 mod sprite;
 mod spritesheet;
 
+pub use animation::*;
 pub use sprite::*;
 pub use spritesheet::*;
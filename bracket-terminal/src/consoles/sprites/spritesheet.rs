@@ -1,11 +1,14 @@
-use crate::prelude::{Font, Sprite};
+use crate::prelude::{AnimationClip, Font, Sprite};
 use bracket_geometry::prelude::Rect;
+use std::collections::HashMap;
 use std::rc::Rc;
 
 #[derive(Clone)]
 pub struct SpriteSheet {
     pub filename: String,
     pub sprites: Vec<Sprite>,
+    pub named_sprites: HashMap<String, usize>,
+    pub animations: Vec<AnimationClip>,
     pub backing: Option<Rc<Box<Font>>>,
 }
 
@@ -14,6 +17,8 @@ impl SpriteSheet {
         Self {
             filename: filename.to_string(),
             sprites: Vec::new(),
+            named_sprites: HashMap::new(),
+            animations: Vec::new(),
             backing: None,
         }
     }
@@ -22,4 +27,50 @@ impl SpriteSheet {
         self.sprites.push(Sprite::new(location_pixel));
         self
     }
+
+    /// Adds a sprite and registers it under a name, for lookup with `sprite_index`.
+    pub fn add_named_sprite<S: ToString>(mut self, name: S, location_pixel: Rect) -> Self {
+        self.named_sprites
+            .insert(name.to_string(), self.sprites.len());
+        self.sprites.push(Sprite::new(location_pixel));
+        self
+    }
+
+    /// Finds a named sprite's index in the sheet.
+    pub fn sprite_index<S: AsRef<str>>(&self, name: S) -> Option<usize> {
+        self.named_sprites.get(name.as_ref()).copied()
+    }
+
+    /// Builds a sheet from a regular grid of `columns` x `rows` sprites, each
+    /// `tile_size` pixels, indexed row-major from the top-left of the texture.
+    pub fn from_grid<S: ToString>(
+        filename: S,
+        tile_size: (u32, u32),
+        columns: u32,
+        rows: u32,
+    ) -> Self {
+        let mut sheet = SpriteSheet::new(filename);
+        for y in 0..rows {
+            for x in 0..columns {
+                sheet.sprites.push(Sprite::new(Rect::with_size(
+                    (x * tile_size.0) as i32,
+                    (y * tile_size.1) as i32,
+                    tile_size.0 as i32,
+                    tile_size.1 as i32,
+                )));
+            }
+        }
+        sheet
+    }
+
+    /// Adds an animation clip to the sheet.
+    pub fn add_animation(mut self, clip: AnimationClip) -> Self {
+        self.animations.push(clip);
+        self
+    }
+
+    /// Finds an animation clip by name.
+    pub fn animation_index<S: AsRef<str>>(&self, name: S) -> Option<usize> {
+        self.animations.iter().position(|a| a.name == name.as_ref())
+    }
 }
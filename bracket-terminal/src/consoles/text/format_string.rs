@@ -1,5 +1,10 @@
 use bracket_color::prelude::*;
 
+/// The result of parsing `#[color]` markup: runs of text with the color each one
+/// should be printed in. `#[name]` pushes a color looked up in the palette registry
+/// (see `register_palette_color`/`add_named_colors_to_palette`), `#[#rrggbb]` or
+/// `#[#rrggbbaa]` pushes a literal hex color, and `#[]` pops back to the previous
+/// color. Unknown names resolve to white.
 #[derive(Debug)]
 pub struct ColoredTextSpans {
     pub length: usize,
@@ -7,6 +12,16 @@ pub struct ColoredTextSpans {
 }
 
 fn find_color(col_name: &str) -> RGBA {
+    if let Some(hex) = col_name.strip_prefix('#') {
+        let parsed = match hex.len() {
+            6 => RGB::from_hex(col_name).ok().map(|rgb| rgb.to_rgba(1.0)),
+            8 => RGBA::from_hex(col_name).ok(),
+            _ => None,
+        };
+        if let Some(rgba) = parsed {
+            return rgba;
+        }
+    }
     if let Some(palette) = palette_color(&col_name) {
         palette
     } else {
@@ -22,10 +37,18 @@ impl ColoredTextSpans {
         };
         let mut color_stack = Vec::new();
 
-        for color_span in text.to_owned().split("#[") {
+        for (i, color_span) in text.split("#[").enumerate() {
             if color_span.is_empty() {
                 continue;
             }
+            // Anything before the first tag is plain text in the default color.
+            if i == 0 {
+                result
+                    .spans
+                    .push((RGBA::from_u8(255, 255, 255, 255), color_span.to_string()));
+                result.length += color_span.chars().count();
+                continue;
+            }
             let mut col_text = color_span.splitn(2, ']');
             let col_name = col_text.next().unwrap();
             if let Some(text_span) = col_text.next() {
@@ -47,3 +70,30 @@ impl ColoredTextSpans {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ColoredTextSpans;
+    use bracket_color::prelude::*;
+
+    #[test]
+    fn color_stack_pushes_and_pops() {
+        register_palette_color("test_red", RGBA::from_u8(255, 0, 0, 255));
+        let spans = ColoredTextSpans::new("You hit the #[test_red]orc#[] hard");
+        assert_eq!(spans.length, "You hit the orc hard".chars().count());
+        assert_eq!(spans.spans.len(), 3);
+        assert_eq!(spans.spans[0].1, "You hit the ");
+        assert_eq!(
+            spans.spans[1],
+            (RGBA::from_u8(255, 0, 0, 255), "orc".to_string())
+        );
+        assert_eq!(spans.spans[2].0, RGBA::from_u8(255, 255, 255, 255));
+    }
+
+    #[test]
+    fn hex_colors_bypass_the_palette() {
+        let spans = ColoredTextSpans::new("#[#ff8800]ember#[#ff880080]fading");
+        assert_eq!(spans.spans[0].0, RGBA::from_u8(255, 136, 0, 255));
+        assert_eq!(spans.spans[1].0, RGBA::from_u8(255, 136, 0, 128));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::prelude::{string_to_cp437, FontCharType, Tile};
+use bracket_color::prelude::RGBA;
+
+/// A pre-encoded run of glyphs with their colors, ready to be copied into a
+/// console row in one operation. Printing a string goes cell-by-cell through
+/// translation and bounds checks; a `GlyphRun` pays those costs once, at build
+/// time, so heavy text UIs can re-print it every frame cheaply with
+/// `BTerm::print_run` or `DrawBatch::print_run`.
+#[derive(Clone, Default)]
+pub struct GlyphRun {
+    tiles: Vec<Tile>,
+}
+
+impl GlyphRun {
+    /// Creates an empty run.
+    pub fn new() -> Self {
+        Self { tiles: Vec::new() }
+    }
+
+    /// Creates a run by CP437-encoding `text` in a single color pair.
+    pub fn from_text<S: ToString>(text: S, fg: RGBA, bg: RGBA) -> Self {
+        let mut run = Self::new();
+        run.append_text(text, fg, bg);
+        run
+    }
+
+    /// Appends a single glyph to the run.
+    pub fn push(&mut self, glyph: FontCharType, fg: RGBA, bg: RGBA) -> &mut Self {
+        self.tiles.push(Tile { glyph, fg, bg });
+        self
+    }
+
+    /// CP437-encodes `text` in a single color pair and appends it to the run.
+    pub fn append_text<S: ToString>(&mut self, text: S, fg: RGBA, bg: RGBA) -> &mut Self {
+        self.tiles.extend(
+            string_to_cp437(text.to_string())
+                .into_iter()
+                .map(|glyph| Tile { glyph, fg, bg }),
+        );
+        self
+    }
+
+    /// The encoded cells, in print order.
+    pub fn tiles(&self) -> &[Tile] {
+        &self.tiles
+    }
+
+    /// The number of cells in the run.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    /// True if the run contains no cells.
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+}
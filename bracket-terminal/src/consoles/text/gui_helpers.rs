@@ -1,5 +1,6 @@
-use crate::prelude::{to_cp437, Console};
+use crate::prelude::{to_cp437, Console, FontCharType};
 use bracket_color::prelude::RGBA;
+use bracket_geometry::prelude::Point;
 
 /// Draws a box, starting at x/y with the extents width/height using CP437 line characters
 pub fn draw_box(
@@ -166,3 +167,166 @@ pub fn draw_bar_vertical(
         }
     }
 }
+
+/// Paints every cell yielded by a point iterator with the specified rendering
+/// information. Pairs with the bracket-geometry iterators (lines, circles) for
+/// procedural painting without manual loops; out-of-bounds points are ignored.
+pub fn paint_region<I>(
+    console: &mut dyn Console,
+    region: I,
+    glyph: FontCharType,
+    fg: RGBA,
+    bg: RGBA,
+) where
+    I: IntoIterator<Item = Point>,
+{
+    for point in region {
+        console.set(point.x, point.y, fg, bg, glyph);
+    }
+}
+
+/// CP437 glyphs that smart wall drawing treats as connectable single-line cells.
+const SINGLE_LINE_GLYPHS: [FontCharType; 12] =
+    [9, 179, 180, 191, 192, 193, 194, 195, 196, 197, 217, 218];
+
+/// CP437 glyphs that smart wall drawing treats as connectable double-line cells.
+const DOUBLE_LINE_GLYPHS: [FontCharType; 12] =
+    [9, 185, 186, 187, 188, 200, 201, 202, 203, 204, 205, 206];
+
+/// Maps a wall-neighbor bitmask to the matching CP437 single-line glyph. The mask
+/// uses bit 1 for a connected neighbor to the north, 2 south, 4 west and 8 east -
+/// the conventional layout for walls computed from map adjacency.
+pub fn wall_glyph(mask: u8) -> FontCharType {
+    match mask & 15 {
+        0 => 9,            // ○ - isolated pillar
+        1..=3 => 179,      // │
+        4 | 8 | 12 => 196, // ─
+        5 => 217,          // ┘
+        6 => 191,          // ┐
+        7 => 180,          // ┤
+        9 => 192,          // └
+        10 => 218,         // ┌
+        11 => 195,         // ├
+        13 => 193,         // ┴
+        14 => 194,         // ┬
+        _ => 197,          // ┼
+    }
+}
+
+/// Maps a wall-neighbor bitmask to the matching CP437 double-line glyph, with the
+/// same bit layout as [`wall_glyph`].
+pub fn wall_glyph_double(mask: u8) -> FontCharType {
+    match mask & 15 {
+        0 => 9,            // ○ - isolated pillar
+        1..=3 => 186,      // ║
+        4 | 8 | 12 => 205, // ═
+        5 => 188,          // ╝
+        6 => 187,          // ╗
+        7 => 185,          // ╣
+        9 => 200,          // ╚
+        10 => 201,         // ╔
+        11 => 204,         // ╠
+        13 => 202,         // ╩
+        14 => 203,         // ╦
+        _ => 206,          // ╬
+    }
+}
+
+/// Builds the north/south/west/east connection bitmask for a cell, from the
+/// connectable line glyphs in its four neighbors.
+fn connection_mask(console: &dyn Console, x: i32, y: i32, connectable: &[FontCharType]) -> u8 {
+    let joined =
+        |glyph: Option<FontCharType>| glyph.is_some_and(|glyph| connectable.contains(&glyph));
+    let mut mask = 0;
+    if joined(console.get_glyph(x, y - 1)) {
+        mask += 1;
+    }
+    if joined(console.get_glyph(x, y + 1)) {
+        mask += 2;
+    }
+    if joined(console.get_glyph(x - 1, y)) {
+        mask += 4;
+    }
+    if joined(console.get_glyph(x + 1, y)) {
+        mask += 8;
+    }
+    mask
+}
+
+fn draw_smart_wall_with(
+    console: &mut dyn Console,
+    x: i32,
+    y: i32,
+    fg: RGBA,
+    bg: RGBA,
+    connectable: &[FontCharType],
+    glyph_for: fn(u8) -> FontCharType,
+) {
+    let mask = connection_mask(console, x, y, connectable);
+    console.set(x, y, fg, bg, glyph_for(mask));
+    // Re-join each neighboring line cell so it connects back to the new cell.
+    for (dx, dy) in [(0, -1), (0, 1), (-1, 0), (1, 0)] {
+        let (nx, ny) = (x + dx, y + dy);
+        if console
+            .get_glyph(nx, ny)
+            .is_some_and(|glyph| connectable.contains(&glyph))
+        {
+            let neighbor_mask = connection_mask(console, nx, ny, connectable);
+            console.set(nx, ny, fg, bg, glyph_for(neighbor_mask));
+        }
+    }
+}
+
+/// Places a single-line box/wall glyph at x/y, selecting the CP437 junction
+/// character (├, ┼, ┤ and friends) from the line glyphs already in the four
+/// neighboring cells, and re-joining those neighbors so they connect back.
+/// Neighbors are repainted with the same colors, since consoles cannot report
+/// cell colors. Requires a console type that can report cell contents (see
+/// [`Console::get_glyph`]).
+pub fn draw_smart_wall(console: &mut dyn Console, x: i32, y: i32, fg: RGBA, bg: RGBA) {
+    draw_smart_wall_with(console, x, y, fg, bg, &SINGLE_LINE_GLYPHS, wall_glyph);
+}
+
+/// [`draw_smart_wall`], using the CP437 double-line glyphs.
+pub fn draw_smart_wall_double(console: &mut dyn Console, x: i32, y: i32, fg: RGBA, bg: RGBA) {
+    draw_smart_wall_with(
+        console,
+        x,
+        y,
+        fg,
+        bg,
+        &DOUBLE_LINE_GLYPHS,
+        wall_glyph_double,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::VirtualConsole;
+    use bracket_geometry::prelude::Point;
+
+    #[test]
+    fn wall_glyphs_cover_the_junctions() {
+        assert_eq!(wall_glyph(0), 9);
+        assert_eq!(wall_glyph(1 | 2), 179);
+        assert_eq!(wall_glyph(4 | 8), 196);
+        assert_eq!(wall_glyph(1 | 2 | 8), 195);
+        assert_eq!(wall_glyph(15), 197);
+        assert_eq!(wall_glyph_double(1 | 2 | 4), 185);
+        assert_eq!(wall_glyph_double(15), 206);
+    }
+
+    #[test]
+    fn smart_walls_join_into_a_cross() {
+        let mut console = VirtualConsole::new(Point::new(5, 5));
+        let fg = RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+        let bg = RGBA::from_f32(0.0, 0.0, 0.0, 1.0);
+        for (x, y) in [(2, 1), (2, 3), (1, 2), (3, 2), (2, 2)] {
+            draw_smart_wall(&mut console, x, y, fg, bg);
+        }
+        assert_eq!(console.get_glyph(2, 2), Some(197)); // the center becomes ┼
+        assert_eq!(console.get_glyph(2, 1), Some(179)); // arms join back
+        assert_eq!(console.get_glyph(1, 2), Some(196));
+    }
+}
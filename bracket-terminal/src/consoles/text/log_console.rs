@@ -0,0 +1,191 @@
+use crate::prelude::{BEvent, DrawBatch, VirtualKeyCode};
+use bracket_color::prelude::{ColorPair, RGBA};
+use bracket_geometry::prelude::{Point, Rect};
+use std::collections::VecDeque;
+
+/// One logged message: text plus the colors to render it with.
+#[derive(Clone, Debug)]
+pub struct LogMessage {
+    pub text: String,
+    pub color: ColorPair,
+}
+
+/// A scrollback message log - the widget every roguelike rebuilds. Owns a ring buffer
+/// of colored messages, word-wraps them to a console region, and scrolls with the
+/// mouse wheel or PageUp/PageDown. Render it into any target console with `render`:
+///
+/// ```ignore
+/// log.render(&mut batch, Rect::with_size(0, 40, 80, 10));
+/// batch.submit(1000)?;
+/// ```
+pub struct LogConsole {
+    capacity: usize,
+    messages: VecDeque<LogMessage>,
+    /// Lines scrolled up from the newest message; 0 means pinned to the bottom.
+    scroll: usize,
+}
+
+impl LogConsole {
+    /// Creates a log retaining at most `capacity` messages; older ones are dropped.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            messages: VecDeque::with_capacity(capacity),
+            scroll: 0,
+        }
+    }
+
+    /// Appends a message in white-on-black.
+    pub fn log<S: ToString>(&mut self, text: S) {
+        self.log_color(
+            text,
+            ColorPair::new(
+                RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+                RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
+            ),
+        );
+    }
+
+    /// Appends a colored message.
+    pub fn log_color<S: ToString>(&mut self, text: S, color: ColorPair) {
+        if self.messages.len() == self.capacity {
+            self.messages.pop_front();
+        }
+        self.messages.push_back(LogMessage {
+            text: text.to_string(),
+            color,
+        });
+    }
+
+    /// Removes all messages and resets scrolling.
+    pub fn clear(&mut self) {
+        self.messages.clear();
+        self.scroll = 0;
+    }
+
+    /// Scrolls towards older messages.
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll += lines;
+    }
+
+    /// Scrolls towards newer messages.
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Jumps back to the newest message.
+    pub fn scroll_to_bottom(&mut self) {
+        self.scroll = 0;
+    }
+
+    /// Feeds an input event to the log: the mouse wheel scrolls by line,
+    /// PageUp/PageDown by `page_lines`. Returns true if the event was consumed.
+    pub fn handle_event(&mut self, event: &BEvent, page_lines: usize) -> bool {
+        match event {
+            BEvent::MouseWheel { delta } => {
+                if delta.y > 0.0 {
+                    self.scroll_up(delta.y.ceil() as usize);
+                } else if delta.y < 0.0 {
+                    self.scroll_down((-delta.y).ceil() as usize);
+                }
+                true
+            }
+            BEvent::KeyboardInput { key, pressed, .. } if *pressed => match key {
+                VirtualKeyCode::PageUp => {
+                    self.scroll_up(page_lines);
+                    true
+                }
+                VirtualKeyCode::PageDown => {
+                    self.scroll_down(page_lines);
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Word-wraps one message to `width` columns.
+    fn wrap(message: &LogMessage, width: usize) -> Vec<(String, ColorPair)> {
+        let mut lines = Vec::new();
+        let mut current = String::new();
+        for word in message.text.split_whitespace() {
+            if !current.is_empty() && current.len() + 1 + word.len() > width {
+                lines.push((current.clone(), message.color));
+                current.clear();
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            // Words longer than the region width are hard-split.
+            let mut word = word;
+            while current.len() + word.len() > width {
+                let split = width - current.len();
+                current.push_str(&word[..split]);
+                lines.push((current.clone(), message.color));
+                current.clear();
+                word = &word[split..];
+            }
+            current.push_str(word);
+        }
+        if !current.is_empty() || lines.is_empty() {
+            lines.push((current, message.color));
+        }
+        lines
+    }
+
+    /// Renders the visible window of the log into `region` (in console cells) on the
+    /// batch's current target console, newest message at the bottom. The scroll
+    /// position is clamped so the log cannot scroll past its oldest line.
+    pub fn render(&mut self, batch: &mut DrawBatch, region: Rect) {
+        let width = region.width().max(1) as usize;
+        let height = region.height().max(0) as usize;
+        if height == 0 {
+            return;
+        }
+
+        let wrapped: Vec<(String, ColorPair)> = self
+            .messages
+            .iter()
+            .flat_map(|m| LogConsole::wrap(m, width))
+            .collect();
+        self.scroll = self.scroll.min(wrapped.len().saturating_sub(height));
+
+        let last = wrapped.len() - self.scroll;
+        let first = last.saturating_sub(height);
+        for (i, (line, color)) in wrapped[first..last].iter().enumerate() {
+            batch.print_color(Point::new(region.x1, region.y1 + i as i32), line, *color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LogConsole;
+    use bracket_color::prelude::{ColorPair, RGBA};
+
+    #[test]
+    fn wrap_splits_on_word_boundaries() {
+        let msg = super::LogMessage {
+            text: "the quick brown fox".to_string(),
+            color: ColorPair::new(
+                RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+                RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
+            ),
+        };
+        let lines = LogConsole::wrap(&msg, 10);
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].0, "the quick");
+        assert_eq!(lines[1].0, "brown fox");
+    }
+
+    #[test]
+    fn ring_buffer_drops_oldest() {
+        let mut log = LogConsole::new(2);
+        log.log("one");
+        log.log("two");
+        log.log("three");
+        assert_eq!(log.messages.len(), 2);
+        assert_eq!(log.messages[0].text, "two");
+    }
+}
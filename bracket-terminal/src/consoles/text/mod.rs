@@ -1,11 +1,21 @@
 mod codepage437;
 mod format_string;
+mod glyph_run;
 mod gui_helpers;
+mod log_console;
 mod multi_tile_sprite;
+mod nine_slice;
+mod text_effect;
 mod textblock;
+mod word_wrap;
 
 pub use codepage437::*;
 pub(crate) use format_string::*;
+pub use glyph_run::*;
 pub use gui_helpers::*;
+pub use log_console::*;
 pub use multi_tile_sprite::*;
+pub use nine_slice::*;
+pub use text_effect::*;
 pub use textblock::*;
+pub use word_wrap::*;
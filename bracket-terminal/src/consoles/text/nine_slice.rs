@@ -0,0 +1,136 @@
+use crate::prelude::{to_cp437, Console, FontCharType};
+use bracket_color::prelude::RGBA;
+use bracket_geometry::prelude::Rect;
+
+/// A nine-slice border theme: the four corner glyphs, the four edge glyphs, and a
+/// fill glyph, drawn at any panel size. Build one from characters or glyph indices,
+/// and reuse it for every panel in your UI:
+///
+/// ```ignore
+/// let theme = BorderTheme::from_chars(['╔', '═', '╗', '║', ' ', '║', '╚', '═', '╝'])
+///     .with_shadow();
+/// theme.draw_panel(&mut console, Rect::with_size(5, 5, 30, 10), fg, bg, Some("Inventory"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct BorderTheme {
+    /// The nine glyphs, row-major: top-left, top, top-right, left, fill, right,
+    /// bottom-left, bottom, bottom-right.
+    pub glyphs: [FontCharType; 9],
+    /// Whether panels cast a one-cell drop shadow to their lower-right.
+    pub shadow: bool,
+}
+
+impl BorderTheme {
+    /// A theme from nine explicit glyph indices (e.g. sprite-font tile numbers).
+    pub fn new(glyphs: [FontCharType; 9]) -> Self {
+        Self {
+            glyphs,
+            shadow: false,
+        }
+    }
+
+    /// A theme from nine characters, translated through CP437.
+    pub fn from_chars(chars: [char; 9]) -> Self {
+        let mut glyphs = [0; 9];
+        for (glyph, c) in glyphs.iter_mut().zip(chars.iter()) {
+            *glyph = to_cp437(*c);
+        }
+        Self::new(glyphs)
+    }
+
+    /// The standard CP437 single-line box theme.
+    pub fn single_line() -> Self {
+        Self::from_chars(['┌', '─', '┐', '│', ' ', '│', '└', '─', '┘'])
+    }
+
+    /// The standard CP437 double-line box theme.
+    pub fn double_line() -> Self {
+        Self::from_chars(['╔', '═', '╗', '║', ' ', '║', '╚', '═', '╝'])
+    }
+
+    /// Enables a one-cell drop shadow on the panel's lower-right.
+    pub fn with_shadow(mut self) -> Self {
+        self.shadow = true;
+        self
+    }
+
+    /// Picks the theme glyph for a cell of a `width` x `height` panel: corner,
+    /// edge or fill depending on where the cell sits.
+    fn glyph_at(&self, x: i32, y: i32, width: i32, height: i32) -> FontCharType {
+        let column = if x == 0 {
+            0
+        } else if x == width - 1 {
+            2
+        } else {
+            1
+        };
+        let row = if y == 0 {
+            0
+        } else if y == height - 1 {
+            2
+        } else {
+            1
+        };
+        self.glyphs[(row * 3 + column) as usize]
+    }
+
+    /// Draws a panel covering `region`, with an optional title embedded in the top
+    /// border. The theme's shadow, if enabled, darkens the cells one to the right
+    /// of and below the panel.
+    pub fn draw_panel(
+        &self,
+        console: &mut dyn Console,
+        region: Rect,
+        fg: RGBA,
+        bg: RGBA,
+        title: Option<&str>,
+    ) {
+        let width = region.width() + 1;
+        let height = region.height() + 1;
+        if width < 2 || height < 2 {
+            return;
+        }
+        for y in 0..height {
+            for x in 0..width {
+                console.set(
+                    region.x1 + x,
+                    region.y1 + y,
+                    fg,
+                    bg,
+                    self.glyph_at(x, y, width, height),
+                );
+            }
+        }
+        if self.shadow {
+            let shade = RGBA::from_f32(0.0, 0.0, 0.0, fg.a);
+            for x in region.x1 + 1..=region.x2 + 1 {
+                console.set_bg(x, region.y2 + 1, shade);
+            }
+            for y in region.y1 + 1..=region.y2 + 1 {
+                console.set_bg(region.x2 + 1, y, shade);
+            }
+        }
+        if let Some(title) = title {
+            let title = format!(" {} ", title);
+            let max = (width - 2).max(0) as usize;
+            let title: String = title.chars().take(max).collect();
+            console.print_color(region.x1 + 1, region.y1, fg, bg, &title);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BorderTheme;
+
+    #[test]
+    fn glyph_selection_covers_corners_edges_and_fill() {
+        let theme = BorderTheme::new([0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(theme.glyph_at(0, 0, 5, 4), 0);
+        assert_eq!(theme.glyph_at(2, 0, 5, 4), 1);
+        assert_eq!(theme.glyph_at(4, 0, 5, 4), 2);
+        assert_eq!(theme.glyph_at(0, 2, 5, 4), 3);
+        assert_eq!(theme.glyph_at(2, 2, 5, 4), 4);
+        assert_eq!(theme.glyph_at(4, 3, 5, 4), 8);
+    }
+}
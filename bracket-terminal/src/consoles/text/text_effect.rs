@@ -0,0 +1,170 @@
+use crate::prelude::{to_cp437, ColorPair, DrawBatch};
+use bracket_color::prelude::{HSV, RGBA};
+use bracket_geometry::prelude::{Point, PointF};
+
+/// How an [`AnimatedText`] presents its string over time.
+#[derive(Clone, Debug)]
+pub enum TextEffect {
+    /// Reveals the string one character at a time.
+    Typewriter { chars_per_second: f32 },
+    /// Fades each character in over `duration_ms`, starting `stagger_ms` apart.
+    FadeIn { duration_ms: f32, stagger_ms: f32 },
+    /// Bobs each character on a sine wave. Requires a fancy (flexible) console
+    /// target, since the offsets are fractional.
+    Wobble { amplitude: f32, frequency_hz: f32 },
+    /// Cycles the foreground hue around the color wheel every `period_ms`.
+    ColorCycle { period_ms: f32 },
+}
+
+/// A string animated by a [`TextEffect`]. Advance it with `tick` each frame and
+/// draw it with `render`; the library keeps the timers, so dialogue scenes don't
+/// need their own:
+///
+/// ```ignore
+/// let mut line = AnimatedText::new(
+///     Point::new(2, 10),
+///     "Welcome, adventurer.",
+///     ColorPair::new(WHITE, BLACK),
+///     TextEffect::Typewriter { chars_per_second: 30.0 },
+/// );
+/// // each tick:
+/// line.tick(ctx.frame_time_ms);
+/// line.render(&mut batch);
+/// ```
+pub struct AnimatedText {
+    pub pos: Point,
+    text: Vec<char>,
+    pub color: ColorPair,
+    effect: TextEffect,
+    elapsed_ms: f32,
+}
+
+impl AnimatedText {
+    pub fn new<S: ToString>(pos: Point, text: S, color: ColorPair, effect: TextEffect) -> Self {
+        Self {
+            pos,
+            text: text.to_string().chars().collect(),
+            color,
+            effect,
+            elapsed_ms: 0.0,
+        }
+    }
+
+    /// Advances the effect by one frame (`ctx.frame_time_ms`).
+    pub fn tick(&mut self, frame_time_ms: f32) {
+        self.elapsed_ms += frame_time_ms;
+    }
+
+    /// Jumps to the fully-revealed state (e.g. when the player presses a key to
+    /// skip a typewriter reveal).
+    pub fn finish(&mut self) {
+        self.elapsed_ms = f32::MAX;
+    }
+
+    /// Whether the effect has run to completion. Continuous effects (wobble,
+    /// color cycling) never finish.
+    pub fn is_finished(&self) -> bool {
+        match self.effect {
+            TextEffect::Typewriter { chars_per_second } => {
+                self.elapsed_ms * chars_per_second / 1000.0 >= self.text.len() as f32
+            }
+            TextEffect::FadeIn {
+                duration_ms,
+                stagger_ms,
+            } => self.elapsed_ms >= stagger_ms * self.text.len() as f32 + duration_ms,
+            _ => false,
+        }
+    }
+
+    /// Draws the string in its current state onto the batch's current target.
+    pub fn render(&self, batch: &mut DrawBatch) {
+        match self.effect {
+            TextEffect::Typewriter { chars_per_second } => {
+                let visible = (self.elapsed_ms * chars_per_second / 1000.0) as usize;
+                for (i, c) in self.text.iter().take(visible).enumerate() {
+                    batch.print_color(
+                        Point::new(self.pos.x + i as i32, self.pos.y),
+                        c.to_string(),
+                        self.color,
+                    );
+                }
+            }
+            TextEffect::FadeIn {
+                duration_ms,
+                stagger_ms,
+            } => {
+                for (i, c) in self.text.iter().enumerate() {
+                    let t = (self.elapsed_ms - stagger_ms * i as f32) / duration_ms;
+                    if t <= 0.0 {
+                        continue;
+                    }
+                    let mut fg = self.color.fg;
+                    fg.a *= t.min(1.0);
+                    batch.print_color(
+                        Point::new(self.pos.x + i as i32, self.pos.y),
+                        c.to_string(),
+                        ColorPair::new(fg, self.color.bg),
+                    );
+                }
+            }
+            TextEffect::Wobble {
+                amplitude,
+                frequency_hz,
+            } => {
+                let phase = self.elapsed_ms / 1000.0 * frequency_hz * std::f32::consts::TAU;
+                for (i, c) in self.text.iter().enumerate() {
+                    // Offset each character's phase so the wave travels along the text.
+                    let y = self.pos.y as f32 + (phase + i as f32 * 0.5).sin() * amplitude;
+                    batch.set_fancy(
+                        PointF::new((self.pos.x + i as i32) as f32, y),
+                        0,
+                        bracket_geometry::prelude::Radians::new(0.0),
+                        PointF::new(1.0, 1.0),
+                        self.color,
+                        to_cp437(*c),
+                    );
+                }
+            }
+            TextEffect::ColorCycle { period_ms } => {
+                let base: HSV = self.color.fg.to_rgb().into();
+                for (i, c) in self.text.iter().enumerate() {
+                    let hue = (base.h
+                        + self.elapsed_ms / period_ms
+                        + i as f32 / self.text.len().max(1) as f32)
+                        .fract();
+                    let fg: RGBA = HSV::from_f32(hue, base.s.max(0.5), base.v.max(0.5))
+                        .to_rgba(self.color.fg.a);
+                    batch.print_color(
+                        Point::new(self.pos.x + i as i32, self.pos.y),
+                        c.to_string(),
+                        ColorPair::new(fg, self.color.bg),
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typewriter_finishes_after_reveal() {
+        let mut line = AnimatedText::new(
+            Point::new(0, 0),
+            "abcd",
+            ColorPair::new(
+                RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
+                RGBA::from_f32(0.0, 0.0, 0.0, 1.0),
+            ),
+            TextEffect::Typewriter {
+                chars_per_second: 10.0,
+            },
+        );
+        line.tick(100.0);
+        assert!(!line.is_finished());
+        line.tick(300.0);
+        assert!(line.is_finished());
+    }
+}
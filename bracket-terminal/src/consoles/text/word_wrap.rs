@@ -0,0 +1,106 @@
+/// How [`wrap_text`] aligns each wrapped line within the target width. This is
+/// distinct from [`TextAlign`](crate::prelude::TextAlign), which anchors a single
+/// line relative to an x coordinate.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum WrapAlign {
+    Left,
+    Center,
+    Right,
+    /// Pads the spaces between words so each line (except the last) fills the
+    /// full width.
+    Justify,
+}
+
+/// Wraps `text` on word boundaries to at most `width` columns, aligning each line
+/// per `align`. Words longer than `width` are hard-split. Returns the finished
+/// lines; callers print them and can size panels from the count.
+pub fn wrap_text(text: &str, width: usize, align: WrapAlign) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines: Vec<Vec<String>> = Vec::new();
+    let mut current: Vec<String> = Vec::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let mut word = word;
+        // A word that cannot fit on this line moves to the next one.
+        if !current.is_empty() && current_len + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+            current_len = 0;
+        }
+        // Words longer than the width are hard-split across lines.
+        while word.len() > width {
+            let (head, tail) = word.split_at(width);
+            lines.push(vec![head.to_string()]);
+            word = tail;
+        }
+        current_len += word.len() + if current.is_empty() { 0 } else { 1 };
+        current.push(word.to_string());
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+
+    let last = lines.len() - 1;
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, words)| {
+            let joined = words.join(" ");
+            let spare = width.saturating_sub(joined.len());
+            match align {
+                WrapAlign::Left => joined,
+                WrapAlign::Center => format!("{}{}", " ".repeat(spare / 2), joined),
+                WrapAlign::Right => format!("{}{}", " ".repeat(spare), joined),
+                WrapAlign::Justify => {
+                    // The last line, and lines without gaps, stay left-aligned.
+                    if i == last || words.len() < 2 || spare == 0 {
+                        joined
+                    } else {
+                        justify(words, spare)
+                    }
+                }
+            }
+        })
+        .collect()
+}
+
+/// Joins `words` with the `spare` extra spaces distributed across the gaps,
+/// leftmost gaps first.
+fn justify(words: &[String], spare: usize) -> String {
+    let gaps = words.len() - 1;
+    let mut line = String::new();
+    for (i, word) in words.iter().enumerate() {
+        line.push_str(word);
+        if i < gaps {
+            let extra = spare / gaps + usize::from(i < spare % gaps);
+            line.push_str(&" ".repeat(1 + extra));
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_on_word_boundaries() {
+        let lines = wrap_text("the quick brown fox jumps", 11, WrapAlign::Left);
+        assert_eq!(lines, vec!["the quick", "brown fox", "jumps"]);
+    }
+
+    #[test]
+    fn center_and_right_pad_lines() {
+        assert_eq!(wrap_text("hi", 6, WrapAlign::Center), vec!["  hi"]);
+        assert_eq!(wrap_text("hi", 6, WrapAlign::Right), vec!["    hi"]);
+    }
+
+    #[test]
+    fn justify_fills_all_but_the_last_line() {
+        let lines = wrap_text("a bb cc d e", 7, WrapAlign::Justify);
+        assert_eq!(lines, vec!["a bb cc", "d e"]);
+        let lines = wrap_text("a b c longer", 6, WrapAlign::Justify);
+        assert_eq!(lines[0], "a  b c");
+        assert_eq!(lines[1], "longer");
+    }
+}
@@ -197,6 +197,38 @@ impl Console for VirtualConsole {
         }
     }
 
+    /// Reads the glyph currently stored in a cell.
+    fn get_glyph(&self, x: i32, y: i32) -> Option<FontCharType> {
+        self.try_at(x, y).map(|idx| self.tiles[idx].glyph)
+    }
+
+    /// Prints a pre-encoded run of cells with a single slice copy.
+    fn print_run(&mut self, x: i32, y: i32, run: &[Tile]) {
+        // The clipped path keeps per-cell checks; the common case is one memcpy.
+        if self.extra_clipping.is_some() {
+            for (i, t) in run.iter().enumerate() {
+                if let Some(idx) = self.try_at(x + i as i32, y) {
+                    self.tiles[idx] = *t;
+                }
+            }
+            return;
+        }
+        if y < 0 || y >= self.height as i32 {
+            return;
+        }
+        let skip = (-x).max(0) as usize;
+        if skip >= run.len() {
+            return;
+        }
+        let start_x = x + skip as i32;
+        if start_x >= self.width as i32 {
+            return;
+        }
+        let count = (run.len() - skip).min((self.width as i32 - start_x) as usize);
+        let idx = self.at(start_x, y);
+        self.tiles[idx..idx + count].copy_from_slice(&run[skip..skip + count]);
+    }
+
     /// Sets a single cell in the console's background
     fn set_bg(&mut self, x: i32, y: i32, bg: RGBA) {
         if let Some(idx) = self.try_at(x, y) {
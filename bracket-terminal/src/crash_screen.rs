@@ -0,0 +1,159 @@
+//! Panic capture. With `enable_panic_capture()` active, a panic inside
+//! `GameState::tick` no longer tears the window down: the panic message and
+//! backtrace render into console 0 as a "blue screen" the player can read,
+//! and a crash log plus a final screenshot land on disk before the game
+//! exits. Without it, panics abort through the event loop as before.
+
+use crate::prelude::{BTerm, VirtualKeyCode};
+use bracket_color::prelude::RGB;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// File the crash log is written to, in the working directory.
+const CRASH_LOG_FILE: &str = "crash-log.txt";
+/// File the final screenshot is written to, in the working directory.
+const CRASH_SCREENSHOT_FILE: &str = "crash-screenshot.png";
+
+static CAPTURE_ENABLED: AtomicBool = AtomicBool::new(false);
+
+pub(crate) struct CrashReport {
+    pub message: String,
+    pub backtrace: String,
+    screenshot_requested: bool,
+}
+
+lazy_static! {
+    static ref CRASH: Mutex<Option<CrashReport>> = Mutex::new(None);
+}
+
+/// Installs a panic hook that records the panic message and backtrace, writes
+/// them to `crash-log.txt`, and lets the main loop divert to a readable crash
+/// screen instead of vanishing. Call once, before `main_loop`.
+pub fn enable_panic_capture() {
+    CAPTURE_ENABLED.store(true, Ordering::Relaxed);
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let message = if let Some(s) = info.payload().downcast_ref::<&str>() {
+            (*s).to_string()
+        } else if let Some(s) = info.payload().downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "Unknown panic payload".to_string()
+        };
+        let message = match info.location() {
+            Some(location) => format!("{} ({}:{})", message, location.file(), location.line()),
+            None => message,
+        };
+        let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let _ = std::fs::write(CRASH_LOG_FILE, format!("{}\n\n{}", message, backtrace));
+        }
+
+        *CRASH.lock() = Some(CrashReport {
+            message,
+            backtrace,
+            screenshot_requested: false,
+        });
+        previous(info);
+    }));
+}
+
+/// True when `enable_panic_capture` has been called.
+pub(crate) fn capture_enabled() -> bool {
+    CAPTURE_ENABLED.load(Ordering::Relaxed)
+}
+
+/// True once a panic has been captured; `tick` is not called again after this.
+pub(crate) fn crashed() -> bool {
+    CRASH.lock().is_some()
+}
+
+/// Renders the captured crash onto console 0 and handles the exit key. Runs in
+/// place of `GameState::tick` once a panic has been caught.
+pub(crate) fn crash_tick(bterm: &mut BTerm) {
+    let mut crash = CRASH.lock();
+    let report = crash.as_mut().unwrap();
+
+    bterm.set_active_console(0);
+    bterm.cls_bg(RGB::from_u8(0, 0, 170));
+    let (width, height) = bterm.get_char_size();
+    let fg = RGB::from_u8(255, 255, 255);
+    let bg = RGB::from_u8(0, 0, 170);
+
+    bterm.print_color_centered(1, RGB::from_u8(255, 255, 0), bg, "The game has crashed");
+    let mut y = 3;
+    for line in wrap_text(&report.message, width as usize - 2) {
+        if y >= height as i32 - 2 {
+            break;
+        }
+        bterm.print_color(1, y, fg, bg, line);
+        y += 1;
+    }
+    y += 1;
+    for line in report.backtrace.lines() {
+        if y >= height as i32 - 2 {
+            break;
+        }
+        let mut line = line.trim_end().to_string();
+        line.truncate(width as usize - 2);
+        bterm.print_color(1, y, RGB::from_u8(170, 170, 255), bg, line);
+        y += 1;
+    }
+    bterm.print_color_centered(
+        height as i32 - 1,
+        RGB::from_u8(255, 255, 0),
+        bg,
+        format!("Details in {} - press Escape to exit", CRASH_LOG_FILE),
+    );
+
+    // One screenshot of the crash screen itself, once it has rendered.
+    if !report.screenshot_requested {
+        report.screenshot_requested = true;
+        bterm.screenshot(CRASH_SCREENSHOT_FILE);
+    }
+
+    if bterm.key == Some(VirtualKeyCode::Escape) {
+        bterm.quit();
+    }
+}
+
+/// Greedy word-wrap for the panic message.
+fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.len() + word.len() + 1 > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::wrap_text;
+
+    #[test]
+    fn wrapping_respects_the_width() {
+        let lines = wrap_text("the quick brown fox jumps over the lazy dog", 12);
+        assert!(lines.iter().all(|l| l.len() <= 12));
+        assert_eq!(
+            lines.join(" "),
+            "the quick brown fox jumps over the lazy dog"
+        );
+    }
+
+    #[test]
+    fn short_text_stays_on_one_line() {
+        assert_eq!(wrap_text("hello world", 40), vec!["hello world"]);
+    }
+}
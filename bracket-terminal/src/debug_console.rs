@@ -0,0 +1,203 @@
+//! An optional Quake-style drop-down developer console. The game binds command
+//! callbacks with `BTerm::register_command`, forwards input events through
+//! `BTerm::debug_console_event` (which captures the keyboard while the console is
+//! open and passes everything through while it is closed), and draws the overlay
+//! each frame with `BTerm::render_debug_console`.
+
+use crate::prelude::{BEvent, DrawBatch, VirtualKeyCode};
+use bracket_color::prelude::{ColorPair, RGBA};
+use bracket_geometry::prelude::{Point, Rect};
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+
+/// A bound console command: receives the whitespace-split arguments (not including
+/// the command name) and may return a line of output to print.
+pub type CommandCallback = Box<dyn FnMut(&[String]) -> Option<String> + Send>;
+
+const OUTPUT_CAPACITY: usize = 100;
+
+lazy_static! {
+    pub(crate) static ref DEBUG_CONSOLE: Mutex<DebugConsoleState> =
+        Mutex::new(DebugConsoleState::new());
+}
+
+pub(crate) struct DebugConsoleState {
+    pub open: bool,
+    input_line: String,
+    history: Vec<String>,
+    history_pos: Option<usize>,
+    output: VecDeque<String>,
+    commands: HashMap<String, CommandCallback>,
+}
+
+impl DebugConsoleState {
+    fn new() -> Self {
+        Self {
+            open: false,
+            input_line: String::new(),
+            history: Vec::new(),
+            history_pos: None,
+            output: VecDeque::new(),
+            commands: HashMap::new(),
+        }
+    }
+
+    pub fn register_command<S: ToString>(&mut self, name: S, callback: CommandCallback) {
+        self.commands.insert(name.to_string(), callback);
+    }
+
+    fn print<S: ToString>(&mut self, line: S) {
+        if self.output.len() == OUTPUT_CAPACITY {
+            self.output.pop_front();
+        }
+        self.output.push_back(line.to_string());
+    }
+
+    fn execute(&mut self) {
+        let line = std::mem::take(&mut self.input_line);
+        self.history_pos = None;
+        if line.trim().is_empty() {
+            return;
+        }
+        self.print(format!("> {}", line));
+        self.history.push(line.clone());
+
+        let words: Vec<String> = line.split_whitespace().map(|s| s.to_string()).collect();
+        if let Some(callback) = self.commands.get_mut(&words[0]) {
+            if let Some(output) = callback(&words[1..]) {
+                self.print(output);
+            }
+        } else if words[0] == "help" {
+            let mut names: Vec<&String> = self.commands.keys().collect();
+            names.sort();
+            let listing = names
+                .iter()
+                .map(|s| s.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.print(format!("Commands: {}", listing));
+        } else {
+            self.print(format!("Unknown command: {}", words[0]));
+        }
+    }
+
+    fn history_up(&mut self) {
+        if self.history.is_empty() {
+            return;
+        }
+        let pos = match self.history_pos {
+            None => self.history.len() - 1,
+            Some(p) => p.saturating_sub(1),
+        };
+        self.history_pos = Some(pos);
+        self.input_line = self.history[pos].clone();
+    }
+
+    fn history_down(&mut self) {
+        match self.history_pos {
+            Some(p) if p + 1 < self.history.len() => {
+                self.history_pos = Some(p + 1);
+                self.input_line = self.history[p + 1].clone();
+            }
+            Some(_) => {
+                self.history_pos = None;
+                self.input_line.clear();
+            }
+            None => {}
+        }
+    }
+
+    /// Handles one input event. Returns true if the console consumed it.
+    pub fn handle_event(&mut self, event: &BEvent) -> bool {
+        if !self.open {
+            // Pass everything through except the toggle key.
+            if let BEvent::KeyboardInput {
+                key: VirtualKeyCode::Grave,
+                pressed: true,
+                ..
+            } = event
+            {
+                self.open = true;
+                return true;
+            }
+            return false;
+        }
+
+        match event {
+            BEvent::Character { c } => {
+                if !c.is_control() && *c != '`' {
+                    self.input_line.push(*c);
+                }
+                true
+            }
+            BEvent::KeyboardInput { key, pressed, .. } if *pressed => {
+                match key {
+                    VirtualKeyCode::Grave | VirtualKeyCode::Escape => self.open = false,
+                    VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => self.execute(),
+                    VirtualKeyCode::Back => {
+                        self.input_line.pop();
+                    }
+                    VirtualKeyCode::Up => self.history_up(),
+                    VirtualKeyCode::Down => self.history_down(),
+                    _ => {}
+                }
+                true
+            }
+            // Swallow key releases too, so the game doesn't see stray up-events.
+            BEvent::KeyboardInput { .. } => true,
+            _ => false,
+        }
+    }
+
+    /// Draws the console into `region` on the batch's current target: a dimmed
+    /// backdrop, the most recent output lines, and the entry line with a caret.
+    pub fn render(&self, batch: &mut DrawBatch, region: Rect) {
+        if !self.open {
+            return;
+        }
+        let fg = RGBA::from_f32(0.8, 0.8, 0.8, 1.0);
+        let bg = RGBA::from_f32(0.0, 0.0, 0.1, 0.85);
+        batch.fill_region(region, ColorPair::new(fg, bg), 32);
+
+        let entry_y = region.y2 - 1;
+        let visible = (entry_y - region.y1).max(0) as usize;
+        let first = self.output.len().saturating_sub(visible);
+        for (i, line) in self.output.iter().skip(first).enumerate() {
+            batch.print_color(
+                Point::new(region.x1, region.y1 + i as i32),
+                line,
+                ColorPair::new(fg, bg),
+            );
+        }
+        batch.print_color(
+            Point::new(region.x1, entry_y),
+            format!("> {}_", self.input_line),
+            ColorPair::new(RGBA::from_f32(1.0, 1.0, 1.0, 1.0), bg),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DebugConsoleState;
+
+    #[test]
+    fn executes_registered_commands() {
+        let mut console = DebugConsoleState::new();
+        console.register_command("echo", Box::new(|args| Some(args.join(" "))));
+        console.input_line = "echo hello world".to_string();
+        console.execute();
+        assert_eq!(console.output.back().unwrap(), "hello world");
+    }
+
+    #[test]
+    fn unknown_commands_report_an_error() {
+        let mut console = DebugConsoleState::new();
+        console.input_line = "frobnicate".to_string();
+        console.execute();
+        assert_eq!(
+            console.output.back().unwrap(),
+            "Unknown command: frobnicate"
+        );
+    }
+}
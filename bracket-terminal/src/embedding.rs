@@ -40,4 +40,13 @@ impl Dictionary {
     pub fn add_resource(&mut self, path: String, bytes: &'static [u8]) {
         self.entries.insert(path, bytes);
     }
+
+    /// Registers an owned byte buffer as a resource at runtime - for assets
+    /// unpacked from an archive or downloaded after startup. The buffer lives
+    /// for the rest of the program; leaking it lets it share the
+    /// `&'static [u8]` entries `link_resource!` creates at compile time.
+    pub fn insert_bytes(&mut self, path: String, bytes: Vec<u8>) {
+        self.entries
+            .insert(path, Box::leak(bytes.into_boxed_slice()));
+    }
 }
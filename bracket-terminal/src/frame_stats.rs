@@ -0,0 +1,162 @@
+//! Frame-time history. The main loops record how long each frame spent in the
+//! game tick, console rebuild, rendering and presentation; `BTerm::frame_stats`
+//! hands back the recent history so games can graph performance and spot
+//! hitches that a smoothed FPS number hides.
+
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+
+/// How many frames of history are kept unless reconfigured.
+const DEFAULT_CAPACITY: usize = 240;
+
+/// One frame's phase timings, in milliseconds.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimes {
+    /// `GameState::tick` (and any fixed-step updates).
+    pub tick: f32,
+    /// Rebuilding dirty console vertex buffers.
+    pub rebuild: f32,
+    /// Rendering the consoles, including any post-process pass.
+    pub render: f32,
+    /// Presenting the frame (buffer swap; blocks on vsync).
+    pub present: f32,
+    /// The whole frame, start to presented.
+    pub total: f32,
+}
+
+/// A ring buffer of recent [`FrameTimes`], oldest first.
+#[derive(Clone)]
+pub struct FrameStats {
+    frames: VecDeque<FrameTimes>,
+    capacity: usize,
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self {
+            frames: VecDeque::with_capacity(DEFAULT_CAPACITY),
+            capacity: DEFAULT_CAPACITY,
+        }
+    }
+}
+
+impl FrameStats {
+    /// The recorded frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = &FrameTimes> {
+        self.frames.iter()
+    }
+
+    /// The most recently completed frame.
+    pub fn latest(&self) -> Option<FrameTimes> {
+        self.frames.back().copied()
+    }
+
+    /// Per-phase averages over the recorded history.
+    pub fn average(&self) -> FrameTimes {
+        if self.frames.is_empty() {
+            return FrameTimes::default();
+        }
+        let mut sum = FrameTimes::default();
+        for frame in &self.frames {
+            sum.tick += frame.tick;
+            sum.rebuild += frame.rebuild;
+            sum.render += frame.render;
+            sum.present += frame.present;
+            sum.total += frame.total;
+        }
+        let n = self.frames.len() as f32;
+        FrameTimes {
+            tick: sum.tick / n,
+            rebuild: sum.rebuild / n,
+            render: sum.render / n,
+            present: sum.present / n,
+            total: sum.total / n,
+        }
+    }
+
+    /// The slowest recorded frame, by total time.
+    pub fn worst(&self) -> Option<FrameTimes> {
+        self.frames
+            .iter()
+            .copied()
+            .max_by(|a, b| a.total.partial_cmp(&b.total).unwrap())
+    }
+
+    /// How many frames are recorded.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// True when no frames have been recorded yet.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub(crate) fn record(&mut self, frame: FrameTimes) {
+        while self.frames.len() >= self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    pub(crate) fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.frames.len() > self.capacity {
+            self.frames.pop_front();
+        }
+    }
+}
+
+lazy_static! {
+    pub(crate) static ref FRAME_STATS: Mutex<FrameStats> = Mutex::new(FrameStats::default());
+}
+
+/// Appends one frame's timings to the history. Called by the back-ends.
+pub(crate) fn record(frame: FrameTimes) {
+    FRAME_STATS.lock().record(frame);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(total: f32) -> FrameTimes {
+        FrameTimes {
+            total,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn history_is_bounded_and_ordered() {
+        let mut stats = FrameStats::default();
+        stats.set_capacity(3);
+        for i in 0..5 {
+            stats.record(frame(i as f32));
+        }
+        assert_eq!(stats.len(), 3);
+        let totals: Vec<f32> = stats.frames().map(|f| f.total).collect();
+        assert_eq!(totals, vec![2.0, 3.0, 4.0]);
+        assert_eq!(stats.latest(), Some(frame(4.0)));
+    }
+
+    #[test]
+    fn averages_and_worst_cover_the_window() {
+        let mut stats = FrameStats::default();
+        stats.record(frame(10.0));
+        stats.record(frame(20.0));
+        assert_eq!(stats.average().total, 15.0);
+        assert_eq!(stats.worst(), Some(frame(20.0)));
+    }
+
+    #[test]
+    fn shrinking_the_capacity_drops_the_oldest() {
+        let mut stats = FrameStats::default();
+        for i in 0..10 {
+            stats.record(frame(i as f32));
+        }
+        stats.set_capacity(2);
+        let totals: Vec<f32> = stats.frames().map(|f| f.total).collect();
+        assert_eq!(totals, vec![8.0, 9.0]);
+    }
+}
@@ -3,4 +3,10 @@ pub use crate::prelude::BTerm;
 /// Implement this trait on your state struct, so the engine knows what to call on each tick.
 pub trait GameState: 'static {
     fn tick(&mut self, ctx: &mut BTerm);
+
+    /// Optional: called at a fixed rate (configured with `BTermBuilder::with_fixed_time_step`),
+    /// zero or more times per rendered frame, for deterministic simulation. `tick` is still
+    /// called every frame for rendering, with `ctx.interpolation_alpha` indicating how far
+    /// into the current fixed step the frame landed. The default implementation does nothing.
+    fn fixed_tick(&mut self, _ctx: &mut BTerm) {}
 }
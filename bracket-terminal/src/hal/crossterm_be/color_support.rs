@@ -0,0 +1,184 @@
+//! Terminal color capability detection and degradation. Modern terminals
+//! accept 24-bit SGR sequences, but plenty of terminals (and multiplexers)
+//! only honor the 256-color or classic 16-color palettes. The active mode is
+//! detected from the environment at startup, and may be overridden through
+//! `InitHints`/`BTermBuilder::with_terminal_color_mode`.
+
+use bracket_color::prelude::RGBA;
+
+/// How colors are emitted to the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminalColorMode {
+    /// Emit 24-bit SGR sequences; colors render exactly as specified.
+    TrueColor,
+    /// Quantize to the nearest entry of the xterm 256-color palette.
+    Palette256,
+    /// Quantize to the nearest of the 16 standard ANSI colors.
+    Palette16,
+}
+
+/// Guesses the terminal's color capability from the environment: `COLORTERM`
+/// advertising `truecolor`/`24bit` wins, then a `TERM` containing `256color`,
+/// otherwise the lowest common denominator of 16 colors.
+pub fn detect_color_mode() -> TerminalColorMode {
+    if let Ok(colorterm) = std::env::var("COLORTERM") {
+        let colorterm = colorterm.to_lowercase();
+        if colorterm.contains("truecolor") || colorterm.contains("24bit") {
+            return TerminalColorMode::TrueColor;
+        }
+    }
+    if let Ok(term) = std::env::var("TERM") {
+        if term.contains("256color") {
+            return TerminalColorMode::Palette256;
+        }
+    }
+    TerminalColorMode::Palette16
+}
+
+/// Converts an RGBA color to the crossterm color that best represents it in
+/// the given mode.
+pub(crate) fn to_crossterm_color(color: RGBA, mode: TerminalColorMode) -> crossterm::style::Color {
+    let r = (color.r * 255.0) as u8;
+    let g = (color.g * 255.0) as u8;
+    let b = (color.b * 255.0) as u8;
+    match mode {
+        TerminalColorMode::TrueColor => crossterm::style::Color::Rgb { r, g, b },
+        TerminalColorMode::Palette256 => {
+            crossterm::style::Color::AnsiValue(nearest_ansi256(r, g, b))
+        }
+        TerminalColorMode::Palette16 => crossterm::style::Color::AnsiValue(nearest_ansi16(r, g, b)),
+    }
+}
+
+/// Maps a color channel (0-255) onto the 6-step cube axis used by entries
+/// 16-231 of the xterm palette.
+fn cube_index(channel: u8) -> u8 {
+    if channel < 48 {
+        0
+    } else if channel < 115 {
+        1
+    } else {
+        (channel - 35) / 40
+    }
+}
+
+/// The actual channel value a cube axis index renders as.
+fn cube_value(index: u8) -> u8 {
+    if index == 0 {
+        0
+    } else {
+        55 + index * 40
+    }
+}
+
+/// The RGB value an xterm 256-color palette entry renders as.
+pub(crate) fn xterm256_rgb(code: u8) -> (u8, u8, u8) {
+    match code {
+        0..=15 => ANSI16[code as usize],
+        16..=231 => {
+            let c = code - 16;
+            (
+                cube_value(c / 36),
+                cube_value((c / 6) % 6),
+                cube_value(c % 6),
+            )
+        }
+        _ => {
+            let v = 8 + (code - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+/// Nearest entry in the xterm 256-color palette: the best of the 6x6x6 color
+/// cube (16-231) and the 24-step grayscale ramp (232-255).
+pub(crate) fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let ir = cube_index(r);
+    let ig = cube_index(g);
+    let ib = cube_index(b);
+    let cube_dist = distance((r, g, b), (cube_value(ir), cube_value(ig), cube_value(ib)));
+
+    // Grayscale ramp: 232 + n renders as 8 + 10n.
+    let average = (u32::from(r) + u32::from(g) + u32::from(b)) / 3;
+    let gray_step = if average > 238 {
+        23
+    } else {
+        (average.saturating_sub(3) / 10) as u8
+    };
+    let gray_value = 8 + gray_step * 10;
+    let gray_dist = distance((r, g, b), (gray_value, gray_value, gray_value));
+
+    if gray_dist < cube_dist {
+        232 + gray_step
+    } else {
+        16 + 36 * ir + 6 * ig + ib
+    }
+}
+
+/// RGB values of the 16 standard ANSI colors.
+const ANSI16: [(u8, u8, u8); 16] = [
+    (0, 0, 0),       // Black
+    (128, 0, 0),     // DarkRed
+    (0, 128, 0),     // DarkGreen
+    (128, 128, 0),   // DarkYellow
+    (0, 0, 128),     // DarkBlue
+    (128, 0, 128),   // DarkMagenta
+    (0, 128, 128),   // DarkCyan
+    (192, 192, 192), // Grey
+    (128, 128, 128), // DarkGrey
+    (255, 0, 0),     // Red
+    (0, 255, 0),     // Green
+    (255, 255, 0),   // Yellow
+    (0, 0, 255),     // Blue
+    (255, 0, 255),   // Magenta
+    (0, 255, 255),   // Cyan
+    (255, 255, 255), // White
+];
+
+/// Nearest of the 16 standard ANSI colors.
+fn nearest_ansi16(r: u8, g: u8, b: u8) -> u8 {
+    let mut best = 0;
+    let mut best_dist = u32::MAX;
+    for (i, entry) in ANSI16.iter().enumerate() {
+        let dist = distance((r, g, b), *entry);
+        if dist < best_dist {
+            best = i as u8;
+            best_dist = dist;
+        }
+    }
+    best
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let dr = i32::from(a.0) - i32::from(b.0);
+    let dg = i32::from(a.1) - i32::from(b.1);
+    let db = i32::from(a.2) - i32::from(b.2);
+    (dr * dr + dg * dg + db * db) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn primaries_map_to_cube_corners() {
+        assert_eq!(nearest_ansi256(255, 0, 0), 196);
+        assert_eq!(nearest_ansi256(0, 255, 0), 46);
+        assert_eq!(nearest_ansi256(0, 0, 255), 21);
+        assert_eq!(nearest_ansi256(0, 0, 0), 16);
+    }
+
+    #[test]
+    fn grays_prefer_the_grayscale_ramp() {
+        let code = nearest_ansi256(120, 120, 120);
+        assert!((232..=255).contains(&code));
+    }
+
+    #[test]
+    fn sixteen_color_quantization() {
+        assert_eq!(nearest_ansi16(255, 0, 0), 9);
+        assert_eq!(nearest_ansi16(0, 0, 0), 0);
+        assert_eq!(nearest_ansi16(250, 250, 250), 15);
+        assert_eq!(nearest_ansi16(130, 130, 130), 8);
+    }
+}
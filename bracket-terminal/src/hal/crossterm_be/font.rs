@@ -2,16 +2,20 @@ use crate::BResult;
 
 #[derive(Clone)]
 pub struct Font {
+    pub bitmap_file: String,
     pub tile_size: (u32, u32),
 }
 
 impl Font {
     pub fn load<S: ToString>(
-        _filename: S,
-        _tile_size: (u32, u32),
+        filename: S,
+        tile_size: (u32, u32),
         _explicit_background: Option<bracket_color::prelude::RGB>,
     ) -> Font {
-        Font { tile_size: (0, 0) }
+        Font {
+            bitmap_file: filename.to_string(),
+            tile_size,
+        }
     }
 
     pub fn setup_gl_texture(&mut self, _gl: &crate::hal::BTermPlatform) -> BResult<()> {
@@ -0,0 +1,375 @@
+//! Pixel-graphics output for capable terminals. Instead of approximating the
+//! consoles with characters, the frame is rasterized on the CPU with the real
+//! font bitmaps and shipped to the terminal through the kitty graphics
+//! protocol or sixels. Enabled with `BTermBuilder::with_terminal_graphics`;
+//! the protocol is chosen by sniffing the environment.
+
+use super::color_support::{nearest_ansi256, xterm256_rgb};
+use crate::consoles::Console;
+use crate::prelude::{embedding, SimpleConsole, SparseConsole, BACKEND_INTERNAL};
+use crate::BResult;
+use bracket_color::prelude::RGBA;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::io::{stdout, Write};
+
+/// Which pixel-graphics protocol frames are emitted with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TerminalGraphics {
+    /// Character-cell rendering; no graphics protocol.
+    Off,
+    /// The kitty graphics protocol (kitty, WezTerm, Konsole).
+    Kitty,
+    /// DEC sixels (xterm -ti vt340, mlterm, foot and friends).
+    Sixel,
+}
+
+/// Guesses which graphics protocol the terminal speaks. Kitty advertises
+/// itself through `KITTY_WINDOW_ID`/`TERM`; sixel support has no reliable
+/// marker, so a handful of known terminals are matched by name.
+pub fn detect_terminal_graphics() -> TerminalGraphics {
+    if std::env::var("KITTY_WINDOW_ID").is_ok() {
+        return TerminalGraphics::Kitty;
+    }
+    if let Ok(term) = std::env::var("TERM") {
+        if term.contains("kitty") {
+            return TerminalGraphics::Kitty;
+        }
+        if term.contains("sixel") || term.contains("mlterm") || term.contains("foot") {
+            return TerminalGraphics::Sixel;
+        }
+    }
+    TerminalGraphics::Off
+}
+
+/// An RGBA font sheet decoded from the console font's bitmap file.
+struct FontSheet {
+    pixels: Vec<u8>,
+    width: u32,
+    height: u32,
+}
+
+lazy_static! {
+    static ref FONT_SHEETS: Mutex<HashMap<String, FontSheet>> = Mutex::new(HashMap::new());
+}
+
+fn load_font_sheet(filename: &str) -> BResult<()> {
+    let mut sheets = FONT_SHEETS.lock();
+    if sheets.contains_key(filename) {
+        return Ok(());
+    }
+    let resource = embedding::EMBED.lock().get_resource(filename.to_string());
+    let img = match resource {
+        Some(bytes) => image::load_from_memory(bytes)?,
+        None => image::open(std::path::Path::new(filename))?,
+    }
+    .to_rgba8();
+    let (width, height) = (img.width(), img.height());
+    sheets.insert(
+        filename.to_string(),
+        FontSheet {
+            pixels: img.into_raw(),
+            width,
+            height,
+        },
+    );
+    Ok(())
+}
+
+/// Rasterizes every console into one RGB frame and emits it via the selected
+/// protocol. Skips the frame entirely when no console is dirty.
+pub(crate) fn render_frame(mode: TerminalGraphics) -> BResult<()> {
+    let mut bi = BACKEND_INTERNAL.lock();
+
+    let mut any_dirty = false;
+    for cons in bi.consoles.iter() {
+        if let Some(sc) = cons.console.as_any().downcast_ref::<SimpleConsole>() {
+            any_dirty |= sc.is_dirty;
+        } else if let Some(sp) = cons.console.as_any().downcast_ref::<SparseConsole>() {
+            any_dirty |= sp.is_dirty;
+        }
+    }
+    if !any_dirty {
+        return Ok(());
+    }
+
+    // Frame dimensions: the largest console footprint in font pixels.
+    let mut frame_w = 0;
+    let mut frame_h = 0;
+    for cons in bi.consoles.iter() {
+        let (w, h) = cons.console.get_char_size();
+        let tile = bi.fonts[cons.font_index].tile_size;
+        frame_w = frame_w.max(w * tile.0);
+        frame_h = frame_h.max(h * tile.1);
+    }
+    if frame_w == 0 || frame_h == 0 {
+        return Ok(());
+    }
+
+    let mut frame = vec![0u8; (frame_w * frame_h * 3) as usize];
+    let num_consoles = bi.consoles.len();
+    for i in 0..num_consoles {
+        let (tile, bitmap_file) = {
+            let font = &bi.fonts[bi.consoles[i].font_index];
+            (font.tile_size, font.bitmap_file.clone())
+        };
+        if tile.0 == 0 || tile.1 == 0 {
+            continue;
+        }
+        load_font_sheet(&bitmap_file)?;
+        let sheets = FONT_SHEETS.lock();
+        let sheet = &sheets[&bitmap_file];
+
+        let cons_any = bi.consoles[i].console.as_any_mut();
+        if let Some(sc) = cons_any.downcast_mut::<SimpleConsole>() {
+            sc.clear_dirty();
+            let (w, h) = (sc.width, sc.height);
+            for (idx, t) in sc.tiles.iter().enumerate() {
+                let x = idx as u32 % w;
+                let y = h - 1 - (idx as u32 / w);
+                blit_glyph(
+                    &mut frame,
+                    (frame_w, frame_h),
+                    sheet,
+                    tile,
+                    (x, y),
+                    t.glyph,
+                    t.fg,
+                    t.bg,
+                );
+            }
+        } else if let Some(sp) = cons_any.downcast_mut::<SparseConsole>() {
+            sp.clear_dirty();
+            let (w, h) = (sp.width, sp.height);
+            for t in sp.tiles.iter() {
+                let x = t.idx as u32 % w;
+                let y = h - 1 - (t.idx as u32 / w);
+                blit_glyph(
+                    &mut frame,
+                    (frame_w, frame_h),
+                    sheet,
+                    tile,
+                    (x, y),
+                    t.glyph,
+                    t.fg,
+                    t.bg,
+                );
+            }
+        }
+    }
+    std::mem::drop(bi);
+
+    let mut out = stdout();
+    crossterm::queue!(out, crossterm::cursor::MoveTo(0, 0))?;
+    match mode {
+        TerminalGraphics::Kitty => emit_kitty(&mut out, &frame, frame_w, frame_h)?,
+        TerminalGraphics::Sixel => emit_sixel(&mut out, &frame, frame_w, frame_h)?,
+        TerminalGraphics::Off => {}
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Draws one glyph cell into the frame: the font texel tints the foreground,
+/// texel transparency shows the background - the same math as the shaders.
+#[allow(clippy::too_many_arguments)]
+fn blit_glyph(
+    frame: &mut [u8],
+    frame_size: (u32, u32),
+    sheet: &FontSheet,
+    tile: (u32, u32),
+    cell: (u32, u32),
+    glyph: crate::FontCharType,
+    fg: RGBA,
+    bg: RGBA,
+) {
+    let glyphs_per_row = sheet.width / tile.0;
+    let src_x = (u32::from(glyph) % glyphs_per_row) * tile.0;
+    let src_y = (u32::from(glyph) / glyphs_per_row) * tile.1;
+    if src_x + tile.0 > sheet.width || src_y + tile.1 > sheet.height {
+        return;
+    }
+
+    for py in 0..tile.1 {
+        let dst_y = cell.1 * tile.1 + py;
+        if dst_y >= frame_size.1 {
+            continue;
+        }
+        for px in 0..tile.0 {
+            let dst_x = cell.0 * tile.0 + px;
+            if dst_x >= frame_size.0 {
+                continue;
+            }
+            let src = ((src_y + py) * sheet.width + src_x + px) as usize * 4;
+            let alpha = (f32::from(sheet.pixels[src + 3]) / 255.0) * fg.a;
+            let dst = (dst_y * frame_size.0 + dst_x) as usize * 3;
+            let blend = |t: u8, f: f32, b: f32, under: u8| {
+                let lit = (f32::from(t) / 255.0) * f * alpha;
+                let back = (b * bg.a) + (f32::from(under) / 255.0) * (1.0 - bg.a);
+                ((lit + back * (1.0 - alpha)) * 255.0) as u8
+            };
+            frame[dst] = blend(sheet.pixels[src], fg.r, bg.r, frame[dst]);
+            frame[dst + 1] = blend(sheet.pixels[src + 1], fg.g, bg.g, frame[dst + 1]);
+            frame[dst + 2] = blend(sheet.pixels[src + 2], fg.b, bg.b, frame[dst + 2]);
+        }
+    }
+}
+
+const BASE64_ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Standard base64 with padding; enough for the kitty payload, so no external
+/// dependency is pulled in.
+fn base64_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        out.push(BASE64_ALPHABET[(b[0] >> 2) as usize] as char);
+        out.push(BASE64_ALPHABET[(((b[0] & 0x03) << 4) | (b[1] >> 4)) as usize] as char);
+        if chunk.len() > 1 {
+            out.push(BASE64_ALPHABET[(((b[1] & 0x0f) << 2) | (b[2] >> 6)) as usize] as char);
+        } else {
+            out.push('=');
+        }
+        if chunk.len() > 2 {
+            out.push(BASE64_ALPHABET[(b[2] & 0x3f) as usize] as char);
+        } else {
+            out.push('=');
+        }
+    }
+    out
+}
+
+/// Kitty graphics protocol: a direct (f=24) RGB transmission, chunked into
+/// 4096-byte base64 payloads as the spec requires.
+fn emit_kitty(out: &mut dyn Write, frame: &[u8], width: u32, height: u32) -> BResult<()> {
+    let payload = base64_encode(frame);
+    let chunks: Vec<&str> = payload
+        .as_bytes()
+        .chunks(4096)
+        .map(|c| std::str::from_utf8(c).unwrap())
+        .collect();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = if i + 1 < chunks.len() { 1 } else { 0 };
+        if i == 0 {
+            write!(
+                out,
+                "\x1b_Gf=24,s={},v={},a=T,m={};{}\x1b\\",
+                width, height, more, chunk
+            )?;
+        } else {
+            write!(out, "\x1b_Gm={};{}\x1b\\", more, chunk)?;
+        }
+    }
+    Ok(())
+}
+
+/// Sixel output: the frame is quantized to the xterm 256-color palette and
+/// emitted in six-row bands with run-length encoding.
+fn emit_sixel(out: &mut dyn Write, frame: &[u8], width: u32, height: u32) -> BResult<()> {
+    let codes: Vec<u8> = frame
+        .chunks(3)
+        .map(|p| nearest_ansi256(p[0], p[1], p[2]))
+        .collect();
+
+    write!(out, "\x1bPq\"1;1;{};{}", width, height)?;
+
+    // Register only the palette entries the frame actually uses.
+    let mut used = [false; 256];
+    for c in &codes {
+        used[*c as usize] = true;
+    }
+    for (code, in_use) in used.iter().enumerate() {
+        if *in_use {
+            let (r, g, b) = xterm256_rgb(code as u8);
+            write!(
+                out,
+                "#{};2;{};{};{}",
+                code,
+                u32::from(r) * 100 / 255,
+                u32::from(g) * 100 / 255,
+                u32::from(b) * 100 / 255
+            )?;
+        }
+    }
+
+    for band in 0..height.div_ceil(6) {
+        let mut first_color_in_band = true;
+        for (code, in_use) in used.iter().enumerate() {
+            if !*in_use {
+                continue;
+            }
+            let mut line = Vec::with_capacity(width as usize);
+            let mut any = false;
+            for x in 0..width {
+                let mut mask = 0u8;
+                for bit in 0..6 {
+                    let y = band * 6 + bit;
+                    if y < height && codes[(y * width + x) as usize] == code as u8 {
+                        mask |= 1 << bit;
+                    }
+                }
+                any |= mask != 0;
+                line.push(mask + 63);
+            }
+            if !any {
+                continue;
+            }
+            if !first_color_in_band {
+                out.write_all(b"$")?;
+            }
+            first_color_in_band = false;
+            write!(out, "#{}", code)?;
+            write_sixel_rle(out, &line)?;
+        }
+        out.write_all(b"-")?;
+    }
+    out.write_all(b"\x1b\\")?;
+    Ok(())
+}
+
+/// Run-length encodes one sixel data line with the `!<count>` repeat
+/// introducer.
+fn write_sixel_rle(out: &mut dyn Write, line: &[u8]) -> BResult<()> {
+    let mut i = 0;
+    while i < line.len() {
+        let ch = line[i];
+        let mut run = 1;
+        while i + run < line.len() && line[i + run] == ch {
+            run += 1;
+        }
+        if run > 3 {
+            write!(out, "!{}", run)?;
+            out.write_all(&[ch])?;
+        } else {
+            for _ in 0..run {
+                out.write_all(&[ch])?;
+            }
+        }
+        i += run;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_values() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn sixel_rle_compresses_runs() {
+        let mut out = Vec::new();
+        write_sixel_rle(&mut out, &[100, 100, 100, 100, 100, 64]).unwrap();
+        assert_eq!(out, b"!5d@");
+    }
+}
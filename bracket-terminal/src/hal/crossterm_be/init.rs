@@ -27,6 +27,15 @@ pub fn init_raw<S: ToString>(
     be.old_width = old_size.0;
     be.old_height = old_size.1;
     be.frame_sleep_time = crate::hal::convert_fps_to_wait(platform_hints.frame_sleep_time);
+    be.resize_scaling = platform_hints.resize_scaling;
+    be.color_mode = platform_hints
+        .color_mode
+        .unwrap_or_else(super::detect_color_mode);
+    be.graphics = if platform_hints.terminal_graphics {
+        super::detect_terminal_graphics()
+    } else {
+        super::TerminalGraphics::Off
+    };
 
     let bterm = BTerm {
         width_pixels,
@@ -46,7 +55,12 @@ pub fn init_raw<S: ToString>(
         quitting: false,
         post_scanlines: false,
         post_screenburn: false,
+        interpolation_alpha: 0.0,
+        ups: 0.0,
+        update_time_ms: 0.0,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        crt_params: crate::prelude::CrtParams::default(),
+        colorblind_mode: None,
     };
     Ok(bterm)
 }
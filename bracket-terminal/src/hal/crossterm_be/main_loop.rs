@@ -58,30 +58,26 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
         while poll(Duration::from_millis(0))? {
             match read().expect("Uh oh") {
                 Event::Mouse(event) => {
-                    //println!("{:?}", event);
-                    // Button capture goes here
-                    // Mouse doesn't seem to support cursor position? That's going to cause issues.
+                    // Terminal mice report character cells, not pixels; cell
+                    // coordinates pass straight through to `mouse_point()`.
                     match event.kind {
                         crossterm::event::MouseEventKind::Down(button) => {
-                            bterm.left_click = true;
-                            bterm.mouse_pos = (event.column as i32 * 8, event.row as i32 * 8);
-                            bterm.on_mouse_position(
-                                event.column as f64 * 8.0,
-                                event.row as f64 * 8.0,
-                            );
+                            bterm.on_mouse_position(event.column as f64, event.row as f64);
                             bterm.on_mouse_button(button as usize, true);
                         }
                         crossterm::event::MouseEventKind::Up(button) => {
+                            bterm.on_mouse_position(event.column as f64, event.row as f64);
                             bterm.on_mouse_button(button as usize, false);
                         }
-                        crossterm::event::MouseEventKind::Drag(..) => {
-                            bterm.on_mouse_position(
-                                event.column as f64 * 8.0,
-                                event.row as f64 * 8.0,
-                            );
+                        crossterm::event::MouseEventKind::Moved
+                        | crossterm::event::MouseEventKind::Drag(..) => {
+                            bterm.on_mouse_position(event.column as f64, event.row as f64);
                         }
-                        _ => {
-                            //eprintln!("{:?}", event);
+                        crossterm::event::MouseEventKind::ScrollUp => {
+                            bterm.on_mouse_wheel(0.0, 1.0);
+                        }
+                        crossterm::event::MouseEventKind::ScrollDown => {
+                            bterm.on_mouse_wheel(0.0, -1.0);
                         }
                     }
                 }
@@ -113,6 +109,14 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     }
                 }
                 Event::Resize(x, y) => {
+                    if BACKEND.lock().resize_scaling {
+                        let mut bi = BACKEND_INTERNAL.lock();
+                        for cons in bi.consoles.iter_mut() {
+                            cons.console.set_char_size(u32::from(x), u32::from(y));
+                        }
+                    }
+                    // The old diff buffer has the wrong dimensions; repaint from scratch.
+                    output_buffer = None;
                     bterm.on_event(BEvent::Resized {
                         new_size: bracket_geometry::prelude::Point::new(x, y),
                         dpi_scale_factor: 1.0,
@@ -136,7 +140,10 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
 
         gamestate.tick(&mut bterm);
 
-        if output_buffer.is_none() {
+        let graphics = BACKEND.lock().graphics;
+        if graphics != super::TerminalGraphics::Off {
+            super::render_frame(graphics)?;
+        } else if output_buffer.is_none() {
             output_buffer = Some(full_redraw()?);
         } else {
             partial_redraw(output_buffer.as_mut().unwrap());
@@ -164,6 +171,7 @@ struct OutputBuffer {
     glyph: char,
     fg: RGBA,
     bg: RGBA,
+    blink: bool,
 }
 
 impl Default for OutputBuffer {
@@ -172,12 +180,14 @@ impl Default for OutputBuffer {
             glyph: ' ',
             fg: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
             bg: RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+            blink: false,
         }
     }
 }
 
 fn full_redraw() -> BResult<Vec<OutputBuffer>> {
     let be = BACKEND.lock();
+    let color_mode = be.color_mode;
     let mut bi = BACKEND_INTERNAL.lock();
 
     let (width, height) = crossterm::terminal::size()?;
@@ -192,6 +202,7 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                 let mut idx = 0;
                 let mut last_bg = RGBA::new();
                 let mut last_fg = RGBA::new();
+                let mut last_blink = false;
                 for y in 0..st.height {
                     queue!(
                         stdout(),
@@ -204,13 +215,9 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                         if t.fg != last_fg {
                             queue!(
                                 stdout(),
-                                crossterm::style::SetForegroundColor(
-                                    crossterm::style::Color::Rgb {
-                                        r: (t.fg.r * 255.0) as u8,
-                                        g: (t.fg.g * 255.0) as u8,
-                                        b: (t.fg.b * 255.0) as u8,
-                                    }
-                                )
+                                crossterm::style::SetForegroundColor(super::to_crossterm_color(
+                                    t.fg, color_mode
+                                ))
                             )
                             .expect("Command fail");
                             last_fg = t.fg;
@@ -218,21 +225,31 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                         if t.bg != last_bg {
                             queue!(
                                 stdout(),
-                                crossterm::style::SetBackgroundColor(
-                                    crossterm::style::Color::Rgb {
-                                        r: (t.bg.r * 255.0) as u8,
-                                        g: (t.bg.g * 255.0) as u8,
-                                        b: (t.bg.b * 255.0) as u8,
-                                    }
-                                )
+                                crossterm::style::SetBackgroundColor(super::to_crossterm_color(
+                                    t.bg, color_mode
+                                ))
                             )
                             .expect("Command fail");
                             last_bg = t.bg;
                         }
+                        let blink = st.blink_cells.contains(&idx);
+                        if blink != last_blink {
+                            queue!(
+                                stdout(),
+                                crossterm::style::SetAttribute(if blink {
+                                    crossterm::style::Attribute::SlowBlink
+                                } else {
+                                    crossterm::style::Attribute::NoBlink
+                                })
+                            )
+                            .expect("Command fail");
+                            last_blink = blink;
+                        }
                         queue!(stdout(), Print(to_char(t.glyph as u8))).expect("Command fail");
                         buffer[buf_idx].glyph = to_char(t.glyph as u8);
                         buffer[buf_idx].fg = t.fg;
                         buffer[buf_idx].bg = t.bg;
+                        buffer[buf_idx].blink = blink;
                         idx += 1;
                         buf_idx += 1;
                     }
@@ -251,19 +268,25 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                     .expect("Command fail");
                     queue!(
                         stdout(),
-                        crossterm::style::SetForegroundColor(crossterm::style::Color::Rgb {
-                            r: (t.fg.r * 255.0) as u8,
-                            g: (t.fg.g * 255.0) as u8,
-                            b: (t.fg.b * 255.0) as u8,
-                        })
+                        crossterm::style::SetForegroundColor(super::to_crossterm_color(
+                            t.fg, color_mode
+                        ))
+                    )
+                    .expect("Command fail");
+                    queue!(
+                        stdout(),
+                        crossterm::style::SetBackgroundColor(super::to_crossterm_color(
+                            t.bg, color_mode
+                        ))
                     )
                     .expect("Command fail");
+                    let blink = st.blink_cells.contains(&t.idx);
                     queue!(
                         stdout(),
-                        crossterm::style::SetBackgroundColor(crossterm::style::Color::Rgb {
-                            r: (t.bg.r * 255.0) as u8,
-                            g: (t.bg.g * 255.0) as u8,
-                            b: (t.bg.b * 255.0) as u8,
+                        crossterm::style::SetAttribute(if blink {
+                            crossterm::style::Attribute::SlowBlink
+                        } else {
+                            crossterm::style::Attribute::NoBlink
                         })
                     )
                     .expect("Command fail");
@@ -273,6 +296,7 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                     buffer[buf_idx].glyph = to_char(t.glyph as u8);
                     buffer[buf_idx].fg = t.fg;
                     buffer[buf_idx].bg = t.bg;
+                    buffer[buf_idx].blink = blink;
                 }
             }
         }
@@ -286,6 +310,7 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
 
 fn partial_redraw(buffer: &mut Vec<OutputBuffer>) {
     let be = BACKEND.lock();
+    let color_mode = be.color_mode;
     let mut bi = BACKEND_INTERNAL.lock();
 
     let (width, height) = crossterm::terminal::size().expect("Failed to get size");
@@ -306,6 +331,7 @@ fn partial_redraw(buffer: &mut Vec<OutputBuffer>) {
                             glyph: to_char(t.glyph as u8),
                             fg: t.fg,
                             bg: t.bg,
+                            blink: st.blink_cells.contains(&idx),
                         };
                         if buffer[buf_idx] != new_output {
                             buffer[buf_idx] = new_output;
@@ -328,6 +354,7 @@ fn partial_redraw(buffer: &mut Vec<OutputBuffer>) {
                         glyph: to_char(t.glyph as u8),
                         fg: t.fg,
                         bg: t.bg,
+                        blink: st.blink_cells.contains(&t.idx),
                     };
                     if buffer[buf_idx] != new_output {
                         buffer[buf_idx] = new_output;
@@ -338,24 +365,36 @@ fn partial_redraw(buffer: &mut Vec<OutputBuffer>) {
         }
     }
 
-    // Render just the dirty tiles
+    // Render just the dirty tiles. Sorting puts them in row-major order so
+    // runs of adjacent cells need no cursor moves at all; overlapping consoles
+    // can mark the same cell twice, hence the dedup.
+    dirty.sort_unstable();
+    dirty.dedup();
     let mut last_bg = RGBA::new();
     let mut last_fg = RGBA::new();
+    let mut last_blink = false;
+    let mut cursor_at = usize::MAX;
     dirty.iter().for_each(|idx| {
         let x = idx % width as usize;
         let y = idx / width as usize;
         let t = &buffer[*idx];
 
-        queue!(stdout(), cursor::MoveTo(x as u16, y as u16)).expect("Command fail");
+        // Printing advances the cursor one cell; only reposition when the
+        // next dirty cell doesn't follow on from the last one. Runs never
+        // continue across a line wrap, where cursor state is unreliable.
+        if *idx != cursor_at {
+            queue!(stdout(), cursor::MoveTo(x as u16, y as u16)).expect("Command fail");
+        }
+        cursor_at = if x + 1 < width as usize {
+            idx + 1
+        } else {
+            usize::MAX
+        };
 
         if t.fg != last_fg {
             queue!(
                 stdout(),
-                crossterm::style::SetForegroundColor(crossterm::style::Color::Rgb {
-                    r: (t.fg.r * 255.0) as u8,
-                    g: (t.fg.g * 255.0) as u8,
-                    b: (t.fg.b * 255.0) as u8,
-                })
+                crossterm::style::SetForegroundColor(super::to_crossterm_color(t.fg, color_mode))
             )
             .expect("Command fail");
             last_fg = t.fg;
@@ -364,15 +403,26 @@ fn partial_redraw(buffer: &mut Vec<OutputBuffer>) {
         if t.bg != last_bg {
             queue!(
                 stdout(),
-                crossterm::style::SetBackgroundColor(crossterm::style::Color::Rgb {
-                    r: (t.bg.r * 255.0) as u8,
-                    g: (t.bg.g * 255.0) as u8,
-                    b: (t.bg.b * 255.0) as u8,
-                })
+                crossterm::style::SetBackgroundColor(super::to_crossterm_color(t.bg, color_mode))
             )
             .expect("Command fail");
             last_bg = t.bg;
         }
+        if t.blink != last_blink {
+            queue!(
+                stdout(),
+                crossterm::style::SetAttribute(if t.blink {
+                    crossterm::style::Attribute::SlowBlink
+                } else {
+                    crossterm::style::Attribute::NoBlink
+                })
+            )
+            .expect("Command fail");
+            last_blink = t.blink;
+        }
         queue!(stdout(), Print(t.glyph)).expect("Command fail");
     });
+
+    // `queue!` only buffers; nothing reaches the terminal until flushed.
+    stdout().flush().expect("Command fail");
 }
@@ -5,6 +5,12 @@ pub use winit::event::VirtualKeyCode;
 mod main_loop;
 pub use main_loop::*;
 
+mod color_support;
+pub(crate) use color_support::to_crossterm_color;
+pub use color_support::{detect_color_mode, TerminalColorMode};
+mod graphics_protocol;
+pub(crate) use graphics_protocol::render_frame;
+pub use graphics_protocol::{detect_terminal_graphics, TerminalGraphics};
 mod font;
 pub use font::*;
 mod init;
@@ -18,6 +24,12 @@ pub struct InitHints {
     pub vsync: bool,
     pub fullscreen: bool,
     pub frame_sleep_time: Option<f32>,
+    pub resize_scaling: bool,
+    /// Override the detected terminal color capability. `None` auto-detects.
+    pub color_mode: Option<TerminalColorMode>,
+    /// Render pixel graphics through a terminal graphics protocol instead of
+    /// characters, when one is detected.
+    pub terminal_graphics: bool,
 }
 
 impl InitHints {
@@ -26,6 +38,9 @@ impl InitHints {
             vsync: true,
             fullscreen: false,
             frame_sleep_time: None,
+            resize_scaling: false,
+            color_mode: None,
+            terminal_graphics: false,
         }
     }
 }
@@ -34,13 +49,19 @@ pub struct PlatformGL {
     old_width: u16,
     old_height: u16,
     pub frame_sleep_time: Option<u64>,
+    pub resize_scaling: bool,
+    pub color_mode: TerminalColorMode,
+    pub graphics: TerminalGraphics,
 }
 
 lazy_static! {
     pub static ref BACKEND: Mutex<PlatformGL> = Mutex::new(PlatformGL {
         old_width: 0,
         old_height: 0,
-        frame_sleep_time: None
+        frame_sleep_time: None,
+        resize_scaling: false,
+        color_mode: TerminalColorMode::TrueColor,
+        graphics: TerminalGraphics::Off
     });
 }
 
@@ -37,6 +37,7 @@ pub fn init_raw<S: ToString>(
     be.window = Some(window);
     be.color_map = color_map;
     be.frame_sleep_time = crate::hal::convert_fps_to_wait(platform_hints.frame_sleep_time);
+    be.resize_scaling = platform_hints.resize_scaling;
 
     let bterm = BTerm {
         width_pixels,
@@ -56,7 +57,12 @@ pub fn init_raw<S: ToString>(
         quitting: false,
         post_scanlines: false,
         post_screenburn: false,
+        interpolation_alpha: 0.0,
+        ups: 0.0,
+        update_time_ms: 0.0,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        crt_params: crate::prelude::CrtParams::default(),
+        colorblind_mode: None,
     };
     Ok(bterm)
 }
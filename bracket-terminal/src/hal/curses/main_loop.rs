@@ -109,6 +109,31 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                             bterm.on_mouse_position(mouse_event.x as f64, mouse_event.y as f64);
                         }
                     }
+                    pancurses::Input::KeyResize => {
+                        pancurses::resize_term(0, 0);
+                        let (rows, cols);
+                        let resize_scaling;
+                        {
+                            let be = BACKEND.lock();
+                            let size = be.window.as_ref().unwrap().get_max_yx();
+                            rows = size.0;
+                            cols = size.1;
+                            resize_scaling = be.resize_scaling;
+                        }
+                        if resize_scaling {
+                            let mut bi = BACKEND_INTERNAL.lock();
+                            for cons in bi.consoles.iter_mut() {
+                                cons.console.set_char_size(cols as u32, rows as u32);
+                            }
+                        }
+                        // The old diff buffer has the wrong dimensions; repaint from scratch.
+                        output_buffer = None;
+                        bterm.on_event(BEvent::Resized {
+                            new_size: bracket_geometry::prelude::Point::new(cols, rows),
+                            dpi_scale_factor: 1.0,
+                        });
+                    }
+
                     _ => {
                         println!("{:#?}", input);
                     }
@@ -154,6 +179,7 @@ struct OutputBuffer {
     glyph: char,
     fg: RGBA,
     bg: RGBA,
+    blink: bool,
 }
 
 impl Default for OutputBuffer {
@@ -162,6 +188,7 @@ impl Default for OutputBuffer {
             glyph: ' ',
             fg: RGBA::from_f32(1.0, 1.0, 1.0, 1.0),
             bg: RGBA::from_f32(0.0, 0.0, 0.0, 0.0),
+            blink: false,
         }
     }
 }
@@ -198,7 +225,12 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                         last_bg = t.bg;
                     }
                     let pair = (cp_bg * 16) + cp_fg;
-                    window.attrset(pancurses::COLOR_PAIR(pair.try_into()?));
+                    let blink = st.blink_cells.contains(&idx);
+                    let mut attrs = pancurses::COLOR_PAIR(pair.try_into()?);
+                    if blink {
+                        attrs |= pancurses::A_BLINK;
+                    }
+                    window.attrset(attrs);
 
                     let ch = to_char(t.glyph as u8);
                     let ty = st.height as i32 - (y as i32 + 1);
@@ -207,6 +239,7 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                     buffer[buf_idx].glyph = ch;
                     buffer[buf_idx].fg = t.fg;
                     buffer[buf_idx].bg = t.bg;
+                    buffer[buf_idx].blink = blink;
                     idx += 1;
                 }
             }
@@ -227,7 +260,12 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                     last_bg = t.bg;
                 }
                 let pair = (cp_bg * 16) + cp_fg;
-                window.attrset(pancurses::COLOR_PAIR(pair.try_into()?));
+                let blink = st.blink_cells.contains(&t.idx);
+                let mut attrs = pancurses::COLOR_PAIR(pair.try_into()?);
+                if blink {
+                    attrs |= pancurses::A_BLINK;
+                }
+                window.attrset(attrs);
                 let ch = to_char(t.glyph as u8);
                 let ty = st.height as i32 - (y as i32 + 1);
                 window.mvaddch(ty, x as i32, ch);
@@ -235,6 +273,7 @@ fn full_redraw() -> BResult<Vec<OutputBuffer>> {
                 buffer[buf_idx].glyph = ch;
                 buffer[buf_idx].fg = t.fg;
                 buffer[buf_idx].bg = t.bg;
+                buffer[buf_idx].blink = blink;
             }
         }
     }
@@ -265,6 +304,7 @@ fn partial_redraw(buf: &mut Vec<OutputBuffer>) {
                         glyph: to_char(t.glyph as u8),
                         fg: t.fg,
                         bg: t.bg,
+                        blink: st.blink_cells.contains(&idx),
                     };
                     let ty = st.height as i32 - (y as i32 + 1);
                     let buf_idx = (ty as usize * width) + x as usize;
@@ -285,6 +325,7 @@ fn partial_redraw(buf: &mut Vec<OutputBuffer>) {
                     glyph: to_char(t.glyph as u8),
                     fg: t.fg,
                     bg: t.bg,
+                    blink: st.blink_cells.contains(&t.idx),
                 };
                 if buf[buf_idx] != new_output {
                     buf[buf_idx] = new_output;
@@ -312,7 +353,11 @@ fn partial_redraw(buf: &mut Vec<OutputBuffer>) {
             last_bg = t.bg;
         }
         let pair = (cp_bg * 16) + cp_fg;
-        window.attrset(pancurses::COLOR_PAIR(pair.try_into().unwrap()));
+        let mut attrs = pancurses::COLOR_PAIR(pair.try_into().unwrap());
+        if t.blink {
+            attrs |= pancurses::A_BLINK;
+        }
+        window.attrset(attrs);
 
         window.mvaddch(y as i32, x as i32, buf[*idx].glyph);
     });
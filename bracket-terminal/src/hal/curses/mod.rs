@@ -26,6 +26,7 @@ pub struct InitHints {
     pub vsync: bool,
     pub fullscreen: bool,
     pub frame_sleep_time: Option<f32>,
+    pub resize_scaling: bool,
 }
 
 impl InitHints {
@@ -34,6 +35,7 @@ impl InitHints {
             vsync: true,
             fullscreen: false,
             frame_sleep_time: None,
+            resize_scaling: false,
         }
     }
 }
@@ -42,13 +44,15 @@ pub struct PlatformGL {
     window: Option<Window>,
     color_map: Vec<CursesColor>,
     pub frame_sleep_time: Option<u64>,
+    pub resize_scaling: bool,
 }
 
 lazy_static! {
     pub static ref BACKEND: Mutex<PlatformGL> = Mutex::new(PlatformGL {
         window: None,
         color_map: Vec::new(),
-        frame_sleep_time: None
+        frame_sleep_time: None,
+        resize_scaling: false
     });
 }
 
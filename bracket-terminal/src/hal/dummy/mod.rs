@@ -70,6 +70,9 @@ pub fn init_raw<S: ToString>(
         quitting: false,
         post_scanlines: false,
         post_screenburn: false,
+        interpolation_alpha: 0.0,
+        ups: 0.0,
+        update_time_ms: 0.0,
     }
 }
 
@@ -1,18 +1,22 @@
 use crate::hal::scaler::FontScaler;
-use crate::hal::{Font, Shader, VertexArray, VertexArrayEntry, BACKEND};
-use crate::prelude::FlexiTile;
+use crate::hal::{Font, PendingUpload, Shader, VertexArray, VertexArrayEntry, BACKEND};
+use crate::prelude::{BlendMode, ConsoleTransform, FlexiTile};
 use crate::BResult;
 use bracket_color::prelude::RGBA;
 use bracket_geometry::prelude::PointF;
 
 pub struct FancyConsoleBackend {
     vao: VertexArray,
+    pending_upload: PendingUpload,
 }
 
 impl FancyConsoleBackend {
     pub fn new(_width: usize, _height: usize, gl: &glow::Context) -> FancyConsoleBackend {
         let vao = FancyConsoleBackend::init_gl_for_console(gl, 1000, 1000);
-        FancyConsoleBackend { vao }
+        FancyConsoleBackend {
+            vao,
+            pending_upload: PendingUpload::None,
+        }
     }
 
     fn init_gl_for_console(
@@ -49,10 +53,11 @@ impl FancyConsoleBackend {
         screen_x: f32,
         screen_y: f32,
         scale: PointF,
+        style: f32,
     ) {
         vertex_buffer.extend_from_slice(&[
-            x, y, 0.0, fg.r, fg.g, fg.b, fg.a, bg.r, bg.g, bg.b, bg.a, ux, uy, rotation, screen_x,
-            screen_y, scale.x, scale.y,
+            x, y, style, fg.r, fg.g, fg.b, fg.a, bg.r, bg.g, bg.b, bg.a, ux, uy, rotation,
+            screen_x, screen_y, scale.x, scale.y,
         ]);
     }
 
@@ -67,6 +72,7 @@ impl FancyConsoleBackend {
         scale: f32,
         scale_center: (i32, i32),
         tiles: &[FlexiTile],
+        styles: &[f32],
         font_scaler: FontScaler,
     ) {
         if tiles.is_empty() {
@@ -93,9 +99,10 @@ impl FancyConsoleBackend {
             (step_x, step_y, left_x, top_y)
         };
 
-        for t in tiles.iter() {
+        for (i, t) in tiles.iter().enumerate() {
             let x = t.position.x;
             let y = t.position.y;
+            let style = styles[i];
 
             let screen_x = ((step_x * x) + left_x) + offset_x;
             let screen_y = ((step_y * y) + top_y) + offset_y;
@@ -119,6 +126,7 @@ impl FancyConsoleBackend {
                 rot_center_x,
                 rot_center_y,
                 t.scale,
+                style,
             );
             FancyConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -132,6 +140,7 @@ impl FancyConsoleBackend {
                 rot_center_x,
                 rot_center_y,
                 t.scale,
+                style,
             );
             FancyConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -145,6 +154,7 @@ impl FancyConsoleBackend {
                 rot_center_x,
                 rot_center_y,
                 t.scale,
+                style,
             );
             FancyConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -158,6 +168,7 @@ impl FancyConsoleBackend {
                 rot_center_x,
                 rot_center_y,
                 t.scale,
+                style,
             );
 
             self.vao.index_buffer.push(index_count);
@@ -170,11 +181,29 @@ impl FancyConsoleBackend {
             index_count += 4;
         }
 
-        self.vao.upload_buffers();
+        self.pending_upload = PendingUpload::Full;
     }
 
-    pub fn gl_draw(&mut self, font: &Font, shader: &Shader) -> BResult<()> {
-        self.vao.draw_elements(shader, font);
+    /// Performs any GPU upload deferred from `rebuild_vertices`. Must run on the
+    /// thread owning the GL context.
+    pub fn upload(&mut self) {
+        match std::mem::take(&mut self.pending_upload) {
+            PendingUpload::None => {}
+            PendingUpload::Full => self.vao.upload_buffers(),
+            PendingUpload::Range(first, last) => self.vao.upload_vertex_range(first, last),
+        }
+    }
+
+    pub fn gl_draw(
+        &mut self,
+        font: &Font,
+        shader: &Shader,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) -> BResult<()> {
+        self.vao
+            .draw_elements(shader, font, blend_mode, transform, tint);
         Ok(())
     }
 }
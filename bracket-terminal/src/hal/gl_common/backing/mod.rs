@@ -1,5 +1,7 @@
 mod simple_console_backing;
 pub(crate) use simple_console_backing::*;
+mod simple_console_instanced;
+pub(crate) use simple_console_instanced::*;
 mod sparse_console_backing;
 pub(crate) use sparse_console_backing::*;
 mod fancy_console_backing;
@@ -9,9 +11,33 @@ pub(crate) use sprite_console_backing::*;
 mod shared_main_loop;
 pub(crate) use shared_main_loop::*;
 
+/// A GPU upload deferred from vertex rebuilding, so that with the `threaded`
+/// feature the CPU-side rebuild can run off the main thread and only the upload
+/// touches the GL context.
+#[derive(Default, Clone, Copy)]
+pub(crate) enum PendingUpload {
+    #[default]
+    None,
+    /// Re-upload the whole vertex and index buffers.
+    Full,
+    /// Re-upload a contiguous float range of the vertex buffer.
+    Range(usize, usize),
+}
+
 pub(crate) enum ConsoleBacking {
-    Simple { backing: SimpleConsoleBackend },
-    Sparse { backing: SparseConsoleBackend },
-    Fancy { backing: FancyConsoleBackend },
-    Sprite { backing: SpriteConsoleBackend },
+    Simple {
+        backing: SimpleConsoleBackend,
+    },
+    SimpleInstanced {
+        backing: SimpleConsoleInstancedBackend,
+    },
+    Sparse {
+        backing: SparseConsoleBackend,
+    },
+    Fancy {
+        backing: FancyConsoleBackend,
+    },
+    Sprite {
+        backing: SpriteConsoleBackend,
+    },
 }
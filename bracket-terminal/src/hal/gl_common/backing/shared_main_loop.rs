@@ -1,26 +1,151 @@
 use crate::hal::scaler::FontScaler;
 use crate::hal::{
-    ConsoleBacking, FancyConsoleBackend, SimpleConsoleBackend, SparseConsoleBackend,
-    SpriteConsoleBackend, BACKEND, CONSOLE_BACKING,
+    ConsoleBacking, FancyConsoleBackend, SimpleConsoleBackend, SimpleConsoleInstancedBackend,
+    SparseConsoleBackend, SpriteConsoleBackend, BACKEND, CONSOLE_BACKING,
+};
+use crate::prelude::{
+    ConsoleCamera, FlexiConsole, FlexiTile, LightingOverlay, SimpleConsole, SparseConsole,
+    SparseTile, SpriteConsole, Tile, BACKEND_INTERNAL,
 };
-use crate::prelude::{FlexiConsole, SimpleConsole, SparseConsole, SpriteConsole, BACKEND_INTERNAL};
 use crate::BResult;
+use bracket_color::prelude::{RGB, RGBA};
+
+/// Multiplies a light color into a cell color, leaving alpha untouched.
+fn apply_light(color: RGBA, light: RGB) -> RGBA {
+    RGBA::from_f32(
+        color.r * light.r,
+        color.g * light.g,
+        color.b * light.b,
+        color.a,
+    )
+}
+
+/// Converts a console's styled-cell map to a per-cell shader flag vector, one
+/// entry per tile slot.
+fn style_flags(
+    styled_cells: &std::collections::HashMap<usize, crate::prelude::GlyphStyle>,
+    len: usize,
+) -> Vec<f32> {
+    let mut styles = vec![0.0; len];
+    for (idx, style) in styled_cells.iter() {
+        if let Some(slot) = styles.get_mut(*idx) {
+            *slot = style.shader_flag();
+        }
+    }
+    styles
+}
+
+/// Applies any lighting overlay and camera window to a simple console's tiles,
+/// returning the dimensions, tile data, style flags and offsets the vertex
+/// builder should use.
+fn prepare_simple_console(
+    sc: &SimpleConsole,
+    lighting: &Option<LightingOverlay>,
+    camera: Option<ConsoleCamera>,
+) -> (u32, u32, Vec<Tile>, Vec<f32>, f32, f32) {
+    let mut tiles: Vec<Tile> = match lighting {
+        Some(light) => sc
+            .tiles
+            .iter()
+            .enumerate()
+            .map(|(idx, t)| {
+                let l = light.light_for_tile_index(idx);
+                Tile {
+                    glyph: t.glyph,
+                    fg: apply_light(t.fg, l),
+                    bg: apply_light(t.bg, l),
+                }
+            })
+            .collect(),
+        None => sc.tiles.clone(),
+    };
+    let styles = style_flags(&sc.styled_cells, tiles.len());
+    // During the hidden blink phase, blinking cells are drawn background-on-background.
+    if !sc.blink_cells.is_empty() && !crate::consoles::blink_visible() {
+        for idx in &sc.blink_cells {
+            if let Some(t) = tiles.get_mut(*idx) {
+                t.fg = t.bg;
+            }
+        }
+    }
+    // Color-cycling cells take their foreground from their group's current color.
+    for (idx, cycle) in sc.cycle_cells.iter() {
+        if let Some(color) = crate::consoles::cycle_color(*cycle) {
+            if let Some(t) = tiles.get_mut(*idx) {
+                t.fg = color;
+            }
+        }
+    }
+    if let Some(cam) = camera {
+        // Carve the visible window out of the oversized console, keeping
+        // the bottom-up row order the vertex builder expects.
+        let (view_width, view_height) = cam.view_size;
+        let mut view_tiles = vec![
+            Tile {
+                glyph: 0,
+                fg: RGBA::from_u8(255, 255, 255, 255),
+                bg: RGBA::from_u8(0, 0, 0, 255),
+            };
+            (view_width * view_height) as usize
+        ];
+        let mut view_styles = vec![0.0; (view_width * view_height) as usize];
+        for view_y in 0..view_height {
+            let console_y = cam.origin.1 + view_y;
+            if console_y >= sc.height {
+                continue;
+            }
+            for view_x in 0..view_width {
+                let console_x = cam.origin.0 + view_x;
+                if console_x >= sc.width {
+                    continue;
+                }
+                let src = ((sc.height - 1 - console_y) * sc.width + console_x) as usize;
+                let dst = ((view_height - 1 - view_y) * view_width + view_x) as usize;
+                view_tiles[dst] = tiles[src];
+                view_styles[dst] = styles[src];
+            }
+        }
+        (
+            view_height,
+            view_width,
+            view_tiles,
+            view_styles,
+            sc.offset_x - cam.offset.0 * (2.0 / view_width as f32),
+            sc.offset_y + cam.offset.1 * (2.0 / view_height as f32),
+        )
+    } else {
+        (sc.height, sc.width, tiles, styles, sc.offset_x, sc.offset_y)
+    }
+}
 
 pub(crate) fn check_console_backing() {
     let mut be = BACKEND.lock();
+    let instanced = be.instanced_consoles;
     let mut consoles = CONSOLE_BACKING.lock();
-    if consoles.is_empty() {
-        // Easy case: there are no consoles so we need to make them all.
-        for cons in &BACKEND_INTERNAL.lock().consoles {
+    let first_new = consoles.len();
+    {
+        // Build backings for any consoles that don't have one yet - all of them on the
+        // first frame, or just the new ones when layers are added at runtime.
+        for cons in BACKEND_INTERNAL.lock().consoles.iter().skip(first_new) {
             let cons_any = cons.console.as_any();
             if let Some(st) = cons_any.downcast_ref::<SimpleConsole>() {
-                consoles.push(ConsoleBacking::Simple {
-                    backing: SimpleConsoleBackend::new(
-                        st.width as usize,
-                        st.height as usize,
-                        be.gl.as_mut().unwrap(),
-                    ),
-                });
+                if instanced {
+                    consoles.push(ConsoleBacking::SimpleInstanced {
+                        backing: SimpleConsoleInstancedBackend::new(
+                            st.width as usize,
+                            st.height as usize,
+                            be.gl.as_mut().unwrap(),
+                        ),
+                    });
+                } else {
+                    consoles.push(ConsoleBacking::Simple {
+                        backing: SimpleConsoleBackend::new(
+                            st.width as usize,
+                            st.height as usize,
+                            be.gl.as_mut().unwrap(),
+                        ),
+                    });
+                }
             } else if let Some(sp) = cons_any.downcast_ref::<SparseConsole>() {
                 consoles.push(ConsoleBacking::Sparse {
                     backing: SparseConsoleBackend::new(
@@ -52,87 +177,363 @@ pub(crate) fn check_console_backing() {
     }
 }
 
+/// CPU-side rebuild work for one console, extracted from the console data under
+/// the backend locks so that with the `threaded` feature the vertex building can
+/// run on a rayon pool. The GPU upload stays on the main thread.
+enum RebuildJob {
+    Simple {
+        height: u32,
+        width: u32,
+        tiles: Vec<Tile>,
+        styles: Vec<f32>,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+        scale_center: (i32, i32),
+        needs_resize: bool,
+        font_scaler: FontScaler,
+    },
+    SimpleInstanced {
+        height: u32,
+        width: u32,
+        tiles: Vec<Tile>,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+        needs_resize: bool,
+    },
+    Sparse {
+        height: u32,
+        width: u32,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+        scale_center: (i32, i32),
+        tiles: Vec<SparseTile>,
+        styles: Vec<f32>,
+        font_scaler: FontScaler,
+        needs_resize: bool,
+    },
+    Fancy {
+        height: u32,
+        width: u32,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+        scale_center: (i32, i32),
+        tiles: Vec<FlexiTile>,
+        styles: Vec<f32>,
+        font_scaler: FontScaler,
+    },
+}
+
+/// Runs one console's vertex rebuild against its backing. Pure CPU work; the
+/// matching upload happens later on the main thread.
+fn run_rebuild_job(backing: &mut ConsoleBacking, job: RebuildJob) {
+    match (backing, job) {
+        (
+            ConsoleBacking::Simple { backing },
+            RebuildJob::Simple {
+                height,
+                width,
+                tiles,
+                styles,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                needs_resize,
+                font_scaler,
+            },
+        ) => {
+            backing.rebuild_vertices(
+                height,
+                width,
+                &tiles,
+                &styles,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                needs_resize,
+                font_scaler,
+            );
+        }
+        (
+            ConsoleBacking::SimpleInstanced { backing },
+            RebuildJob::SimpleInstanced {
+                height,
+                width,
+                tiles,
+                offset_x,
+                offset_y,
+                scale,
+                needs_resize,
+            },
+        ) => {
+            backing.rebuild_vertices(
+                height,
+                width,
+                &tiles,
+                offset_x,
+                offset_y,
+                scale,
+                needs_resize,
+            );
+        }
+        (
+            ConsoleBacking::Sparse { backing },
+            RebuildJob::Sparse {
+                height,
+                width,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                tiles,
+                styles,
+                font_scaler,
+                needs_resize,
+            },
+        ) => {
+            backing.rebuild_vertices(
+                height,
+                width,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                &tiles,
+                &styles,
+                font_scaler,
+                needs_resize,
+            );
+        }
+        (
+            ConsoleBacking::Fancy { backing },
+            RebuildJob::Fancy {
+                height,
+                width,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                tiles,
+                styles,
+                font_scaler,
+            },
+        ) => {
+            backing.rebuild_vertices(
+                height,
+                width,
+                offset_x,
+                offset_y,
+                scale,
+                scale_center,
+                &tiles,
+                &styles,
+                font_scaler,
+            );
+        }
+        // Jobs are built from the backing they will run against, so the types
+        // always line up.
+        _ => unreachable!("Rebuild job does not match its console backing"),
+    }
+}
+
 pub(crate) fn rebuild_consoles() {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("rebuild_consoles").entered();
     let must_resize = BACKEND.lock().screen_scaler.get_resized_and_reset();
+    let mut rebuilt = 0;
     let mut consoles = CONSOLE_BACKING.lock();
     let mut bi = BACKEND_INTERNAL.lock();
     let ss = bi.sprite_sheets.clone();
+
+    // Phase 1: extract the rebuild inputs for every dirty console. This is the
+    // only phase that needs the console objects themselves.
+    let mut jobs: Vec<Option<RebuildJob>> = Vec::with_capacity(consoles.len());
     for (i, c) in consoles.iter_mut().enumerate() {
         let font_index = bi.consoles[i].font_index;
         let glyph_dimensions = bi.fonts[font_index].font_dimensions_glyphs;
         let tex_dimensions = bi.fonts[font_index].font_dimensions_texture;
         let cons = &mut bi.consoles[i];
-        match c {
-            ConsoleBacking::Simple { backing } => {
+        let job = match c {
+            ConsoleBacking::Simple { .. } => {
+                let lighting = cons.lighting.clone();
+                let camera = cons.camera;
                 let mut sc = cons
                     .console
                     .as_any_mut()
                     .downcast_mut::<SimpleConsole>()
                     .unwrap();
                 if sc.is_dirty {
-                    backing.rebuild_vertices(
-                        sc.height,
-                        sc.width,
-                        &sc.tiles,
-                        sc.offset_x,
-                        sc.offset_y,
-                        sc.scale,
-                        sc.scale_center,
-                        sc.needs_resize_internal || must_resize,
-                        FontScaler::new(glyph_dimensions, tex_dimensions),
-                    );
+                    let (height, width, tiles, styles, offset_x, offset_y) =
+                        prepare_simple_console(sc, &lighting, camera);
+                    let needs_resize = sc.needs_resize_internal || must_resize;
                     sc.needs_resize_internal = false;
+                    Some(RebuildJob::Simple {
+                        height,
+                        width,
+                        tiles,
+                        styles,
+                        offset_x,
+                        offset_y,
+                        scale: sc.scale,
+                        scale_center: sc.scale_center,
+                        needs_resize,
+                        font_scaler: FontScaler::new(glyph_dimensions, tex_dimensions),
+                    })
+                } else {
+                    None
                 }
             }
-            ConsoleBacking::Sparse { backing } => {
-                let mut sc = bi.consoles[i]
+            ConsoleBacking::SimpleInstanced { .. } => {
+                let lighting = cons.lighting.clone();
+                let camera = cons.camera;
+                let mut sc = cons
+                    .console
+                    .as_any_mut()
+                    .downcast_mut::<SimpleConsole>()
+                    .unwrap();
+                if sc.is_dirty {
+                    // The instanced path does not support glyph styles; the style
+                    // flags are discarded.
+                    let (height, width, tiles, _styles, offset_x, offset_y) =
+                        prepare_simple_console(sc, &lighting, camera);
+                    let needs_resize = sc.needs_resize_internal || must_resize;
+                    sc.needs_resize_internal = false;
+                    Some(RebuildJob::SimpleInstanced {
+                        height,
+                        width,
+                        tiles,
+                        offset_x,
+                        offset_y,
+                        scale: sc.scale,
+                        needs_resize,
+                    })
+                } else {
+                    None
+                }
+            }
+            ConsoleBacking::Sparse { .. } => {
+                let lighting = cons.lighting.clone();
+                let mut sc = cons
                     .console
                     .as_any_mut()
                     .downcast_mut::<SparseConsole>()
                     .unwrap();
                 if sc.is_dirty {
-                    backing.rebuild_vertices(
-                        sc.height,
-                        sc.width,
-                        sc.offset_x,
-                        sc.offset_y,
-                        sc.scale,
-                        sc.scale_center,
-                        &sc.tiles,
-                        FontScaler::new(glyph_dimensions, tex_dimensions),
-                        must_resize,
-                    );
+                    let mut tiles: Vec<SparseTile> = match &lighting {
+                        Some(light) => sc
+                            .tiles
+                            .iter()
+                            .map(|t| {
+                                let l = light.light_for_tile_index(t.idx);
+                                SparseTile {
+                                    idx: t.idx,
+                                    glyph: t.glyph,
+                                    fg: apply_light(t.fg, l),
+                                    bg: apply_light(t.bg, l),
+                                }
+                            })
+                            .collect(),
+                        None => sc.tiles.clone(),
+                    };
+                    if !sc.blink_cells.is_empty() && !crate::consoles::blink_visible() {
+                        for t in tiles.iter_mut() {
+                            if sc.blink_cells.contains(&t.idx) {
+                                t.fg = t.bg;
+                            }
+                        }
+                    }
+                    if !sc.cycle_cells.is_empty() {
+                        for t in tiles.iter_mut() {
+                            if let Some(cycle) = sc.cycle_cells.get(&t.idx) {
+                                if let Some(color) = crate::consoles::cycle_color(*cycle) {
+                                    t.fg = color;
+                                }
+                            }
+                        }
+                    }
+                    let styles: Vec<f32> = tiles
+                        .iter()
+                        .map(|t| {
+                            sc.styled_cells
+                                .get(&t.idx)
+                                .map_or(0.0, |style| style.shader_flag())
+                        })
+                        .collect();
                     sc.needs_resize_internal = false;
+                    Some(RebuildJob::Sparse {
+                        height: sc.height,
+                        width: sc.width,
+                        offset_x: sc.offset_x,
+                        offset_y: sc.offset_y,
+                        scale: sc.scale,
+                        scale_center: sc.scale_center,
+                        tiles,
+                        styles,
+                        font_scaler: FontScaler::new(glyph_dimensions, tex_dimensions),
+                        needs_resize: must_resize,
+                    })
+                } else {
+                    None
                 }
             }
-            ConsoleBacking::Fancy { backing } => {
-                let mut fc = bi.consoles[i]
+            ConsoleBacking::Fancy { .. } => {
+                let mut fc = cons
                     .console
                     .as_any_mut()
                     .downcast_mut::<FlexiConsole>()
                     .unwrap();
                 if fc.is_dirty {
                     fc.tiles.sort_by(|a, b| a.z_order.cmp(&b.z_order));
-                    backing.rebuild_vertices(
-                        fc.height,
-                        fc.width,
-                        fc.offset_x,
-                        fc.offset_y,
-                        fc.scale,
-                        fc.scale_center,
-                        &fc.tiles,
-                        FontScaler::new(glyph_dimensions, tex_dimensions),
-                    );
+                    // Tiles are styled by the cell their (floored) position lands in;
+                    // tile positions store the row pre-inverted, so the cell index is
+                    // a direct row-major lookup.
+                    let styles: Vec<f32> = fc
+                        .tiles
+                        .iter()
+                        .map(|t| {
+                            let x = t.position.x.floor() as i32;
+                            let y = t.position.y.floor() as i32;
+                            if x < 0 || y < 0 || x >= fc.width as i32 || y >= fc.height as i32 {
+                                0.0
+                            } else {
+                                let idx = (y as u32 * fc.width + x as u32) as usize;
+                                fc.styled_cells
+                                    .get(&idx)
+                                    .map_or(0.0, |style| style.shader_flag())
+                            }
+                        })
+                        .collect();
                     fc.needs_resize_internal = false;
+                    Some(RebuildJob::Fancy {
+                        height: fc.height,
+                        width: fc.width,
+                        offset_x: fc.offset_x,
+                        offset_y: fc.offset_y,
+                        scale: fc.scale,
+                        scale_center: fc.scale_center,
+                        tiles: fc.tiles.clone(),
+                        styles,
+                        font_scaler: FontScaler::new(glyph_dimensions, tex_dimensions),
+                    })
+                } else {
+                    None
                 }
             }
+            // Sprite consoles rebuild inline: their sheet holds a non-Send GPU
+            // handle, and sprite counts are small enough not to matter.
             ConsoleBacking::Sprite { backing } => {
-                let mut sc = bi.consoles[i]
+                let mut sc = cons
                     .console
                     .as_any_mut()
                     .downcast_mut::<SpriteConsole>()
                     .unwrap();
                 if sc.is_dirty {
+                    rebuilt += 1;
                     sc.sprites.sort_by(|a, b| a.z_order.cmp(&b.z_order));
                     backing.rebuild_vertices(
                         sc.height,
@@ -142,32 +543,126 @@ pub(crate) fn rebuild_consoles() {
                     );
                     sc.needs_resize_internal = false;
                 }
+                None
             }
+        };
+        if job.is_some() {
+            rebuilt += 1;
+        }
+        jobs.push(job);
+    }
+
+    // Phase 2: build the vertex data. CPU-only, so with the `threaded` feature
+    // each console rebuilds on the rayon pool.
+    #[cfg(feature = "threaded")]
+    {
+        use rayon::prelude::*;
+        consoles
+            .par_iter_mut()
+            .zip(jobs.into_par_iter())
+            .for_each(|(c, job)| {
+                if let Some(job) = job {
+                    run_rebuild_job(c, job);
+                }
+            });
+    }
+    #[cfg(not(feature = "threaded"))]
+    for (c, job) in consoles.iter_mut().zip(jobs.into_iter()) {
+        if let Some(job) = job {
+            run_rebuild_job(c, job);
+        }
+    }
+
+    // Phase 3: the deferred GPU uploads, on the thread owning the GL context.
+    for c in consoles.iter_mut() {
+        match c {
+            ConsoleBacking::Simple { backing } => backing.upload(),
+            ConsoleBacking::SimpleInstanced { backing } => backing.upload(),
+            ConsoleBacking::Sparse { backing } => backing.upload(),
+            ConsoleBacking::Fancy { backing } => backing.upload(),
+            ConsoleBacking::Sprite { backing } => backing.upload(),
         }
     }
+
+    let mut counts = crate::perf_overlay::RENDER_COUNTS.lock();
+    counts.consoles_rebuilt = rebuilt;
+    counts.consoles_total = consoles.len();
+}
+
+/// Renders a single console layer, as `render_consoles` does for all of them. Used for
+/// per-console screenshot capture.
+pub(crate) fn render_single_console(index: usize) -> BResult<()> {
+    let bi = BACKEND_INTERNAL.lock();
+    let mut consoles = CONSOLE_BACKING.lock();
+    if let Some(c) = consoles.get_mut(index) {
+        let cons = &bi.consoles[index];
+        let font = &bi.fonts[cons.font_index];
+        let shader = &bi.shaders[cons.shader_index];
+        match c {
+            ConsoleBacking::Simple { backing } => {
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
+            }
+            ConsoleBacking::SimpleInstanced { backing } => {
+                backing.gl_draw(font, cons.blend_mode, &cons.transform, cons.tint)?;
+            }
+            ConsoleBacking::Sparse { backing } => {
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
+            }
+            ConsoleBacking::Fancy { backing } => {
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
+            }
+            ConsoleBacking::Sprite { backing } => {
+                backing.gl_draw(
+                    bi.sprite_sheets[0].backing.as_ref().unwrap(),
+                    shader,
+                    cons.blend_mode,
+                    &cons.transform,
+                    cons.tint,
+                )?;
+            }
+        }
+    }
+    Ok(())
 }
 
 pub(crate) fn render_consoles() -> BResult<()> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("render_consoles").entered();
     let bi = BACKEND_INTERNAL.lock();
     let mut consoles = CONSOLE_BACKING.lock();
+    let mut drawn = 0;
     for (i, c) in consoles.iter_mut().enumerate() {
         let cons = &bi.consoles[i];
+        if !cons.visible {
+            continue;
+        }
+        drawn += 1;
         let font = &bi.fonts[cons.font_index];
         let shader = &bi.shaders[cons.shader_index];
         match c {
             ConsoleBacking::Simple { backing } => {
-                backing.gl_draw(font, shader)?;
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
+            }
+            ConsoleBacking::SimpleInstanced { backing } => {
+                backing.gl_draw(font, cons.blend_mode, &cons.transform, cons.tint)?;
             }
             ConsoleBacking::Sparse { backing } => {
-                backing.gl_draw(font, shader)?;
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
             }
             ConsoleBacking::Fancy { backing } => {
-                backing.gl_draw(font, shader)?;
+                backing.gl_draw(font, shader, cons.blend_mode, &cons.transform, cons.tint)?;
             }
             ConsoleBacking::Sprite { backing } => {
-                backing.gl_draw(bi.sprite_sheets[0].backing.as_ref().unwrap(), shader)?;
+                backing.gl_draw(
+                    bi.sprite_sheets[0].backing.as_ref().unwrap(),
+                    shader,
+                    cons.blend_mode,
+                    &cons.transform,
+                    cons.tint,
+                )?;
             }
         }
     }
+    crate::perf_overlay::RENDER_COUNTS.lock().consoles_drawn = drawn;
     Ok(())
 }
@@ -1,6 +1,6 @@
 use crate::hal::scaler::FontScaler;
-use crate::hal::{Font, Shader, VertexArray, VertexArrayEntry, BACKEND};
-use crate::prelude::Tile;
+use crate::hal::{Font, PendingUpload, Shader, VertexArray, VertexArrayEntry, BACKEND};
+use crate::prelude::{BlendMode, ConsoleTransform, Tile};
 use crate::BResult;
 use bracket_color::prelude::RGBA;
 
@@ -8,7 +8,9 @@ pub struct SimpleConsoleBackend {
     vao: VertexArray,
     vertex_counter: usize,
     index_counter: usize,
-    previous_console : Option<Vec<Tile>>,
+    previous_console: Option<Vec<Tile>>,
+    previous_styles: Option<Vec<f32>>,
+    pending_upload: PendingUpload,
 }
 
 impl SimpleConsoleBackend {
@@ -21,6 +23,8 @@ impl SimpleConsoleBackend {
             vertex_counter: 0,
             index_counter: 0,
             previous_console: None,
+            previous_styles: None,
+            pending_upload: PendingUpload::None,
         };
         result.vao.vertex_buffer.resize(vertex_capacity, 0.0);
         result.vao.index_buffer.resize(index_capacity, 0);
@@ -57,10 +61,11 @@ impl SimpleConsoleBackend {
         uy: f32,
         offset_x: f32,
         offset_y: f32,
+        style: f32,
     ) {
         self.vao.vertex_buffer[self.vertex_counter] = x + offset_x;
         self.vao.vertex_buffer[self.vertex_counter + 1] = y + offset_y;
-        self.vao.vertex_buffer[self.vertex_counter + 2] = 0.0f32;
+        self.vao.vertex_buffer[self.vertex_counter + 2] = style;
         self.vao.vertex_buffer[self.vertex_counter + 3] = fg.r;
         self.vao.vertex_buffer[self.vertex_counter + 4] = fg.g;
         self.vao.vertex_buffer[self.vertex_counter + 5] = fg.b;
@@ -81,6 +86,7 @@ impl SimpleConsoleBackend {
         height: u32,
         width: u32,
         tiles: &Vec<Tile>,
+        styles: &[f32],
         offset_x: f32,
         offset_y: f32,
         scale: f32,
@@ -88,13 +94,97 @@ impl SimpleConsoleBackend {
         needs_resize: bool,
         font_scaler: FontScaler,
     ) {
+        // Dirty-rectangle path: when the console layout is unchanged, patch only the
+        // cells that differ from the last build and upload just that buffer range,
+        // rather than rebuilding and re-uploading every quad.
         if !needs_resize {
-            if let Some(old) = &self.previous_console {
-                if old.len() == tiles.len() {
-                    let no_change = tiles.iter().zip(old.iter()).all(|(a, b)| *a==*b);
-                    if no_change {
+            if let (Some(old), Some(old_styles)) = (&self.previous_console, &self.previous_styles) {
+                if old.len() == tiles.len() && old_styles.len() == styles.len() {
+                    let mut first_changed = usize::MAX;
+                    let mut last_changed = 0;
+                    for (idx, (new_tile, old_tile)) in tiles.iter().zip(old.iter()).enumerate() {
+                        if *new_tile != *old_tile || styles[idx] != old_styles[idx] {
+                            first_changed = first_changed.min(idx);
+                            last_changed = last_changed.max(idx);
+                        }
+                    }
+                    if first_changed == usize::MAX {
                         return;
                     }
+
+                    let (step_x, step_y, left_x, top_y) = {
+                        let be = BACKEND.lock();
+                        let (step_x, step_y) = be.screen_scaler.calc_step(width, height, scale);
+                        let (left_x, top_y) = be.screen_scaler.top_left_pixel();
+                        (step_x, step_y, left_x, top_y)
+                    };
+
+                    let old = self.previous_console.take().unwrap();
+                    let old_styles = self.previous_styles.take().unwrap();
+                    for (idx, (tile, _)) in tiles
+                        .iter()
+                        .zip(old.iter())
+                        .enumerate()
+                        .filter(|(idx, (a, b))| *a != *b || styles[*idx] != old_styles[*idx])
+                    {
+                        let x = idx as u32 % width;
+                        let y = idx as u32 / width;
+                        let screen_x = left_x + step_x * x as f32;
+                        let screen_y = top_y + step_y * y as f32;
+                        let gp = font_scaler.glyph_position(tile.glyph);
+                        let style = styles[idx];
+
+                        self.vertex_counter = idx * 52;
+                        self.push_point(
+                            screen_x + step_x,
+                            screen_y + step_y,
+                            tile.fg,
+                            tile.bg,
+                            gp.glyph_right,
+                            gp.glyph_top,
+                            offset_x,
+                            offset_y,
+                            style,
+                        );
+                        self.push_point(
+                            screen_x + step_x,
+                            screen_y,
+                            tile.fg,
+                            tile.bg,
+                            gp.glyph_right,
+                            gp.glyph_bottom,
+                            offset_x,
+                            offset_y,
+                            style,
+                        );
+                        self.push_point(
+                            screen_x,
+                            screen_y,
+                            tile.fg,
+                            tile.bg,
+                            gp.glyph_left,
+                            gp.glyph_bottom,
+                            offset_x,
+                            offset_y,
+                            style,
+                        );
+                        self.push_point(
+                            screen_x,
+                            screen_y + step_y,
+                            tile.fg,
+                            tile.bg,
+                            gp.glyph_left,
+                            gp.glyph_top,
+                            offset_x,
+                            offset_y,
+                            style,
+                        );
+                    }
+                    self.pending_upload =
+                        PendingUpload::Range(first_changed * 52, (last_changed + 1) * 52);
+                    self.previous_console = Some(tiles.clone());
+                    self.previous_styles = Some(styles.to_vec());
+                    return;
                 }
             }
         }
@@ -107,6 +197,7 @@ impl SimpleConsoleBackend {
             self.vao.vertex_buffer.resize(vertex_capacity, 0.0);
             self.vao.index_buffer.resize(index_capacity, 0);
             self.previous_console = None;
+            self.previous_styles = None;
         }
 
         self.vertex_counter = 0;
@@ -131,6 +222,7 @@ impl SimpleConsoleBackend {
                 let fg = tiles[((y * width) + x) as usize].fg;
                 let bg = tiles[((y * width) + x) as usize].bg;
                 let glyph = tiles[((y * width) + x) as usize].glyph;
+                let style = styles[((y * width) + x) as usize];
                 let gp = font_scaler.glyph_position(glyph);
 
                 self.push_point(
@@ -142,6 +234,7 @@ impl SimpleConsoleBackend {
                     gp.glyph_top,
                     offset_x,
                     offset_y,
+                    style,
                 );
                 self.push_point(
                     screen_x + step_x,
@@ -152,6 +245,7 @@ impl SimpleConsoleBackend {
                     gp.glyph_bottom,
                     offset_x,
                     offset_y,
+                    style,
                 );
                 self.push_point(
                     screen_x,
@@ -162,6 +256,7 @@ impl SimpleConsoleBackend {
                     gp.glyph_bottom,
                     offset_x,
                     offset_y,
+                    style,
                 );
                 self.push_point(
                     screen_x,
@@ -172,6 +267,7 @@ impl SimpleConsoleBackend {
                     gp.glyph_top,
                     offset_x,
                     offset_y,
+                    style,
                 );
 
                 self.vao.index_buffer[self.index_counter] = index_count;
@@ -188,12 +284,31 @@ impl SimpleConsoleBackend {
             screen_y += step_y;
         }
 
-        self.vao.upload_buffers();
+        self.pending_upload = PendingUpload::Full;
         self.previous_console = Some(tiles.clone());
+        self.previous_styles = Some(styles.to_vec());
     }
 
-    pub fn gl_draw(&mut self, font: &Font, shader: &Shader) -> BResult<()> {
-        self.vao.draw_elements(shader, font);
+    /// Performs any GPU upload deferred from `rebuild_vertices`. Must run on the
+    /// thread owning the GL context.
+    pub fn upload(&mut self) {
+        match std::mem::take(&mut self.pending_upload) {
+            PendingUpload::None => {}
+            PendingUpload::Full => self.vao.upload_buffers(),
+            PendingUpload::Range(first, last) => self.vao.upload_vertex_range(first, last),
+        }
+    }
+
+    pub fn gl_draw(
+        &mut self,
+        font: &Font,
+        shader: &Shader,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) -> BResult<()> {
+        self.vao
+            .draw_elements(shader, font, blend_mode, transform, tint);
         Ok(())
     }
 }
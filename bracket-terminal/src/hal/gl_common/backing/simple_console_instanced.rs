@@ -0,0 +1,299 @@
+use crate::gl_error_wrap;
+use crate::hal::{shader_strings, BufferId, Font, PendingUpload, Shader, VertexArrayId, BACKEND};
+use crate::prelude::{BlendMode, ConsoleTransform, Tile};
+use crate::BResult;
+use bracket_color::prelude::RGBA;
+use glow::HasContext;
+use std::mem;
+
+/// Floats of per-instance data for each console cell: cell position (2), foreground
+/// color (4), background color (4) and glyph index (1).
+const FLOATS_PER_INSTANCE: usize = 11;
+
+/// Instanced rendering path for simple consoles. Instead of rebuilding four full
+/// vertices per cell on the CPU, it draws one unit quad instanced per cell; the quad is
+/// positioned and textured in the vertex shader from a compact per-instance buffer.
+/// This cuts the per-cell upload from 52 floats to 11 and makes very large consoles
+/// (300x200 and up) practical at high frame rates.
+pub struct SimpleConsoleInstancedBackend {
+    vao: Option<VertexArrayId>,
+    quad_vbo: Option<BufferId>,
+    quad_ebo: Option<BufferId>,
+    instance_vbo: Option<BufferId>,
+    instance_buffer: Vec<f32>,
+    instance_count: usize,
+    shader: Shader,
+    console_origin: (f32, f32),
+    cell_step: (f32, f32),
+    previous_console: Option<Vec<Tile>>,
+    pending_upload: PendingUpload,
+}
+
+impl SimpleConsoleInstancedBackend {
+    pub fn new(width: usize, height: usize, gl: &glow::Context) -> SimpleConsoleInstancedBackend {
+        let shader = Shader::new(
+            gl,
+            shader_strings::CONSOLE_INSTANCED_VS,
+            shader_strings::CONSOLE_WITH_BG_FS,
+        );
+
+        let mut backend = SimpleConsoleInstancedBackend {
+            vao: None,
+            quad_vbo: None,
+            quad_ebo: None,
+            instance_vbo: None,
+            instance_buffer: Vec::with_capacity(FLOATS_PER_INSTANCE * width * height),
+            instance_count: 0,
+            shader,
+            console_origin: (0.0, 0.0),
+            cell_step: (0.0, 0.0),
+            previous_console: None,
+            pending_upload: PendingUpload::None,
+        };
+        unsafe {
+            gl_error_wrap!(
+                gl,
+                backend.vao = Some(gl.create_vertex_array().expect("Unable to create VAO"))
+            );
+            gl_error_wrap!(
+                gl,
+                backend.quad_vbo = Some(gl.create_buffer().expect("Unable to create VBO"))
+            );
+            gl_error_wrap!(
+                gl,
+                backend.quad_ebo = Some(gl.create_buffer().expect("Unable to create EBO"))
+            );
+            gl_error_wrap!(
+                gl,
+                backend.instance_vbo = Some(gl.create_buffer().expect("Unable to create VBO"))
+            );
+
+            gl.bind_vertex_array(backend.vao);
+
+            // The shared unit quad: corners in cell space, expanded per instance in the
+            // vertex shader.
+            let quad_corners: [f32; 8] = [0.0, 0.0, 1.0, 0.0, 1.0, 1.0, 0.0, 1.0];
+            let quad_indices: [i32; 6] = [0, 1, 3, 1, 2, 3];
+            gl.bind_buffer(glow::ARRAY_BUFFER, backend.quad_vbo);
+            gl_error_wrap!(
+                gl,
+                gl.buffer_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    quad_corners.align_to::<u8>().1,
+                    glow::STATIC_DRAW,
+                )
+            );
+            gl_error_wrap!(
+                gl,
+                gl.vertex_attrib_pointer_f32(
+                    0,
+                    2,
+                    glow::FLOAT,
+                    false,
+                    2 * mem::size_of::<f32>() as i32,
+                    0
+                )
+            );
+            gl_error_wrap!(gl, gl.enable_vertex_attrib_array(0));
+
+            gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, backend.quad_ebo);
+            gl_error_wrap!(
+                gl,
+                gl.buffer_data_u8_slice(
+                    glow::ELEMENT_ARRAY_BUFFER,
+                    quad_indices.align_to::<u8>().1,
+                    glow::STATIC_DRAW,
+                )
+            );
+
+            // Per-instance data: advance these attributes once per quad, not per vertex.
+            gl.bind_buffer(glow::ARRAY_BUFFER, backend.instance_vbo);
+            let stride = (FLOATS_PER_INSTANCE * mem::size_of::<f32>()) as i32;
+            let entries: [(u32, i32, i32); 4] = [
+                (1, 2, 0),  // Cell position
+                (2, 4, 2),  // Foreground
+                (3, 4, 6),  // Background
+                (4, 1, 10), // Glyph
+            ];
+            for (index, size, offset) in &entries {
+                gl_error_wrap!(
+                    gl,
+                    gl.vertex_attrib_pointer_f32(
+                        *index,
+                        *size,
+                        glow::FLOAT,
+                        false,
+                        stride,
+                        offset * mem::size_of::<f32>() as i32,
+                    )
+                );
+                gl_error_wrap!(gl, gl.enable_vertex_attrib_array(*index));
+                gl_error_wrap!(gl, gl.vertex_attrib_divisor(*index, 1));
+            }
+
+            gl_error_wrap!(gl, gl.bind_vertex_array(None));
+        }
+
+        backend
+    }
+
+    /// Rebuilds the per-instance buffer. Cell geometry lives in the shader, so this
+    /// only has to refresh the layout uniforms and, when the tiles actually changed,
+    /// re-upload the compact instance data.
+    #[allow(clippy::too_many_arguments)]
+    pub fn rebuild_vertices(
+        &mut self,
+        height: u32,
+        width: u32,
+        tiles: &Vec<Tile>,
+        offset_x: f32,
+        offset_y: f32,
+        scale: f32,
+        needs_resize: bool,
+    ) {
+        let (step_x, step_y, left_x, top_y) = {
+            let be = BACKEND.lock();
+            let (step_x, step_y) = be.screen_scaler.calc_step(width, height, scale);
+            let (left_x, top_y) = be.screen_scaler.top_left_pixel();
+            (step_x, step_y, left_x, top_y)
+        };
+        self.console_origin = (left_x + offset_x, top_y + offset_y);
+        self.cell_step = (step_x, step_y);
+
+        if !needs_resize {
+            if let Some(old) = &self.previous_console {
+                if *old == *tiles {
+                    return;
+                }
+            }
+        }
+
+        self.instance_buffer.clear();
+        for (idx, tile) in tiles.iter().enumerate() {
+            let x = idx as u32 % width;
+            let y = idx as u32 / width;
+            self.instance_buffer.push(x as f32);
+            self.instance_buffer.push(y as f32);
+            self.instance_buffer.push(tile.fg.r);
+            self.instance_buffer.push(tile.fg.g);
+            self.instance_buffer.push(tile.fg.b);
+            self.instance_buffer.push(tile.fg.a);
+            self.instance_buffer.push(tile.bg.r);
+            self.instance_buffer.push(tile.bg.g);
+            self.instance_buffer.push(tile.bg.b);
+            self.instance_buffer.push(tile.bg.a);
+            self.instance_buffer.push(f32::from(tile.glyph));
+        }
+        self.instance_count = tiles.len();
+
+        self.pending_upload = PendingUpload::Full;
+        self.previous_console = Some(tiles.clone());
+    }
+
+    /// Performs any GPU upload deferred from `rebuild_vertices`. Must run on the
+    /// thread owning the GL context.
+    pub fn upload(&mut self) {
+        if !matches!(
+            std::mem::take(&mut self.pending_upload),
+            PendingUpload::Full
+        ) {
+            return;
+        }
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        unsafe {
+            gl_error_wrap!(gl, gl.bind_vertex_array(self.vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, self.instance_vbo);
+            gl_error_wrap!(
+                gl,
+                gl.buffer_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    self.instance_buffer.align_to::<u8>().1,
+                    glow::DYNAMIC_DRAW,
+                )
+            );
+            gl_error_wrap!(gl, gl.bind_vertex_array(None));
+        }
+    }
+
+    pub fn gl_draw(
+        &mut self,
+        font: &Font,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) -> BResult<()> {
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        let (physical_width, physical_height) = be.screen_scaler.physical_size;
+        let (offset_x, offset_y) = if physical_width > 0 && physical_height > 0 {
+            (
+                transform.offset_pixels.0 * 2.0 / physical_width as f32,
+                -transform.offset_pixels.1 * 2.0 / physical_height as f32,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let aspect = if physical_height > 0 {
+            physical_width as f32 / physical_height as f32
+        } else {
+            1.0
+        };
+        let (blend_src, blend_dst) = match blend_mode {
+            BlendMode::Alpha => (glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA),
+            BlendMode::Additive => (glow::SRC_ALPHA, glow::ONE),
+            BlendMode::Multiply => (glow::DST_COLOR, glow::ZERO),
+            BlendMode::Screen => (glow::ONE, glow::ONE_MINUS_SRC_COLOR),
+        };
+        unsafe {
+            gl_error_wrap!(gl, gl.bind_vertex_array(self.vao));
+            gl.bind_buffer(glow::ARRAY_BUFFER, self.quad_vbo);
+            gl.bind_buffer(glow::ELEMENT_ARRAY_BUFFER, self.quad_ebo);
+            self.shader.useProgram(gl);
+            self.shader.setVec2(
+                gl,
+                "consoleOrigin",
+                self.console_origin.0,
+                self.console_origin.1,
+            );
+            self.shader
+                .setVec2(gl, "cellStep", self.cell_step.0, self.cell_step.1);
+            self.shader.setVec2(
+                gl,
+                "fontGlyphDims",
+                font.font_dimensions_glyphs.0 as f32,
+                font.font_dimensions_glyphs.1 as f32,
+            );
+            self.shader.setVec2(
+                gl,
+                "fontTexDims",
+                font.font_dimensions_texture.0,
+                font.font_dimensions_texture.1,
+            );
+            self.shader
+                .setVec2(gl, "transformOffset", offset_x, offset_y);
+            self.shader
+                .setFloat(gl, "transformRotation", transform.rotation_radians);
+            self.shader.setFloat(gl, "transformScale", transform.scale);
+            self.shader.setFloat(gl, "transformAspect", aspect);
+            self.shader
+                .setVec4(gl, "consoleTint", tint.r, tint.g, tint.b, tint.a);
+            font.bind_texture(gl);
+            gl_error_wrap!(gl, gl.enable(glow::BLEND));
+            gl_error_wrap!(gl, gl.blend_func(blend_src, blend_dst));
+            gl_error_wrap!(
+                gl,
+                gl.draw_elements_instanced(
+                    glow::TRIANGLES,
+                    6,
+                    glow::UNSIGNED_INT,
+                    0,
+                    self.instance_count as i32,
+                )
+            );
+            gl_error_wrap!(gl, gl.disable(glow::BLEND));
+            gl_error_wrap!(gl, gl.bind_vertex_array(None));
+        }
+        Ok(())
+    }
+}
@@ -1,18 +1,25 @@
 use crate::hal::scaler::FontScaler;
-use crate::hal::{Font, Shader, VertexArray, VertexArrayEntry, BACKEND};
-use crate::prelude::SparseTile;
+use crate::hal::{Font, PendingUpload, Shader, VertexArray, VertexArrayEntry, BACKEND};
+use crate::prelude::{BlendMode, ConsoleTransform, SparseTile};
 use crate::BResult;
 use bracket_color::prelude::RGBA;
 
 pub struct SparseConsoleBackend {
     vao: VertexArray,
-    previous_console : Option<Vec<SparseTile>>,
+    previous_console: Option<Vec<SparseTile>>,
+    previous_styles: Option<Vec<f32>>,
+    pending_upload: PendingUpload,
 }
 
 impl SparseConsoleBackend {
     pub fn new(_width: usize, _height: usize, gl: &glow::Context) -> SparseConsoleBackend {
         let vao = SparseConsoleBackend::init_gl_for_console(gl, 1000, 1000);
-        SparseConsoleBackend { vao, previous_console: None }
+        SparseConsoleBackend {
+            vao,
+            previous_console: None,
+            previous_styles: None,
+            pending_upload: PendingUpload::None,
+        }
     }
 
     fn init_gl_for_console(
@@ -34,6 +41,7 @@ impl SparseConsoleBackend {
     }
 
     /// Helper to push a point to the shader.
+    #[allow(clippy::too_many_arguments)]
     fn push_point(
         vertex_buffer: &mut Vec<f32>,
         x: f32,
@@ -42,9 +50,10 @@ impl SparseConsoleBackend {
         bg: RGBA,
         ux: f32,
         uy: f32,
+        style: f32,
     ) {
         vertex_buffer.extend_from_slice(&[
-            x, y, 0.0, fg.r, fg.g, fg.b, fg.a, bg.r, bg.g, bg.b, bg.a, ux, uy,
+            x, y, style, fg.r, fg.g, fg.b, fg.a, bg.r, bg.g, bg.b, bg.a, ux, uy,
         ]);
     }
 
@@ -59,13 +68,14 @@ impl SparseConsoleBackend {
         scale: f32,
         scale_center: (i32, i32),
         tiles: &Vec<SparseTile>,
+        styles: &[f32],
         font_scaler: FontScaler,
         needs_resize: bool,
     ) {
         if !needs_resize {
-            if let Some(old) = &self.previous_console {
-                if old.len() == tiles.len() {
-                    let no_change = tiles.iter().zip(old.iter()).all(|(a, b)| *a==*b);
+            if let (Some(old), Some(old_styles)) = (&self.previous_console, &self.previous_styles) {
+                if old.len() == tiles.len() && *old_styles == styles {
+                    let no_change = tiles.iter().zip(old.iter()).all(|(a, b)| *a == *b);
                     if no_change {
                         return;
                     }
@@ -92,7 +102,7 @@ impl SparseConsoleBackend {
         //    - 2.0 * (scale_center.0 - width as i32 / 2) as f32 * (scale - 1.0) / width as f32;
         //let screen_y_start: f32 = -1.0 * scale
         //    + 2.0 * (scale_center.1 - height as i32 / 2) as f32 * (scale - 1.0) / height as f32;
-        for t in tiles.iter() {
+        for (i, t) in tiles.iter().enumerate() {
             let x = t.idx % width as usize;
             let y = t.idx / width as usize;
 
@@ -101,6 +111,7 @@ impl SparseConsoleBackend {
             let fg = t.fg;
             let bg = t.bg;
             let glyph = t.glyph;
+            let style = styles[i];
             let gp = font_scaler.glyph_position(glyph);
 
             SparseConsoleBackend::push_point(
@@ -111,6 +122,7 @@ impl SparseConsoleBackend {
                 bg,
                 gp.glyph_right,
                 gp.glyph_top,
+                style,
             );
             SparseConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -120,6 +132,7 @@ impl SparseConsoleBackend {
                 bg,
                 gp.glyph_right,
                 gp.glyph_bottom,
+                style,
             );
             SparseConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -129,6 +142,7 @@ impl SparseConsoleBackend {
                 bg,
                 gp.glyph_left,
                 gp.glyph_bottom,
+                style,
             );
             SparseConsoleBackend::push_point(
                 &mut self.vao.vertex_buffer,
@@ -138,6 +152,7 @@ impl SparseConsoleBackend {
                 bg,
                 gp.glyph_left,
                 gp.glyph_top,
+                style,
             );
 
             self.vao.index_buffer.push(index_count);
@@ -150,12 +165,31 @@ impl SparseConsoleBackend {
             index_count += 4;
         }
 
-        self.vao.upload_buffers();
+        self.pending_upload = PendingUpload::Full;
         self.previous_console = Some(tiles.clone());
+        self.previous_styles = Some(styles.to_vec());
+    }
+
+    /// Performs any GPU upload deferred from `rebuild_vertices`. Must run on the
+    /// thread owning the GL context.
+    pub fn upload(&mut self) {
+        match std::mem::take(&mut self.pending_upload) {
+            PendingUpload::None => {}
+            PendingUpload::Full => self.vao.upload_buffers(),
+            PendingUpload::Range(first, last) => self.vao.upload_vertex_range(first, last),
+        }
     }
 
-    pub fn gl_draw(&mut self, font: &Font, shader: &Shader) -> BResult<()> {
-        self.vao.draw_elements(shader, font);
+    pub fn gl_draw(
+        &mut self,
+        font: &Font,
+        shader: &Shader,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) -> BResult<()> {
+        self.vao
+            .draw_elements(shader, font, blend_mode, transform, tint);
         Ok(())
     }
 }
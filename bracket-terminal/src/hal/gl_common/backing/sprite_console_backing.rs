@@ -1,16 +1,20 @@
-use crate::hal::{Font, Shader, VertexArray, VertexArrayEntry};
-use crate::prelude::{RenderSprite, SpriteSheet};
+use crate::hal::{Font, PendingUpload, Shader, VertexArray, VertexArrayEntry};
+use crate::prelude::{BlendMode, ConsoleTransform, RenderSprite, SpriteSheet};
 use crate::BResult;
 use bracket_color::prelude::RGBA;
 
 pub struct SpriteConsoleBackend {
     vao: VertexArray,
+    pending_upload: PendingUpload,
 }
 
 impl SpriteConsoleBackend {
     pub fn new(_width: usize, _height: usize, gl: &glow::Context) -> SpriteConsoleBackend {
         let vao = SpriteConsoleBackend::init_gl_for_console(gl, 1000, 100);
-        SpriteConsoleBackend { vao }
+        SpriteConsoleBackend {
+            vao,
+            pending_upload: PendingUpload::None,
+        }
     }
 
     fn init_gl_for_console(
@@ -148,11 +152,29 @@ impl SpriteConsoleBackend {
             index_count += 4;
         }
 
-        self.vao.upload_buffers();
+        self.pending_upload = PendingUpload::Full;
     }
 
-    pub fn gl_draw(&mut self, font: &Font, shader: &Shader) -> BResult<()> {
-        self.vao.draw_elements(shader, font);
+    /// Performs any GPU upload deferred from `rebuild_vertices`. Must run on the
+    /// thread owning the GL context.
+    pub fn upload(&mut self) {
+        match std::mem::take(&mut self.pending_upload) {
+            PendingUpload::None => {}
+            PendingUpload::Full => self.vao.upload_buffers(),
+            PendingUpload::Range(first, last) => self.vao.upload_vertex_range(first, last),
+        }
+    }
+
+    pub fn gl_draw(
+        &mut self,
+        font: &Font,
+        shader: &Shader,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) -> BResult<()> {
+        self.vao
+            .draw_elements(shader, font, blend_mode, transform, tint);
         Ok(())
     }
 }
@@ -32,7 +32,10 @@ impl Font {
             tile_size,
             explicit_background: None,
             font_dimensions_glyphs: (tile_size.0 / width, tile_size.1 / height),
-            font_dimensions_texture: ( tile_size.0 as f32 / width as f32, tile_size.1 as f32 / height as f32 ),
+            font_dimensions_texture: (
+                tile_size.0 as f32 / width as f32,
+                tile_size.1 as f32 / height as f32,
+            ),
         }
     }
 
@@ -60,12 +63,51 @@ impl Font {
             tile_size,
             explicit_background,
             font_dimensions_glyphs: (img.width() / tile_size.0, img.height() / tile_size.1),
-            font_dimensions_texture: ( tile_size.0 as f32 / img.width() as f32, tile_size.1 as f32 / img.height() as f32),
+            font_dimensions_texture: (
+                tile_size.0 as f32 / img.width() as f32,
+                tile_size.1 as f32 / img.height() as f32,
+            ),
         }
     }
 
+    /// Builds a font from an in-memory image, registering the bytes with the
+    /// embedded-resource dictionary under `filename` so later texture loads
+    /// find them. Use for assets unpacked from archives or downloaded at
+    /// runtime rather than shipped as files.
+    pub fn load_bytes<S: ToString>(
+        filename: S,
+        bytes: &[u8],
+        tile_size: (u32, u32),
+        explicit_background: Option<RGB>,
+    ) -> Font {
+        embedding::EMBED
+            .lock()
+            .insert_bytes(filename.to_string(), bytes.to_vec());
+        Font::load(filename, tile_size, explicit_background)
+    }
+
+    /// Loads the font's bitmap as an RGBA image, applying the explicit background color
+    /// (if one is set) as transparency. Does not require a GL context; used by the
+    /// offscreen CPU rasterizer.
+    pub(crate) fn load_rgba(&self) -> image::RgbaImage {
+        let mut img = Font::load_image(&self.bitmap_file).to_rgba8();
+        if let Some(bg_rgb) = self.explicit_background {
+            let bg_r = (bg_rgb.r * 255.0) as u8;
+            let bg_g = (bg_rgb.g * 255.0) as u8;
+            let bg_b = (bg_rgb.b * 255.0) as u8;
+            for p in img.pixels_mut() {
+                if p[0] == bg_r && p[1] == bg_g && p[2] == bg_b {
+                    *p = image::Rgba([0, 0, 0, 0]);
+                }
+            }
+        }
+        img
+    }
+
     /// Load a font, and allocate it as an OpenGL resource. Returns the OpenGL binding number (which is also set in the structure).
     pub fn setup_gl_texture(&mut self, gl: &glow::Context) -> BResult<TextureId> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("font_upload", filename = %self.bitmap_file).entered();
         let texture;
 
         unsafe {
@@ -160,6 +202,15 @@ mod tests {
         assert_eq!(f.height, 2);
     }
 
+    #[test]
+    // Tests that a byte-slice font registers and loads like a file-based one.
+    fn make_font_from_bytes() {
+        let bytes = include_bytes!("../../../resources/terminal8x8.png");
+        let f = Font::load_bytes("runtime/terminal8x8.png", bytes, (8, 8), None);
+        assert_eq!(f.width, 128);
+        assert_eq!(f.height, 128);
+    }
+
     #[test]
     // Tests that we make an RGB triplet at defaults and it is black.
     fn make_font_from_file() {
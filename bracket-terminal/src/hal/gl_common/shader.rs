@@ -115,10 +115,24 @@ impl Shader {
         gl.uniform_2_f32(gl.get_uniform_location(self.ID, name).as_ref(), x, y);
     }
 
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_safety_doc)]
+    /// ------------------------------------------------------------------------
+    pub unsafe fn setVec4(&self, gl: &glow::Context, name: &str, x: f32, y: f32, z: f32, w: f32) {
+        gl.uniform_4_f32(gl.get_uniform_location(self.ID, name).as_ref(), x, y, z, w);
+    }
+
     #[allow(non_snake_case)]
     #[allow(clippy::missing_safety_doc)]
     /// ------------------------------------------------------------------------
     pub unsafe fn setMat4(&self, gl: &glow::Context, name: &str, mat: &[f32; 16]) {
         gl.uniform_matrix_4_f32_slice(gl.get_uniform_location(self.ID, name).as_ref(), false, mat);
     }
+
+    #[allow(non_snake_case)]
+    #[allow(clippy::missing_safety_doc)]
+    /// ------------------------------------------------------------------------
+    pub unsafe fn setMat3(&self, gl: &glow::Context, name: &str, mat: &[f32; 9]) {
+        gl.uniform_matrix_3_f32_slice(gl.get_uniform_location(self.ID, name).as_ref(), false, mat);
+    }
 }
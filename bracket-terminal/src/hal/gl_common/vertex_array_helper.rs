@@ -1,6 +1,8 @@
 use super::{BufferId, Font, Shader, VertexArrayId};
 use crate::gl_error_wrap;
 use crate::hal::BACKEND;
+use crate::prelude::{BlendMode, ConsoleTransform};
+use bracket_color::prelude::RGBA;
 use glow::HasContext;
 use std::mem;
 
@@ -111,18 +113,76 @@ impl VertexArray {
         }
     }
 
-    pub(crate) fn draw_elements(&self, shader: &Shader, font: &Font) {
+    /// Uploads a contiguous range of the vertex buffer in place, leaving the rest of the
+    /// GPU-side buffer untouched - used by the dirty-rectangle patching path.
+    pub(crate) fn upload_vertex_range(&self, first_float: usize, last_float: usize) {
         let be = BACKEND.lock();
         let gl = be.gl.as_ref().unwrap();
         unsafe {
             self.bind(gl);
-            shader.useProgram(gl);
-            font.bind_texture(gl);
-            gl_error_wrap!(gl, gl.enable(glow::BLEND));
             gl_error_wrap!(
                 gl,
-                gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA)
+                gl.buffer_sub_data_u8_slice(
+                    glow::ARRAY_BUFFER,
+                    (first_float * mem::size_of::<f32>()) as i32,
+                    self.vertex_buffer[first_float..last_float]
+                        .align_to::<u8>()
+                        .1,
+                )
             );
+            gl_error_wrap!(gl, gl.bind_vertex_array(None));
+        }
+    }
+
+    pub(crate) fn draw_elements(
+        &self,
+        shader: &Shader,
+        font: &Font,
+        blend_mode: BlendMode,
+        transform: &ConsoleTransform,
+        tint: RGBA,
+    ) {
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        let (physical_width, physical_height) = be.screen_scaler.physical_size;
+        let (offset_x, offset_y) = if physical_width > 0 && physical_height > 0 {
+            (
+                transform.offset_pixels.0 * 2.0 / physical_width as f32,
+                -transform.offset_pixels.1 * 2.0 / physical_height as f32,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+        let aspect = if physical_height > 0 {
+            physical_width as f32 / physical_height as f32
+        } else {
+            1.0
+        };
+        let (blend_src, blend_dst) = match blend_mode {
+            BlendMode::Alpha => (glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA),
+            BlendMode::Additive => (glow::SRC_ALPHA, glow::ONE),
+            BlendMode::Multiply => (glow::DST_COLOR, glow::ZERO),
+            BlendMode::Screen => (glow::ONE, glow::ONE_MINUS_SRC_COLOR),
+        };
+        unsafe {
+            self.bind(gl);
+            shader.useProgram(gl);
+            shader.setVec2(gl, "transformOffset", offset_x, offset_y);
+            shader.setFloat(gl, "transformRotation", transform.rotation_radians);
+            shader.setFloat(gl, "transformScale", transform.scale);
+            shader.setFloat(gl, "transformAspect", aspect);
+            shader.setVec4(gl, "consoleTint", tint.r, tint.g, tint.b, tint.a);
+            if font.width > 0 && font.height > 0 {
+                shader.setVec2(
+                    gl,
+                    "texelSize",
+                    1.0 / font.width as f32,
+                    1.0 / font.height as f32,
+                );
+            }
+            font.bind_texture(gl);
+            gl_error_wrap!(gl, gl.enable(glow::BLEND));
+            gl_error_wrap!(gl, gl.blend_func(blend_src, blend_dst));
             gl_error_wrap!(
                 gl,
                 gl.draw_elements(
@@ -56,6 +56,9 @@ pub use dummy::*;
 #[cfg(any(feature = "opengl", feature = "webgpu"))]
 mod scaler;
 
+#[cfg(any(feature = "opengl", feature = "webgpu"))]
+pub use scaler::ScalingMode;
+
 /// Provides a base abstract platform for BTerm to run on, with specialized content.
 pub struct BTermPlatform {
     pub platform: PlatformGL,
@@ -0,0 +1,45 @@
+//! Optional egui layer over the native OpenGL back-end. Window events are
+//! offered to egui before the game sees them, and the UI paints over the
+//! consoles (after any post-processing) each frame. Build your UI inside
+//! `GameState::tick` through `BTerm::egui_ctx`.
+
+use parking_lot::Mutex;
+
+lazy_static! {
+    /// The live egui context, cloned out through `BTerm::egui_ctx`.
+    pub static ref EGUI_CTX: Mutex<Option<egui::CtxRef>> = Mutex::new(None);
+}
+
+type WindowedContext = glutin::WindowedContext<glutin::PossiblyCurrent>;
+
+pub(crate) struct EguiLayer {
+    glue: egui_glow::EguiGlow,
+    gl: glow_egui::Context,
+}
+
+impl EguiLayer {
+    pub(crate) fn new(wc: &WindowedContext) -> Self {
+        let gl = unsafe {
+            glow_egui::Context::from_loader_function(|ptr| wc.get_proc_address(ptr) as *const _)
+        };
+        let glue = egui_glow::EguiGlow::new(wc, &gl);
+        *EGUI_CTX.lock() = Some(glue.ctx().clone());
+        Self { glue, gl }
+    }
+
+    /// Offers a window event to egui. True means egui claimed it (typing into
+    /// a text field, clicking a widget) and the game should not see it.
+    pub(crate) fn on_event(&mut self, event: &glutin::event::WindowEvent) -> bool {
+        self.glue.on_event(event)
+    }
+
+    pub(crate) fn begin_frame(&mut self, wc: &WindowedContext) {
+        self.glue.begin_frame(wc.window());
+    }
+
+    /// Finishes the egui frame and paints it over the rendered consoles.
+    pub(crate) fn end_frame_and_paint(&mut self, wc: &WindowedContext) {
+        let (_needs_repaint, shapes) = self.glue.end_frame(wc.window());
+        self.glue.paint(wc, &self.gl, shapes);
+    }
+}
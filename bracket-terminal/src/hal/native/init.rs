@@ -13,11 +13,22 @@ pub fn init_raw<S: ToString>(
     platform_hints: InitHints,
 ) -> BResult<BTerm> {
     let mut scaler = ScreenScaler::new(platform_hints.desired_gutter, width_pixels, height_pixels);
+    scaler.scaling_mode = platform_hints.scaling_mode;
     let el = EventLoop::new();
-    let wb = WindowBuilder::new()
+    let mut wb = WindowBuilder::new()
         .with_title(window_title.to_string())
         .with_min_inner_size(scaler.new_window_size())
-        .with_inner_size(scaler.new_window_size());
+        .with_inner_size(scaler.new_window_size())
+        .with_decorations(!platform_hints.borderless)
+        .with_always_on_top(platform_hints.always_on_top)
+        .with_resizable(platform_hints.resizable)
+        .with_transparent(platform_hints.transparent);
+    if let Some((width, height)) = platform_hints.min_inner_size {
+        wb = wb.with_min_inner_size(glutin::dpi::LogicalSize::new(width, height));
+    }
+    if let Some((width, height)) = platform_hints.max_inner_size {
+        wb = wb.with_max_inner_size(glutin::dpi::LogicalSize::new(width, height));
+    }
     let windowed_context = ContextBuilder::new()
         .with_gl(platform_hints.gl_version)
         .with_gl_profile(platform_hints.gl_profile)
@@ -27,14 +38,30 @@ pub fn init_raw<S: ToString>(
         .build_windowed(wb, &el)?;
     let windowed_context = unsafe { windowed_context.make_current().unwrap() };
 
+    // Resolve the requested monitor, falling back to the first available one.
+    let monitor = platform_hints
+        .monitor
+        .and_then(|index| el.available_monitors().nth(index))
+        .or_else(|| el.available_monitors().next());
+
     if platform_hints.fullscreen {
-        if let Some(mh) = el.available_monitors().next() {
+        if let Some(mh) = monitor {
             windowed_context
                 .window()
                 .set_fullscreen(Some(glutin::window::Fullscreen::Borderless(Some(mh))));
         } else {
             return Err("No available monitor found".into());
         }
+    } else if platform_hints.monitor.is_some() || platform_hints.window_position.is_some() {
+        // Position the window on the requested monitor, offset by any requested position.
+        let mut position = monitor
+            .map(|mh| mh.position())
+            .unwrap_or_else(|| glutin::dpi::PhysicalPosition::new(0, 0));
+        if let Some((x, y)) = platform_hints.window_position {
+            position.x += x;
+            position.y += y;
+        }
+        windowed_context.window().set_outer_position(position);
     }
 
     let gl = unsafe {
@@ -92,6 +119,10 @@ pub fn init_raw<S: ToString>(
     let mut be = BACKEND.lock();
     be.gl = Some(gl);
     be.quad_vao = Some(quad_vao);
+    {
+        use raw_window_handle::HasRawWindowHandle;
+        be.raw_window_handle = Some(windowed_context.window().raw_window_handle());
+    }
     be.context_wrapper = Some(WrappedContext {
         el,
         wc: windowed_context,
@@ -99,6 +130,12 @@ pub fn init_raw<S: ToString>(
     be.backing_buffer = Some(backing_fbo);
     be.frame_sleep_time = crate::hal::convert_fps_to_wait(platform_hints.frame_sleep_time);
     be.resize_scaling = platform_hints.resize_scaling;
+    be.fullscreen = platform_hints.fullscreen;
+    be.transparent = platform_hints.transparent;
+    be.vsync = platform_hints.vsync;
+    be.frame_pacing = platform_hints.frame_pacing;
+    be.fixed_time_step = platform_hints.fixed_time_step;
+    be.instanced_consoles = platform_hints.instanced_consoles;
     be.screen_scaler = scaler;
 
     BACKEND_INTERNAL.lock().shaders = shaders;
@@ -121,7 +158,12 @@ pub fn init_raw<S: ToString>(
         quitting: false,
         post_scanlines: false,
         post_screenburn: false,
+        interpolation_alpha: 0.0,
+        ups: 0.0,
+        update_time_ms: 0.0,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        crt_params: crate::prelude::CrtParams::default(),
+        colorblind_mode: None,
     };
     Ok(bterm)
 }
@@ -37,7 +37,12 @@ fn on_resize(
     //println!("{:#?}", physical_size);
     INPUT.lock().set_scale_factor(dpi_scale_factor);
     let mut be = BACKEND.lock();
-    be.screen_scaler.change_physical_size_smooth(physical_size.width, physical_size.height, dpi_scale_factor as f32, font_max_size);
+    be.screen_scaler.change_physical_size_smooth(
+        physical_size.width,
+        physical_size.height,
+        dpi_scale_factor as f32,
+        font_max_size,
+    );
     if send_event {
         bterm.resize_pixels(
             physical_size.width as u32,
@@ -57,14 +62,14 @@ fn on_resize(
             )
         );
     }
-    let new_fb = Framebuffer::build_fbo(
-        gl, 
-        physical_size.width as i32, 
-        physical_size.height as i32
-    )?;
+    let new_fb =
+        Framebuffer::build_fbo(gl, physical_size.width as i32, physical_size.height as i32)?;
     be.backing_buffer = Some(new_fb);
     bterm.on_event(BEvent::Resized {
-        new_size: Point::new(be.screen_scaler.available_width, be.screen_scaler.available_height),
+        new_size: Point::new(
+            be.screen_scaler.available_width,
+            be.screen_scaler.available_height,
+        ),
         dpi_scale_factor: dpi_scale_factor as f32,
     });
 
@@ -88,25 +93,33 @@ struct ResizeEvent {
     send_event: bool,
 }
 
+/// One-time upload of font and sprite sheet textures to the GPU.
+fn setup_gl_resources() -> BResult<()> {
+    let be = BACKEND.lock();
+    let gl = be.gl.as_ref().unwrap();
+    let mut bit = BACKEND_INTERNAL.lock();
+    for f in bit.fonts.iter_mut() {
+        f.setup_gl_texture(gl)?;
+    }
+
+    for s in bit.sprite_sheets.iter_mut() {
+        let mut f = Font::new(&s.filename.to_string(), 1, 1, (1, 1));
+        f.setup_gl_texture(gl)?;
+        s.backing = Some(Rc::new(Box::new(f)));
+    }
+    Ok(())
+}
+
 pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<()> {
     let now = Instant::now();
     let mut prev_seconds = now.elapsed().as_secs();
     let mut prev_ms = now.elapsed().as_millis();
     let mut frames = 0;
+    let mut updates = 0;
 
-    {
-        let be = BACKEND.lock();
-        let gl = be.gl.as_ref().unwrap();
-        let mut bit = BACKEND_INTERNAL.lock();
-        for f in bit.fonts.iter_mut() {
-            f.setup_gl_texture(gl)?;
-        }
-
-        for s in bit.sprite_sheets.iter_mut() {
-            let mut f = Font::new(&s.filename.to_string(), 1, 1, (1, 1));
-            f.setup_gl_texture(gl)?;
-            s.backing = Some(Rc::new(Box::new(f)));
-        }
+    let mut gl_resources_ready = crate::resource_loader::resources_ready();
+    if gl_resources_ready {
+        setup_gl_resources()?;
     }
 
     // We're doing a little dance here to get around lifetime/borrow checking.
@@ -125,12 +138,39 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
     )?; // Additional resize to handle some X11 cases
 
     let mut queued_resize_event: Option<ResizeEvent> = None;
+    let mut fixed_time_accumulator: f32 = 0.0;
     let spin_sleeper = spin_sleep::SpinSleeper::default();
     let my_window_id = wc.window().id();
 
+    #[cfg(feature = "bracket-egui")]
+    let mut egui_layer = super::egui_support::EguiLayer::new(&wc);
+
     el.run(move |event, _, control_flow| {
-        let wait_time = BACKEND.lock().frame_sleep_time.unwrap_or(33); // Hoisted to reduce locks
-        *control_flow = TICK_TYPE;
+        let (wait_time, pacing) = {
+            // Hoisted to reduce locks. When vsync is pacing the frames and no explicit FPS cap
+            // was requested, skip the spin-sleeper entirely so we don't double-throttle.
+            let be = BACKEND.lock();
+            let fallback = if be.vsync { 0 } else { 33 };
+            let wait = match be.frame_pacing {
+                FramePacing::Uncapped => be.frame_sleep_time.unwrap_or(fallback),
+                FramePacing::CapFps(fps) => 1000 / u64::from(fps.max(1)),
+                FramePacing::EventDriven => be.frame_sleep_time.unwrap_or(0),
+            };
+            (wait, be.frame_pacing)
+        };
+        *control_flow = match pacing {
+            // Power-saving mode: wake only for input, or for the frame timer if a cap is set.
+            FramePacing::EventDriven => {
+                if wait_time > 0 {
+                    ControlFlow::WaitUntil(
+                        Instant::now() + std::time::Duration::from_millis(wait_time),
+                    )
+                } else {
+                    ControlFlow::Wait
+                }
+            }
+            _ => TICK_TYPE,
+        };
 
         if bterm.quitting {
             *control_flow = ControlFlow::Exit;
@@ -143,6 +183,83 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     return;
                 }
 
+                // Defer texture uploads until async resources land; show a
+                // blank loading screen meanwhile.
+                if !gl_resources_ready {
+                    if crate::resource_loader::resources_ready() {
+                        setup_gl_resources().expect("Failed to set up GL resources");
+                        gl_resources_ready = true;
+                    } else {
+                        unsafe {
+                            let be = BACKEND.lock();
+                            let gl = be.gl.as_ref().unwrap();
+                            gl.clear_color(0.0, 0.0, 0.0, 1.0);
+                            gl.clear(glow::COLOR_BUFFER_BIT);
+                        }
+                        wc.swap_buffers().unwrap();
+                        return;
+                    }
+                }
+
+                // Apply any queued window changes
+                {
+                    let mut be = BACKEND.lock();
+                    if let Some(title) = be.request_window_title.take() {
+                        wc.window().set_title(&title);
+                    }
+                    if let Some((bytes, width, height)) = be.request_window_icon.take() {
+                        wc.window().set_window_icon(
+                            glutin::window::Icon::from_rgba(bytes, width, height).ok(),
+                        );
+                    }
+                    if let Some((x, y)) = be.request_window_position.take() {
+                        wc.window()
+                            .set_outer_position(glutin::dpi::PhysicalPosition::new(x, y));
+                    }
+                    if let Some(visible) = be.request_cursor_visible.take() {
+                        wc.window().set_cursor_visible(visible);
+                    }
+                    if let Some(icon) = be.request_cursor_icon.take() {
+                        use crate::prelude::CursorIcon;
+                        wc.window().set_cursor_icon(match icon {
+                            CursorIcon::Crosshair => glutin::window::CursorIcon::Crosshair,
+                            CursorIcon::Hand => glutin::window::CursorIcon::Hand,
+                            CursorIcon::Text => glutin::window::CursorIcon::Text,
+                            CursorIcon::Wait => glutin::window::CursorIcon::Wait,
+                            CursorIcon::Help => glutin::window::CursorIcon::Help,
+                            CursorIcon::NotAllowed => glutin::window::CursorIcon::NotAllowed,
+                            CursorIcon::Move => glutin::window::CursorIcon::Move,
+                            // Custom image cursors aren't supported by the native
+                            // windowing layer; fall back to the default arrow.
+                            CursorIcon::Default | CursorIcon::Custom(_) => {
+                                glutin::window::CursorIcon::Default
+                            }
+                        });
+                    }
+                    if let Some(fullscreen) = be.request_fullscreen.take() {
+                        if fullscreen {
+                            let monitor = wc.window().current_monitor();
+                            wc.window().set_fullscreen(Some(
+                                glutin::window::Fullscreen::Borderless(monitor),
+                            ));
+                        } else {
+                            wc.window().set_fullscreen(None);
+                        }
+                        be.fullscreen = fullscreen;
+                        // Re-run the resize path so consoles re-scale to the new surface.
+                        // The window will also send Resized once the mode change lands.
+                        queued_resize_event = Some(ResizeEvent {
+                            physical_size: wc.window().inner_size(),
+                            dpi_scale_factor: wc.window().scale_factor(),
+                            send_event: true,
+                        });
+                    }
+                }
+                if let Some(capture) = INPUT.lock().take_capture_request() {
+                    let _ = wc.window().set_cursor_grab(capture);
+                    wc.window().set_cursor_visible(!capture);
+                }
+
                 let execute_ms = now.elapsed().as_millis() as u64 - prev_ms as u64;
                 if execute_ms >= wait_time {
                     if queued_resize_event.is_some() {
@@ -159,7 +276,9 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         queued_resize_event = None;
                     }
 
-                    tock(
+                    #[cfg(feature = "bracket-egui")]
+                    egui_layer.begin_frame(&wc);
+                    let mut frame_times = tock(
                         &mut bterm,
                         wc.window().scale_factor() as f32,
                         &mut gamestate,
@@ -167,15 +286,24 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                         &mut prev_seconds,
                         &mut prev_ms,
                         &now,
+                        &mut fixed_time_accumulator,
+                        &mut updates,
                     );
+                    #[cfg(feature = "bracket-egui")]
+                    egui_layer.end_frame_and_paint(&wc);
+                    let present_timer = Instant::now();
                     wc.swap_buffers().unwrap();
+                    frame_times.present = present_timer.elapsed().as_secs_f32() * 1000.0;
+                    frame_times.total = frame_timer.elapsed().as_secs_f32() * 1000.0;
+                    crate::frame_stats::record(frame_times);
                     // Moved from new events, which doesn't make sense
                     clear_input_state(&mut bterm);
                 }
 
-                // Wait for an appropriate amount of time
+                // Wait for an appropriate amount of time. Event-driven pacing waits in the
+                // event loop itself, so there's nothing to burn here.
                 let time_since_last_frame = frame_timer.elapsed().as_millis() as u64;
-                if time_since_last_frame < wait_time {
+                if pacing != FramePacing::EventDriven && time_since_last_frame < wait_time {
                     let delay = u64::min(33, wait_time - time_since_last_frame);
                     //println!("Frame time: {}ms, Delay: {}ms", time_since_last_frame, delay);
                     //*control_flow = ControlFlow::WaitUntil(Instant::now() + std::time::Duration::from_millis(delay));
@@ -184,6 +312,12 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     //*control_flow = ControlFlow::WaitUntil(Instant::now() + std::time::Duration::from_millis(1));
                 }
             }
+            Event::DeviceEvent {
+                event: glutin::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                INPUT.lock().on_mouse_delta(delta.0 as f32, delta.1 as f32);
+            }
             Event::WindowEvent { event, window_id } => {
                 // Fast return for other windows
                 if *window_id != my_window_id {
@@ -191,6 +325,15 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     return;
                 }
 
+                #[cfg(feature = "tracing")]
+                let _span = tracing::trace_span!("event_dispatch").entered();
+
+                // Let egui claim pointer/keyboard events aimed at its widgets.
+                #[cfg(feature = "bracket-egui")]
+                if egui_layer.on_event(event) {
+                    return;
+                }
+
                 // Handle Window Events
                 match event {
                     WindowEvent::Moved(physical_position) => {
@@ -239,6 +382,30 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
                     WindowEvent::CursorEntered { .. } => bterm.on_event(BEvent::CursorEntered),
                     WindowEvent::CursorLeft { .. } => bterm.on_event(BEvent::CursorLeft),
 
+                    WindowEvent::Touch(touch) => {
+                        let loc = touch.location;
+                        match touch.phase {
+                            glutin::event::TouchPhase::Started => {
+                                bterm.on_touch_start(loc.x, loc.y)
+                            }
+                            glutin::event::TouchPhase::Moved => bterm.on_touch_move(loc.x, loc.y),
+                            glutin::event::TouchPhase::Ended
+                            | glutin::event::TouchPhase::Cancelled => {
+                                bterm.on_touch_end(loc.x, loc.y)
+                            }
+                        }
+                    }
+
+                    WindowEvent::MouseWheel { delta, .. } => {
+                        let (x, y) = match delta {
+                            glutin::event::MouseScrollDelta::LineDelta(x, y) => (*x, *y),
+                            glutin::event::MouseScrollDelta::PixelDelta(pos) => {
+                                (pos.x as f32, pos.y as f32)
+                            }
+                        };
+                        bterm.on_mouse_wheel(x, y);
+                    }
+
                     WindowEvent::MouseInput { button, state, .. } => {
                         let button = match button {
                             MouseButton::Left => 0,
@@ -291,7 +458,224 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
     });
 }
 
+/// Persistent timing state for `run_single_frame`, which can't keep locals alive between
+/// calls the way `main_loop`'s closure does.
+struct SingleFrameState {
+    now: Option<Instant>,
+    prev_seconds: u64,
+    prev_ms: u128,
+    frames: i32,
+    fixed_time_accumulator: f32,
+    updates: i32,
+}
+
+lazy_static::lazy_static! {
+    static ref SINGLE_FRAME: parking_lot::Mutex<SingleFrameState> =
+        parking_lot::Mutex::new(SingleFrameState {
+            now: None,
+            prev_seconds: 0,
+            prev_ms: 0,
+            frames: 0,
+            fixed_time_accumulator: 0.0,
+            updates: 0,
+        });
+}
+
+/// Pumps pending window events and renders exactly one frame, returning control to the
+/// caller afterwards. Use this instead of `main_loop` when the host application owns the
+/// event loop (editors, engines, test harnesses). Not compatible with `main_loop` - pick
+/// one driving style and stick with it.
+pub fn run_single_frame<GS: GameState>(bterm: &mut BTerm, gamestate: &mut GS) -> BResult<()> {
+    use glutin::platform::run_return::EventLoopExtRunReturn;
+
+    let wrap = { std::mem::replace(&mut BACKEND.lock().context_wrapper, None) };
+    let mut unwrap =
+        wrap.ok_or("Platform context is unavailable - was main_loop already started?")?;
+
+    let mut sf = SINGLE_FRAME.lock();
+    if sf.now.is_none() {
+        setup_gl_resources()?;
+        on_resize(
+            bterm,
+            unwrap.wc.window().inner_size(),
+            unwrap.wc.window().scale_factor(),
+            true,
+        )?;
+        sf.now = Some(Instant::now());
+    }
+    let now = sf.now.unwrap();
+    let my_window_id = unwrap.wc.window().id();
+
+    // Drain pending events, then hand control straight back
+    {
+        let wc = &unwrap.wc;
+        let bterm = &mut *bterm;
+        unwrap.el.run_return(|event, _, control_flow| match &event {
+            Event::MainEventsCleared => {
+                *control_flow = ControlFlow::Exit;
+            }
+            Event::DeviceEvent {
+                event: glutin::event::DeviceEvent::MouseMotion { delta },
+                ..
+            } => {
+                INPUT.lock().on_mouse_delta(delta.0 as f32, delta.1 as f32);
+            }
+            Event::WindowEvent { event, window_id } => {
+                if *window_id != my_window_id {
+                    return;
+                }
+                match event {
+                    WindowEvent::Resized(_physical_size) => {
+                        let scale_factor = wc.window().scale_factor();
+                        let physical_size = wc.window().inner_size();
+                        wc.resize(physical_size);
+                        on_resize(bterm, physical_size, scale_factor, true).unwrap();
+                    }
+                    WindowEvent::CloseRequested => {
+                        if !INPUT.lock().use_events {
+                            bterm.quitting = true;
+                        } else {
+                            bterm.on_event(BEvent::CloseRequested);
+                        }
+                    }
+                    WindowEvent::ReceivedCharacter(char) => {
+                        bterm.on_event(BEvent::Character { c: *char });
+                    }
+                    WindowEvent::Focused(focused) => {
+                        bterm.on_event(BEvent::Focused { focused: *focused });
+                    }
+                    WindowEvent::CursorMoved { position: pos, .. } => {
+                        bterm.on_mouse_position(pos.x, pos.y);
+                    }
+                    WindowEvent::CursorEntered { .. } => bterm.on_event(BEvent::CursorEntered),
+                    WindowEvent::CursorLeft { .. } => bterm.on_event(BEvent::CursorLeft),
+                    WindowEvent::Touch(touch) => {
+                        let loc = touch.location;
+                        match touch.phase {
+                            glutin::event::TouchPhase::Started => {
+                                bterm.on_touch_start(loc.x, loc.y)
+                            }
+                            glutin::event::TouchPhase::Moved => bterm.on_touch_move(loc.x, loc.y),
+                            glutin::event::TouchPhase::Ended
+                            | glutin::event::TouchPhase::Cancelled => {
+                                bterm.on_touch_end(loc.x, loc.y)
+                            }
+                        }
+                    }
+                    WindowEvent::MouseWheel { delta, .. } => {
+                        let (x, y) = match delta {
+                            glutin::event::MouseScrollDelta::LineDelta(x, y) => (*x, *y),
+                            glutin::event::MouseScrollDelta::PixelDelta(pos) => {
+                                (pos.x as f32, pos.y as f32)
+                            }
+                        };
+                        bterm.on_mouse_wheel(x, y);
+                    }
+                    WindowEvent::MouseInput { button, state, .. } => {
+                        let button = match button {
+                            MouseButton::Left => 0,
+                            MouseButton::Right => 1,
+                            MouseButton::Middle => 2,
+                            MouseButton::Other(num) => 3 + *num as usize,
+                        };
+                        bterm.on_mouse_button(
+                            button,
+                            *state == glutin::event::ElementState::Pressed,
+                        );
+                    }
+                    WindowEvent::KeyboardInput {
+                        input:
+                            glutin::event::KeyboardInput {
+                                virtual_keycode: Some(virtual_keycode),
+                                state,
+                                scancode,
+                                ..
+                            },
+                        ..
+                    } => bterm.on_key(
+                        *virtual_keycode,
+                        *scancode,
+                        *state == glutin::event::ElementState::Pressed,
+                    ),
+                    WindowEvent::ModifiersChanged(modifiers) => {
+                        bterm.shift = modifiers.shift();
+                        bterm.alt = modifiers.alt();
+                        bterm.control = modifiers.ctrl();
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        });
+    }
+
+    // Render the frame
+    let SingleFrameState {
+        ref mut prev_seconds,
+        ref mut prev_ms,
+        ref mut frames,
+        ref mut fixed_time_accumulator,
+        ref mut updates,
+        ..
+    } = *sf;
+    let mut frame_times = tock(
+        bterm,
+        unwrap.wc.window().scale_factor() as f32,
+        gamestate,
+        frames,
+        prev_seconds,
+        prev_ms,
+        &now,
+        fixed_time_accumulator,
+        updates,
+    );
+    let present_timer = Instant::now();
+    unwrap.wc.swap_buffers().map_err(|e| e.to_string())?;
+    frame_times.present = present_timer.elapsed().as_secs_f32() * 1000.0;
+    frame_times.total =
+        frame_times.tick + frame_times.rebuild + frame_times.render + frame_times.present;
+    crate::frame_stats::record(frame_times);
+    clear_input_state(bterm);
+
+    BACKEND.lock().context_wrapper = Some(unwrap);
+    Ok(())
+}
+
+// Uploads the whole-frame colorblind-simulation uniforms. The matrix is
+// row-major; the shader multiplies vector * matrix, which undoes the
+// column-major upload's implicit transpose.
+unsafe fn set_colorblind_uniforms(
+    shader: &Shader,
+    gl: &glow::Context,
+    mode: Option<bracket_color::prelude::ColorblindMode>,
+) {
+    shader.setBool(gl, "colorblindEnabled", mode.is_some());
+    if let Some(mode) = mode {
+        let m = mode.matrix();
+        shader.setMat3(
+            gl,
+            "colorblindMatrix",
+            &[
+                m[0][0], m[0][1], m[0][2], m[1][0], m[1][1], m[1][2], m[2][0], m[2][1], m[2][2],
+            ],
+        );
+    }
+}
+
+/// Gathers the viewport/backing-buffer details handed to staged GL callbacks.
+fn callback_info(be: &PlatformGL, use_post_pass: bool) -> GlCallbackInfo {
+    GlCallbackInfo {
+        viewport: be.screen_scaler.physical_size,
+        backing_texture: if use_post_pass {
+            be.backing_buffer.as_ref().map(|fb| fb.texture)
+        } else {
+            None
+        },
+    }
+}
+
 /// Internal handling of the main loop.
+#[allow(clippy::too_many_arguments)]
 fn tock<GS: GameState>(
     bterm: &mut BTerm,
     scale_factor: f32,
@@ -300,7 +684,9 @@ fn tock<GS: GameState>(
     prev_seconds: &mut u64,
     prev_ms: &mut u128,
     now: &Instant,
-) {
+    fixed_time_accumulator: &mut f32,
+    updates: &mut i32,
+) -> crate::frame_stats::FrameTimes {
     // Check that the console backings match our actual consoles
     check_console_backing();
 
@@ -309,7 +695,9 @@ fn tock<GS: GameState>(
 
     if now_seconds > *prev_seconds {
         bterm.fps = *frames as f32 / (now_seconds - *prev_seconds) as f32;
+        bterm.ups = *updates as f32 / (now_seconds - *prev_seconds) as f32;
         *frames = 0;
+        *updates = 0;
         *prev_seconds = now_seconds;
     }
 
@@ -320,10 +708,15 @@ fn tock<GS: GameState>(
     }
 
     // Console structure - doesn't really have to be every frame...
+    let phase_timer = Instant::now();
     rebuild_consoles();
+    let rebuild_ms = phase_timer.elapsed().as_secs_f32() * 1000.0;
 
-    // Bind to the backing buffer
-    if bterm.post_scanlines {
+    // Bind to the backing buffer if a post-processing pass will run
+    let use_post_pass = bterm.post_scanlines
+        || bterm.colorblind_mode.is_some()
+        || BACKEND_INTERNAL.lock().post_shader.is_some();
+    if use_post_pass {
         let be = BACKEND.lock();
         be.backing_buffer
             .as_ref()
@@ -331,15 +724,77 @@ fn tock<GS: GameState>(
             .bind(be.gl.as_ref().unwrap());
     }
 
-    // Clear the screen
+    // Clear the screen. A transparent window clears with alpha 0 so the desktop shows through.
     unsafe {
         let be = BACKEND.lock();
-        be.gl.as_ref().unwrap().clear_color(0.0, 0.0, 0.0, 1.0);
+        let clear_alpha = if be.transparent { 0.0 } else { 1.0 };
+        be.gl
+            .as_ref()
+            .unwrap()
+            .clear_color(0.0, 0.0, 0.0, clear_alpha);
         be.gl.as_ref().unwrap().clear(glow::COLOR_BUFFER_BIT);
     }
 
-    // Run the main loop
-    gamestate.tick(bterm);
+    let phase_timer = Instant::now();
+
+    // Fixed time-step updates: consume whole steps from the accumulator, then expose the
+    // leftover fraction to the renderer as an interpolation alpha.
+    if let Some(step_ms) = BACKEND.lock().fixed_time_step {
+        *fixed_time_accumulator += bterm.frame_time_ms;
+        // Clamp the backlog so a long stall doesn't trigger a spiral of catch-up ticks
+        let max_backlog = step_ms * 10.0;
+        if *fixed_time_accumulator > max_backlog {
+            *fixed_time_accumulator = max_backlog;
+        }
+        while *fixed_time_accumulator >= step_ms {
+            if crate::crash_screen::crashed() {
+                break;
+            }
+            if crate::crash_screen::capture_enabled() {
+                let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    gamestate.fixed_tick(bterm)
+                }));
+            } else {
+                gamestate.fixed_tick(bterm);
+            }
+            *fixed_time_accumulator -= step_ms;
+            *updates += 1;
+        }
+        bterm.interpolation_alpha = *fixed_time_accumulator / step_ms;
+        bterm.update_time_ms = step_ms;
+    }
+
+    // Run the main loop. With panic capture enabled, a panicking tick diverts
+    // to the crash screen instead of unwinding through the event loop.
+    #[cfg(feature = "tracing")]
+    let tick_span = tracing::trace_span!("tick").entered();
+    if crate::crash_screen::crashed() {
+        crate::crash_screen::crash_tick(bterm);
+    } else if crate::crash_screen::capture_enabled() {
+        let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| gamestate.tick(bterm)));
+    } else {
+        gamestate.tick(bterm);
+    }
+    #[cfg(feature = "tracing")]
+    drop(tick_span);
+
+    let tick_ms = phase_timer.elapsed().as_secs_f32() * 1000.0;
+    let phase_timer = Instant::now();
+
+    // Diagnostics overlay, composited over whatever the game drew.
+    if crate::perf_overlay::enabled() {
+        crate::perf_overlay::draw(bterm);
+    }
+
+    // Pre-render hook: custom GL layers drawn before the consoles, so the
+    // console output composites over them.
+    {
+        let be = BACKEND.lock();
+        if let Some(callback) = be.gl_pre_render_callback.as_ref() {
+            let info = callback_info(&be, use_post_pass);
+            callback(gamestate, be.gl.as_ref().unwrap(), &info);
+        }
+    }
 
     // Tell each console to draw itself
     render_consoles().unwrap();
@@ -351,10 +806,18 @@ fn tock<GS: GameState>(
             let gl = be.gl.as_ref().unwrap();
             callback(gamestate, gl);
         }
+        // Post-render hook: same stage, but handed viewport/backing-buffer
+        // information. Runs before the post-process pass.
+        if let Some(callback) = be.gl_post_render_callback.as_ref() {
+            let info = callback_info(&be, use_post_pass);
+            callback(gamestate, be.gl.as_ref().unwrap(), &info);
+        }
     }
 
-    if bterm.post_scanlines {
+    if use_post_pass {
         // Now we return to the primary screen
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("post_process").entered();
         let be = BACKEND.lock();
         be.backing_buffer
             .as_ref()
@@ -362,7 +825,30 @@ fn tock<GS: GameState>(
             .default(be.gl.as_ref().unwrap());
         unsafe {
             let bi = BACKEND_INTERNAL.lock();
-            if bterm.post_scanlines {
+            if let Some(post) = &bi.post_shader {
+                let gl = be.gl.as_ref().unwrap();
+                post.shader.useProgram(gl);
+                post.shader.setVec3(
+                    gl,
+                    "screenSize",
+                    scale_factor * bterm.width_pixels as f32,
+                    scale_factor * bterm.height_pixels as f32,
+                    0.0,
+                );
+                for (name, value) in &post.uniforms {
+                    match value {
+                        crate::prelude::PostShaderUniform::Float(f) => {
+                            post.shader.setFloat(gl, name, *f)
+                        }
+                        crate::prelude::PostShaderUniform::Vec3(x, y, z) => {
+                            post.shader.setVec3(gl, name, *x, *y, *z)
+                        }
+                        crate::prelude::PostShaderUniform::Bool(b) => {
+                            post.shader.setBool(gl, name, *b)
+                        }
+                    }
+                }
+            } else if bterm.post_scanlines {
                 bi.shaders[3].useProgram(be.gl.as_ref().unwrap());
                 bi.shaders[3].setVec3(
                     be.gl.as_ref().unwrap(),
@@ -379,8 +865,26 @@ fn tock<GS: GameState>(
                     bterm.screen_burn_color.g,
                     bterm.screen_burn_color.b,
                 );
+                bi.shaders[3].setVec4(
+                    be.gl.as_ref().unwrap(),
+                    "crtParams",
+                    bterm.crt_params.scanline_intensity,
+                    bterm.crt_params.mask_intensity,
+                    bterm.crt_params.curvature,
+                    bterm.crt_params.vignette,
+                );
+                set_colorblind_uniforms(
+                    &bi.shaders[3],
+                    be.gl.as_ref().unwrap(),
+                    bterm.colorblind_mode,
+                );
             } else {
                 bi.shaders[2].useProgram(be.gl.as_ref().unwrap());
+                set_colorblind_uniforms(
+                    &bi.shaders[2],
+                    be.gl.as_ref().unwrap(),
+                    bterm.colorblind_mode,
+                );
             }
             be.gl
                 .as_ref()
@@ -428,5 +932,157 @@ fn tock<GS: GameState>(
             .expect("Failed to save buffer to the specified path");
         }
         be.request_screenshot = None;
+
+        // Region variant: read back just the requested pixel rectangle
+        if let Some((filename, region)) = be.request_screenshot_region.take() {
+            let w = region.width().max(1) as u32;
+            let h = region.height().max(1) as u32;
+            // The framebuffer origin is bottom-left; the region is given top-left
+            let gl_y = bterm.height_pixels as i32 - region.y2;
+            let gl = be.gl.as_ref().unwrap();
+
+            let mut img = image::DynamicImage::new_rgba8(w, h);
+            let pixels = img.as_mut_rgba8().unwrap();
+
+            unsafe {
+                gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+                gl.read_pixels(
+                    region.x1,
+                    gl_y,
+                    w as i32,
+                    h as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(pixels),
+                );
+            }
+
+            image::save_buffer(
+                &filename,
+                &image::imageops::flip_vertical(&img),
+                w,
+                h,
+                image::ColorType::Rgba8,
+            )
+            .expect("Failed to save buffer to the specified path");
+        }
+
+        // Per-console variant: render one layer into an offscreen FBO and read it back
+        if let Some((filename, console_index)) = be.request_screenshot_console.take() {
+            let w = be.screen_scaler.physical_size.0 as i32;
+            let h = be.screen_scaler.physical_size.1 as i32;
+            let gl = be.gl.as_ref().unwrap();
+
+            let fbo = Framebuffer::build_fbo(gl, w, h).expect("Failed to build framebuffer");
+            fbo.bind(gl);
+            unsafe {
+                gl.clear_color(0.0, 0.0, 0.0, 0.0);
+                gl.clear(glow::COLOR_BUFFER_BIT);
+            }
+            render_single_console(console_index).expect("Failed to render console");
+
+            let mut img = image::DynamicImage::new_rgba8(w as u32, h as u32);
+            let pixels = img.as_mut_rgba8().unwrap();
+
+            unsafe {
+                gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+                gl.read_pixels(
+                    0,
+                    0,
+                    w,
+                    h,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(pixels),
+                );
+            }
+            fbo.default(gl);
+
+            image::save_buffer(
+                &filename,
+                &image::imageops::flip_vertical(&img),
+                w as u32,
+                h as u32,
+                image::ColorType::Rgba8,
+            )
+            .expect("Failed to save buffer to the specified path");
+        }
+
+        // In-memory variant: capture the frame and park it for the game to collect
+        if be.request_screenshot_image {
+            let w = bterm.width_pixels;
+            let h = bterm.height_pixels;
+            let mut img = image::DynamicImage::new_rgba8(w, h);
+            let pixels = img.as_mut_rgba8().unwrap();
+            let gl = be.gl.as_ref().unwrap();
+
+            unsafe {
+                gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+                gl.read_pixels(
+                    0,
+                    0,
+                    w as i32,
+                    h as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(pixels),
+                );
+            }
+
+            be.screenshot_image = Some(image::imageops::flip_vertical(&img));
+            be.request_screenshot_image = false;
+        }
+    }
+
+    // Recording handler - capture a frame when enough time has elapsed since the last one
+    {
+        let mut be = BACKEND.lock();
+        let capture = if let Some(rec) = be.recording.as_mut() {
+            rec.time_since_capture_ms += bterm.frame_time_ms;
+            if rec.time_since_capture_ms >= rec.frame_interval_ms {
+                rec.time_since_capture_ms -= rec.frame_interval_ms;
+                true
+            } else {
+                false
+            }
+        } else {
+            false
+        };
+        if capture {
+            let w = bterm.width_pixels;
+            let h = bterm.height_pixels;
+            let mut img = image::DynamicImage::new_rgba8(w, h);
+            let pixels = img.as_mut_rgba8().unwrap();
+            let gl = be.gl.as_ref().unwrap();
+
+            unsafe {
+                gl.pixel_store_i32(glow::PACK_ALIGNMENT, 1);
+                gl.read_pixels(
+                    0,
+                    0,
+                    w as i32,
+                    h as i32,
+                    glow::RGBA,
+                    glow::UNSIGNED_BYTE,
+                    glow::PixelPackData::Slice(pixels),
+                );
+            }
+
+            be.recording
+                .as_mut()
+                .unwrap()
+                .frames
+                .push(image::imageops::flip_vertical(&img));
+        }
+    }
+
+    // Present time and the frame total are filled in by the caller, after the
+    // buffer swap.
+    crate::frame_stats::FrameTimes {
+        tick: tick_ms,
+        rebuild: rebuild_ms,
+        render: phase_timer.elapsed().as_secs_f32() * 1000.0,
+        present: 0.0,
+        total: 0.0,
     }
 }
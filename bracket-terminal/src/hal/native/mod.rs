@@ -1,15 +1,34 @@
+#[cfg(feature = "bracket-egui")]
+pub(crate) mod egui_support;
 mod init;
+#[cfg(feature = "bracket-egui")]
+pub use egui_support::EGUI_CTX;
 pub mod shader_strings;
 pub use init::*;
 mod mainloop;
+use crate::hal::scaler::{default_gutter_size, ScreenScaler};
 use crate::hal::ConsoleBacking;
 pub use mainloop::*;
 use parking_lot::Mutex;
 use std::any::Any;
-use crate::hal::scaler::{ScreenScaler, default_gutter_size};
 
 pub type GlCallback = fn(&mut dyn Any, &glow::Context);
 
+/// Viewport and render-target details handed to the staged GL callbacks.
+pub struct GlCallbackInfo {
+    /// Physical drawable size, in pixels.
+    pub viewport: (u32, u32),
+    /// The texture backing the post-process framebuffer when rendering is
+    /// going through one; `None` when drawing straight to the window.
+    pub backing_texture: Option<super::TextureId>,
+}
+
+/// A GL callback that also receives viewport/backing-buffer information, for
+/// compositing custom layers with the consoles. Set `gl_pre_render_callback`
+/// to draw underneath the consoles (a 3D dungeon view behind the UI), or
+/// `gl_post_render_callback` to draw over them before post-processing.
+pub type GlStageCallback = fn(&mut dyn Any, &glow::Context, &GlCallbackInfo);
+
 lazy_static! {
     pub static ref BACKEND: Mutex<PlatformGL> = Mutex::new(PlatformGL {
         gl: None,
@@ -18,10 +37,30 @@ lazy_static! {
         backing_buffer: None,
         frame_sleep_time: None,
         gl_callback: None,
+        gl_pre_render_callback: None,
+        gl_post_render_callback: None,
         resize_scaling: false,
         resize_request: None,
         request_screenshot: None,
+        request_screenshot_region: None,
+        request_screenshot_console: None,
+        request_screenshot_image: false,
+        screenshot_image: None,
+        request_window_title: None,
+        request_window_icon: None,
+        request_fullscreen: None,
+        fullscreen: false,
+        transparent: false,
+        request_window_position: None,
+        request_cursor_visible: None,
+        request_cursor_icon: None,
+        vsync: true,
+        frame_pacing: FramePacing::Uncapped,
+        fixed_time_step: None,
+        recording: None,
+        instanced_consoles: false,
         screen_scaler: ScreenScaler::default(),
+        raw_window_handle: None,
     });
 }
 
@@ -36,10 +75,32 @@ pub struct PlatformGL {
     pub backing_buffer: Option<super::Framebuffer>,
     pub frame_sleep_time: Option<u64>,
     pub gl_callback: Option<GlCallback>,
+    pub gl_pre_render_callback: Option<GlStageCallback>,
+    pub gl_post_render_callback: Option<GlStageCallback>,
     pub resize_scaling: bool,
     pub resize_request: Option<(u32, u32)>,
     pub request_screenshot: Option<String>,
+    pub request_screenshot_region: Option<(String, bracket_geometry::prelude::Rect)>,
+    pub request_screenshot_console: Option<(String, usize)>,
+    pub request_screenshot_image: bool,
+    pub screenshot_image: Option<image::RgbaImage>,
+    pub request_window_title: Option<String>,
+    pub request_window_icon: Option<(Vec<u8>, u32, u32)>,
+    pub request_fullscreen: Option<bool>,
+    pub fullscreen: bool,
+    pub transparent: bool,
+    pub request_window_position: Option<(i32, i32)>,
+    pub request_cursor_visible: Option<bool>,
+    pub request_cursor_icon: Option<crate::prelude::CursorIcon>,
+    pub vsync: bool,
+    pub frame_pacing: FramePacing,
+    pub fixed_time_step: Option<f32>,
+    pub recording: Option<Recording>,
+    pub instanced_consoles: bool,
     pub screen_scaler: ScreenScaler,
+    /// Captured at window creation; the window itself moves into the event
+    /// loop, so third-party integrations read the handle from here.
+    pub raw_window_handle: Option<raw_window_handle::RawWindowHandle>,
 }
 
 unsafe impl Send for PlatformGL {}
@@ -60,6 +121,18 @@ pub struct InitHints {
     pub frame_sleep_time: Option<f32>,
     pub resize_scaling: bool,
     pub desired_gutter: u32,
+    pub scaling_mode: crate::hal::scaler::ScalingMode,
+    pub borderless: bool,
+    pub always_on_top: bool,
+    pub resizable: bool,
+    pub min_inner_size: Option<(u32, u32)>,
+    pub max_inner_size: Option<(u32, u32)>,
+    pub transparent: bool,
+    pub monitor: Option<usize>,
+    pub window_position: Option<(i32, i32)>,
+    pub frame_pacing: FramePacing,
+    pub fixed_time_step: Option<f32>,
+    pub instanced_consoles: bool,
 }
 
 impl InitHints {
@@ -74,6 +147,18 @@ impl InitHints {
             frame_sleep_time: None,
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
+            scaling_mode: crate::hal::scaler::ScalingMode::Stretch,
+            borderless: false,
+            always_on_top: false,
+            resizable: true,
+            min_inner_size: None,
+            max_inner_size: None,
+            transparent: false,
+            monitor: None,
+            window_position: None,
+            frame_pacing: FramePacing::Uncapped,
+            fixed_time_step: None,
+            instanced_consoles: false,
         }
     }
 }
@@ -90,7 +175,57 @@ impl Default for InitHints {
             frame_sleep_time: None,
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
+            scaling_mode: crate::hal::scaler::ScalingMode::Stretch,
+            borderless: false,
+            always_on_top: false,
+            resizable: true,
+            min_inner_size: None,
+            max_inner_size: None,
+            transparent: false,
+            monitor: None,
+            window_position: None,
+            frame_pacing: FramePacing::Uncapped,
+            fixed_time_step: None,
+            instanced_consoles: false,
+        }
+    }
+}
+
+/// Controls how the native main loop paces frames.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FramePacing {
+    /// Render as fast as the platform allows (subject to vsync).
+    Uncapped,
+    /// Sleep between frames to approximate the requested frames-per-second.
+    CapFps(u32),
+    /// Switch the event loop to waiting, redrawing only when input arrives or the frame
+    /// timer (if an FPS cap is set) elapses. Ideal for turn-based games that would
+    /// otherwise burn a CPU core while idle.
+    EventDriven,
+}
+
+/// An in-progress frame recording. Frames are captured from the back buffer at the
+/// requested rate and encoded as an animated GIF when the recording is stopped.
+pub struct Recording {
+    pub filename: String,
+    pub frame_interval_ms: f32,
+    pub time_since_capture_ms: f32,
+    pub frames: Vec<image::RgbaImage>,
+}
+
+impl Recording {
+    /// Encode the captured frames as a looping animated GIF at the path given to
+    /// `BTerm::start_recording`.
+    pub fn save(self) -> crate::BResult<()> {
+        use image::codecs::gif::{GifEncoder, Repeat};
+        let file = std::fs::File::create(&self.filename)?;
+        let mut encoder = GifEncoder::new(file);
+        encoder.set_repeat(Repeat::Infinite)?;
+        let delay = image::Delay::from_numer_denom_ms(self.frame_interval_ms as u32, 1);
+        for frame in self.frames {
+            encoder.encode_frame(image::Frame::from_parts(frame, 0, 0, delay))?;
         }
+        Ok(())
     }
 }
 
@@ -4,10 +4,20 @@ out vec4 FragColor;
 in vec2 TexCoords;
 
 uniform sampler2D screenTexture;
+uniform bool colorblindEnabled;
+// Row-major colorblind simulation matrix, applied in linear light.
+uniform mat3 colorblindMatrix;
+
+vec3 apply_colorblind(vec3 col) {
+    if (!colorblindEnabled) { return col; }
+    vec3 linear_col = pow(col, vec3(2.2));
+    vec3 simulated = clamp(linear_col * colorblindMatrix, 0.0, 1.0);
+    return pow(simulated, vec3(1.0 / 2.2));
+}
 
 void main()
 {
-    vec3 col = texture(screenTexture, TexCoords).rgb;
+    vec3 col = apply_colorblind(texture(screenTexture, TexCoords).rgb);
     FragColor = vec4(col, 1.0);
 }"#;
 
@@ -30,14 +40,41 @@ out vec4 FragColor;
 in vec4 ourColor;
 in vec2 TexCoord;
 in vec4 ourBackground;
+in float glyphStyle;
 
 // texture sampler
 uniform sampler2D texture1;
 
+// One texel of the font texture, for outline/shadow neighbor sampling
+uniform vec2 texelSize;
+
+bool is_glyph(vec4 texel) {
+    return texel.r >= 0.1f || texel.g >= 0.1f || texel.b >= 0.1f;
+}
+
 void main()
 {
     vec4 original = texture(texture1, TexCoord);
-    if (original.r < 0.1f && original.g < 0.1f && original.b < 0.1f) discard;
+    if (original.r < 0.1f && original.g < 0.1f && original.b < 0.1f) {
+        if (glyphStyle > 1.5) {
+            // Drop shadow: the glyph one texel up-left casts onto this texel.
+            if (is_glyph(texture(texture1, TexCoord + vec2(-texelSize.x, texelSize.y)))) {
+                FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+                return;
+            }
+        } else if (glyphStyle > 0.5) {
+            // Outline: any of the four direct neighbors is part of the glyph.
+            bool edge = is_glyph(texture(texture1, TexCoord + vec2(texelSize.x, 0.0)))
+                || is_glyph(texture(texture1, TexCoord - vec2(texelSize.x, 0.0)))
+                || is_glyph(texture(texture1, TexCoord + vec2(0.0, texelSize.y)))
+                || is_glyph(texture(texture1, TexCoord - vec2(0.0, texelSize.y)));
+            if (edge) {
+                FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+                return;
+            }
+        }
+        discard;
+    }
     vec4 fg = original * ourColor;
 	FragColor = fg;
 }
@@ -52,13 +89,31 @@ layout (location = 3) in vec2 aTexCoord;
 out vec4 ourColor;
 out vec4 ourBackground;
 out vec2 TexCoord;
+out float glyphStyle;
+
+uniform vec2 transformOffset;
+uniform float transformRotation;
+uniform float transformScale;
+uniform float transformAspect;
+uniform vec4 consoleTint;
+
+vec2 console_transform(vec2 pos) {
+    pos *= transformScale;
+    float tc = cos(transformRotation);
+    float ts = sin(transformRotation);
+    pos.x *= transformAspect;
+    pos = vec2(pos.x * tc - pos.y * ts, pos.x * ts + pos.y * tc);
+    pos.x /= transformAspect;
+    return pos + transformOffset;
+}
 
 void main()
 {
-	gl_Position = vec4(aPos, 1.0);
-	ourColor = aColor;
-	ourBackground = bColor;
+	gl_Position = vec4(console_transform(aPos.xy), 0.0, 1.0);
+	ourColor = aColor * consoleTint;
+	ourBackground = bColor * consoleTint;
 	TexCoord = vec2(aTexCoord.x, aTexCoord.y);
+	glyphStyle = aPos.z;
 }"#;
 
 pub static CONSOLE_WITH_BG_FS: &str = r#"#version 330 core
@@ -67,15 +122,42 @@ out vec4 FragColor;
 in vec4 ourColor;
 in vec2 TexCoord;
 in vec4 ourBackground;
+in float glyphStyle;
 
 // texture sampler
 uniform sampler2D texture1;
+// One texel of the font texture, for outline/shadow neighbor sampling
+uniform vec2 texelSize;
+
+bool is_glyph(vec4 texel) {
+    return (texel.r > 0.1f || texel.g > 0.1f || texel.b > 0.1f) && texel.a > 0.1f;
+}
 
 void main()
 {
     vec4 original = texture(texture1, TexCoord);
-    vec4 fg = (original.r > 0.1f || original.g > 0.1f || original.b > 0.1f) && original.a > 0.1f ? original * ourColor : ourBackground;
-	FragColor = fg;
+    if (is_glyph(original)) {
+        FragColor = original * ourColor;
+        return;
+    }
+    if (glyphStyle > 1.5) {
+        // Drop shadow: the glyph one texel up-left casts onto this texel.
+        if (is_glyph(texture(texture1, TexCoord + vec2(-texelSize.x, texelSize.y)))) {
+            FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+            return;
+        }
+    } else if (glyphStyle > 0.5) {
+        // Outline: any of the four direct neighbors is part of the glyph.
+        bool edge = is_glyph(texture(texture1, TexCoord + vec2(texelSize.x, 0.0)))
+            || is_glyph(texture(texture1, TexCoord - vec2(texelSize.x, 0.0)))
+            || is_glyph(texture(texture1, TexCoord + vec2(0.0, texelSize.y)))
+            || is_glyph(texture(texture1, TexCoord - vec2(0.0, texelSize.y)));
+        if (edge) {
+            FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+            return;
+        }
+    }
+    FragColor = ourBackground;
 }
 "#;
 
@@ -88,13 +170,80 @@ layout (location = 3) in vec2 aTexCoord;
 out vec4 ourColor;
 out vec4 ourBackground;
 out vec2 TexCoord;
+out float glyphStyle;
+
+uniform vec2 transformOffset;
+uniform float transformRotation;
+uniform float transformScale;
+uniform float transformAspect;
+uniform vec4 consoleTint;
+
+vec2 console_transform(vec2 pos) {
+    pos *= transformScale;
+    float tc = cos(transformRotation);
+    float ts = sin(transformRotation);
+    pos.x *= transformAspect;
+    pos = vec2(pos.x * tc - pos.y * ts, pos.x * ts + pos.y * tc);
+    pos.x /= transformAspect;
+    return pos + transformOffset;
+}
 
 void main()
 {
-	gl_Position = vec4(aPos, 1.0);
-	ourColor = aColor;
-	ourBackground = bColor;
+	gl_Position = vec4(console_transform(aPos.xy), 0.0, 1.0);
+	ourColor = aColor * consoleTint;
+	ourBackground = bColor * consoleTint;
 	TexCoord = vec2(aTexCoord.x, aTexCoord.y);
+	glyphStyle = aPos.z;
+}"#;
+
+pub static CONSOLE_INSTANCED_VS: &str = r#"#version 330 core
+layout (location = 0) in vec2 aCorner;
+layout (location = 1) in vec2 aCell;
+layout (location = 2) in vec4 aColor;
+layout (location = 3) in vec4 bColor;
+layout (location = 4) in float aGlyph;
+
+out vec4 ourColor;
+out vec4 ourBackground;
+out vec2 TexCoord;
+out float glyphStyle;
+
+uniform vec2 consoleOrigin;
+uniform vec2 cellStep;
+uniform vec2 fontGlyphDims;
+uniform vec2 fontTexDims;
+
+uniform vec2 transformOffset;
+uniform float transformRotation;
+uniform float transformScale;
+uniform float transformAspect;
+uniform vec4 consoleTint;
+
+vec2 console_transform(vec2 pos) {
+    pos *= transformScale;
+    float tc = cos(transformRotation);
+    float ts = sin(transformRotation);
+    pos.x *= transformAspect;
+    pos = vec2(pos.x * tc - pos.y * ts, pos.x * ts + pos.y * tc);
+    pos.x /= transformAspect;
+    return pos + transformOffset;
+}
+
+void main()
+{
+    vec2 pos = consoleOrigin + ((aCell + aCorner) * cellStep);
+    gl_Position = vec4(console_transform(pos), 0.0, 1.0);
+
+    float glyphX = mod(aGlyph, fontGlyphDims.x);
+    float glyphY = fontGlyphDims.y - floor(aGlyph / fontGlyphDims.x);
+    TexCoord = vec2(
+        (glyphX + aCorner.x) * fontTexDims.x,
+        ((glyphY - 1.0) + aCorner.y) * fontTexDims.y
+    );
+    ourColor = aColor * consoleTint;
+    ourBackground = bColor * consoleTint;
+    glyphStyle = 0.0;
 }"#;
 
 pub static SCANLINES_FS: &str = r#"#version 330 core
@@ -106,6 +255,18 @@ uniform sampler2D screenTexture;
 uniform vec3 screenSize;
 uniform bool screenBurn;
 uniform vec3 screenBurnColor;
+// x = scanline intensity, y = grille mask, z = curvature, w = vignette
+uniform vec4 crtParams;
+uniform bool colorblindEnabled;
+// Row-major colorblind simulation matrix, applied in linear light.
+uniform mat3 colorblindMatrix;
+
+vec3 apply_colorblind(vec3 col) {
+    if (!colorblindEnabled) { return col; }
+    vec3 linear_col = pow(col, vec3(2.2));
+    vec3 simulated = clamp(linear_col * colorblindMatrix, 0.0, 1.0);
+    return pow(simulated, vec3(1.0 / 2.2));
+}
 
 float random(vec2 p) {
     return fract(cos(dot(p, vec2(23.14069263277926, 2.665144142690225))) * 12345.6789);
@@ -113,9 +274,27 @@ float random(vec2 p) {
 
 void main()
 {
-    vec3 col = texture(screenTexture, TexCoords).rgb;
-    float scanLine = mod(gl_FragCoord.y, 2.0) * 0.25;
+    vec2 uv = TexCoords;
+    vec2 centered = uv - 0.5;
+    if (crtParams.z > 0.0) {
+        uv = uv + centered * dot(centered, centered) * crtParams.z;
+        if (uv.x < 0.0 || uv.x > 1.0 || uv.y < 0.0 || uv.y > 1.0) {
+            FragColor = vec4(0.0, 0.0, 0.0, 1.0);
+            return;
+        }
+    }
+    vec3 col = apply_colorblind(texture(screenTexture, uv).rgb);
+    float scanLine = mod(gl_FragCoord.y, 2.0) * crtParams.x;
     vec3 scanColor = col.rgb - scanLine;
+    if (crtParams.y > 0.0) {
+        float phase = mod(gl_FragCoord.x, 3.0);
+        vec3 grille = vec3(1.0 - crtParams.y);
+        if (phase < 1.0) { grille.r = 1.0; }
+        else if (phase < 2.0) { grille.g = 1.0; }
+        else { grille.b = 1.0; }
+        scanColor *= grille;
+    }
+    scanColor *= 1.0 - crtParams.w * dot(centered, centered) * 2.0;
 
     if (col.r < 0.1f && col.g < 0.1f && col.b < 0.1f) {
         if (screenBurn) {
@@ -149,15 +328,42 @@ out vec4 FragColor;
 in vec4 ourColor;
 in vec2 TexCoord;
 in vec4 ourBackground;
+in float glyphStyle;
 
 // texture sampler
 uniform sampler2D texture1;
+// One texel of the font texture, for outline/shadow neighbor sampling
+uniform vec2 texelSize;
+
+bool is_glyph(vec4 texel) {
+    return (texel.r > 0.1f || texel.g > 0.1f || texel.b > 0.1f) && texel.a > 0.1f;
+}
 
 void main()
 {
     vec4 original = texture(texture1, TexCoord);
-    vec4 fg = (original.r > 0.1f || original.g > 0.1f || original.b > 0.1f) && original.a > 0.1f ? original * ourColor : ourBackground;
-	FragColor = fg;
+    if (is_glyph(original)) {
+        FragColor = original * ourColor;
+        return;
+    }
+    if (glyphStyle > 1.5) {
+        // Drop shadow: the glyph one texel up-left casts onto this texel.
+        if (is_glyph(texture(texture1, TexCoord + vec2(-texelSize.x, texelSize.y)))) {
+            FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+            return;
+        }
+    } else if (glyphStyle > 0.5) {
+        // Outline: any of the four direct neighbors is part of the glyph.
+        bool edge = is_glyph(texture(texture1, TexCoord + vec2(texelSize.x, 0.0)))
+            || is_glyph(texture(texture1, TexCoord - vec2(texelSize.x, 0.0)))
+            || is_glyph(texture(texture1, TexCoord + vec2(0.0, texelSize.y)))
+            || is_glyph(texture(texture1, TexCoord - vec2(0.0, texelSize.y)));
+        if (edge) {
+            FragColor = vec4(0.0, 0.0, 0.0, ourColor.a);
+            return;
+        }
+    }
+    FragColor = ourBackground;
 }
 "#;
 
@@ -172,6 +378,7 @@ layout (location = 5) in vec2 aScale;
 out vec4 ourColor;
 out vec4 ourBackground;
 out vec2 TexCoord;
+out float glyphStyle;
 
 mat2 r2d(float a) {
 	float c = cos(a), s = sin(a);
@@ -181,6 +388,22 @@ mat2 r2d(float a) {
     );
 }
 
+uniform vec2 transformOffset;
+uniform float transformRotation;
+uniform float transformScale;
+uniform float transformAspect;
+uniform vec4 consoleTint;
+
+vec2 console_transform(vec2 pos) {
+    pos *= transformScale;
+    float tc = cos(transformRotation);
+    float ts = sin(transformRotation);
+    pos.x *= transformAspect;
+    pos = vec2(pos.x * tc - pos.y * ts, pos.x * ts + pos.y * tc);
+    pos.x /= transformAspect;
+    return pos + transformOffset;
+}
+
 void main()
 {
     float rot = aRotate.x;
@@ -190,10 +413,11 @@ void main()
     base_pos *= aScale;
     base_pos += center_pos;
 
-	gl_Position = vec4(base_pos, 0.0, 1.0);
-	ourColor = aColor;
-	ourBackground = bColor;
+	gl_Position = vec4(console_transform(base_pos), 0.0, 1.0);
+	ourColor = aColor * consoleTint;
+	ourBackground = bColor * consoleTint;
 	TexCoord = vec2(aTexCoord.x, aTexCoord.y);
+	glyphStyle = aPos.z;
 }"#;
 
 pub static SPRITE_CONSOLE_FS: &str = r#"#version 330 core
@@ -223,13 +447,55 @@ layout (location = 4) in vec2 aScale;
 out vec4 ourColor;
 out vec2 TexCoord;
 
+uniform vec2 transformOffset;
+uniform float transformRotation;
+uniform float transformScale;
+uniform float transformAspect;
+uniform vec4 consoleTint;
+
+vec2 console_transform(vec2 pos) {
+    pos *= transformScale;
+    float tc = cos(transformRotation);
+    float ts = sin(transformRotation);
+    pos.x *= transformAspect;
+    pos = vec2(pos.x * tc - pos.y * ts, pos.x * ts + pos.y * tc);
+    pos.x /= transformAspect;
+    return pos + transformOffset;
+}
+
 void main()
 {
     vec2 base_pos = aRelativePos;
     vec2 scaled = base_pos * aScale;
     vec2 translated = scaled + aTransform.xy;
 
-	gl_Position = vec4(translated, 1.0, 1.0);
-	ourColor = aColor;
+	gl_Position = vec4(console_transform(translated), 1.0, 1.0);
+	ourColor = aColor * consoleTint;
 	TexCoord = vec2(aTexCoord.x, aTexCoord.y);
 }"#;
+
+pub static PALETTE_FS: &str = r#"#version 330 core
+out vec4 FragColor;
+
+in vec2 TexCoords;
+
+uniform sampler2D screenTexture;
+uniform float paletteCount;
+uniform vec3 palette[64];
+
+void main()
+{
+    vec3 col = texture(screenTexture, TexCoords).rgb;
+    vec3 best = palette[0];
+    float bestDistance = 999999.0;
+    for (int i = 0; i < 64; i++) {
+        if (float(i) >= paletteCount) break;
+        vec3 delta = col - palette[i];
+        float dist = dot(delta, delta);
+        if (dist < bestDistance) {
+            bestDistance = dist;
+            best = palette[i];
+        }
+    }
+    FragColor = vec4(best, 1.0);
+}"#;
@@ -14,7 +14,6 @@ pub(crate) fn default_gutter_size() -> u32 {
     0
 }
 
-
 /// Provides a consistent font to texture coordinates mapping service.
 pub struct FontScaler {
     font_dimensions_glyphs: (u16, u16),
@@ -35,9 +34,9 @@ impl FontScaler {
         font_dimensions_texture: (f32, f32),
     ) -> Self {
         Self {
-            font_dimensions_glyphs : (
+            font_dimensions_glyphs: (
                 font_dimensions_glyphs.0 as u16,
-                font_dimensions_glyphs.1 as u16
+                font_dimensions_glyphs.1 as u16,
             ),
             font_dimensions_texture,
         }
@@ -54,11 +53,30 @@ impl FontScaler {
         let glyph_bottom = f32::from(glyph_y - 1) * self.font_dimensions_texture.1;
 
         GlyphPosition {
-            glyph_left, glyph_right, glyph_top, glyph_bottom
+            glyph_left,
+            glyph_right,
+            glyph_top,
+            glyph_bottom,
         }
     }
 }
 
+/// Controls how the console grid maps onto a window whose aspect ratio
+/// doesn't match it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScalingMode {
+    /// Fill the window, stretching glyphs if the aspect ratios differ (the default).
+    Stretch,
+    /// Keep the console's aspect ratio, letterboxing with black bars.
+    PreserveAspect,
+    /// Keep the aspect ratio and fill the window, cropping the overflowing axis.
+    Crop,
+    /// Scale the console by the largest whole multiple of its base pixel size
+    /// that fits, centered. Keeps small fonts crisp at the cost of larger bars
+    /// than `PreserveAspect`.
+    PixelPerfect,
+}
+
 pub struct ScreenScaler {
     pub desired_gutter: u32,
     pub smooth_gutter_x: u32,
@@ -71,6 +89,15 @@ pub struct ScreenScaler {
     pub gutter_bottom: u32,
     pub available_width: u32,
     pub available_height: u32,
+    pub scaling_mode: ScalingMode,
+    /// Extra margin applied to each side by the scaling mode: positive for
+    /// letterbox bars, negative when cropping off-screen.
+    pub mode_offset: (i32, i32),
+    /// The console's pixel size at 1:1 scale, set at creation. Anchors the
+    /// pixel-perfect mode and the effective-scale calculation.
+    pub base_size: (u32, u32),
+    /// How much larger the rendered console is than its base pixel size.
+    pub effective_scale: f32,
     aspect_ratio: f32,
     resized: bool,
 }
@@ -89,6 +116,10 @@ impl ScreenScaler {
             gutter_bottom: 0,
             available_width: 0,
             available_height: 0,
+            scaling_mode: ScalingMode::Stretch,
+            mode_offset: (0, 0),
+            base_size: (0, 0),
+            effective_scale: 1.0,
             aspect_ratio: 1.0,
             resized: true,
         }
@@ -107,6 +138,10 @@ impl ScreenScaler {
             gutter_bottom: 0,
             available_width: 0,
             available_height: 0,
+            scaling_mode: ScalingMode::Stretch,
+            mode_offset: (0, 0),
+            base_size: (desired_width, desired_height),
+            effective_scale: 1.0,
             aspect_ratio: desired_height as f32 / desired_width as f32,
             resized: true,
         };
@@ -115,7 +150,8 @@ impl ScreenScaler {
     }
 
     pub fn new_window_size(&mut self) -> LogicalSize<u32> {
-        self.aspect_ratio = (self.physical_size.1 + self.desired_gutter) as f32 / (self.physical_size.0 + self.desired_gutter) as f32;
+        self.aspect_ratio = (self.physical_size.1 + self.desired_gutter) as f32
+            / (self.physical_size.0 + self.desired_gutter) as f32;
         LogicalSize::new(
             self.physical_size.0 + self.desired_gutter,
             self.physical_size.1 + self.desired_gutter,
@@ -136,7 +172,13 @@ impl ScreenScaler {
         self.recalculate_coordinates();
     }
 
-    pub fn change_physical_size_smooth(&mut self, width: u32, height: u32, scale: f32, max_font: (u32, u32)) {
+    pub fn change_physical_size_smooth(
+        &mut self,
+        width: u32,
+        height: u32,
+        scale: f32,
+        max_font: (u32, u32),
+    ) {
         self.scale_factor = scale;
         self.physical_size.0 = width;
         self.physical_size.1 = height;
@@ -160,14 +202,14 @@ impl ScreenScaler {
         let half_gutter = total_gutter / 2;
 
         let (extra_left, extra_right) = if self.smooth_gutter_x % 2 == 0 {
-            (self.smooth_gutter_x/2, self.smooth_gutter_x/2)
+            (self.smooth_gutter_x / 2, self.smooth_gutter_x / 2)
         } else {
-            ((self.smooth_gutter_x/2)+1, self.smooth_gutter_x/2)
+            ((self.smooth_gutter_x / 2) + 1, self.smooth_gutter_x / 2)
         };
         let (extra_top, extra_bottom) = if self.smooth_gutter_y % 2 == 0 {
-            (self.smooth_gutter_y/2, self.smooth_gutter_y/2)
+            (self.smooth_gutter_y / 2, self.smooth_gutter_y / 2)
         } else {
-            ((self.smooth_gutter_y/2)+1, self.smooth_gutter_y/2)
+            ((self.smooth_gutter_y / 2) + 1, self.smooth_gutter_y / 2)
         };
 
         if total_gutter % 2 == 0 {
@@ -177,16 +219,75 @@ impl ScreenScaler {
             self.gutter_bottom = half_gutter + extra_bottom;
         } else {
             self.gutter_left = half_gutter + extra_left;
-            self.gutter_right = half_gutter+1 + extra_right;
+            self.gutter_right = half_gutter + 1 + extra_right;
             self.gutter_top = half_gutter + extra_top;
-            self.gutter_bottom = half_gutter+1 + extra_bottom;
+            self.gutter_bottom = half_gutter + 1 + extra_bottom;
         }
 
         self.available_width = self.physical_size.0 - (total_gutter + extra_left + extra_right);
         self.available_height = self.physical_size.1 - (total_gutter + extra_top + extra_bottom);
+        self.apply_scaling_mode();
         self.resized = true;
     }
 
+    /// Adjusts the drawable area for the selected scaling mode: letterboxing
+    /// shrinks it symmetrically, cropping grows it past the window edges.
+    fn apply_scaling_mode(&mut self) {
+        self.mode_offset = (0, 0);
+        self.effective_scale = if self.base_size.0 > 0 {
+            self.available_width as f32 / self.base_size.0 as f32
+        } else {
+            1.0
+        };
+        if self.scaling_mode == ScalingMode::Stretch {
+            return;
+        }
+        let avail_w = self.available_width as f32;
+        let avail_h = self.available_height as f32;
+        if avail_w < 1.0 || avail_h < 1.0 || self.aspect_ratio <= 0.0 {
+            return;
+        }
+        let (content_w, content_h) = if self.scaling_mode == ScalingMode::PixelPerfect {
+            if self.base_size.0 == 0 || self.base_size.1 == 0 {
+                return;
+            }
+            let base_w = self.base_size.0 as f32;
+            let base_h = self.base_size.1 as f32;
+            let scale = f32::max(
+                1.0,
+                f32::min((avail_w / base_w).floor(), (avail_h / base_h).floor()),
+            );
+            (base_w * scale, base_h * scale)
+        } else {
+            let fit_height = avail_w * self.aspect_ratio;
+            let width_bound = match self.scaling_mode {
+                ScalingMode::PreserveAspect => fit_height <= avail_h,
+                _ => fit_height >= avail_h,
+            };
+            if width_bound {
+                (avail_w, fit_height)
+            } else {
+                (avail_h / self.aspect_ratio, avail_h)
+            }
+        };
+        self.mode_offset = (
+            ((avail_w - content_w) / 2.0) as i32,
+            ((avail_h - content_h) / 2.0) as i32,
+        );
+        self.available_width = content_w as u32;
+        self.available_height = content_h as u32;
+        if self.base_size.0 > 0 {
+            self.effective_scale = content_w / self.base_size.0 as f32;
+        }
+    }
+
+    /// Switches scaling modes at runtime and re-derives the drawable area, flagging
+    /// the consoles for a rebuild.
+    pub fn set_scaling_mode(&mut self, mode: ScalingMode) {
+        self.scaling_mode = mode;
+        self.recalculate_coordinates();
+    }
+
     pub fn pixel_to_screen(&self, x: u32, y: u32) -> (f32, f32) {
         (
             ((x as f32 / self.physical_size.0 as f32) * 2.0) - 1.0,
@@ -194,14 +295,26 @@ impl ScreenScaler {
         )
     }
 
+    /// As `pixel_to_screen`, but accepting coordinates outside the window for
+    /// cropped output.
+    fn pixel_to_screen_signed(&self, x: f32, y: f32) -> (f32, f32) {
+        (
+            ((x / self.physical_size.0 as f32) * 2.0) - 1.0,
+            ((y / self.physical_size.1 as f32) * 2.0) - 1.0,
+        )
+    }
+
     pub fn top_left_pixel(&self) -> (f32, f32) {
-        self.pixel_to_screen(self.gutter_left, self.gutter_top)
+        self.pixel_to_screen_signed(
+            self.gutter_left as f32 + self.mode_offset.0 as f32,
+            self.gutter_top as f32 + self.mode_offset.1 as f32,
+        )
     }
 
     pub fn bottom_right_pixel(&self) -> (f32, f32) {
-        self.pixel_to_screen(
-            self.physical_size.0 - self.gutter_right,
-            self.physical_size.1 - self.gutter_bottom
+        self.pixel_to_screen_signed(
+            (self.physical_size.0 - self.gutter_right) as f32 - self.mode_offset.0 as f32,
+            (self.physical_size.1 - self.gutter_bottom) as f32 - self.mode_offset.1 as f32,
         )
     }
 
@@ -219,4 +332,43 @@ impl ScreenScaler {
         self.resized = false;
         result
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::{ScalingMode, ScreenScaler};
+
+    #[test]
+    fn letterboxing_a_wide_window_adds_side_bars() {
+        // Square console in a 2:1 window: bars left and right, none above/below.
+        let mut scaler = ScreenScaler::new(0, 400, 400);
+        scaler.set_scaling_mode(ScalingMode::PreserveAspect);
+        scaler.change_physical_size(800, 400, 1.0);
+        assert_eq!(scaler.mode_offset, (200, 0));
+        assert_eq!(scaler.available_width, 400);
+        assert_eq!(scaler.available_height, 400);
+    }
+
+    #[test]
+    fn pixel_perfect_snaps_to_whole_multiples() {
+        let mut scaler = ScreenScaler::new(0, 320, 200);
+        scaler.set_scaling_mode(ScalingMode::PixelPerfect);
+        // 2.5x wide, 2.2x tall: snaps to 2x, centered.
+        scaler.change_physical_size(800, 440, 1.0);
+        assert_eq!(scaler.available_width, 640);
+        assert_eq!(scaler.available_height, 400);
+        assert_eq!(scaler.mode_offset, (80, 20));
+        assert_eq!(scaler.effective_scale, 2.0);
+    }
+
+    #[test]
+    fn cropping_a_wide_window_overflows_vertically() {
+        let mut scaler = ScreenScaler::new(0, 400, 400);
+        scaler.set_scaling_mode(ScalingMode::Crop);
+        scaler.change_physical_size(800, 400, 1.0);
+        assert_eq!(scaler.mode_offset, (0, -200));
+        assert_eq!(scaler.available_height, 800);
+        // The drawn quad extends past the top and bottom of the window.
+        assert!(scaler.top_left_pixel().1 < -1.0);
+        assert!(scaler.bottom_right_pixel().1 > 1.0);
+    }
+}
@@ -4,6 +4,10 @@ mod mouse;
 pub use mouse::*;
 mod external;
 pub use external::*;
+mod touch;
+pub use touch::*;
+mod virtual_input;
+pub use virtual_input::*;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsCast;
 
@@ -46,4 +50,44 @@ pub fn bind_wasm_events(canvas: &web_sys::HtmlCanvasElement) {
 
     canvas.set_onmouseup(Some(mouseunclick_callback.as_ref().unchecked_ref()));
     mouseunclick_callback.forget();
+
+    // Handle mouse wheel
+    let mousewheel_callback = Closure::wrap(Box::new(|e: web_sys::WheelEvent| {
+        on_mouse_wheel(e.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    canvas.set_onwheel(Some(mousewheel_callback.as_ref().unchecked_ref()));
+    mousewheel_callback.forget();
+
+    // Handle touch contacts starting
+    let touchstart_callback = Closure::wrap(Box::new(|e: web_sys::TouchEvent| {
+        on_touch_start(e.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    canvas.set_ontouchstart(Some(touchstart_callback.as_ref().unchecked_ref()));
+    touchstart_callback.forget();
+
+    // Handle touch contacts moving
+    let touchmove_callback = Closure::wrap(Box::new(|e: web_sys::TouchEvent| {
+        on_touch_move(e.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    canvas.set_ontouchmove(Some(touchmove_callback.as_ref().unchecked_ref()));
+    touchmove_callback.forget();
+
+    // Handle touch contacts ending
+    let touchend_callback = Closure::wrap(Box::new(|e: web_sys::TouchEvent| {
+        on_touch_end(e.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    canvas.set_ontouchend(Some(touchend_callback.as_ref().unchecked_ref()));
+    touchend_callback.forget();
+
+    // Cancelled touches are treated as the contact ending
+    let touchcancel_callback = Closure::wrap(Box::new(|e: web_sys::TouchEvent| {
+        on_touch_end(e.clone());
+    }) as Box<dyn FnMut(_)>);
+
+    canvas.set_ontouchcancel(Some(touchcancel_callback.as_ref().unchecked_ref()));
+    touchcancel_callback.forget();
 }
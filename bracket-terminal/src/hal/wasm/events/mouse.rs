@@ -5,6 +5,11 @@ pub static mut GLOBAL_MOUSE_POS: (i32, i32) = (0, 0);
 
 /// Event called via the web interface to indicate mouse movement
 pub fn on_mouse_move(mouse: web_sys::MouseEvent) {
+    let move_x = mouse.movement_x();
+    let move_y = mouse.movement_y();
+    if move_x != 0 || move_y != 0 {
+        INPUT.lock().on_mouse_delta(move_x as f32, move_y as f32);
+    }
     let off_x = mouse.offset_x();
     let off_y = mouse.offset_y();
     unsafe {
@@ -37,3 +42,12 @@ pub fn on_mouse_up(_mouse: web_sys::MouseEvent) {
         GLOBAL_LEFT_CLICK = false;
     }
 }
+
+/// Event called via the web interface to indicate mouse wheel/trackpad scrolling.
+/// The DOM reports positive `deltaY` for scrolling towards the user; flip it to match
+/// the native (winit) convention of positive meaning away from the user.
+pub fn on_mouse_wheel(wheel: web_sys::WheelEvent) {
+    INPUT
+        .lock()
+        .on_mouse_wheel(wheel.delta_x() as f32, -wheel.delta_y() as f32);
+}
@@ -0,0 +1,84 @@
+use super::{GLOBAL_LEFT_CLICK, GLOBAL_MOUSE_POS};
+use crate::prelude::{BEvent, INPUT};
+use bracket_geometry::prelude::Point;
+use wasm_bindgen::JsCast;
+
+/// Extracts the primary touch location from a touch event, in canvas-relative pixels.
+fn touch_position(event: &web_sys::TouchEvent) -> Option<(i32, i32)> {
+    let touch = event.changed_touches().item(0)?;
+    let (left, top) = if let Some(target) = event.target() {
+        if let Ok(element) = target.dyn_into::<web_sys::Element>() {
+            let bounds = element.get_bounding_client_rect();
+            (bounds.left(), bounds.top())
+        } else {
+            (0.0, 0.0)
+        }
+    } else {
+        (0.0, 0.0)
+    };
+    Some((
+        touch.client_x() - left as i32,
+        touch.client_y() - top as i32,
+    ))
+}
+
+/// Event called via the web interface when a touch contact starts. Touches drive the mouse
+/// position, and (unless disabled in `INPUT`) are also reported as left mouse clicks.
+pub fn on_touch_start(event: web_sys::TouchEvent) {
+    event.prevent_default();
+    if let Some((x, y)) = touch_position(&event) {
+        let mut input = INPUT.lock();
+        input.on_mouse_pixel_position(x as f64, y as f64);
+        let tile = input.mouse_tile(0);
+        input.push_event(BEvent::TouchStarted {
+            pixel: Point::new(x, y),
+            tile,
+        });
+        let emulate_mouse = input.touch_emulates_mouse;
+        if emulate_mouse {
+            input.on_mouse_button_down(0);
+        }
+        unsafe {
+            GLOBAL_MOUSE_POS = (x, y);
+            if emulate_mouse {
+                GLOBAL_LEFT_CLICK = true;
+            }
+        }
+    }
+}
+
+/// Event called via the web interface when a touch contact moves.
+pub fn on_touch_move(event: web_sys::TouchEvent) {
+    event.prevent_default();
+    if let Some((x, y)) = touch_position(&event) {
+        let mut input = INPUT.lock();
+        input.on_mouse_pixel_position(x as f64, y as f64);
+        let tile = input.mouse_tile(0);
+        input.push_event(BEvent::TouchMoved {
+            pixel: Point::new(x, y),
+            tile,
+        });
+        unsafe {
+            GLOBAL_MOUSE_POS = (x, y);
+        }
+    }
+}
+
+/// Event called via the web interface when a touch contact ends or is cancelled.
+pub fn on_touch_end(event: web_sys::TouchEvent) {
+    event.prevent_default();
+    if let Some((x, y)) = touch_position(&event) {
+        let mut input = INPUT.lock();
+        let tile = input.mouse_tile(0);
+        input.push_event(BEvent::TouchEnded {
+            pixel: Point::new(x, y),
+            tile,
+        });
+        if input.touch_emulates_mouse {
+            input.on_mouse_button_up(0);
+            unsafe {
+                GLOBAL_LEFT_CLICK = false;
+            }
+        }
+    }
+}
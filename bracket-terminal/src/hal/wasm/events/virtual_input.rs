@@ -0,0 +1,165 @@
+//! Soft-keyboard and on-screen d-pad support for phones and tablets. The soft
+//! keyboard is summoned by focusing a hidden `<input>` element; whatever the
+//! user types is routed through `BEvent::Character`, exactly like physical
+//! keystrokes. The d-pad is a translucent overlay of arrow buttons that
+//! synthesize cursor-key presses for movement.
+
+use super::GLOBAL_KEY;
+use crate::prelude::{BEvent, VirtualKeyCode, INPUT};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+const KEYBOARD_ID: &str = "bracket-soft-keyboard";
+const DPAD_ID: &str = "bracket-dpad";
+
+fn document() -> Option<web_sys::Document> {
+    web_sys::window().and_then(|w| w.document())
+}
+
+/// Focuses (or blurs) the hidden input element, which makes mobile browsers
+/// show (or hide) the soft keyboard.
+pub fn show_virtual_keyboard(show: bool) {
+    let document = match document() {
+        Some(d) => d,
+        None => return,
+    };
+    if show {
+        let input_el = match document.get_element_by_id(KEYBOARD_ID) {
+            Some(el) => el,
+            None => create_keyboard_input(&document),
+        };
+        if let Some(el) = input_el.dyn_ref::<web_sys::HtmlElement>() {
+            let _ = el.focus();
+        }
+    } else if let Some(el) = document.get_element_by_id(KEYBOARD_ID) {
+        if let Some(el) = el.dyn_ref::<web_sys::HtmlElement>() {
+            let _ = el.blur();
+        }
+    }
+}
+
+fn create_keyboard_input(document: &web_sys::Document) -> web_sys::Element {
+    let el = document.create_element("input").unwrap();
+    el.set_id(KEYBOARD_ID);
+    let _ = el.set_attribute("type", "text");
+    let _ = el.set_attribute("autocapitalize", "none");
+    let _ = el.set_attribute("autocomplete", "off");
+    // Parked off-screen: it has to be focusable, but should never be seen.
+    let _ = el.set_attribute("style", "position:absolute;left:-1000px;top:0;opacity:0;");
+    document.body().unwrap().append_child(&el).unwrap();
+
+    let on_input = Closure::wrap(Box::new(|e: web_sys::Event| {
+        if let Some(target) = e.target() {
+            if let Ok(input) = target.dyn_into::<web_sys::HtmlInputElement>() {
+                let value = input.value();
+                let mut inp = INPUT.lock();
+                for c in value.chars() {
+                    inp.push_event(BEvent::Character { c });
+                }
+                input.set_value("");
+            }
+        }
+    }) as Box<dyn FnMut(_)>);
+    el.add_event_listener_with_callback("input", on_input.as_ref().unchecked_ref())
+        .unwrap();
+    on_input.forget();
+    el
+}
+
+/// Shows or hides the on-screen directional pad overlay.
+pub fn show_virtual_dpad(show: bool) {
+    let document = match document() {
+        Some(d) => d,
+        None => return,
+    };
+    match document.get_element_by_id(DPAD_ID) {
+        Some(el) => {
+            if let Some(el) = el.dyn_ref::<web_sys::HtmlElement>() {
+                let _ = el
+                    .style()
+                    .set_property("display", if show { "grid" } else { "none" });
+            }
+        }
+        None => {
+            if show {
+                create_dpad(&document);
+            }
+        }
+    }
+}
+
+fn create_dpad(document: &web_sys::Document) {
+    let container = document.create_element("div").unwrap();
+    container.set_id(DPAD_ID);
+    let _ = container.set_attribute(
+        "style",
+        "position:fixed;bottom:16px;left:16px;display:grid;\
+         grid-template-columns:repeat(3,48px);grid-template-rows:repeat(3,48px);\
+         gap:4px;opacity:0.6;z-index:1000;",
+    );
+    document.body().unwrap().append_child(&container).unwrap();
+
+    add_dpad_button(document, &container, "\u{25b2}", (2, 1), VirtualKeyCode::Up);
+    add_dpad_button(
+        document,
+        &container,
+        "\u{25c0}",
+        (1, 2),
+        VirtualKeyCode::Left,
+    );
+    add_dpad_button(
+        document,
+        &container,
+        "\u{25b6}",
+        (3, 2),
+        VirtualKeyCode::Right,
+    );
+    add_dpad_button(
+        document,
+        &container,
+        "\u{25bc}",
+        (2, 3),
+        VirtualKeyCode::Down,
+    );
+}
+
+fn add_dpad_button(
+    document: &web_sys::Document,
+    parent: &web_sys::Element,
+    label: &str,
+    grid: (u32, u32),
+    key: VirtualKeyCode,
+) {
+    let btn = document.create_element("button").unwrap();
+    btn.set_text_content(Some(label));
+    let _ = btn.set_attribute(
+        "style",
+        &format!("grid-column:{};grid-row:{};font-size:24px;", grid.0, grid.1),
+    );
+    parent.append_child(&btn).unwrap();
+
+    let down = Closure::wrap(Box::new(move |e: web_sys::Event| {
+        e.prevent_default();
+        unsafe {
+            GLOBAL_KEY = Some(key);
+        }
+        INPUT.lock().on_key_down(key, 0);
+    }) as Box<dyn FnMut(_)>);
+    btn.add_event_listener_with_callback("mousedown", down.as_ref().unchecked_ref())
+        .unwrap();
+    btn.add_event_listener_with_callback("touchstart", down.as_ref().unchecked_ref())
+        .unwrap();
+    down.forget();
+
+    let up = Closure::wrap(Box::new(move |e: web_sys::Event| {
+        e.prevent_default();
+        INPUT.lock().on_key_up(key, 0);
+    }) as Box<dyn FnMut(_)>);
+    btn.add_event_listener_with_callback("mouseup", up.as_ref().unchecked_ref())
+        .unwrap();
+    btn.add_event_listener_with_callback("touchend", up.as_ref().unchecked_ref())
+        .unwrap();
+    btn.add_event_listener_with_callback("touchcancel", up.as_ref().unchecked_ref())
+        .unwrap();
+    up.forget();
+}
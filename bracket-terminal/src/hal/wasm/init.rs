@@ -5,7 +5,7 @@ pub fn init_raw<S: ToString>(
     width_pixels: u32,
     height_pixels: u32,
     _window_title: S,
-    _platform_hints: InitHints,
+    platform_hints: InitHints,
 ) -> BResult<BTerm> {
     use super::super::*;
     use super::*;
@@ -77,6 +77,10 @@ pub fn init_raw<S: ToString>(
     be.gl = Some(gl);
     be.quad_vao = Some(quad_vao);
     be.backing_buffer = Some(backing_fbo);
+    be.resize_scaling = platform_hints.resize_scaling;
+    be.screen_scaler.scaling_mode = platform_hints.scaling_mode;
+    be.screen_scaler.base_size = (width_pixels, height_pixels);
+    be.frame_sleep_time = crate::hal::convert_fps_to_wait(platform_hints.frame_sleep_time);
 
     BACKEND_INTERNAL.lock().shaders = shaders;
 
@@ -98,6 +102,11 @@ pub fn init_raw<S: ToString>(
         quitting: false,
         post_scanlines: false,
         post_screenburn: false,
+        interpolation_alpha: 0.0,
+        ups: 0.0,
+        update_time_ms: 0.0,
         screen_burn_color: bracket_color::prelude::RGB::from_f32(0.0, 1.0, 1.0),
+        crt_params: crate::prelude::CrtParams::default(),
+        colorblind_mode: None,
     })
 }
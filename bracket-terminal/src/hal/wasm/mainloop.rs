@@ -12,28 +12,128 @@ fn window() -> web_sys::Window {
     web_sys::window().expect("no global `window` exists")
 }
 
+fn set_canvas_cursor(css: &str) {
+    if let Some(document) = window().document() {
+        if let Some(canvas) = document.get_element_by_id("canvas") {
+            if let Some(element) = canvas.dyn_ref::<web_sys::HtmlElement>() {
+                let _ = element.style().set_property("cursor", css);
+            }
+        }
+    }
+}
+
+fn largest_active_font() -> (u32, u32) {
+    let bi = BACKEND_INTERNAL.lock();
+    let mut max_width = 0;
+    let mut max_height = 0;
+    bi.consoles.iter().for_each(|c| {
+        let size = bi.fonts[c.font_index].tile_size;
+        if size.0 > max_width {
+            max_width = size.0;
+        }
+        if size.1 > max_height {
+            max_height = size.1;
+        }
+    });
+    (max_width, max_height)
+}
+
+/// Polled every frame: when CSS layout or the device pixel ratio changes the
+/// canvas's client size, grow the drawing buffer to match and run the same
+/// resize path as the native backend.
+fn check_canvas_resize(bterm: &mut BTerm) {
+    let document = match window().document() {
+        Some(d) => d,
+        None => return,
+    };
+    let canvas = match document.get_element_by_id("canvas") {
+        Some(c) => c,
+        None => return,
+    };
+    let canvas: web_sys::HtmlCanvasElement = match canvas.dyn_into() {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    let dpi = window().device_pixel_ratio();
+    let new_width = (canvas.client_width() as f64 * dpi) as u32;
+    let new_height = (canvas.client_height() as f64 * dpi) as u32;
+    if new_width == 0 || new_height == 0 {
+        return;
+    }
+    if new_width == canvas.width() && new_height == canvas.height() {
+        return;
+    }
+    canvas.set_width(new_width);
+    canvas.set_height(new_height);
+
+    let font_max_size = largest_active_font();
+    crate::prelude::INPUT.lock().set_scale_factor(dpi);
+    let resize_scaling;
+    let (avail_w, avail_h);
+    {
+        let mut be = BACKEND.lock();
+        be.screen_scaler.change_physical_size_smooth(
+            new_width,
+            new_height,
+            dpi as f32,
+            font_max_size,
+        );
+        resize_scaling = be.resize_scaling;
+        avail_w = be.screen_scaler.available_width;
+        avail_h = be.screen_scaler.available_height;
+        let gl = be.gl.as_ref().unwrap();
+        unsafe {
+            gl.viewport(0, 0, new_width as i32, new_height as i32);
+        }
+        let new_fb = Framebuffer::build_fbo(gl, new_width as i32, new_height as i32);
+        be.backing_buffer = Some(new_fb);
+    }
+    bterm.resize_pixels(new_width, new_height, resize_scaling);
+    bterm.on_event(crate::prelude::BEvent::Resized {
+        new_size: bracket_geometry::prelude::Point::new(avail_w, avail_h),
+        dpi_scale_factor: dpi as f32,
+    });
+
+    if resize_scaling {
+        let mut bit = BACKEND_INTERNAL.lock();
+        let num_consoles = bit.consoles.len();
+        for i in 0..num_consoles {
+            let font_size = bit.fonts[bit.consoles[i].font_index].tile_size;
+            let chr_w = avail_w / font_size.0;
+            let chr_h = avail_h / font_size.1;
+            bit.consoles[i].console.set_char_size(chr_w, chr_h);
+        }
+    }
+}
+
 fn request_animation_frame(f: &Closure<dyn FnMut()>) {
     window()
         .request_animation_frame(f.as_ref().unchecked_ref())
         .expect("should register `requestAnimationFrame` OK");
 }
 
+fn setup_gl_resources() -> BResult<()> {
+    let be = BACKEND.lock();
+    let gl = be.gl.as_ref().unwrap();
+    let mut bit = BACKEND_INTERNAL.lock();
+    for f in bit.fonts.iter_mut() {
+        f.setup_gl_texture(gl)?;
+    }
+
+    for s in bit.sprite_sheets.iter_mut() {
+        let mut f = Font::new(&s.filename.to_string(), 1, 1, (1, 1));
+        f.setup_gl_texture(gl)?;
+        s.backing = Some(Rc::new(Box::new(f)));
+    }
+    Ok(())
+}
+
 pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<()> {
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 
-    {
-        let be = BACKEND.lock();
-        let gl = be.gl.as_ref().unwrap();
-        let mut bit = BACKEND_INTERNAL.lock();
-        for f in bit.fonts.iter_mut() {
-            f.setup_gl_texture(gl)?;
-        }
-
-        for s in bit.sprite_sheets.iter_mut() {
-            let mut f = Font::new(&s.filename.to_string(), 1, 1, (1, 1));
-            f.setup_gl_texture(gl)?;
-            s.backing = Some(Rc::new(Box::new(f)));
-        }
+    let mut gl_resources_ready = crate::resource_loader::resources_ready();
+    if gl_resources_ready {
+        setup_gl_resources()?;
     }
 
     let now = wasm_timer::Instant::now();
@@ -43,7 +143,39 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
 
     let f = Rc::new(RefCell::new(None));
     let g = f.clone();
+    let mut next_frame_due_ms: u64 = 0;
     *g.borrow_mut() = Some(Closure::wrap(Box::new(move || {
+        // Frame pacing: honor an fps cap by skipping whole rAF callbacks,
+        // so ticks stay aligned with the browser's paint clock instead of
+        // sleeping inside it. Without a cap, every rAF ticks.
+        if let Some(interval_ms) = BACKEND.lock().frame_sleep_time {
+            let now_ms = now.elapsed().as_millis() as u64;
+            if now_ms < next_frame_due_ms {
+                request_animation_frame(f.borrow().as_ref().unwrap());
+                return;
+            }
+            // Schedule the next tick, snapping forward if we fell more than
+            // one whole interval behind - same catch-up rule as the native
+            // spin-sleeper.
+            next_frame_due_ms = if now_ms > next_frame_due_ms + interval_ms {
+                now_ms + interval_ms
+            } else {
+                next_frame_due_ms + interval_ms
+            };
+        }
+
+        // Defer texture uploads until async resources land; show a blank
+        // loading screen meanwhile.
+        if !gl_resources_ready {
+            if crate::resource_loader::resources_ready() {
+                setup_gl_resources().expect("Failed to set up GL resources");
+                gl_resources_ready = true;
+            } else {
+                request_animation_frame(f.borrow().as_ref().unwrap());
+                return;
+            }
+        }
+
         // Read in event results
         unsafe {
             bterm.key = GLOBAL_KEY;
@@ -56,6 +188,66 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
             bterm.on_mouse_position(GLOBAL_MOUSE_POS.0 as f64, GLOBAL_MOUSE_POS.1 as f64);
         }
 
+        // Track canvas size changes before rendering
+        check_canvas_resize(&mut bterm);
+
+        // Apply any queued window changes
+        {
+            let mut be = BACKEND.lock();
+            if let Some(title) = be.request_window_title.take() {
+                if let Some(document) = window().document() {
+                    document.set_title(&title);
+                }
+            }
+            if let Some(fullscreen) = be.request_fullscreen.take() {
+                if let Some(document) = window().document() {
+                    if fullscreen {
+                        if let Some(canvas) = document.get_element_by_id("canvas") {
+                            let _ = canvas.request_fullscreen();
+                        }
+                    } else {
+                        document.exit_fullscreen();
+                    }
+                }
+                be.fullscreen = fullscreen;
+            }
+            if let Some(visible) = be.request_cursor_visible.take() {
+                set_canvas_cursor(if visible { "auto" } else { "none" });
+            }
+            if let Some(icon) = be.request_cursor_icon.take() {
+                use crate::prelude::CursorIcon;
+                let css = match &icon {
+                    CursorIcon::Default => "auto".to_string(),
+                    CursorIcon::Crosshair => "crosshair".to_string(),
+                    CursorIcon::Hand => "pointer".to_string(),
+                    CursorIcon::Text => "text".to_string(),
+                    CursorIcon::Wait => "wait".to_string(),
+                    CursorIcon::Help => "help".to_string(),
+                    CursorIcon::NotAllowed => "not-allowed".to_string(),
+                    CursorIcon::Move => "move".to_string(),
+                    CursorIcon::Custom(url) => format!("url({}), auto", url),
+                };
+                set_canvas_cursor(&css);
+            }
+            if let Some(show) = be.request_virtual_keyboard.take() {
+                show_virtual_keyboard(show);
+            }
+            if let Some(show) = be.request_virtual_dpad.take() {
+                show_virtual_dpad(show);
+            }
+        }
+        if let Some(capture) = crate::prelude::INPUT.lock().take_capture_request() {
+            if let Some(document) = window().document() {
+                if capture {
+                    if let Some(canvas) = document.get_element_by_id("canvas") {
+                        canvas.request_pointer_lock();
+                    }
+                } else {
+                    document.exit_pointer_lock();
+                }
+            }
+        }
+
         // Call the tock function
         tock(
             &mut bterm,
@@ -80,6 +272,110 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
     Ok(())
 }
 
+/// Persistent timing state for `run_single_frame`.
+struct SingleFrameState {
+    now: Option<wasm_timer::Instant>,
+    prev_seconds: u64,
+    prev_ms: u128,
+    frames: i32,
+}
+
+lazy_static::lazy_static! {
+    static ref SINGLE_FRAME: parking_lot::Mutex<SingleFrameState> =
+        parking_lot::Mutex::new(SingleFrameState {
+            now: None,
+            prev_seconds: 0,
+            prev_ms: 0,
+            frames: 0,
+        });
+}
+
+/// Renders exactly one frame and returns, for hosts that drive their own
+/// requestAnimationFrame loop instead of handing control to `main_loop`.
+pub fn run_single_frame<GS: GameState>(bterm: &mut BTerm, gamestate: &mut GS) -> BResult<()> {
+    let mut sf = SINGLE_FRAME.lock();
+    if sf.now.is_none() {
+        let be = BACKEND.lock();
+        let gl = be.gl.as_ref().unwrap();
+        let mut bit = BACKEND_INTERNAL.lock();
+        for f in bit.fonts.iter_mut() {
+            f.setup_gl_texture(gl)?;
+        }
+        for s in bit.sprite_sheets.iter_mut() {
+            let mut f = Font::new(&s.filename.to_string(), 1, 1, (1, 1));
+            f.setup_gl_texture(gl)?;
+            s.backing = Some(Rc::new(Box::new(f)));
+        }
+        sf.now = Some(wasm_timer::Instant::now());
+    }
+    let now = sf.now.unwrap();
+
+    // Read in event results
+    unsafe {
+        bterm.key = GLOBAL_KEY;
+        bterm.mouse_pos = (GLOBAL_MOUSE_POS.0, GLOBAL_MOUSE_POS.1);
+        bterm.left_click = GLOBAL_LEFT_CLICK;
+        bterm.shift = GLOBAL_MODIFIERS.0;
+        bterm.control = GLOBAL_MODIFIERS.1;
+        bterm.alt = GLOBAL_MODIFIERS.2;
+        bterm.web_button = GLOBAL_BUTTON.clone();
+        bterm.on_mouse_position(GLOBAL_MOUSE_POS.0 as f64, GLOBAL_MOUSE_POS.1 as f64);
+    }
+
+    check_canvas_resize(bterm);
+
+    let SingleFrameState {
+        ref mut prev_seconds,
+        ref mut prev_ms,
+        ref mut frames,
+        ..
+    } = *sf;
+    tock(bterm, gamestate, frames, prev_seconds, prev_ms, &now);
+
+    // Clear any input
+    clear_input_state(bterm);
+    unsafe {
+        GLOBAL_KEY = None;
+        GLOBAL_MODIFIERS = (false, false, false);
+        GLOBAL_LEFT_CLICK = false;
+        GLOBAL_BUTTON = None;
+    }
+    Ok(())
+}
+
+// Uploads the whole-frame colorblind-simulation uniforms. The matrix is
+// row-major; the shader multiplies vector * matrix, which undoes the
+// column-major upload's implicit transpose.
+unsafe fn set_colorblind_uniforms(
+    shader: &Shader,
+    gl: &glow::Context,
+    mode: Option<bracket_color::prelude::ColorblindMode>,
+) {
+    shader.setBool(gl, "colorblindEnabled", mode.is_some());
+    if let Some(mode) = mode {
+        let m = mode.matrix();
+        shader.setMat3(
+            gl,
+            "colorblindMatrix",
+            &[
+                m[0][0], m[0][1], m[0][2], m[1][0], m[1][1], m[1][2], m[2][0], m[2][1], m[2][2],
+            ],
+        );
+    }
+}
+
+/// Gathers the viewport/backing-buffer details handed to staged GL callbacks.
+fn callback_info(be: &PlatformGL, use_post_pass: bool) -> GlCallbackInfo {
+    GlCallbackInfo {
+        viewport: be.screen_scaler.physical_size,
+        backing_texture: if use_post_pass {
+            be.backing_buffer.as_ref().map(|fb| fb.texture)
+        } else {
+            None
+        },
+    }
+}
+
 fn tock<GS: GameState>(
     bterm: &mut BTerm,
     gamestate: &mut GS,
@@ -106,13 +402,30 @@ fn tock<GS: GameState>(
         *prev_ms = now_ms;
     }
 
-    gamestate.tick(bterm);
+    let phase_timer = wasm_timer::Instant::now();
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("tick").entered();
+        gamestate.tick(bterm);
+    }
+    let tick_ms = phase_timer.elapsed().as_secs_f32() * 1000.0;
+
+    // Diagnostics overlay, composited over whatever the game drew.
+    if crate::perf_overlay::enabled() {
+        crate::perf_overlay::draw(bterm);
+    }
 
     // Console structure - doesn't really have to be every frame...
+    let phase_timer = wasm_timer::Instant::now();
     rebuild_consoles();
+    let rebuild_ms = phase_timer.elapsed().as_secs_f32() * 1000.0;
+    let phase_timer = wasm_timer::Instant::now();
 
-    // Bind to the backing buffer
-    if bterm.post_scanlines {
+    // Bind to the backing buffer if a post-processing pass will run
+    let use_post_pass = bterm.post_scanlines
+        || bterm.colorblind_mode.is_some()
+        || BACKEND_INTERNAL.lock().post_shader.is_some();
+    if use_post_pass {
         let be = BACKEND.lock();
         be.backing_buffer
             .as_ref()
@@ -127,6 +440,16 @@ fn tock<GS: GameState>(
         be.gl.as_ref().unwrap().clear(glow::COLOR_BUFFER_BIT);
     }
 
+    // Pre-render hook: custom GL layers drawn before the consoles, so the
+    // console output composites over them.
+    {
+        let be = BACKEND.lock();
+        if let Some(callback) = be.gl_pre_render_callback.as_ref() {
+            let info = callback_info(&be, use_post_pass);
+            callback(gamestate, be.gl.as_ref().unwrap(), &info);
+        }
+    }
+
     // Tell each console to draw itself
     render_consoles().unwrap();
 
@@ -137,10 +460,18 @@ fn tock<GS: GameState>(
             let gl = be.gl.as_ref().unwrap();
             callback(gamestate, gl);
         }
+        // Post-render hook: same stage, with viewport/backing-buffer details.
+        // Runs before the post-process pass.
+        if let Some(callback) = be.gl_post_render_callback.as_ref() {
+            let info = callback_info(&be, use_post_pass);
+            callback(gamestate, be.gl.as_ref().unwrap(), &info);
+        }
     }
 
-    if bterm.post_scanlines {
+    if use_post_pass {
         // Now we return to the primary screen
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("post_process").entered();
         let be = BACKEND.lock();
         be.backing_buffer
             .as_ref()
@@ -148,7 +479,30 @@ fn tock<GS: GameState>(
             .default(be.gl.as_ref().unwrap());
         unsafe {
             let bi = BACKEND_INTERNAL.lock();
-            if bterm.post_scanlines {
+            if let Some(post) = &bi.post_shader {
+                let gl = be.gl.as_ref().unwrap();
+                post.shader.useProgram(gl);
+                post.shader.setVec3(
+                    gl,
+                    "screenSize",
+                    bterm.width_pixels as f32,
+                    bterm.height_pixels as f32,
+                    0.0,
+                );
+                for (name, value) in &post.uniforms {
+                    match value {
+                        crate::prelude::PostShaderUniform::Float(f) => {
+                            post.shader.setFloat(gl, name, *f)
+                        }
+                        crate::prelude::PostShaderUniform::Vec3(x, y, z) => {
+                            post.shader.setVec3(gl, name, *x, *y, *z)
+                        }
+                        crate::prelude::PostShaderUniform::Bool(b) => {
+                            post.shader.setBool(gl, name, *b)
+                        }
+                    }
+                }
+            } else if bterm.post_scanlines {
                 bi.shaders[3].useProgram(be.gl.as_ref().unwrap());
                 bi.shaders[3].setVec3(
                     be.gl.as_ref().unwrap(),
@@ -165,8 +519,26 @@ fn tock<GS: GameState>(
                     bterm.screen_burn_color.g,
                     bterm.screen_burn_color.b,
                 );
+                bi.shaders[3].setVec4(
+                    be.gl.as_ref().unwrap(),
+                    "crtParams",
+                    bterm.crt_params.scanline_intensity,
+                    bterm.crt_params.mask_intensity,
+                    bterm.crt_params.curvature,
+                    bterm.crt_params.vignette,
+                );
+                set_colorblind_uniforms(
+                    &bi.shaders[3],
+                    be.gl.as_ref().unwrap(),
+                    bterm.colorblind_mode,
+                );
             } else {
                 bi.shaders[2].useProgram(be.gl.as_ref().unwrap());
+                set_colorblind_uniforms(
+                    &bi.shaders[2],
+                    be.gl.as_ref().unwrap(),
+                    bterm.colorblind_mode,
+                );
             }
             be.gl
                 .as_ref()
@@ -179,4 +551,14 @@ fn tock<GS: GameState>(
             be.gl.as_ref().unwrap().draw_arrays(glow::TRIANGLES, 0, 6);
         }
     }
+
+    // The browser presents the frame, so there is no separate present phase.
+    let render_ms = phase_timer.elapsed().as_secs_f32() * 1000.0;
+    crate::frame_stats::record(crate::frame_stats::FrameTimes {
+        tick: tick_ms,
+        rebuild: rebuild_ms,
+        render: render_ms,
+        present: 0.0,
+        total: tick_ms + rebuild_ms + render_ms,
+    });
 }
@@ -5,19 +5,34 @@ pub use init::*;
 mod events;
 pub use events::*;
 mod mainloop;
+use crate::hal::scaler::{default_gutter_size, ScreenScaler};
 use crate::hal::ConsoleBacking;
 pub use mainloop::*;
 use parking_lot::Mutex;
 use std::any::Any;
-use crate::hal::scaler::{ScreenScaler, default_gutter_size};
 
 pub type GlCallback = fn(&mut dyn Any, &glow::Context);
 
+/// Viewport and render-target details handed to the staged GL callbacks.
+pub struct GlCallbackInfo {
+    /// Physical drawable size, in pixels.
+    pub viewport: (u32, u32),
+    /// The post-process framebuffer's texture when one is in use; `None` when
+    /// drawing straight to the canvas.
+    pub backing_texture: Option<super::TextureId>,
+}
+
+/// A GL callback carrying viewport/backing-buffer information, for custom
+/// layers composited with the consoles.
+pub type GlStageCallback = fn(&mut dyn Any, &glow::Context, &GlCallbackInfo);
+
 pub struct InitHints {
     pub vsync: bool,
     pub fullscreen: bool,
     pub frame_sleep_time: Option<f32>,
     pub desired_gutter: u32,
+    pub scaling_mode: crate::hal::scaler::ScalingMode,
+    pub resize_scaling: bool,
 }
 
 impl InitHints {
@@ -27,6 +42,8 @@ impl InitHints {
             fullscreen: false,
             frame_sleep_time: None,
             desired_gutter: 0,
+            scaling_mode: crate::hal::scaler::ScalingMode::Stretch,
+            resize_scaling: false,
         }
     }
 }
@@ -36,7 +53,19 @@ pub struct PlatformGL {
     pub quad_vao: Option<glow::WebVertexArrayKey>,
     pub backing_buffer: Option<super::Framebuffer>,
     pub gl_callback: Option<GlCallback>,
+    pub gl_pre_render_callback: Option<GlStageCallback>,
+    pub gl_post_render_callback: Option<GlStageCallback>,
+    pub request_window_title: Option<String>,
+    pub request_fullscreen: Option<bool>,
+    pub request_cursor_visible: Option<bool>,
+    pub request_cursor_icon: Option<crate::prelude::CursorIcon>,
+    pub request_virtual_keyboard: Option<bool>,
+    pub request_virtual_dpad: Option<bool>,
+    pub fullscreen: bool,
     pub screen_scaler: ScreenScaler,
+    pub instanced_consoles: bool,
+    pub resize_scaling: bool,
+    pub frame_sleep_time: Option<u64>,
 }
 
 lazy_static! {
@@ -44,8 +73,20 @@ lazy_static! {
         gl: None,
         quad_vao: None,
         gl_callback: None,
+        gl_pre_render_callback: None,
+        gl_post_render_callback: None,
         backing_buffer: None,
+        request_window_title: None,
+        request_fullscreen: None,
+        request_cursor_visible: None,
+        request_cursor_icon: None,
+        request_virtual_keyboard: None,
+        request_virtual_dpad: None,
+        fullscreen: false,
         screen_scaler: ScreenScaler::default(),
+        instanced_consoles: false,
+        resize_scaling: false,
+        frame_sleep_time: None,
     });
 }
 
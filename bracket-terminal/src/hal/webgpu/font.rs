@@ -38,7 +38,10 @@ impl Font {
             tile_size,
             explicit_background: None,
             font_dimensions_glyphs: (tile_size.0 / width, tile_size.1 / height),
-            font_dimensions_texture: ( tile_size.0 as f32 / width as f32, tile_size.1 as f32 / height as f32 ),
+            font_dimensions_texture: (
+                tile_size.0 as f32 / width as f32,
+                tile_size.1 as f32 / height as f32,
+            ),
             view: None,
             sampler: None,
             bind_group: None,
@@ -70,7 +73,10 @@ impl Font {
             tile_size,
             explicit_background,
             font_dimensions_glyphs: (img.width() / tile_size.0, img.height() / tile_size.1),
-            font_dimensions_texture: ( tile_size.0 as f32 / img.width() as f32, tile_size.1 as f32 / img.height() as f32),
+            font_dimensions_texture: (
+                tile_size.0 as f32 / img.width() as f32,
+                tile_size.1 as f32 / img.height() as f32,
+            ),
             view: None,
             sampler: None,
             bind_group: None,
@@ -80,6 +86,8 @@ impl Font {
 
     /// Load a font, and allocate it as an OpenGL resource. Returns the OpenGL binding number (which is also set in the structure).
     pub fn setup_wgpu_texture(&mut self, wgpu: &WgpuLink) -> BResult<usize> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("font_upload", filename = %self.bitmap_file).entered();
         let texture = 0;
 
         // Ensure image is in the correct orientation and handle explicit backgrounds
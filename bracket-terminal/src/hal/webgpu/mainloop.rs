@@ -6,12 +6,12 @@ use super::{
 };
 use crate::{
     gamestate::{BTerm, GameState},
+    hal::scaler::FontScaler,
     input::{clear_input_state, BEvent},
     prelude::{
         FlexiConsole, SimpleConsole, SparseConsole, SpriteConsole, BACKEND, BACKEND_INTERNAL, INPUT,
     },
     BResult,
-    hal::scaler::FontScaler,
 };
 use bracket_geometry::prelude::Point;
 use std::mem::size_of;
@@ -58,12 +58,7 @@ pub fn main_loop<GS: GameState>(mut bterm: BTerm, mut gamestate: GS) -> BResult<
     let el = unwrap.el;
     let window = unwrap.window;
 
-    on_resize(
-        &mut bterm,
-        window.inner_size(),
-        window.scale_factor(),
-        true,
-    )?; // Additional resize to handle some X11 cases
+    on_resize(&mut bterm, window.inner_size(), window.scale_factor(), true)?; // Additional resize to handle some X11 cases
 
     let mut queued_resize_event: Option<ResizeEvent> = None;
     let spin_sleeper = spin_sleep::SpinSleeper::default();
@@ -246,7 +241,12 @@ fn on_resize(
     //println!("{:#?}", physical_size);
     INPUT.lock().set_scale_factor(dpi_scale_factor);
     let mut be = BACKEND.lock();
-    be.screen_scaler.change_physical_size_smooth(physical_size.width, physical_size.height, dpi_scale_factor as f32, font_max_size);
+    be.screen_scaler.change_physical_size_smooth(
+        physical_size.width,
+        physical_size.height,
+        dpi_scale_factor as f32,
+        font_max_size,
+    );
     if send_event {
         bterm.resize_pixels(
             physical_size.width as u32,
@@ -273,7 +273,10 @@ fn on_resize(
 
     // Messaging
     bterm.on_event(BEvent::Resized {
-        new_size: Point::new(be.screen_scaler.available_width, be.screen_scaler.available_height),
+        new_size: Point::new(
+            be.screen_scaler.available_width,
+            be.screen_scaler.available_height,
+        ),
         dpi_scale_factor: dpi_scale_factor as f32,
     });
 
@@ -324,7 +327,11 @@ fn tock<GS: GameState>(
     rebuild_consoles();
 
     // Run the main loop
-    gamestate.tick(bterm);
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("tick").entered();
+        gamestate.tick(bterm);
+    }
 
     // Tell each console to draw itself
     render_consoles().unwrap();
@@ -341,6 +348,8 @@ fn tock<GS: GameState>(
     // Present the output now that we've done all the layers and
     // backing buffer/post-process
     {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::trace_span!("post_process").entered();
         let mut be = BACKEND.lock();
         if let Some(wgpu) = be.wgpu.as_ref() {
             if let Ok(current_tex) = wgpu.surface.get_current_texture() {
@@ -349,6 +358,7 @@ fn tock<GS: GameState>(
                     bterm.post_scanlines,
                     bterm.post_screenburn,
                     bterm.screen_burn_color,
+                    bterm.colorblind_mode,
                 );
                 let target = current_tex
                     .texture
@@ -366,6 +376,8 @@ fn tock<GS: GameState>(
 }
 
 pub(crate) fn rebuild_consoles() {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("rebuild_consoles").entered();
     let must_resize = BACKEND.lock().screen_scaler.get_resized_and_reset();
     let mut consoles = CONSOLE_BACKING.lock();
     let mut bi = BACKEND_INTERNAL.lock();
@@ -478,12 +490,17 @@ pub(crate) fn rebuild_consoles() {
 }
 
 pub(crate) fn render_consoles() -> BResult<()> {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!("render_consoles").entered();
     let bi = BACKEND_INTERNAL.lock();
     let mut consoles = CONSOLE_BACKING.lock();
     //let output = BACKEND.lock().backing_buffer.as_ref().unwrap().view();
     clear_screen_pass()?;
     for (i, c) in consoles.iter_mut().enumerate() {
         let cons = &bi.consoles[i];
+        if !cons.visible {
+            continue;
+        }
         let font = &bi.fonts[cons.font_index];
         match c {
             ConsoleBacking::Simple { backing } => {
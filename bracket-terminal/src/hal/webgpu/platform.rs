@@ -1,9 +1,9 @@
 //! WGPU Platform definition
 
+use super::Framebuffer;
+use crate::hal::scaler::{default_gutter_size, ScreenScaler};
 use wgpu::{Adapter, Device, Instance, Queue, Surface, SurfaceConfiguration};
 use winit::{event_loop::EventLoop, window::Window};
-use super::Framebuffer;
-use crate::hal::scaler::{ ScreenScaler, default_gutter_size };
 
 /// Defines the WGPU platform
 pub struct PlatformGL {
@@ -48,6 +48,7 @@ pub struct InitHints {
     pub frame_sleep_time: Option<f32>,
     pub resize_scaling: bool,
     pub desired_gutter: u32,
+    pub scaling_mode: crate::hal::scaler::ScalingMode,
 }
 
 impl InitHints {
@@ -58,6 +59,7 @@ impl InitHints {
             frame_sleep_time: None,
             resize_scaling: false,
             desired_gutter: default_gutter_size(),
+            scaling_mode: crate::hal::scaler::ScalingMode::Stretch,
         }
     }
 }
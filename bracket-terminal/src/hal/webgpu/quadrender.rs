@@ -2,7 +2,7 @@
 
 use super::{vertex_array_helper::FloatBuffer, Shader, WgpuLink};
 use crate::BResult;
-use bracket_color::prelude::RGB;
+use bracket_color::prelude::{ColorblindMode, RGB};
 use wgpu::util::DeviceExt;
 use wgpu::{Buffer, BufferUsages, TextureView};
 
@@ -146,6 +146,7 @@ impl QuadRender {
         enable_scan_lines: bool,
         enable_screen_burn: bool,
         screen_burn_color: RGB,
+        colorblind_mode: Option<ColorblindMode>,
     ) {
         // Update the render effects uniform
         self.uniform.enable_scan_lines = if enable_scan_lines { 1.0 } else { 0.0 };
@@ -156,6 +157,13 @@ impl QuadRender {
             screen_burn_color.b,
             1.0,
         ];
+        self.uniform.enable_colorblind = if colorblind_mode.is_some() { 1.0 } else { 0.0 };
+        if let Some(mode) = colorblind_mode {
+            let m = mode.matrix();
+            self.uniform.colorblind_row0 = [m[0][0], m[0][1], m[0][2], 0.0];
+            self.uniform.colorblind_row1 = [m[1][0], m[1][1], m[1][2], 0.0];
+            self.uniform.colorblind_row2 = [m[2][0], m[2][1], m[2][2], 0.0];
+        }
 
         let uniform_buffer = wgpu
             .device
@@ -238,6 +246,11 @@ struct QuadUniform {
     enable_screen_burn: f32,
     padding: [f32; 2],
     screen_burn_color: [f32; 4],
+    enable_colorblind: f32,
+    colorblind_padding: [f32; 3],
+    colorblind_row0: [f32; 4],
+    colorblind_row1: [f32; 4],
+    colorblind_row2: [f32; 4],
 }
 
 unsafe impl bytemuck::Pod for QuadUniform {}
@@ -250,6 +263,11 @@ impl QuadUniform {
             enable_screen_burn: 0.0,
             padding: [0.0, 0.0],
             screen_burn_color: [0.0, 0.0, 0.0, 1.0],
+            enable_colorblind: 0.0,
+            colorblind_padding: [0.0, 0.0, 0.0],
+            colorblind_row0: [0.0, 0.0, 0.0, 0.0],
+            colorblind_row1: [0.0, 0.0, 0.0, 0.0],
+            colorblind_row2: [0.0, 0.0, 0.0, 0.0],
         }
     }
 }
@@ -5,6 +5,11 @@ struct BackingUnform {
     enable_screen_burn: f32;
     padding: vec2<f32>;
     screen_burn_color: vec4<f32>;
+    enable_colorblind: f32;
+    colorblind_padding: vec3<f32>;
+    colorblind_row0: vec4<f32>;
+    colorblind_row1: vec4<f32>;
+    colorblind_row2: vec4<f32>;
 };
 [[group(1), binding(0)]]
 var<uniform> post_process: BackingUnform;
@@ -39,7 +44,23 @@ var s_font: sampler;
 
 [[stage(fragment)]]
 fn main(in: VertexOutput) -> [[location(0)]] vec4<f32> {
-    let base = textureSample(t_font, s_font, in.tex_coords);
+    var base = textureSample(t_font, s_font, in.tex_coords);
+
+    // Colorblindness simulation: matrix-multiply in approximately linear light.
+    if (post_process.enable_colorblind > 0.0) {
+        let linear_col = pow(base.rgb, vec3<f32>(2.2, 2.2, 2.2));
+        let simulated = clamp(
+            vec3<f32>(
+                dot(post_process.colorblind_row0.rgb, linear_col),
+                dot(post_process.colorblind_row1.rgb, linear_col),
+                dot(post_process.colorblind_row2.rgb, linear_col)
+            ),
+            vec3<f32>(0.0, 0.0, 0.0),
+            vec3<f32>(1.0, 1.0, 1.0)
+        );
+        let inv_gamma = 1.0 / 2.2;
+        base = vec4<f32>(pow(simulated, vec3<f32>(inv_gamma, inv_gamma, inv_gamma)), base.a);
+    }
 
     if (post_process.enable_scan_lines > 0.0) {
         let scan_line : f32 = (in.clip_position.y % 2.0) * 0.25;
@@ -1,6 +1,6 @@
 use crate::prelude::{
-    init_raw, BTerm, CharacterTranslationMode, FlexiConsole, Font, InitHints, SimpleConsole,
-    SparseConsole, SpriteConsole, SpriteSheet, INPUT,
+    init_raw, BTerm, BlendMode, CharacterTranslationMode, FlexiConsole, Font, InitHints,
+    SimpleConsole, SparseConsole, SpriteConsole, SpriteSheet, INPUT,
 };
 use crate::BResult;
 use bracket_color::prelude::RGB;
@@ -68,6 +68,8 @@ pub struct BTermBuilder {
     platform_hints: InitHints,
     advanced_input: bool,
     sprite_sheets: Vec<SpriteSheet>,
+    blend_modes: Vec<(usize, BlendMode)>,
+    post_scanlines: Option<bool>,
 }
 
 impl Default for BTermBuilder {
@@ -84,6 +86,8 @@ impl Default for BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         }
     }
 }
@@ -104,6 +108,8 @@ impl BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         }
     }
 
@@ -121,6 +127,8 @@ impl BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         };
         cb.fonts.push(BuilderFont {
             path: "terminal8x8.png".to_string(),
@@ -155,6 +163,8 @@ impl BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         };
         cb.fonts.push(BuilderFont {
             path: "terminal8x8.png".to_string(),
@@ -184,6 +194,8 @@ impl BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         };
         cb.fonts.push(BuilderFont {
             path: "vga8x16.png".to_string(),
@@ -221,6 +233,8 @@ impl BTermBuilder {
             platform_hints: InitHints::new(),
             advanced_input: false,
             sprite_sheets: Vec::new(),
+            blend_modes: Vec::new(),
+            post_scanlines: None,
         };
         cb.fonts.push(BuilderFont {
             path: "vga8x16.png".to_string(),
@@ -450,28 +464,185 @@ impl BTermBuilder {
         self
     }
 
+    /// Request a borderless (undecorated) window. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_borderless(mut self, borderless: bool) -> Self {
+        self.platform_hints.borderless = borderless;
+        self
+    }
+
+    /// Request that the window stay on top of other windows - useful for overlay tools.
+    /// Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_always_on_top(mut self, always_on_top: bool) -> Self {
+        self.platform_hints.always_on_top = always_on_top;
+        self
+    }
+
+    /// Allow or disallow the user resizing the window. Defaults to resizable. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_resizable(mut self, resizable: bool) -> Self {
+        self.platform_hints.resizable = resizable;
+        self
+    }
+
+    /// Constrain the window's inner size to never shrink below the specified pixel dimensions.
+    /// Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_min_inner_size<T>(mut self, width: T, height: T) -> Self
+    where
+        T: TryInto<u32>,
+    {
+        self.platform_hints.min_inner_size = Some((
+            width.try_into().ok().expect("Must be convertible to a u32"),
+            height
+                .try_into()
+                .ok()
+                .expect("Must be convertible to a u32"),
+        ));
+        self
+    }
+
+    /// Constrain the window's inner size to never grow beyond the specified pixel dimensions.
+    /// Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_max_inner_size<T>(mut self, width: T, height: T) -> Self
+    where
+        T: TryInto<u32>,
+    {
+        self.platform_hints.max_inner_size = Some((
+            width.try_into().ok().expect("Must be convertible to a u32"),
+            height
+                .try_into()
+                .ok()
+                .expect("Must be convertible to a u32"),
+        ));
+        self
+    }
+
+    /// Open the window on the monitor with the specified index (in enumeration order).
+    /// Out-of-range indices fall back to the primary display. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_monitor(mut self, monitor: usize) -> Self {
+        self.platform_hints.monitor = Some(monitor);
+        self
+    }
+
+    /// Open the window at the specified pixel position - relative to the chosen monitor if
+    /// one was requested with `with_monitor`, otherwise to the desktop origin. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_window_position<T>(mut self, x: T, y: T) -> Self
+    where
+        T: TryInto<i32>,
+    {
+        self.platform_hints.window_position = Some((
+            x.try_into().ok().expect("Must be convertible to an i32"),
+            y.try_into().ok().expect("Must be convertible to an i32"),
+        ));
+        self
+    }
+
+    /// Request a transparent window surface, clearing with alpha 0 instead of opaque black so
+    /// the desktop shows through - useful for overlay tools. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_transparent_window(mut self) -> Self {
+        self.platform_hints.transparent = true;
+        self
+    }
+
     /// Push platform-specific initialization hints to the builder. THIS REMOVES CROSS-PLATFORM COMPATIBILITY
     pub fn with_platform_specific(mut self, hints: InitHints) -> Self {
         self.platform_hints = hints;
         self
     }
 
+    /// Run `GameState::fixed_tick` at the requested rate (in Hz), independently of the render
+    /// rate, for deterministic simulation. `tick` still runs every frame with
+    /// `BTerm::interpolation_alpha` set for render interpolation. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_fixed_time_step(mut self, hz: f32) -> Self {
+        self.platform_hints.fixed_time_step = Some(1000.0 / hz);
+        self
+    }
+
+    /// Renders simple consoles with one instanced quad per console, keeping per-cell data
+    /// in a compact GPU buffer instead of rebuilding full quad geometry on the CPU. Worth
+    /// enabling for very large consoles (300x200 and up). Custom per-console shaders set
+    /// with `set_console_shader` do not apply to instanced consoles. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_instanced_consoles(mut self) -> Self {
+        self.platform_hints.instanced_consoles = true;
+        self
+    }
+
+    /// Selects a frame-pacing policy for the native main loop: `Uncapped`, `CapFps(n)`, or
+    /// `EventDriven` (redraw only on input/timer - ideal for turn-based games). Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_frame_pacing(mut self, pacing: crate::hal::FramePacing) -> Self {
+        self.platform_hints.frame_pacing = pacing;
+        self
+    }
+
+    /// Run the simulation (`GameState::fixed_tick`) at the requested rate in updates per
+    /// second, decoupled from rendering. Synonym for `with_fixed_time_step`; pair it with
+    /// `with_render_rate` to cap rendering separately. Native OpenGL only.
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub fn with_update_rate(self, hz: f32) -> Self {
+        self.with_fixed_time_step(hz)
+    }
+
+    /// Cap rendering (`GameState::tick` and drawing) at the requested frames per second,
+    /// independently of any simulation rate set with `with_update_rate`. Synonym for
+    /// `with_fps_cap`.
+    pub fn with_render_rate(self, fps: f32) -> Self {
+        self.with_fps_cap(fps)
+    }
+
     /// Instructs the back-end (not all of them honor it; WASM and Amethyst do their own thing) to try to limit frame-rate and CPU utilization.
     pub fn with_fps_cap(mut self, fps: f32) -> Self {
         self.platform_hints.frame_sleep_time = Some(1.0 / fps);
         self
     }
 
+    /// Overrides the detected terminal color capability: 24-bit SGR, 256-color
+    /// or 16-color output. The default auto-detects from `COLORTERM`/`TERM`.
+    /// Crossterm only.
+    #[cfg(feature = "cross_term")]
+    pub fn with_terminal_color_mode(mut self, color_mode: crate::hal::TerminalColorMode) -> Self {
+        self.platform_hints.color_mode = Some(color_mode);
+        self
+    }
+
+    /// Render real font graphics through the kitty graphics protocol or sixels
+    /// when the terminal supports one, instead of approximating with
+    /// characters. Falls back to character output otherwise. Crossterm only.
+    #[cfg(feature = "cross_term")]
+    pub fn with_terminal_graphics(mut self, terminal_graphics: bool) -> Self {
+        self.platform_hints.terminal_graphics = terminal_graphics;
+        self
+    }
+
+    /// Enables the scanline post-processing effect from the first frame, with or without
+    /// the screen-burn effect. Equivalent to calling `BTerm::with_post_scanlines` after
+    /// `build`. GPU back-ends only; the terminal back-ends ignore it.
+    pub fn with_post_scanlines(mut self, with_burn: bool) -> Self {
+        self.post_scanlines = Some(with_burn);
+        self
+    }
+
     /// Enables input event queue
     pub fn with_advanced_input(mut self, advanced_input: bool) -> Self {
         self.advanced_input = advanced_input;
         self
     }
 
-    /// Enable resize changing console size, rather than scaling. Native OpenGL only.
-    #[cfg(all(
-        any(feature = "opengl", feature = "webgpu"),
-        not(target_arch = "wasm32")
+    /// Enable resize changing console size, rather than scaling. Supported on
+    /// native OpenGL, the web, and the terminal back-ends.
+    #[cfg(any(
+        feature = "opengl",
+        feature = "webgpu",
+        feature = "cross_term",
+        feature = "curses"
     ))]
     pub fn with_automatic_console_resize(mut self, resize_scaling: bool) -> Self {
         self.platform_hints.resize_scaling = resize_scaling;
@@ -485,17 +656,35 @@ impl BTermBuilder {
         self
     }
 
+    /// Sets the blend mode for the most recently added console - how that layer
+    /// composites over the layers beneath it. Defaults to alpha blending.
+    pub fn with_blend_mode(mut self, blend_mode: BlendMode) -> Self {
+        if !self.consoles.is_empty() {
+            self.blend_modes.push((self.consoles.len() - 1, blend_mode));
+        }
+        self
+    }
+
     /// Request a pixel gutter around the rendered area. The gutter is
     /// applied with half at each side of the screen, centering it. If you
     /// want a 4 pixel gutter on every side, request a gutter size of 8.
     pub fn with_gutter(mut self, desired_gutter: u32) -> Self {
         #[cfg(any(feature = "opengl", feature = "webgpu"))]
         {
-        self.platform_hints.desired_gutter = desired_gutter;
+            self.platform_hints.desired_gutter = desired_gutter;
         }
         self
     }
 
+    /// Selects how the console maps onto a window whose aspect ratio differs:
+    /// stretch to fill (the default), letterbox, or crop. Can be changed at
+    /// runtime with `BTerm::set_scaling_mode`.
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub fn with_scaling_mode(mut self, scaling_mode: crate::hal::ScalingMode) -> Self {
+        self.platform_hints.scaling_mode = scaling_mode;
+        self
+    }
+
     /// Combine all of the builder parameters, and return an BTerm context ready to go.
     pub fn build(self) -> BResult<BTerm> {
         let mut context = init_raw(
@@ -597,6 +786,14 @@ impl BTermBuilder {
             }
         }
 
+        for (console, blend_mode) in &self.blend_modes {
+            context.set_console_blend_mode(*console, *blend_mode);
+        }
+
+        if let Some(with_burn) = self.post_scanlines {
+            context.with_post_scanlines(with_burn);
+        }
+
         if self.advanced_input {
             INPUT.lock().activate_event_queue();
         }
@@ -1,8 +1,9 @@
 pub use crate::prelude::VirtualKeyCode;
-pub use bracket_geometry::prelude::Point;
+pub use bracket_geometry::prelude::{Point, PointF};
 
 /// Available device events
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BEvent {
     /// The window was resized
     Resized {
@@ -34,12 +35,37 @@ pub enum BEvent {
     /// A mouse button was pressed or released
     MouseClick { button: usize, pressed: bool },
 
+    /// The mouse wheel (or trackpad scroll) moved. Positive `y` indicates scrolling away
+    /// from the user, matching the `winit` convention. Trackpads may report fractional lines.
+    MouseWheel {
+        #[cfg_attr(feature = "serde", serde(with = "point_f_serde"))]
+        delta: PointF,
+    },
+
+    /// The mouse moved, reported as a raw relative motion delta in pixels. Most useful
+    /// with mouse capture (`Input::set_mouse_capture`), where absolute cursor positions
+    /// stop changing.
+    MouseDelta {
+        #[cfg_attr(feature = "serde", serde(with = "point_f_serde"))]
+        delta: PointF,
+    },
+
     /// Mouse button is down
     MouseButtonDown { button: usize },
 
     /// Mouse button is up
     MouseButtonUp { button: usize },
 
+    /// A touch contact started. `pixel` is the touch location in pixel coordinates; `tile` is
+    /// the same location translated into the active console's coordinate space.
+    TouchStarted { pixel: Point, tile: Point },
+
+    /// A touch contact moved.
+    TouchMoved { pixel: Point, tile: Point },
+
+    /// A touch contact ended, or was cancelled by the OS.
+    TouchEnded { pixel: Point, tile: Point },
+
     /// A key on the keyboard was pressed or released.
     KeyboardInput {
         key: VirtualKeyCode,
@@ -47,6 +73,10 @@ pub enum BEvent {
         pressed: bool,
     },
 
+    /// A console layer's character grid was resized with `BTerm::set_console_size`.
+    /// Use this to re-lay out UI that depends on the console's dimensions.
+    ConsoleResized { layer: usize, new_size: Point },
+
     /// The window's scale factor was changed. You generally don't need to do anything for this, unless you are working with
     /// pixel coordinates.
     ScaleFactorChanged {
@@ -54,3 +84,20 @@ pub enum BEvent {
         dpi_scale_factor: f32,
     },
 }
+
+/// `PointF` is an `ultraviolet` type without serde support, so scroll deltas are
+/// serialized as an `(x, y)` tuple.
+#[cfg(feature = "serde")]
+mod point_f_serde {
+    use bracket_geometry::prelude::PointF;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(point: &PointF, serializer: S) -> Result<S::Ok, S::Error> {
+        (point.x, point.y).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PointF, D::Error> {
+        let (x, y) = <(f32, f32)>::deserialize(deserializer)?;
+        Ok(PointF::new(x, y))
+    }
+}
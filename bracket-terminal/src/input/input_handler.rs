@@ -1,7 +1,29 @@
+use super::input_map::{Binding, InputMap};
+use super::recorder::{apply_replayed_event, InputRecording, RecordedEvent};
 use super::BEvent;
-use crate::prelude::{BTerm, VirtualKeyCode};
-use bracket_geometry::prelude::Point;
-use std::collections::{HashSet, VecDeque};
+use crate::prelude::{BTerm, VirtualKeyCode, INPUT};
+use bracket_geometry::prelude::{Point, PointF};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Key-repeat timing. Times are in milliseconds and are driven by the frame
+/// clock, so repeat behaves identically on every backend regardless of how the
+/// OS delivers (or doesn't deliver) its own key-repeat events.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyRepeat {
+    /// How long a key must be held before it starts repeating.
+    pub initial_delay_ms: f32,
+    /// The time between repeats once repeating has started.
+    pub interval_ms: f32,
+}
+
+impl Default for KeyRepeat {
+    fn default() -> Self {
+        Self {
+            initial_delay_ms: 400.0,
+            interval_ms: 70.0,
+        }
+    }
+}
 
 /// Internal: clears the current frame's input state. Used by HAL backends to indicate the start of a new frame
 /// for input.
@@ -9,6 +31,18 @@ pub(crate) fn clear_input_state(term: &mut BTerm) {
     term.key = None;
     term.left_click = false;
     term.web_button = None;
+    let replayed = {
+        let mut input = INPUT.lock();
+        input.reset_scroll_delta();
+        input.reset_mouse_delta();
+        input.tick_key_repeat(term.frame_time_ms);
+        input.next_frame()
+    };
+    for event in replayed {
+        apply_replayed_event(term, event);
+    }
+    crate::consoles::tick_blink(term.frame_time_ms);
+    crate::consoles::tick_color_cycles(term.frame_time_ms);
 }
 
 /// Represents the current input state. The old key/mouse fields remain available for compatibility.
@@ -19,9 +53,25 @@ pub struct Input {
     mouse_buttons: HashSet<usize>,
     mouse_pixel: (f64, f64),
     mouse_tile: Vec<(i32, i32)>,
+    scroll_delta: PointF,
+    pub(crate) touch_emulates_mouse: bool,
     pub(crate) use_events: bool,
     event_queue: VecDeque<BEvent>,
     scale_factor: f64,
+    recording: Option<InputRecording>,
+    replay: Option<(InputRecording, usize)>,
+    frame: u64,
+    input_map: InputMap,
+    gamepad_buttons: HashSet<usize>,
+    key_repeat: Option<KeyRepeat>,
+    key_down_ms: HashMap<VirtualKeyCode, f32>,
+    repeat_fired: HashSet<VirtualKeyCode>,
+    scancode_to_key: HashMap<u32, VirtualKeyCode>,
+    key_chars: HashMap<VirtualKeyCode, char>,
+    pending_char_key: Option<VirtualKeyCode>,
+    mouse_delta: PointF,
+    mouse_captured: bool,
+    capture_request: Option<bool>,
 }
 
 impl Input {
@@ -33,12 +83,170 @@ impl Input {
             mouse_buttons: HashSet::new(),
             mouse_pixel: (0.0, 0.0),
             mouse_tile: Vec::new(),
+            scroll_delta: PointF::zero(),
+            touch_emulates_mouse: true,
             event_queue: VecDeque::new(),
             use_events: false, // Not enabled by default so that systems not using it don't fill up RAM for no reason
             scale_factor: 1.0,
+            recording: None,
+            replay: None,
+            frame: 0,
+            input_map: InputMap::new(),
+            gamepad_buttons: HashSet::new(),
+            key_repeat: Some(KeyRepeat::default()),
+            key_down_ms: HashMap::new(),
+            repeat_fired: HashSet::new(),
+            scancode_to_key: HashMap::new(),
+            key_chars: HashMap::new(),
+            pending_char_key: None,
+            mouse_delta: PointF::zero(),
+            mouse_captured: false,
+            capture_request: None,
+        }
+    }
+
+    /// Requests mouse capture. While captured, the window grabs (and hides) the OS
+    /// cursor - pointer lock on wasm - and relative motion keeps arriving through
+    /// `mouse_delta` and `BEvent::MouseDelta` even though the absolute position
+    /// stops at the window edge. The back-end applies the change on the next frame.
+    pub fn set_mouse_capture(&mut self, capture: bool) {
+        self.capture_request = Some(capture);
+        self.mouse_captured = capture;
+    }
+
+    /// True if mouse capture has been requested/enabled.
+    pub fn is_mouse_captured(&self) -> bool {
+        self.mouse_captured
+    }
+
+    /// The raw relative mouse motion accumulated during the current frame, in
+    /// pixels. Zero if the mouse hasn't moved.
+    pub fn mouse_delta(&self) -> PointF {
+        self.mouse_delta
+    }
+
+    /// The `VirtualKeyCode` the OS reports for a physical scancode, learned from
+    /// observed key presses. Scancodes identify key *positions*, so this is the
+    /// way to implement layout-independent movement bindings (WASD positions on
+    /// AZERTY or Dvorak): bind to the scancode, then use this to display the key.
+    /// Returns None for scancodes that haven't been seen yet.
+    pub fn key_by_scancode(&self, scan_code: u32) -> Option<VirtualKeyCode> {
+        self.scancode_to_key.get(&scan_code).copied()
+    }
+
+    /// The character a key produces in the user's keyboard layout, learned by
+    /// pairing key presses with the character events that follow them. Falls back
+    /// to a US-layout baseline for keys that haven't been typed yet, and None for
+    /// keys that produce no character.
+    pub fn logical_char_for(&self, key: VirtualKeyCode) -> Option<char> {
+        self.key_chars
+            .get(&key)
+            .copied()
+            .or_else(|| baseline_char_for(key))
+    }
+
+    /// Sets the key-repeat timing, or disables repeat entirely with `None`.
+    /// Repeat is synthesized from the frame clock, independently of any OS-level
+    /// key-repeat events, so it is uniform across backends. The default is
+    /// `Some(KeyRepeat::default())`.
+    pub fn set_key_repeat(&mut self, repeat: Option<KeyRepeat>) {
+        self.key_repeat = repeat;
+    }
+
+    /// The current key-repeat configuration, or None if repeat is disabled.
+    pub fn key_repeat(&self) -> Option<KeyRepeat> {
+        self.key_repeat
+    }
+
+    /// True on the frame a key is first pressed, and again each time the repeat
+    /// interval elapses after the initial delay while the key stays held. With
+    /// key repeat disabled it is true only on the initial press. Use this for
+    /// menu navigation and anything else that should step rather than run while
+    /// a key is held.
+    pub fn is_key_pressed_repeating(&self, key: VirtualKeyCode) -> bool {
+        self.repeat_fired.contains(&key)
+    }
+
+    /// The action-to-binding map consulted by `action_pressed`.
+    pub fn input_map(&self) -> &InputMap {
+        &self.input_map
+    }
+
+    /// Mutable access to the action map, for registering actions and rebinding.
+    pub fn input_map_mut(&mut self) -> &mut InputMap {
+        &mut self.input_map
+    }
+
+    /// True if any binding attached to the named action is currently held.
+    pub fn action_pressed(&self, action: &str) -> bool {
+        self.input_map
+            .bindings_for(action)
+            .iter()
+            .any(|binding| match binding {
+                Binding::Key(key) => self.keys_down.contains(key),
+                Binding::MouseButton(button) => self.mouse_buttons.contains(button),
+                Binding::GamepadButton(button) => self.gamepad_buttons.contains(button),
+            })
+    }
+
+    /// Feeds gamepad button state in from an external gamepad library (the
+    /// library has no gamepad backend of its own), so that `GamepadButton`
+    /// bindings work with `action_pressed`.
+    pub fn set_gamepad_button(&mut self, button: usize, pressed: bool) {
+        if pressed {
+            self.gamepad_buttons.insert(button);
+        } else {
+            self.gamepad_buttons.remove(&button);
         }
     }
 
+    /// Checks to see if a gamepad button (as fed in with `set_gamepad_button`)
+    /// is pressed.
+    pub fn is_gamepad_button_pressed(&self, button: usize) -> bool {
+        self.gamepad_buttons.contains(&button)
+    }
+
+    /// Starts recording all incoming input events, tagged with frame indices.
+    /// Discards any recording already in progress.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(InputRecording::new());
+        self.frame = 0;
+    }
+
+    /// Stops recording and returns the captured session, or None if no recording
+    /// was in progress.
+    pub fn stop_recording(&mut self) -> Option<InputRecording> {
+        self.recording.take()
+    }
+
+    /// True if input events are currently being recorded.
+    pub fn is_recording(&self) -> bool {
+        self.recording.is_some()
+    }
+
+    /// Starts replaying a recorded input session. While a replay is active, live
+    /// input is suppressed and the recorded events are fed back on the frames on
+    /// which they were recorded. The replay stops automatically (and live input
+    /// resumes) once every recorded event has been delivered.
+    pub fn start_replay(&mut self, recording: InputRecording) {
+        self.keys_down.clear();
+        self.scancodes.clear();
+        self.mouse_buttons.clear();
+        self.event_queue.clear();
+        self.replay = Some((recording, 0));
+        self.frame = 0;
+    }
+
+    /// Abandons an in-progress replay, resuming live input.
+    pub fn stop_replay(&mut self) {
+        self.replay = None;
+    }
+
+    /// True if a recorded input session is currently being replayed.
+    pub fn is_replaying(&self) -> bool {
+        self.replay.is_some()
+    }
+
     /// Checks to see if a key is pressed. True if it is, false otherwise.
     pub fn is_key_pressed(&self, key: VirtualKeyCode) -> bool {
         self.keys_down.contains(&key)
@@ -95,6 +303,19 @@ impl Input {
         (self.mouse_pixel.0, self.mouse_pixel.1)
     }
 
+    /// Returns the scroll wheel movement accumulated during the current frame. Positive `y`
+    /// indicates scrolling away from the user. Zero if the wheel hasn't moved.
+    pub fn scroll_delta(&self) -> PointF {
+        self.scroll_delta
+    }
+
+    /// By default, touch input drives the mouse position and taps are reported as left
+    /// mouse clicks. Disable this if you want to handle the raw `BEvent::Touch` events
+    /// yourself.
+    pub fn set_touch_emulates_mouse(&mut self, enabled: bool) {
+        self.touch_emulates_mouse = enabled;
+    }
+
     /// Call this to enable the event queue. Otherwise, events will not be tracked/stored outside of the
     /// HAL setup (to avoid continually filling a buffer that isn't being used).
     pub fn activate_event_queue(&mut self) {
@@ -134,14 +355,49 @@ impl Input {
 
     /// Internal - do not use
     pub(crate) fn on_key_down(&mut self, key: VirtualKeyCode, scan_code: u32) {
+        // OS key-repeat re-delivers key-down events; only the first one starts
+        // the repeat timer.
+        if let std::collections::hash_map::Entry::Vacant(e) = self.key_down_ms.entry(key) {
+            e.insert(0.0);
+            self.repeat_fired.insert(key);
+        }
         self.keys_down.insert(key);
         self.scancodes.insert(scan_code);
+        self.scancode_to_key.insert(scan_code, key);
     }
 
     /// Internal - do not use
     pub(crate) fn on_key_up(&mut self, key: VirtualKeyCode, scan_code: u32) {
         self.keys_down.remove(&key);
         self.scancodes.remove(&scan_code);
+        self.key_down_ms.remove(&key);
+        self.repeat_fired.remove(&key);
+    }
+
+    /// Internal: advances the key-repeat timers by one frame's worth of time,
+    /// refreshing the set of keys reported by `is_key_pressed_repeating`.
+    pub(crate) fn tick_key_repeat(&mut self, frame_time_ms: f32) {
+        self.repeat_fired.clear();
+        if let Some(repeat) = self.key_repeat {
+            let delay = repeat.initial_delay_ms;
+            let interval = repeat.interval_ms.max(1.0);
+            for (key, elapsed) in self.key_down_ms.iter_mut() {
+                let previous = *elapsed;
+                *elapsed += frame_time_ms;
+                let fired = if previous < delay {
+                    *elapsed >= delay
+                } else {
+                    ((previous - delay) / interval) as u32 != ((*elapsed - delay) / interval) as u32
+                };
+                if fired {
+                    self.repeat_fired.insert(*key);
+                }
+            }
+        } else {
+            for elapsed in self.key_down_ms.values_mut() {
+                *elapsed += frame_time_ms;
+            }
+        }
     }
 
     /// Internal - do not use
@@ -164,6 +420,39 @@ impl Input {
         });
     }
 
+    /// Internal - do not use
+    pub(crate) fn on_mouse_wheel(&mut self, x: f32, y: f32) {
+        self.scroll_delta.x += x;
+        self.scroll_delta.y += y;
+        self.push_event(BEvent::MouseWheel {
+            delta: PointF::new(x, y),
+        });
+    }
+
+    /// Internal - do not use
+    pub(crate) fn reset_scroll_delta(&mut self) {
+        self.scroll_delta = PointF::zero();
+    }
+
+    /// Internal - do not use
+    pub(crate) fn on_mouse_delta(&mut self, x: f32, y: f32) {
+        self.mouse_delta.x += x;
+        self.mouse_delta.y += y;
+        self.push_event(BEvent::MouseDelta {
+            delta: PointF::new(x, y),
+        });
+    }
+
+    /// Internal - do not use
+    pub(crate) fn reset_mouse_delta(&mut self) {
+        self.mouse_delta = PointF::zero();
+    }
+
+    /// Internal: hands the pending capture request to the HAL back-end.
+    pub(crate) fn take_capture_request(&mut self) -> Option<bool> {
+        self.capture_request.take()
+    }
+
     /// Internal - do not use
     pub(crate) fn on_mouse_tile_position(&mut self, console: usize, x: i32, y: i32) {
         while self.mouse_tile.len() < console + 1 {
@@ -174,8 +463,199 @@ impl Input {
 
     /// Internal - do not use
     pub(crate) fn push_event(&mut self, event: BEvent) {
+        if self.replay.is_some() {
+            // Live input is suppressed while a replay is driving the event queue.
+            return;
+        }
+        if let Some(recording) = &mut self.recording {
+            recording.events.push(RecordedEvent {
+                frame: self.frame,
+                event: event.clone(),
+            });
+        }
+        self.observe_event(&event);
         if self.use_events {
             self.event_queue.push_front(event);
         }
     }
+
+    /// Internal: pushes a replayed event onto the event queue, bypassing the
+    /// live-input suppression that `push_event` applies during a replay.
+    pub(crate) fn replay_push(&mut self, event: BEvent) {
+        self.observe_event(&event);
+        if self.use_events {
+            self.event_queue.push_front(event);
+        }
+    }
+
+    /// Internal: learns the key-to-character mapping of the user's keyboard
+    /// layout by pairing each key press with the character event that follows it.
+    fn observe_event(&mut self, event: &BEvent) {
+        match event {
+            BEvent::KeyboardInput {
+                key, pressed: true, ..
+            } => self.pending_char_key = Some(*key),
+            BEvent::Character { c } => {
+                if let Some(key) = self.pending_char_key.take() {
+                    if !c.is_control() {
+                        self.key_chars.insert(key, *c);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Internal: advances the frame counter at the start of each frame, and
+    /// returns any replayed events that are due on the new frame.
+    pub(crate) fn next_frame(&mut self) -> Vec<BEvent> {
+        let mut due = Vec::new();
+        let mut finished = false;
+        if let Some((recording, cursor)) = &mut self.replay {
+            while *cursor < recording.events.len() && recording.events[*cursor].frame <= self.frame
+            {
+                due.push(recording.events[*cursor].event.clone());
+                *cursor += 1;
+            }
+            finished = *cursor == recording.events.len();
+        }
+        if finished {
+            self.replay = None;
+        }
+        self.frame += 1;
+        due
+    }
+}
+
+/// The character a key produces on a US layout, used as the fallback for
+/// `Input::logical_char_for` before the real mapping has been observed.
+fn baseline_char_for(key: VirtualKeyCode) -> Option<char> {
+    use VirtualKeyCode::*;
+    let c = match key {
+        A => 'a',
+        B => 'b',
+        C => 'c',
+        D => 'd',
+        E => 'e',
+        F => 'f',
+        G => 'g',
+        H => 'h',
+        I => 'i',
+        J => 'j',
+        K => 'k',
+        L => 'l',
+        M => 'm',
+        N => 'n',
+        O => 'o',
+        P => 'p',
+        Q => 'q',
+        R => 'r',
+        S => 's',
+        T => 't',
+        U => 'u',
+        V => 'v',
+        W => 'w',
+        X => 'x',
+        Y => 'y',
+        Z => 'z',
+        Key1 | Numpad1 => '1',
+        Key2 | Numpad2 => '2',
+        Key3 | Numpad3 => '3',
+        Key4 | Numpad4 => '4',
+        Key5 | Numpad5 => '5',
+        Key6 | Numpad6 => '6',
+        Key7 | Numpad7 => '7',
+        Key8 | Numpad8 => '8',
+        Key9 | Numpad9 => '9',
+        Key0 | Numpad0 => '0',
+        Space => ' ',
+        Minus | NumpadSubtract => '-',
+        Equals => '=',
+        Comma => ',',
+        Period | NumpadDecimal => '.',
+        Slash | NumpadDivide => '/',
+        Semicolon => ';',
+        Apostrophe => '\'',
+        LBracket => '[',
+        RBracket => ']',
+        Backslash => '\\',
+        Grave => '`',
+        NumpadAdd => '+',
+        NumpadMultiply => '*',
+        _ => return None,
+    };
+    Some(c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Input, KeyRepeat};
+    use crate::prelude::VirtualKeyCode;
+
+    #[test]
+    fn key_repeat_fires_after_delay_then_at_interval() {
+        let mut input = Input::new();
+        input.set_key_repeat(Some(KeyRepeat {
+            initial_delay_ms: 400.0,
+            interval_ms: 70.0,
+        }));
+        input.on_key_down(VirtualKeyCode::Down, 0);
+        assert!(input.is_key_pressed_repeating(VirtualKeyCode::Down));
+
+        input.tick_key_repeat(100.0); // 100ms held - still inside the delay
+        assert!(!input.is_key_pressed_repeating(VirtualKeyCode::Down));
+        input.tick_key_repeat(350.0); // 450ms - crossed the initial delay
+        assert!(input.is_key_pressed_repeating(VirtualKeyCode::Down));
+        input.tick_key_repeat(10.0); // 460ms - before the 470ms repeat boundary
+        assert!(!input.is_key_pressed_repeating(VirtualKeyCode::Down));
+        input.tick_key_repeat(20.0); // 480ms - crossed the 470ms boundary
+        assert!(input.is_key_pressed_repeating(VirtualKeyCode::Down));
+
+        input.on_key_up(VirtualKeyCode::Down, 0);
+        assert!(!input.is_key_pressed_repeating(VirtualKeyCode::Down));
+    }
+
+    #[test]
+    fn mouse_delta_accumulates_within_a_frame() {
+        let mut input = Input::new();
+        input.on_mouse_delta(3.0, -1.0);
+        input.on_mouse_delta(2.0, 4.0);
+        assert_eq!(input.mouse_delta().x, 5.0);
+        assert_eq!(input.mouse_delta().y, 3.0);
+        input.reset_mouse_delta();
+        assert_eq!(input.mouse_delta().x, 0.0);
+        assert_eq!(input.mouse_delta().y, 0.0);
+    }
+
+    #[test]
+    fn scancode_and_layout_queries_learn_from_observed_input() {
+        use crate::input::BEvent;
+        let mut input = Input::new();
+        // Physical scancode 17 is the W position; on AZERTY the OS reports Z.
+        input.on_key_down(VirtualKeyCode::Z, 17);
+        assert_eq!(input.key_by_scancode(17), Some(VirtualKeyCode::Z));
+        assert_eq!(input.key_by_scancode(99), None);
+
+        // Before any typing, the US-layout baseline answers.
+        assert_eq!(input.logical_char_for(VirtualKeyCode::Z), Some('z'));
+        // Pairing a key press with its character event teaches the real layout.
+        input.push_event(BEvent::KeyboardInput {
+            key: VirtualKeyCode::Z,
+            scan_code: 17,
+            pressed: true,
+        });
+        input.push_event(BEvent::Character { c: 'w' });
+        assert_eq!(input.logical_char_for(VirtualKeyCode::Z), Some('w'));
+        assert_eq!(input.logical_char_for(VirtualKeyCode::LShift), None);
+    }
+
+    #[test]
+    fn disabled_repeat_only_reports_the_initial_press() {
+        let mut input = Input::new();
+        input.set_key_repeat(None);
+        input.on_key_down(VirtualKeyCode::Down, 0);
+        assert!(input.is_key_pressed_repeating(VirtualKeyCode::Down));
+        input.tick_key_repeat(10_000.0);
+        assert!(!input.is_key_pressed_repeating(VirtualKeyCode::Down));
+    }
 }
@@ -0,0 +1,123 @@
+//! Named-action input mapping. Register game actions ("move_north", "open_inventory")
+//! with default bindings, let the player rebind them on an options screen, and query
+//! `INPUT.lock().action_pressed("move_north")` instead of matching key codes all over
+//! the game. With the `serde` feature the whole map serializes, so bindings can be
+//! saved alongside the rest of the game's settings.
+
+use crate::prelude::VirtualKeyCode;
+use std::collections::HashMap;
+
+/// One physical input that can trigger an action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Binding {
+    /// A keyboard key.
+    Key(VirtualKeyCode),
+    /// A mouse button: 0 = left, 1 = right, 2 = middle, matching the rest of the
+    /// input API.
+    MouseButton(usize),
+    /// A gamepad button, by index. The library has no gamepad backend of its own;
+    /// feed gamepad state in with `Input::set_gamepad_button` from e.g. a `gilrs`
+    /// integration.
+    GamepadButton(usize),
+}
+
+/// Maps named actions to the bindings that trigger them. An action may have any
+/// number of bindings (keyboard plus gamepad, for example).
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputMap {
+    bindings: HashMap<String, Vec<Binding>>,
+}
+
+impl InputMap {
+    /// Creates an empty input map.
+    pub fn new() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// Registers an action with its default bindings, replacing any bindings the
+    /// action already had.
+    pub fn add_action<S: ToString>(&mut self, action: S, defaults: &[Binding]) {
+        self.bindings.insert(action.to_string(), defaults.to_vec());
+    }
+
+    /// Adds a binding to an action, registering the action if it is new. Does
+    /// nothing if the binding is already present.
+    pub fn bind<S: ToString>(&mut self, action: S, binding: Binding) {
+        let bindings = self.bindings.entry(action.to_string()).or_default();
+        if !bindings.contains(&binding) {
+            bindings.push(binding);
+        }
+    }
+
+    /// Removes one binding from an action. Returns true if it was present.
+    pub fn unbind(&mut self, action: &str, binding: Binding) -> bool {
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            let before = bindings.len();
+            bindings.retain(|b| *b != binding);
+            return bindings.len() != before;
+        }
+        false
+    }
+
+    /// Removes every binding from an action, leaving it registered but inert.
+    pub fn clear_bindings(&mut self, action: &str) {
+        if let Some(bindings) = self.bindings.get_mut(action) {
+            bindings.clear();
+        }
+    }
+
+    /// The bindings currently attached to an action. Empty for unknown actions.
+    pub fn bindings_for(&self, action: &str) -> &[Binding] {
+        self.bindings.get(action).map_or(&[], |b| b.as_slice())
+    }
+
+    /// Every registered action name, sorted - convenient for options screens.
+    pub fn actions(&self) -> Vec<&str> {
+        let mut actions: Vec<&str> = self.bindings.keys().map(String::as_str).collect();
+        actions.sort_unstable();
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Binding, InputMap};
+    use crate::prelude::VirtualKeyCode;
+
+    #[test]
+    fn rebinding_replaces_the_default() {
+        let mut map = InputMap::new();
+        map.add_action("move_north", &[Binding::Key(VirtualKeyCode::W)]);
+        assert!(map.unbind("move_north", Binding::Key(VirtualKeyCode::W)));
+        map.bind("move_north", Binding::Key(VirtualKeyCode::Up));
+        assert_eq!(
+            map.bindings_for("move_north"),
+            &[Binding::Key(VirtualKeyCode::Up)]
+        );
+    }
+
+    #[test]
+    fn action_pressed_tracks_key_state() {
+        let mut input = crate::input::Input::new();
+        input
+            .input_map_mut()
+            .add_action("move_north", &[Binding::Key(VirtualKeyCode::W)]);
+        assert!(!input.action_pressed("move_north"));
+        input.on_key_down(VirtualKeyCode::W, 0);
+        assert!(input.action_pressed("move_north"));
+        input.on_key_up(VirtualKeyCode::W, 0);
+        assert!(!input.action_pressed("move_north"));
+    }
+
+    #[test]
+    fn actions_are_listed_sorted() {
+        let mut map = InputMap::new();
+        map.add_action("zoom", &[]);
+        map.add_action("attack", &[Binding::MouseButton(0)]);
+        assert_eq!(map.actions(), vec!["attack", "zoom"]);
+    }
+}
@@ -2,7 +2,11 @@ mod input_handler;
 pub use input_handler::*;
 mod event_queue;
 pub use event_queue::*;
+mod input_map;
+pub use input_map::{Binding, InputMap};
+mod recorder;
 use parking_lot::Mutex;
+pub use recorder::{InputRecording, RecordedEvent};
 
 lazy_static! {
     pub static ref INPUT: Mutex<Input> = Mutex::new(Input::new());
@@ -0,0 +1,153 @@
+//! Input recording and deterministic replay. Start a recording with
+//! `INPUT.lock().start_recording()`; every `BEvent` that arrives is logged with
+//! the frame on which it arrived. Stop it to obtain an [`InputRecording`],
+//! which (with the `serde` feature) can be saved to a file and attached to a
+//! bug report. Feeding a recording to `Input::start_replay` suppresses live
+//! input and replays the captured events into `GameState::tick` on the same
+//! frames on which they were recorded.
+
+use super::BEvent;
+use crate::prelude::{BTerm, INPUT};
+
+/// One recorded input event, tagged with the frame index (relative to the start
+/// of the recording) on which it arrived.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RecordedEvent {
+    pub frame: u64,
+    pub event: BEvent,
+}
+
+/// A captured input session: every `BEvent` that arrived while recording was
+/// active, in arrival order, with frame indices.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputRecording {
+    pub events: Vec<RecordedEvent>,
+}
+
+impl InputRecording {
+    /// Creates an empty recording.
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    /// Saves the recording to a JSON file. Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> crate::BResult<()> {
+        let file = std::fs::File::create(path)?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self)?;
+        Ok(())
+    }
+
+    /// Loads a recording previously written with [`InputRecording::save`].
+    /// Requires the `serde` feature.
+    #[cfg(feature = "serde")]
+    pub fn load<P: AsRef<std::path::Path>>(path: P) -> crate::BResult<InputRecording> {
+        let file = std::fs::File::open(path)?;
+        let recording = serde_json::from_reader(std::io::BufReader::new(file))?;
+        Ok(recording)
+    }
+}
+
+/// Internal: applies one replayed event to the terminal and input state, and
+/// pushes it onto the event queue as if it had arrived from the HAL. State
+/// side-effects reuse the live-input paths; their own `push_event` calls are
+/// suppressed while a replay is active, so each recorded event is queued
+/// exactly once.
+pub(crate) fn apply_replayed_event(term: &mut BTerm, event: BEvent) {
+    match &event {
+        BEvent::KeyboardInput {
+            key,
+            scan_code,
+            pressed,
+        } => {
+            let mut input = INPUT.lock();
+            if *pressed {
+                term.key = Some(*key);
+                input.on_key_down(*key, *scan_code);
+            } else {
+                input.on_key_up(*key, *scan_code);
+            }
+        }
+        BEvent::MouseClick { button, pressed } => {
+            if *button == 0 && *pressed {
+                term.left_click = true;
+            }
+            let mut input = INPUT.lock();
+            if *pressed {
+                input.on_mouse_button_down(*button);
+            } else {
+                input.on_mouse_button_up(*button);
+            }
+        }
+        BEvent::CursorMoved { position } => {
+            term.on_mouse_position(position.x as f64, position.y as f64);
+        }
+        BEvent::MouseWheel { delta } => {
+            term.on_mouse_wheel(delta.x, delta.y);
+        }
+        BEvent::MouseDelta { delta } => {
+            INPUT.lock().on_mouse_delta(delta.x, delta.y);
+        }
+        _ => {}
+    }
+    INPUT.lock().replay_push(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InputRecording, RecordedEvent};
+    use crate::input::{BEvent, Input};
+
+    #[test]
+    fn recording_tags_events_with_frame_indices() {
+        let mut input = Input::new();
+        input.start_recording();
+        input.push_event(BEvent::Character { c: 'a' });
+        input.next_frame();
+        input.push_event(BEvent::Character { c: 'b' });
+        let recording = input.stop_recording().unwrap();
+
+        assert_eq!(recording.events.len(), 2);
+        assert_eq!(recording.events[0].frame, 0);
+        assert_eq!(recording.events[1].frame, 1);
+    }
+
+    #[test]
+    fn replay_delivers_events_on_recorded_frames_then_stops() {
+        let recording = InputRecording {
+            events: vec![
+                RecordedEvent {
+                    frame: 0,
+                    event: BEvent::Character { c: 'a' },
+                },
+                RecordedEvent {
+                    frame: 2,
+                    event: BEvent::Character { c: 'b' },
+                },
+            ],
+        };
+
+        let mut input = Input::new();
+        input.start_replay(recording);
+        assert_eq!(input.next_frame(), vec![BEvent::Character { c: 'a' }]);
+        assert!(input.next_frame().is_empty());
+        assert_eq!(input.next_frame(), vec![BEvent::Character { c: 'b' }]);
+        assert!(!input.is_replaying());
+    }
+
+    #[test]
+    fn live_input_is_suppressed_during_replay() {
+        let mut input = Input::new();
+        input.activate_event_queue();
+        input.start_replay(InputRecording {
+            events: vec![RecordedEvent {
+                frame: 5,
+                event: BEvent::CloseRequested,
+            }],
+        });
+        input.push_event(BEvent::Character { c: 'x' });
+        assert!(input.pop().is_none());
+    }
+}
@@ -1,13 +1,25 @@
 #[macro_use]
 extern crate lazy_static;
+mod bmfont;
 mod bterm;
+#[cfg(feature = "serde")]
+pub mod builder_config;
 mod consoles;
+pub mod crash_screen;
+mod debug_console;
 pub mod embedding;
+pub mod frame_stats;
 mod gamestate;
 mod hal;
 mod initializer;
 mod input;
+#[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+pub mod offscreen;
+mod perf_overlay;
+pub mod resource_loader;
 pub mod rex;
+mod tiled;
+mod ui;
 
 pub type BResult<T> = anyhow::Result<T, Box<dyn std::error::Error + Send + Sync>>;
 pub(crate) use input::clear_input_state;
@@ -22,16 +34,25 @@ compile_error!("Default features (opengl) must be disabled for other back-ends")
 
 pub mod prelude {
 
+    pub use crate::bmfont::*;
     pub use crate::bterm::*;
     pub use crate::consoles::*;
+    pub use crate::crash_screen::enable_panic_capture;
+    pub use crate::debug_console::CommandCallback;
     pub use crate::embedding;
     pub use crate::embedding::EMBED;
+    pub use crate::frame_stats::{FrameStats, FrameTimes};
     pub use crate::gamestate::GameState;
     pub use crate::hal::{init_raw, BTermPlatform, Font, InitHints, Shader, BACKEND};
     pub use crate::initializer::*;
-    pub use crate::input::{BEvent, Input, INPUT};
+    pub use crate::input::{
+        BEvent, Binding, Input, InputMap, InputRecording, KeyRepeat, RecordedEvent, INPUT,
+    };
+    pub use crate::resource_loader::{load_resource_async, resources_ready, LoadState, LOADER};
     pub use crate::rex;
     pub use crate::rex::*;
+    pub use crate::tiled::*;
+    pub use crate::ui::*;
     pub use crate::BResult;
     pub use crate::FontCharType;
     pub use bracket_color::prelude::*;
@@ -47,6 +68,21 @@ pub mod prelude {
     #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
     pub use crate::hal::GlCallback;
 
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub use crate::hal::{GlCallbackInfo, GlStageCallback};
+
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub use crate::hal::FramePacing;
+
+    #[cfg(any(feature = "opengl", feature = "webgpu"))]
+    pub use crate::hal::ScalingMode;
+
+    #[cfg(feature = "opengl")]
+    pub use crate::hal::run_single_frame;
+
+    #[cfg(all(feature = "opengl", not(target_arch = "wasm32")))]
+    pub use crate::offscreen::render_to_image;
+
     #[cfg(target_arch = "wasm32")]
     pub use crate::hal::VirtualKeyCode;
 
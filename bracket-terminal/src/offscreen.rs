@@ -0,0 +1,99 @@
+//! Offscreen console rendering. Rasterizes a console with a font on the CPU, producing
+//! an `image::RgbaImage` with no window, GL context or event loop required - useful for
+//! generating map previews from CLI tools.
+
+use crate::consoles::{Console, SimpleConsole, SparseConsole};
+use crate::prelude::Font;
+use crate::BResult;
+use bracket_color::prelude::RGBA;
+
+/// Rasterizes a console to an RGBA image using the specified font. Supports simple and
+/// sparse consoles; cells without data in a sparse console are left transparent. The
+/// resulting image is `console width * tile width` by `console height * tile height`
+/// pixels, and can be saved with `image::RgbaImage::save`.
+pub fn render_to_image(console: &dyn Console, font: &Font) -> BResult<image::RgbaImage> {
+    let (width, height) = console.get_char_size();
+    let (tile_width, tile_height) = font.tile_size;
+    let sheet = font.load_rgba();
+    let glyphs_per_row = sheet.width() / tile_width;
+    let mut img = image::RgbaImage::new(width * tile_width, height * tile_height);
+
+    let cons_any = console.as_any();
+    if let Some(sc) = cons_any.downcast_ref::<SimpleConsole>() {
+        for (i, tile) in sc.tiles.iter().enumerate() {
+            let x = i as u32 % width;
+            let y = height - 1 - (i as u32 / width);
+            blit_glyph(
+                &mut img,
+                &sheet,
+                font,
+                glyphs_per_row,
+                (x, y),
+                tile.glyph,
+                tile.fg,
+                tile.bg,
+            );
+        }
+    } else if let Some(sp) = cons_any.downcast_ref::<SparseConsole>() {
+        for tile in &sp.tiles {
+            let x = tile.idx as u32 % width;
+            let y = height - 1 - (tile.idx as u32 / width);
+            blit_glyph(
+                &mut img,
+                &sheet,
+                font,
+                glyphs_per_row,
+                (x, y),
+                tile.glyph,
+                tile.fg,
+                tile.bg,
+            );
+        }
+    } else {
+        return Err("Offscreen rendering supports simple and sparse consoles only".into());
+    }
+
+    Ok(img)
+}
+
+/// Draws a single glyph cell: the font texel tints the foreground color, and texel
+/// transparency shows the background - matching what the console shaders do.
+#[allow(clippy::too_many_arguments)]
+fn blit_glyph(
+    target: &mut image::RgbaImage,
+    sheet: &image::RgbaImage,
+    font: &Font,
+    glyphs_per_row: u32,
+    cell: (u32, u32),
+    glyph: crate::FontCharType,
+    fg: RGBA,
+    bg: RGBA,
+) {
+    let (tile_width, tile_height) = font.tile_size;
+    let src_x = (u32::from(glyph) % glyphs_per_row) * tile_width;
+    let src_y = (u32::from(glyph) / glyphs_per_row) * tile_height;
+    if src_x + tile_width > sheet.width() || src_y + tile_height > sheet.height() {
+        return;
+    }
+
+    for py in 0..tile_height {
+        for px in 0..tile_width {
+            let texel = sheet.get_pixel(src_x + px, src_y + py);
+            let alpha = (f32::from(texel[3]) / 255.0) * fg.a;
+            let tint = |t: u8, f: f32, b: f32| {
+                let lit = (f32::from(t) / 255.0) * f;
+                (((lit * alpha) + (b * (1.0 - alpha))) * 255.0) as u8
+            };
+            target.put_pixel(
+                cell.0 * tile_width + px,
+                cell.1 * tile_height + py,
+                image::Rgba([
+                    tint(texel[0], fg.r, bg.r),
+                    tint(texel[1], fg.g, bg.g),
+                    tint(texel[2], fg.b, bg.b),
+                    ((alpha + (bg.a * (1.0 - alpha))) * 255.0) as u8,
+                ]),
+            );
+        }
+    }
+}
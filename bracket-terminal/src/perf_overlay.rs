@@ -0,0 +1,147 @@
+//! A built-in performance overlay. Toggled with `BTerm::show_perf_overlay`, it
+//! draws a small diagnostics panel onto the topmost console each frame: the
+//! FPS and frame time, per-phase timing bars fed by [`crate::frame_stats`], an
+//! FPS history graph, and how many consoles were rebuilt and drawn.
+
+use crate::prelude::BTerm;
+use bracket_color::prelude::RGB;
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static SHOW: AtomicBool = AtomicBool::new(false);
+
+/// How many console layers the back-end rebuilt and drew last frame. Updated
+/// by the render path, displayed by the overlay.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct RenderCounts {
+    pub consoles_rebuilt: usize,
+    pub consoles_drawn: usize,
+    pub consoles_total: usize,
+}
+
+lazy_static! {
+    pub(crate) static ref RENDER_COUNTS: Mutex<RenderCounts> = Mutex::new(RenderCounts::default());
+}
+
+pub(crate) fn set_enabled(show: bool) {
+    SHOW.store(show, Ordering::Relaxed);
+}
+
+pub(crate) fn enabled() -> bool {
+    SHOW.load(Ordering::Relaxed)
+}
+
+/// Width of the overlay panel, in characters.
+const PANEL_WIDTH: i32 = 30;
+/// Width of a full phase bar, in characters.
+const BAR_WIDTH: usize = 12;
+
+/// Draws the overlay onto the topmost console. Called by the main loops after
+/// `GameState::tick`, so it composites over whatever the game drew.
+pub(crate) fn draw(bterm: &mut BTerm) {
+    let saved_console = bterm.active_console;
+    bterm.set_active_console(crate::bterm::BACKEND_INTERNAL.lock().consoles.len() - 1);
+
+    let stats = crate::frame_stats::FRAME_STATS.lock().clone();
+    let average = stats.average();
+    let counts = *RENDER_COUNTS.lock();
+
+    let fg = RGB::from_u8(255, 255, 255);
+    let dim = RGB::from_u8(170, 170, 170);
+    let bg = RGB::from_u8(0, 0, 0);
+    let x = 1;
+
+    bterm.print_color(
+        x,
+        1,
+        fg,
+        bg,
+        format!("FPS {:>5.1}  frame {:>5.2}ms", bterm.fps, average.total),
+    );
+
+    // One bar per phase, scaled so the average total fills the bar.
+    let scale = average.total.max(0.001);
+    let phases = [
+        ("tick", average.tick),
+        ("rebuild", average.rebuild),
+        ("render", average.render),
+        ("present", average.present),
+    ];
+    for (i, (name, ms)) in phases.iter().enumerate() {
+        bterm.print_color(
+            x,
+            2 + i as i32,
+            dim,
+            bg,
+            format!("{:<7} {:<12} {:>5.2}", name, bar(*ms, scale), ms),
+        );
+    }
+
+    bterm.print_color(
+        x,
+        6,
+        dim,
+        bg,
+        format!(
+            "rebuilt {}/{}  drawn {}/{}",
+            counts.consoles_rebuilt,
+            counts.consoles_total,
+            counts.consoles_drawn,
+            counts.consoles_total
+        ),
+    );
+
+    bterm.print_color(x, 7, fg, bg, graph(&stats, PANEL_WIDTH as usize - 2));
+
+    bterm.set_active_console(saved_console);
+}
+
+/// A bar of '#' characters, `BAR_WIDTH` long when `ms` equals `scale`.
+fn bar(ms: f32, scale: f32) -> String {
+    let filled = ((ms / scale) * BAR_WIDTH as f32).round() as usize;
+    "#".repeat(filled.min(BAR_WIDTH))
+}
+
+/// The most recent frame totals as a one-row graph, slowest frame at full
+/// height, using CP437-safe characters.
+fn graph(stats: &crate::frame_stats::FrameStats, width: usize) -> String {
+    const LEVELS: [char; 4] = ['_', '.', 'o', '#'];
+    let worst = stats.worst().map(|f| f.total).unwrap_or(0.0).max(0.001);
+    let skip = stats.len().saturating_sub(width);
+    stats
+        .frames()
+        .skip(skip)
+        .map(|f| {
+            let level = ((f.total / worst) * (LEVELS.len() - 1) as f32).round() as usize;
+            LEVELS[level.min(LEVELS.len() - 1)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame_stats::{FrameStats, FrameTimes};
+
+    #[test]
+    fn bars_scale_and_clamp() {
+        assert_eq!(bar(0.0, 16.0), "");
+        assert_eq!(bar(8.0, 16.0), "#".repeat(BAR_WIDTH / 2));
+        assert_eq!(bar(99.0, 16.0), "#".repeat(BAR_WIDTH));
+    }
+
+    #[test]
+    fn graphs_trim_to_the_requested_width() {
+        let mut stats = FrameStats::default();
+        for i in 0..20 {
+            stats.record(FrameTimes {
+                total: i as f32,
+                ..Default::default()
+            });
+        }
+        let graph = graph(&stats, 8);
+        assert_eq!(graph.chars().count(), 8);
+        // The newest (slowest) frame is at full height.
+        assert!(graph.ends_with('#'));
+    }
+}
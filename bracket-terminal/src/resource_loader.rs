@@ -0,0 +1,183 @@
+//! Asynchronous asset loading. Resources requested with `load_resource_async`
+//! are fetched in the background - over HTTP on wasm, on a worker thread on
+//! native - and registered with the embedded-resource dictionary when they
+//! arrive. The main loops defer GPU texture uploads (showing a blank loading
+//! screen) until every pending resource has landed, so large fonts and sprite
+//! sheets no longer have to be baked into the binary.
+
+use crate::prelude::embedding::EMBED;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+
+/// Where an asynchronous load currently stands.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LoadState {
+    /// The request is still in flight.
+    InFlight,
+    /// The resource arrived and is registered with `EMBED`.
+    Loaded,
+    /// The load failed; the payload is a human-readable reason.
+    Failed(String),
+}
+
+#[derive(Default)]
+pub struct ResourceLoader {
+    requests: HashMap<String, LoadState>,
+}
+
+lazy_static! {
+    pub static ref LOADER: Mutex<ResourceLoader> = Mutex::new(ResourceLoader::default());
+}
+
+impl ResourceLoader {
+    /// True when nothing is still in flight.
+    pub fn ready(&self) -> bool {
+        !self
+            .requests
+            .values()
+            .any(|state| *state == LoadState::InFlight)
+    }
+
+    /// (completed, total) request counts, for progress displays.
+    pub fn progress(&self) -> (usize, usize) {
+        let total = self.requests.len();
+        let done = self
+            .requests
+            .values()
+            .filter(|state| **state != LoadState::InFlight)
+            .count();
+        (done, total)
+    }
+
+    /// The state of one request, if it was ever started.
+    pub fn state<S: ToString>(&self, path: S) -> Option<LoadState> {
+        self.requests.get(&path.to_string()).cloned()
+    }
+
+    /// Every load that failed, with its reason.
+    pub fn failures(&self) -> Vec<(String, String)> {
+        self.requests
+            .iter()
+            .filter_map(|(path, state)| match state {
+                LoadState::Failed(reason) => Some((path.clone(), reason.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn mark(&mut self, path: &str, state: LoadState) {
+        self.requests.insert(path.to_string(), state);
+    }
+}
+
+/// Begins fetching a resource in the background. Already-embedded and
+/// already-requested paths are no-ops. Call before `main_loop`; texture
+/// uploads wait until every request has finished.
+pub fn load_resource_async<S: ToString>(path: S) {
+    let path = path.to_string();
+    if EMBED.lock().get_resource(path.clone()).is_some() {
+        return;
+    }
+    {
+        let mut loader = LOADER.lock();
+        if loader.requests.contains_key(&path) {
+            return;
+        }
+        loader.mark(&path, LoadState::InFlight);
+    }
+    start_load(path);
+}
+
+/// True when every requested resource has arrived (or failed).
+pub fn resources_ready() -> bool {
+    LOADER.lock().ready()
+}
+
+fn register_bytes(path: &str, bytes: Vec<u8>) {
+    EMBED.lock().insert_bytes(path.to_string(), bytes);
+    LOADER.lock().mark(path, LoadState::Loaded);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn start_load(path: String) {
+    std::thread::spawn(move || match std::fs::read(&path) {
+        Ok(bytes) => register_bytes(&path, bytes),
+        Err(e) => LOADER.lock().mark(&path, LoadState::Failed(e.to_string())),
+    });
+}
+
+#[cfg(target_arch = "wasm32")]
+fn start_load(path: String) {
+    use wasm_bindgen::prelude::*;
+    use wasm_bindgen::JsCast;
+
+    let xhr = match web_sys::XmlHttpRequest::new() {
+        Ok(xhr) => xhr,
+        Err(_) => {
+            LOADER.lock().mark(
+                &path,
+                LoadState::Failed("XmlHttpRequest unavailable".to_string()),
+            );
+            return;
+        }
+    };
+    if xhr.open("GET", &path).is_err() {
+        LOADER
+            .lock()
+            .mark(&path, LoadState::Failed("Malformed request".to_string()));
+        return;
+    }
+    xhr.set_response_type(web_sys::XmlHttpRequestResponseType::Arraybuffer);
+
+    let xhr_result = xhr.clone();
+    let path_cb = path.clone();
+    let onload = Closure::wrap(Box::new(move || {
+        let status = xhr_result.status().unwrap_or(0);
+        if status == 200 {
+            if let Ok(buffer) = xhr_result.response() {
+                let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+                register_bytes(&path_cb, bytes);
+                return;
+            }
+        }
+        LOADER.lock().mark(
+            &path_cb,
+            LoadState::Failed(format!("HTTP status {}", status)),
+        );
+    }) as Box<dyn FnMut()>);
+    xhr.set_onload(Some(onload.as_ref().unchecked_ref()));
+    onload.forget();
+
+    if xhr.send().is_err() {
+        LOADER
+            .lock()
+            .mark(&path, LoadState::Failed("Send failed".to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embedded_resources_need_no_fetch() {
+        load_resource_async("resources/terminal8x8.png");
+        assert!(LOADER.lock().state("resources/terminal8x8.png").is_none());
+    }
+
+    #[test]
+    fn missing_files_are_reported_as_failures() {
+        load_resource_async("resources/does-not-exist.png");
+        for _ in 0..100 {
+            if LOADER.lock().state("resources/does-not-exist.png") != Some(LoadState::InFlight) {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert!(matches!(
+            LOADER.lock().state("resources/does-not-exist.png"),
+            Some(LoadState::Failed(_))
+        ));
+        assert!(!LOADER.lock().failures().is_empty());
+    }
+}
@@ -148,6 +148,30 @@ impl XpFile {
         Ok(XpFile { version, layers })
     }
 
+    /// Capture a console's current contents as a single-layer REX Paint image,
+    /// ready to `write`/`save`. The inverse of `xp_to_console`.
+    pub fn from_console(console: &dyn Console) -> XpFile {
+        XpFile {
+            version: -1,
+            layers: vec![console.to_xp_layer()],
+        }
+    }
+
+    /// Capture several consoles as one multi-layer REX Paint image, first console
+    /// at the bottom of the layer stack.
+    pub fn from_consoles(consoles: &[&dyn Console]) -> XpFile {
+        XpFile {
+            version: -1,
+            layers: consoles.iter().map(|c| c.to_xp_layer()).collect(),
+        }
+    }
+
+    /// Write a xp image to a file on disk.
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> io::Result<()> {
+        let mut f = std::fs::File::create(path)?;
+        self.write(&mut f)
+    }
+
     /// Write a xp image to a stream
     pub fn write<W: Write>(&self, f: &mut W) -> io::Result<()> {
         let mut wr = GzEncoder::new(f, Compression::best());
@@ -192,6 +216,122 @@ pub fn xp_to_console(
     }
 }
 
+/// Applies a single layer of an XpFile to a console, with 0,0 offset by offset_x and
+/// offset_y. Cells with a transparent background are skipped, so underlying console
+/// contents show through.
+pub fn xp_layer_to_console(
+    layer: &XpLayer,
+    mut console: impl AsMut<dyn Console>,
+    offset_x: i32,
+    offset_y: i32,
+) {
+    for y in 0..layer.height {
+        for x in 0..layer.width {
+            let cell = layer.get(x, y).unwrap();
+            if !cell.bg.is_transparent() {
+                console.as_mut().set(
+                    x as i32 + offset_x,
+                    y as i32 + offset_y,
+                    RGB::from_xp(cell.fg).into(),
+                    RGB::from_xp(cell.bg).into(),
+                    cell.ch as FontCharType,
+                );
+            }
+        }
+    }
+}
+
+/// Renders a multi-layer XpFile across consecutive console layers: XP layer 0 onto
+/// `first_layer`, XP layer 1 onto `first_layer + 1`, and so on, preserving the
+/// file's layering and transparency. XP layers beyond the registered console count
+/// are dropped. Restores the previously active console before returning.
+pub fn xp_to_layers(xp: &XpFile, bterm: &mut crate::prelude::BTerm, first_layer: usize) {
+    let previous = bterm.active_console;
+    for (i, layer) in xp.layers.iter().enumerate() {
+        if !bterm.set_active_console_checked(first_layer + i) {
+            break;
+        }
+        for y in 0..layer.height {
+            for x in 0..layer.width {
+                let cell = layer.get(x, y).unwrap();
+                if !cell.bg.is_transparent() {
+                    bterm.set(
+                        x as i32,
+                        y as i32,
+                        RGB::from_xp(cell.fg),
+                        RGB::from_xp(cell.bg),
+                        cell.ch as FontCharType,
+                    );
+                }
+            }
+        }
+    }
+    bterm.set_active_console(previous);
+}
+
+/// Plays a sequence of XpFiles as an animation, advanced by frame time - the
+/// player half of exporting frames from REX Paint:
+///
+/// ```ignore
+/// let mut anim = XpSequence::new(frames, 120.0);
+/// // each tick:
+/// anim.tick(ctx.frame_time_ms);
+/// xp_to_draw_batch(anim.current(), &mut batch, 0, 0);
+/// ```
+pub struct XpSequence {
+    frames: Vec<XpFile>,
+    frame_duration_ms: f32,
+    elapsed_ms: f32,
+    looping: bool,
+}
+
+impl XpSequence {
+    /// Creates a looping sequence showing each frame for `frame_duration_ms`.
+    /// Panics if `frames` is empty.
+    pub fn new(frames: Vec<XpFile>, frame_duration_ms: f32) -> Self {
+        assert!(!frames.is_empty(), "XpSequence requires at least one frame");
+        Self {
+            frames,
+            frame_duration_ms: frame_duration_ms.max(1.0),
+            elapsed_ms: 0.0,
+            looping: true,
+        }
+    }
+
+    /// Makes the sequence stop on its last frame instead of looping.
+    pub fn play_once(mut self) -> Self {
+        self.looping = false;
+        self
+    }
+
+    /// Advances the animation clock.
+    pub fn tick(&mut self, frame_time_ms: f32) {
+        self.elapsed_ms += frame_time_ms;
+        let total = self.frame_duration_ms * self.frames.len() as f32;
+        if self.looping {
+            self.elapsed_ms %= total;
+        } else {
+            self.elapsed_ms = self.elapsed_ms.min(total);
+        }
+    }
+
+    /// The index of the frame currently showing.
+    pub fn current_frame(&self) -> usize {
+        ((self.elapsed_ms / self.frame_duration_ms) as usize).min(self.frames.len() - 1)
+    }
+
+    /// The XpFile currently showing; draw it with `xp_to_console`,
+    /// `xp_to_draw_batch` or `xp_to_layers`.
+    pub fn current(&self) -> &XpFile {
+        &self.frames[self.current_frame()]
+    }
+
+    /// True once a non-looping sequence has reached its final frame.
+    pub fn is_finished(&self) -> bool {
+        !self.looping && self.current_frame() == self.frames.len() - 1
+    }
+}
+
 /// Applies an XpFile to a given draw batch, with 0,0 offset by offset_x and offset-y.
 pub fn xp_to_draw_batch(xp: &XpFile, draw_batch: &mut DrawBatch, offset_x: i32, offset_y: i32) {
     for layer in &xp.layers {
@@ -239,6 +379,40 @@ mod tests {
         assert_eq!(xp, xp2);
     }
 
+    #[test]
+    fn test_sequence_advances_and_loops() {
+        let frames = vec![XpFile::new(2, 2), XpFile::new(2, 2), XpFile::new(2, 2)];
+        let mut anim = XpSequence::new(frames.clone(), 100.0);
+        assert_eq!(anim.current_frame(), 0);
+        anim.tick(150.0);
+        assert_eq!(anim.current_frame(), 1);
+        anim.tick(200.0); // wraps past the end
+        assert_eq!(anim.current_frame(), 0);
+
+        let mut once = XpSequence::new(frames, 100.0).play_once();
+        once.tick(1000.0);
+        assert_eq!(once.current_frame(), 2);
+        assert!(once.is_finished());
+    }
+
+    #[test]
+    fn test_from_console() {
+        use crate::prelude::VirtualConsole;
+        let mut console = VirtualConsole::new(Point::new(4, 3));
+        console.print(0, 0, "Hi");
+        let xp = XpFile::from_console(&console);
+        assert_eq!(xp.layers.len(), 1);
+        assert_eq!(xp.layers[0].width, 4);
+        assert_eq!(xp.layers[0].height, 3);
+        assert_eq!(xp.layers[0].get(0, 0).unwrap().ch, 'H' as u32);
+        assert_eq!(xp.layers[0].get(1, 0).unwrap().ch, 'i' as u32);
+
+        let mut f = Cursor::new(Vec::new());
+        xp.write(&mut f).unwrap();
+        f.seek(SeekFrom::Start(0)).unwrap();
+        assert_eq!(XpFile::read(&mut f).unwrap(), xp);
+    }
+
     #[test]
     fn test_image() {
         let mut f = File::open("resources/mltest.xp").unwrap();
@@ -0,0 +1,327 @@
+//! Tiled (`.tmx`, XML format) map import. Parses tile layers (CSV encoding) and
+//! object layers with properties, so maps designed in Tiled can be blitted onto
+//! consoles: each tile GID maps onto a glyph/sprite index in the font or sprite
+//! sheet matching its tileset.
+
+use crate::prelude::{embedding, Console};
+use crate::BResult;
+use bracket_color::prelude::RGBA;
+use std::collections::HashMap;
+
+/// Tiled packs horizontal/vertical/diagonal flip flags into the top bits of a GID;
+/// this masks them off. Flips are not currently honored when blitting.
+const GID_MASK: u32 = 0x1FFF_FFFF;
+
+/// One tileset reference: which GID range it covers and which font or sprite sheet
+/// (by name) the game registered for it.
+#[derive(Clone, Debug, Default)]
+pub struct TiledTileset {
+    pub first_gid: u32,
+    pub name: String,
+    /// The `source` attribute for external (`.tsx`) tilesets, if present.
+    pub source: Option<String>,
+}
+
+/// One tile layer: a row-major (top-left origin) grid of GIDs, 0 meaning empty.
+#[derive(Clone, Debug, Default)]
+pub struct TiledLayer {
+    pub name: String,
+    pub width: usize,
+    pub height: usize,
+    pub gids: Vec<u32>,
+}
+
+impl TiledLayer {
+    /// The GID at (x, y), or 0 if out of range.
+    pub fn gid(&self, x: usize, y: usize) -> u32 {
+        if x < self.width && y < self.height {
+            self.gids[y * self.width + x]
+        } else {
+            0
+        }
+    }
+}
+
+/// One placed object from an object layer, with its custom properties.
+#[derive(Clone, Debug, Default)]
+pub struct TiledObject {
+    pub id: u32,
+    pub name: String,
+    pub object_type: String,
+    /// Position and size in pixels, as Tiled stores them.
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// The tile GID for tile objects, if any.
+    pub gid: Option<u32>,
+    pub properties: HashMap<String, String>,
+}
+
+/// A parsed Tiled map: dimensions, tilesets, tile layers and objects.
+#[derive(Clone, Debug, Default)]
+pub struct TiledMap {
+    pub width: usize,
+    pub height: usize,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub tilesets: Vec<TiledTileset>,
+    pub layers: Vec<TiledLayer>,
+    pub objects: Vec<TiledObject>,
+}
+
+impl TiledMap {
+    /// Loads and parses a `.tmx` file through the resource system, so it works with
+    /// both embedded resources and the filesystem.
+    pub fn load<S: ToString>(filename: S) -> BResult<TiledMap> {
+        let filename = filename.to_string();
+        let resource = embedding::EMBED.lock().get_resource(filename.clone());
+        let text = match resource {
+            Some(bytes) => String::from_utf8(bytes.to_vec())?,
+            None => std::fs::read_to_string(&filename)?,
+        };
+        TiledMap::parse(&text)
+    }
+
+    /// Parses TMX XML that is already in memory. Tile layers must use the CSV
+    /// encoding (Tiled's default); base64 layers are rejected with an error.
+    pub fn parse(xml: &str) -> BResult<TiledMap> {
+        let mut map = TiledMap::default();
+        let mut remaining = xml;
+        let mut current_layer: Option<TiledLayer> = None;
+        let mut in_object = false;
+
+        while let Some(start) = remaining.find('<') {
+            remaining = &remaining[start + 1..];
+            // Skip declarations and comments.
+            if remaining.starts_with('?') || remaining.starts_with('!') {
+                continue;
+            }
+            let end = match remaining.find('>') {
+                Some(end) => end,
+                None => break,
+            };
+            let tag = &remaining[..end];
+            remaining = &remaining[end + 1..];
+
+            let closing = tag.starts_with('/');
+            let tag = tag.trim_start_matches('/').trim_end_matches('/');
+            let (name, attr_text) = match tag.find(char::is_whitespace) {
+                Some(split) => (&tag[..split], &tag[split..]),
+                None => (tag, ""),
+            };
+            let attrs = parse_attributes(attr_text);
+            let num = |key: &str| {
+                attrs
+                    .get(key)
+                    .and_then(|v| v.parse::<u32>().ok())
+                    .unwrap_or(0)
+            };
+            let float = |key: &str| {
+                attrs
+                    .get(key)
+                    .and_then(|v| v.parse::<f32>().ok())
+                    .unwrap_or(0.0)
+            };
+
+            match (name, closing) {
+                ("map", false) => {
+                    map.width = num("width") as usize;
+                    map.height = num("height") as usize;
+                    map.tile_width = num("tilewidth");
+                    map.tile_height = num("tileheight");
+                }
+                ("tileset", false) => map.tilesets.push(TiledTileset {
+                    first_gid: num("firstgid"),
+                    name: attrs.get("name").cloned().unwrap_or_default(),
+                    source: attrs.get("source").cloned(),
+                }),
+                ("layer", false) => {
+                    current_layer = Some(TiledLayer {
+                        name: attrs.get("name").cloned().unwrap_or_default(),
+                        width: num("width") as usize,
+                        height: num("height") as usize,
+                        gids: Vec::new(),
+                    });
+                }
+                ("layer", true) => {
+                    if let Some(layer) = current_layer.take() {
+                        map.layers.push(layer);
+                    }
+                }
+                ("data", false) => {
+                    match attrs.get("encoding").map(String::as_str) {
+                        Some("csv") => {}
+                        other => {
+                            return Err(format!(
+                            "Unsupported layer encoding {:?}; re-save the map with CSV tile data",
+                            other
+                        )
+                            .into())
+                        }
+                    }
+                    let end = remaining
+                        .find("</data>")
+                        .ok_or("Unterminated <data> element")?;
+                    if let Some(layer) = current_layer.as_mut() {
+                        layer.gids = remaining[..end]
+                            .split(',')
+                            .filter_map(|v| v.trim().parse::<u32>().ok())
+                            .map(|gid| gid & GID_MASK)
+                            .collect();
+                        if layer.gids.len() != layer.width * layer.height {
+                            return Err(format!(
+                                "Layer '{}' has {} tiles, expected {}",
+                                layer.name,
+                                layer.gids.len(),
+                                layer.width * layer.height
+                            )
+                            .into());
+                        }
+                    }
+                    remaining = &remaining[end..];
+                }
+                ("object", false) => {
+                    map.objects.push(TiledObject {
+                        id: num("id"),
+                        name: attrs.get("name").cloned().unwrap_or_default(),
+                        object_type: attrs
+                            .get("type")
+                            .or_else(|| attrs.get("class"))
+                            .cloned()
+                            .unwrap_or_default(),
+                        x: float("x"),
+                        y: float("y"),
+                        width: float("width"),
+                        height: float("height"),
+                        gid: attrs
+                            .get("gid")
+                            .and_then(|v| v.parse::<u32>().ok())
+                            .map(|g| g & GID_MASK),
+                        properties: HashMap::new(),
+                    });
+                    in_object = true;
+                }
+                ("object", true) => in_object = false,
+                ("property", false) if in_object => {
+                    if let (Some(key), Some(value)) = (attrs.get("name"), attrs.get("value")) {
+                        if let Some(object) = map.objects.last_mut() {
+                            object.properties.insert(key.clone(), value.clone());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        if map.width == 0 || map.height == 0 {
+            return Err("No <map> element found; is this a TMX file?".into());
+        }
+        Ok(map)
+    }
+
+    /// The tileset covering `gid`, if any.
+    pub fn tileset_for(&self, gid: u32) -> Option<&TiledTileset> {
+        self.tilesets
+            .iter()
+            .filter(|ts| ts.first_gid <= gid)
+            .max_by_key(|ts| ts.first_gid)
+    }
+
+    /// Blits one tile layer onto a console: each non-empty GID becomes the glyph
+    /// `gid - first_gid` of its tileset, in white on black - ready for a console
+    /// whose font is laid out like the Tiled tileset image. Empty cells are left
+    /// untouched, so layers stack.
+    pub fn layer_to_console(
+        &self,
+        layer: usize,
+        console: &mut dyn Console,
+        offset_x: i32,
+        offset_y: i32,
+    ) {
+        let layer = &self.layers[layer];
+        let fg = RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+        let bg = RGBA::from_f32(0.0, 0.0, 0.0, 1.0);
+        for y in 0..layer.height {
+            for x in 0..layer.width {
+                let gid = layer.gid(x, y);
+                if gid == 0 {
+                    continue;
+                }
+                let glyph = gid - self.tileset_for(gid).map_or(1, |ts| ts.first_gid);
+                console.set(
+                    x as i32 + offset_x,
+                    y as i32 + offset_y,
+                    fg,
+                    bg,
+                    glyph as crate::FontCharType,
+                );
+            }
+        }
+    }
+}
+
+/// Parses `key="value"` attribute pairs from the inside of a tag.
+fn parse_attributes(text: &str) -> HashMap<String, String> {
+    let mut attrs = HashMap::new();
+    let mut rest = text;
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_string();
+        rest = &rest[eq + 1..];
+        let quote = match rest.chars().next() {
+            Some(q @ ('"' | '\'')) => q,
+            _ => break,
+        };
+        rest = &rest[1..];
+        let end = match rest.find(quote) {
+            Some(end) => end,
+            None => break,
+        };
+        attrs.insert(key, rest[..end].to_string());
+        rest = &rest[end + 1..];
+    }
+    attrs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TiledMap;
+
+    const SAMPLE: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<map version="1.10" orientation="orthogonal" width="3" height="2" tilewidth="16" tileheight="16">
+ <tileset firstgid="1" name="terrain" tilewidth="16" tileheight="16"/>
+ <layer id="1" name="ground" width="3" height="2">
+  <data encoding="csv">
+1,2,3,
+4,0,6
+</data>
+ </layer>
+ <objectgroup id="2" name="spawns">
+  <object id="1" name="start" type="spawn" x="16" y="32" width="16" height="16">
+   <properties>
+    <property name="faction" value="player"/>
+   </properties>
+  </object>
+ </objectgroup>
+</map>"#;
+
+    #[test]
+    fn parses_layers_and_objects() {
+        let map = TiledMap::parse(SAMPLE).unwrap();
+        assert_eq!((map.width, map.height), (3, 2));
+        assert_eq!(map.layers.len(), 1);
+        assert_eq!(map.layers[0].gid(0, 0), 1);
+        assert_eq!(map.layers[0].gid(2, 1), 6);
+        assert_eq!(map.layers[0].gid(1, 1), 0);
+        assert_eq!(map.objects.len(), 1);
+        let object = &map.objects[0];
+        assert_eq!(object.object_type, "spawn");
+        assert_eq!(object.properties["faction"], "player");
+        assert_eq!(map.tileset_for(3).unwrap().name, "terrain");
+    }
+
+    #[test]
+    fn rejects_base64_data() {
+        let xml = SAMPLE.replace("encoding=\"csv\"", "encoding=\"base64\"");
+        assert!(TiledMap::parse(&xml).is_err());
+    }
+}
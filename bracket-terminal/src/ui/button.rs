@@ -0,0 +1,67 @@
+use super::{UiEvent, UiTheme, Widget};
+use crate::prelude::{DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// A push button rendered as `[ label ]`. Activates on click, or Return/Space while
+/// focused, emitting [`UiEvent::ButtonClicked`].
+pub struct Button {
+    id: String,
+    pub label: String,
+    bounds: Rect,
+}
+
+impl Button {
+    /// Creates a button at `pos` (the left end of the label). The clickable region is
+    /// sized from the label.
+    pub fn new<S: ToString, L: ToString>(id: S, label: L, pos: Point) -> Self {
+        let label = label.to_string();
+        let bounds = Rect::with_size(pos.x, pos.y, label.len() as i32 + 4, 1);
+        Self {
+            id: id.to_string(),
+            label,
+            bounds,
+        }
+    }
+
+    fn press(&self, events: &mut Vec<UiEvent>) {
+        events.push(UiEvent::ButtonClicked(self.id.clone()));
+    }
+}
+
+impl Widget for Button {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>) {
+        if let VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter | VirtualKeyCode::Space = key {
+            self.press(events);
+        }
+    }
+
+    fn click(&mut self, _pos: Point, events: &mut Vec<UiEvent>) {
+        self.press(events);
+    }
+
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, focused: bool) {
+        let color = if focused { theme.focused } else { theme.text };
+        batch.print_color(
+            Point::new(self.bounds.x1, self.bounds.y1),
+            format!("[ {} ]", self.label),
+            color,
+        );
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
@@ -0,0 +1,156 @@
+use super::{UiEvent, UiTheme, Widget};
+use crate::prelude::{DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// A modal dialog: a framed box with a title, body text, and a row of buttons.
+/// While open it grabs all input routed through the [`Ui`](super::Ui). Left/Right
+/// pick a button, Return confirms (emitting [`UiEvent::DialogButton`]) and closes,
+/// Escape closes with no event. Closed dialogs neither render nor take focus.
+pub struct ModalDialog {
+    id: String,
+    pub title: String,
+    pub body: Vec<String>,
+    pub buttons: Vec<String>,
+    pub selected_button: usize,
+    bounds: Rect,
+    open: bool,
+}
+
+impl ModalDialog {
+    pub fn new<S: ToString, T: ToString>(
+        id: S,
+        title: T,
+        body: Vec<String>,
+        buttons: Vec<String>,
+        bounds: Rect,
+    ) -> Self {
+        Self {
+            id: id.to_string(),
+            title: title.to_string(),
+            body,
+            buttons,
+            selected_button: 0,
+            bounds,
+            open: false,
+        }
+    }
+
+    pub fn show(&mut self) {
+        self.open = true;
+        self.selected_button = 0;
+    }
+
+    pub fn hide(&mut self) {
+        self.open = false;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// The screen position of each button's `[ label ]`, laid out left to right on
+    /// the bottom interior row.
+    fn button_positions(&self) -> Vec<(Rect, &str)> {
+        let mut positions = Vec::new();
+        let y = self.bounds.y2 - 1;
+        let mut x = self.bounds.x1 + 2;
+        for label in &self.buttons {
+            let width = label.len() as i32 + 4;
+            positions.push((Rect::with_size(x, y, width, 1), label.as_str()));
+            x += width + 1;
+        }
+        positions
+    }
+}
+
+impl Widget for ModalDialog {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn focusable(&self) -> bool {
+        self.open
+    }
+
+    fn is_modal_open(&self) -> bool {
+        self.open
+    }
+
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>) {
+        match key {
+            VirtualKeyCode::Left => {
+                self.selected_button = self.selected_button.saturating_sub(1);
+            }
+            VirtualKeyCode::Right if self.selected_button + 1 < self.buttons.len() => {
+                self.selected_button += 1;
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                events.push(UiEvent::DialogButton {
+                    id: self.id.clone(),
+                    button: self.selected_button,
+                });
+                self.open = false;
+            }
+            VirtualKeyCode::Escape => self.open = false,
+            _ => {}
+        }
+    }
+
+    fn click(&mut self, pos: Point, events: &mut Vec<UiEvent>) {
+        for (i, (rect, _)) in self.button_positions().iter().enumerate() {
+            if rect.point_in_rect(pos) {
+                events.push(UiEvent::DialogButton {
+                    id: self.id.clone(),
+                    button: i,
+                });
+                self.open = false;
+                return;
+            }
+        }
+    }
+
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, _focused: bool) {
+        if !self.open {
+            return;
+        }
+        batch.fill_region(self.bounds, theme.text, 32);
+        batch.draw_double_box(self.bounds, theme.border);
+        batch.print_color(
+            Point::new(self.bounds.x1 + 2, self.bounds.y1),
+            format!(" {} ", self.title),
+            theme.title,
+        );
+        for (i, line) in self.body.iter().enumerate() {
+            batch.print_color(
+                Point::new(self.bounds.x1 + 2, self.bounds.y1 + 2 + i as i32),
+                line,
+                theme.text,
+            );
+        }
+        for (i, (rect, label)) in self.button_positions().iter().enumerate() {
+            let color = if i == self.selected_button {
+                theme.selected
+            } else {
+                theme.text
+            };
+            batch.print_color(
+                Point::new(rect.x1, rect.y1),
+                format!("[ {} ]", label),
+                color,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
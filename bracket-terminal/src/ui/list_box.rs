@@ -0,0 +1,108 @@
+use super::{UiEvent, UiTheme, Widget};
+use crate::prelude::{DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// A scrollable, selectable list of strings. Up/Down (or clicking) moves the
+/// selection, emitting [`UiEvent::SelectionChanged`]; Return emits
+/// [`UiEvent::ItemActivated`] for the selected entry.
+pub struct ListBox {
+    id: String,
+    pub items: Vec<String>,
+    pub selected: usize,
+    bounds: Rect,
+    scroll: usize,
+}
+
+impl ListBox {
+    pub fn new<S: ToString>(id: S, items: Vec<String>, bounds: Rect) -> Self {
+        Self {
+            id: id.to_string(),
+            items,
+            selected: 0,
+            bounds,
+            scroll: 0,
+        }
+    }
+
+    fn select(&mut self, index: usize, events: &mut Vec<UiEvent>) {
+        if index != self.selected && index < self.items.len() {
+            self.selected = index;
+            events.push(UiEvent::SelectionChanged {
+                id: self.id.clone(),
+                index,
+            });
+        }
+        // Keep the selection scrolled into view.
+        let height = self.bounds.height().max(1) as usize;
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + height {
+            self.scroll = self.selected + 1 - height;
+        }
+    }
+}
+
+impl Widget for ListBox {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>) {
+        match key {
+            VirtualKeyCode::Up => self.select(self.selected.saturating_sub(1), events),
+            VirtualKeyCode::Down => self.select(self.selected + 1, events),
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter
+                if self.selected < self.items.len() =>
+            {
+                events.push(UiEvent::ItemActivated {
+                    id: self.id.clone(),
+                    index: self.selected,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn click(&mut self, pos: Point, events: &mut Vec<UiEvent>) {
+        let index = (pos.y - self.bounds.y1) as usize + self.scroll;
+        self.select(index, events);
+    }
+
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, focused: bool) {
+        let height = self.bounds.height().max(1) as usize;
+        for (row, (i, item)) in self
+            .items
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(height)
+            .enumerate()
+        {
+            let color = if i == self.selected {
+                theme.selected
+            } else if focused {
+                theme.focused
+            } else {
+                theme.text
+            };
+            batch.print_color(
+                Point::new(self.bounds.x1, self.bounds.y1 + row as i32),
+                item,
+                color,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
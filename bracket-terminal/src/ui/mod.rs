@@ -0,0 +1,244 @@
+//! A small retained-mode widget toolkit on top of [`DrawBatch`]: buttons,
+//! selectable lists, radio groups, and modal dialogs with keyboard and mouse
+//! navigation, themes, and focus handling. Build a [`Ui`], add widgets, forward
+//! input to it, render it each frame, and poll its events each tick:
+//!
+//! ```ignore
+//! let mut ui = Ui::new(UiTheme::default());
+//! ui.add(Button::new("quit", "Quit", Point::new(2, 10)));
+//! // each tick:
+//! INPUT.lock().for_each_message(|event| { ui.handle_event(&event); });
+//! ui.render(&mut batch);
+//! for event in ui.poll_events() {
+//!     if let UiEvent::ButtonClicked(id) = event { /* ... */ }
+//! }
+//! ```
+
+mod button;
+mod dialog;
+mod list_box;
+mod radio_group;
+mod text_input;
+mod theme;
+mod widget;
+
+pub use button::Button;
+pub use dialog::ModalDialog;
+pub use list_box::ListBox;
+pub use radio_group::RadioGroup;
+pub use text_input::TextInput;
+pub use theme::UiTheme;
+pub use widget::Widget;
+
+use crate::prelude::{BEvent, DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::Point;
+
+/// Something a widget did in response to input, polled off the [`Ui`] each tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UiEvent {
+    /// A [`Button`] was activated.
+    ButtonClicked(String),
+    /// A [`ListBox`]'s selection moved.
+    SelectionChanged { id: String, index: usize },
+    /// A [`ListBox`] entry was activated with Return.
+    ItemActivated { id: String, index: usize },
+    /// A [`RadioGroup`] choice changed.
+    RadioSelected { id: String, index: usize },
+    /// A [`ModalDialog`] was confirmed with the given button index.
+    DialogButton { id: String, button: usize },
+    /// A [`TextInput`]'s contents changed.
+    TextChanged { id: String, text: String },
+    /// Return was pressed in a [`TextInput`].
+    TextSubmitted { id: String, text: String },
+}
+
+/// A retained collection of widgets sharing a theme, keyboard focus, and an event
+/// queue. Tab cycles focus between focusable widgets; an open [`ModalDialog`]
+/// grabs all input until it closes.
+pub struct Ui {
+    widgets: Vec<Box<dyn Widget>>,
+    focus: usize,
+    pub theme: UiTheme,
+    events: Vec<UiEvent>,
+}
+
+impl Ui {
+    pub fn new(theme: UiTheme) -> Self {
+        Self {
+            widgets: Vec::new(),
+            focus: 0,
+            theme,
+            events: Vec::new(),
+        }
+    }
+
+    /// Adds a widget; the first focusable widget added receives initial focus.
+    pub fn add<W: Widget + 'static>(&mut self, widget: W) -> &mut Self {
+        self.widgets.push(Box::new(widget));
+        self
+    }
+
+    /// Looks up a widget by id as its concrete type, for mutating its state
+    /// (list items, dialog visibility, and so on) after construction.
+    pub fn widget_mut<W: Widget + 'static, S: AsRef<str>>(&mut self, id: S) -> Option<&mut W> {
+        self.widgets
+            .iter_mut()
+            .find(|w| w.id() == id.as_ref())
+            .and_then(|w| w.as_any_mut().downcast_mut::<W>())
+    }
+
+    /// The index of the widget currently grabbing all input, if any.
+    fn modal(&self) -> Option<usize> {
+        self.widgets.iter().position(|w| w.is_modal_open())
+    }
+
+    fn cycle_focus(&mut self) {
+        if self.widgets.is_empty() {
+            return;
+        }
+        let len = self.widgets.len();
+        for offset in 1..=len {
+            let i = (self.focus + offset) % len;
+            if self.widgets[i].focusable() {
+                self.focus = i;
+                return;
+            }
+        }
+    }
+
+    /// Routes one input event into the toolkit. Returns true if a widget consumed
+    /// it. Key presses go to the open modal dialog if there is one, otherwise Tab
+    /// cycles focus and other keys go to the focused widget.
+    pub fn handle_event(&mut self, event: &BEvent) -> bool {
+        if let BEvent::Character { c } = event {
+            if let Some(modal) = self.modal() {
+                self.widgets[modal].character(*c, &mut self.events);
+                return true;
+            }
+            if let Some(widget) = self.widgets.get_mut(self.focus) {
+                if widget.focusable() {
+                    widget.character(*c, &mut self.events);
+                    return true;
+                }
+            }
+            return false;
+        }
+        let key = match event {
+            BEvent::KeyboardInput { key, pressed, .. } if *pressed => *key,
+            _ => return false,
+        };
+        if let Some(modal) = self.modal() {
+            self.widgets[modal].key(key, &mut self.events);
+            return true;
+        }
+        if key == VirtualKeyCode::Tab {
+            self.cycle_focus();
+            return true;
+        }
+        if let Some(widget) = self.widgets.get_mut(self.focus) {
+            if widget.focusable() {
+                widget.key(key, &mut self.events);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Routes a mouse click at `tile` (in console cells, e.g. from
+    /// `INPUT.lock().mouse_tile(layer)`). The clicked widget receives focus.
+    /// Returns true if a widget was hit; an open modal dialog swallows all clicks.
+    pub fn click(&mut self, tile: Point) -> bool {
+        if let Some(modal) = self.modal() {
+            self.widgets[modal].click(tile, &mut self.events);
+            return true;
+        }
+        for (i, widget) in self.widgets.iter_mut().enumerate() {
+            if widget.focusable() && widget.bounds().point_in_rect(tile) {
+                self.focus = i;
+                widget.click(tile, &mut self.events);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Advances every widget's time-dependent state (e.g. caret blinking); call
+    /// once per tick with `ctx.frame_time_ms`.
+    pub fn tick(&mut self, frame_time_ms: f32) {
+        for widget in self.widgets.iter_mut() {
+            widget.tick(frame_time_ms);
+        }
+    }
+
+    /// Draws every widget onto the batch's current target, the open modal dialog
+    /// last so it overlays the rest.
+    pub fn render(&self, batch: &mut DrawBatch) {
+        let modal = self.modal();
+        for (i, widget) in self.widgets.iter().enumerate() {
+            if Some(i) != modal {
+                widget.render(batch, &self.theme, i == self.focus && modal.is_none());
+            }
+        }
+        if let Some(modal) = modal {
+            self.widgets[modal].render(batch, &self.theme, true);
+        }
+    }
+
+    /// Takes the events emitted since the last poll.
+    pub fn poll_events(&mut self) -> Vec<UiEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_event(key: VirtualKeyCode) -> BEvent {
+        BEvent::KeyboardInput {
+            key,
+            scan_code: 0,
+            pressed: true,
+        }
+    }
+
+    #[test]
+    fn tab_cycles_focus_and_enter_clicks() {
+        let mut ui = Ui::new(UiTheme::default());
+        ui.add(Button::new("a", "A", Point::new(0, 0)));
+        ui.add(Button::new("b", "B", Point::new(0, 1)));
+        ui.handle_event(&key_event(VirtualKeyCode::Tab));
+        ui.handle_event(&key_event(VirtualKeyCode::Return));
+        assert_eq!(
+            ui.poll_events(),
+            vec![UiEvent::ButtonClicked("b".to_string())]
+        );
+    }
+
+    #[test]
+    fn open_dialog_grabs_input() {
+        let mut ui = Ui::new(UiTheme::default());
+        ui.add(Button::new("a", "A", Point::new(0, 0)));
+        ui.add(ModalDialog::new(
+            "confirm",
+            "Confirm",
+            vec!["Really?".to_string()],
+            vec!["Yes".to_string(), "No".to_string()],
+            bracket_geometry::prelude::Rect::with_size(10, 10, 30, 8),
+        ));
+        ui.widget_mut::<ModalDialog, _>("confirm").unwrap().show();
+        ui.handle_event(&key_event(VirtualKeyCode::Right));
+        ui.handle_event(&key_event(VirtualKeyCode::Return));
+        assert_eq!(
+            ui.poll_events(),
+            vec![UiEvent::DialogButton {
+                id: "confirm".to_string(),
+                button: 1
+            }]
+        );
+        assert!(!ui
+            .widget_mut::<ModalDialog, _>("confirm")
+            .unwrap()
+            .is_open());
+    }
+}
@@ -0,0 +1,83 @@
+use super::{UiEvent, UiTheme, Widget};
+use crate::prelude::{DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// A vertical group of mutually-exclusive options, rendered as `(*) option` lines.
+/// Up/Down or clicking changes the selection, emitting [`UiEvent::RadioSelected`].
+pub struct RadioGroup {
+    id: String,
+    pub options: Vec<String>,
+    pub selected: usize,
+    bounds: Rect,
+}
+
+impl RadioGroup {
+    /// Creates a group at `pos`, one option per row below it.
+    pub fn new<S: ToString>(id: S, options: Vec<String>, pos: Point) -> Self {
+        let width = options.iter().map(|o| o.len()).max().unwrap_or(0) as i32 + 4;
+        let bounds = Rect::with_size(pos.x, pos.y, width, options.len() as i32);
+        Self {
+            id: id.to_string(),
+            options,
+            selected: 0,
+            bounds,
+        }
+    }
+
+    fn select(&mut self, index: usize, events: &mut Vec<UiEvent>) {
+        if index != self.selected && index < self.options.len() {
+            self.selected = index;
+            events.push(UiEvent::RadioSelected {
+                id: self.id.clone(),
+                index,
+            });
+        }
+    }
+}
+
+impl Widget for RadioGroup {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>) {
+        match key {
+            VirtualKeyCode::Up => self.select(self.selected.saturating_sub(1), events),
+            VirtualKeyCode::Down => self.select(self.selected + 1, events),
+            _ => {}
+        }
+    }
+
+    fn click(&mut self, pos: Point, events: &mut Vec<UiEvent>) {
+        self.select((pos.y - self.bounds.y1) as usize, events);
+    }
+
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, focused: bool) {
+        for (i, option) in self.options.iter().enumerate() {
+            let mark = if i == self.selected { "(*)" } else { "( )" };
+            let color = if focused && i == self.selected {
+                theme.focused
+            } else {
+                theme.text
+            };
+            batch.print_color(
+                Point::new(self.bounds.x1, self.bounds.y1 + i as i32),
+                format!("{} {}", mark, option),
+                color,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
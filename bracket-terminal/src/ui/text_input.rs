@@ -0,0 +1,253 @@
+use super::{UiEvent, UiTheme, Widget};
+use crate::prelude::{DrawBatch, VirtualKeyCode, INPUT};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// How long the caret stays in each blink phase, in milliseconds.
+const BLINK_MS: f32 = 530.0;
+
+/// A single-line text entry field. Characters arrive through the [`Ui`](super::Ui)
+/// event routing; the widget supports cursor movement (Left/Right/Home/End),
+/// Shift-selection, Backspace/Delete, an optional maximum length, and renders
+/// itself with a blinking caret, scrolling horizontally to keep the caret in view.
+/// Emits [`UiEvent::TextChanged`] on edits and [`UiEvent::TextSubmitted`] on Return.
+pub struct TextInput {
+    id: String,
+    chars: Vec<char>,
+    /// The caret position, as a character index into the text.
+    cursor: usize,
+    /// The other end of the selection, if one is active.
+    anchor: Option<usize>,
+    max_length: Option<usize>,
+    bounds: Rect,
+    scroll: usize,
+    blink_timer_ms: f32,
+}
+
+impl TextInput {
+    /// Creates an empty input occupying one row of `width` cells at `pos`.
+    pub fn new<S: ToString>(id: S, pos: Point, width: i32) -> Self {
+        Self {
+            id: id.to_string(),
+            chars: Vec::new(),
+            cursor: 0,
+            anchor: None,
+            max_length: None,
+            bounds: Rect::with_size(pos.x, pos.y, width, 1),
+            scroll: 0,
+            blink_timer_ms: 0.0,
+        }
+    }
+
+    /// Caps the text at `max` characters; further typing is ignored.
+    pub fn with_max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    /// The current contents.
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    /// Replaces the contents, moving the caret to the end.
+    pub fn set_text<S: ToString>(&mut self, text: S) {
+        self.chars = text.to_string().chars().collect();
+        if let Some(max) = self.max_length {
+            self.chars.truncate(max);
+        }
+        self.cursor = self.chars.len();
+        self.anchor = None;
+        self.scroll_into_view();
+    }
+
+    /// The selected range as (start, end) character indices, if any.
+    fn selection(&self) -> Option<(usize, usize)> {
+        match self.anchor {
+            Some(anchor) if anchor != self.cursor => {
+                Some((anchor.min(self.cursor), anchor.max(self.cursor)))
+            }
+            _ => None,
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        if let Some((start, end)) = self.selection() {
+            self.chars.drain(start..end);
+            self.cursor = start;
+            self.anchor = None;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Moves the caret, extending the selection if shift is held.
+    fn move_cursor(&mut self, to: usize, shift: bool) {
+        if shift {
+            if self.anchor.is_none() {
+                self.anchor = Some(self.cursor);
+            }
+        } else {
+            self.anchor = None;
+        }
+        self.cursor = to.min(self.chars.len());
+        self.blink_timer_ms = 0.0;
+        self.scroll_into_view();
+    }
+
+    /// Adjusts the horizontal scroll so the caret stays visible.
+    fn scroll_into_view(&mut self) {
+        let width = self.bounds.width().max(1) as usize;
+        if self.cursor < self.scroll {
+            self.scroll = self.cursor;
+        } else if self.cursor >= self.scroll + width {
+            self.scroll = self.cursor + 1 - width;
+        }
+    }
+
+    fn changed(&self, events: &mut Vec<UiEvent>) {
+        events.push(UiEvent::TextChanged {
+            id: self.id.clone(),
+            text: self.text(),
+        });
+    }
+}
+
+impl Widget for TextInput {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn bounds(&self) -> Rect {
+        self.bounds
+    }
+
+    fn tick(&mut self, frame_time_ms: f32) {
+        self.blink_timer_ms += frame_time_ms;
+    }
+
+    fn character(&mut self, c: char, events: &mut Vec<UiEvent>) {
+        if c.is_control() {
+            return;
+        }
+        self.delete_selection();
+        if let Some(max) = self.max_length {
+            if self.chars.len() >= max {
+                return;
+            }
+        }
+        self.chars.insert(self.cursor, c);
+        self.cursor += 1;
+        self.blink_timer_ms = 0.0;
+        self.scroll_into_view();
+        self.changed(events);
+    }
+
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>) {
+        let shift = {
+            let input = INPUT.lock();
+            input.is_key_pressed(VirtualKeyCode::LShift)
+                || input.is_key_pressed(VirtualKeyCode::RShift)
+        };
+        match key {
+            VirtualKeyCode::Left => self.move_cursor(self.cursor.saturating_sub(1), shift),
+            VirtualKeyCode::Right => self.move_cursor(self.cursor + 1, shift),
+            VirtualKeyCode::Home => self.move_cursor(0, shift),
+            VirtualKeyCode::End => self.move_cursor(self.chars.len(), shift),
+            VirtualKeyCode::Back => {
+                if self.delete_selection() {
+                    self.changed(events);
+                } else if self.cursor > 0 {
+                    self.cursor -= 1;
+                    self.chars.remove(self.cursor);
+                    self.scroll_into_view();
+                    self.changed(events);
+                }
+            }
+            VirtualKeyCode::Delete => {
+                if self.delete_selection() {
+                    self.changed(events);
+                } else if self.cursor < self.chars.len() {
+                    self.chars.remove(self.cursor);
+                    self.changed(events);
+                }
+            }
+            VirtualKeyCode::Return | VirtualKeyCode::NumpadEnter => {
+                events.push(UiEvent::TextSubmitted {
+                    id: self.id.clone(),
+                    text: self.text(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn click(&mut self, pos: Point, _events: &mut Vec<UiEvent>) {
+        self.move_cursor((pos.x - self.bounds.x1) as usize + self.scroll, false);
+    }
+
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, focused: bool) {
+        let width = self.bounds.width().max(1) as usize;
+        let scroll = self.scroll;
+        let selection = self.selection();
+        let caret_on = focused && (self.blink_timer_ms / BLINK_MS) as i32 % 2 == 0;
+        let base = if focused { theme.focused } else { theme.text };
+        for column in 0..width {
+            let index = scroll + column;
+            let glyph = self.chars.get(index).copied().unwrap_or(' ');
+            let selected = selection.is_some_and(|(s, e)| index >= s && index < e);
+            let caret_here = focused && index == self.cursor && caret_on;
+            let color = if caret_here || selected {
+                theme.selected
+            } else {
+                base
+            };
+            batch.print_color(
+                Point::new(self.bounds.x1 + column as i32, self.bounds.y1),
+                glyph.to_string(),
+                color,
+            );
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typing_and_backspace_edit_the_text() {
+        let mut events = Vec::new();
+        let mut input = TextInput::new("name", Point::new(0, 0), 10).with_max_length(3);
+        input.character('a', &mut events);
+        input.character('b', &mut events);
+        input.character('c', &mut events);
+        input.character('d', &mut events); // over max length, dropped
+        assert_eq!(input.text(), "abc");
+        input.key(VirtualKeyCode::Back, &mut events);
+        assert_eq!(input.text(), "ab");
+        assert_eq!(events.len(), 4);
+    }
+
+    #[test]
+    fn selection_is_replaced_by_typing() {
+        let mut events = Vec::new();
+        let mut input = TextInput::new("name", Point::new(0, 0), 10);
+        input.set_text("hello");
+        input.move_cursor(1, false);
+        input.move_cursor(4, true);
+        assert_eq!(input.selection(), Some((1, 4)));
+        input.character('u', &mut events);
+        assert_eq!(input.text(), "huo");
+        assert_eq!(input.cursor, 2);
+    }
+}
@@ -0,0 +1,33 @@
+use bracket_color::prelude::{ColorPair, RGBA};
+
+/// Colors shared by every widget in a [`Ui`](super::Ui). Swap the theme on the `Ui`
+/// to restyle the whole interface at once.
+#[derive(Clone, Debug)]
+pub struct UiTheme {
+    /// Ordinary widget text.
+    pub text: ColorPair,
+    /// Text of the widget holding keyboard focus.
+    pub focused: ColorPair,
+    /// The selected entry of a list or radio group.
+    pub selected: ColorPair,
+    /// Box borders and dialog frames.
+    pub border: ColorPair,
+    /// Dialog titles.
+    pub title: ColorPair,
+}
+
+impl Default for UiTheme {
+    fn default() -> Self {
+        let white = RGBA::from_f32(1.0, 1.0, 1.0, 1.0);
+        let grey = RGBA::from_f32(0.7, 0.7, 0.7, 1.0);
+        let black = RGBA::from_f32(0.0, 0.0, 0.0, 1.0);
+        let yellow = RGBA::from_f32(1.0, 1.0, 0.0, 1.0);
+        Self {
+            text: ColorPair::new(grey, black),
+            focused: ColorPair::new(yellow, black),
+            selected: ColorPair::new(black, white),
+            border: ColorPair::new(grey, black),
+            title: ColorPair::new(white, black),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use super::{UiEvent, UiTheme};
+use crate::prelude::{DrawBatch, VirtualKeyCode};
+use bracket_geometry::prelude::{Point, Rect};
+use std::any::Any;
+
+/// One retained-mode widget. Widgets live inside a [`Ui`](super::Ui), which routes
+/// keyboard input to the focused widget, mouse clicks to the widget under the
+/// cursor, and renders everything each frame. Widgets report what happened by
+/// pushing [`UiEvent`]s, which the game polls off the `Ui` each tick.
+pub trait Widget {
+    /// The identifier reported in this widget's events.
+    fn id(&self) -> &str;
+
+    /// The screen region (in console cells) the widget occupies.
+    fn bounds(&self) -> Rect;
+
+    /// Whether Tab stops on this widget. Defaults to true.
+    fn focusable(&self) -> bool {
+        true
+    }
+
+    /// Whether the widget is currently grabbing all input (an open modal dialog).
+    fn is_modal_open(&self) -> bool {
+        false
+    }
+
+    /// Advances time-dependent state (caret blinking and the like). Defaults to
+    /// doing nothing.
+    fn tick(&mut self, _frame_time_ms: f32) {}
+
+    /// Handles a key press routed to this widget.
+    fn key(&mut self, key: VirtualKeyCode, events: &mut Vec<UiEvent>);
+
+    /// Handles a typed character routed to this widget. Defaults to ignoring it;
+    /// text-entry widgets override this.
+    fn character(&mut self, _c: char, _events: &mut Vec<UiEvent>) {}
+
+    /// Handles a mouse click at `pos` (already verified to be inside `bounds`).
+    fn click(&mut self, pos: Point, events: &mut Vec<UiEvent>);
+
+    /// Draws the widget.
+    fn render(&self, batch: &mut DrawBatch, theme: &UiTheme, focused: bool);
+
+    /// Access to the concrete widget type, for games that need to mutate widget
+    /// state after construction (e.g. replace a list's items).
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}